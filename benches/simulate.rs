@@ -0,0 +1,53 @@
+//! Benchmarks the solver strategies of `src/simulation.rs`:
+//!
+//! ```bash
+//! cargo bench --bench simulate
+//! ```
+
+// The benches are built with cfg(test) but without the tests of the modules
+#[allow(dead_code, unused_imports)]
+#[path = "../src/engine.rs"]
+mod engine;
+#[allow(dead_code, unused_imports)]
+#[path = "../src/simulation.rs"]
+mod simulation;
+
+use criterion::{criterion_group, criterion_main, Criterion};
+
+use simulation::{FirstCandidate, RandomCandidate, Simulator, Strategy, MAX_GUESSES};
+
+const GAMES: usize = 100;
+
+/// Every four letter word of a handful of letters, the word lists aren't in the repository
+fn words() -> Vec<Vec<char>> {
+    let letters = ['A', 'E', 'I', 'K', 'L', 'S', 'T'];
+    let mut words = vec![Vec::new()];
+    for _ in 0..4 {
+        words = words
+            .into_iter()
+            .flat_map(|word: Vec<char>| {
+                letters.iter().map(move |letter| {
+                    let mut word = word.clone();
+                    word.push(*letter);
+                    word
+                })
+            })
+            .collect();
+    }
+    words
+}
+
+fn strategies(c: &mut Criterion) {
+    let simulator = Simulator::new(words(), MAX_GUESSES);
+    let strategies: Vec<Box<dyn Strategy>> =
+        vec![Box::new(FirstCandidate), Box::new(RandomCandidate::new(1))];
+
+    for mut strategy in strategies {
+        c.bench_function(strategy.name(), |b| {
+            b.iter(|| simulator.benchmark(strategy.as_mut(), GAMES))
+        });
+    }
+}
+
+criterion_group!(benches, strategies);
+criterion_main!(benches);
@@ -0,0 +1,259 @@
+//! Validates the word lists and generates them as Rust source, so that a broken list fails the
+//! build instead of panicking in the players' browsers. The words of each list are sorted,
+//! partitioned by their length and front coded to shrink the WASM bundle, see
+//! `parse_all_words()` in `src/manager.rs` for their use. The full lists are only validated,
+//! they are deployed next to index.html and fetched after the first paint. The daily words are
+//! encrypted by their dates, so that the coming words can't be read off the binary.
+
+use std::collections::{BTreeMap, HashSet};
+use std::env;
+use std::fmt::Write as _;
+use std::fs;
+use std::path::Path;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+// The list hash is only used by the app
+#[allow(dead_code)]
+#[path = "src/cipher.rs"]
+mod cipher;
+
+use cipher::DAILY_EPOCH_DAYS;
+
+// Same as `DAILY_WORD_LEN` of src/manager.rs, the daily words are all of this length
+const DAILY_WORD_LEN: usize = 5;
+// The daily lists have to reach this many days past the build
+const DAILY_MARGIN_DAYS: u64 = 30;
+// At most this many problems are listed per file
+const MAX_REPORTED: usize = 20;
+
+struct Language {
+    name: &'static str,
+    alphabet: &'static str,
+    full: &'static str,
+    common: &'static str,
+    easy: &'static str,
+    profanities: Option<&'static str>,
+    daily: &'static str,
+}
+
+// Same letters as the keyboards of `Language::keyboard_rows()`
+const FINNISH: Language = Language {
+    name: "finnish",
+    alphabet: "QWERTYUIOPASDFGHJKLÖÄZXCVBNM",
+    full: "full-words.txt",
+    common: "common-words.txt",
+    easy: "easy-words.txt",
+    profanities: Some("profanities.txt"),
+    daily: "daily-words.txt",
+};
+
+const SWEDISH: Language = Language {
+    name: "swedish",
+    alphabet: "QWERTYUIOPÅASDFGHJKLÖÄZXCVBNM",
+    full: "sv-full-words.txt",
+    common: "sv-common-words.txt",
+    easy: "sv-common-words.txt",
+    profanities: None,
+    daily: "sv-daily-words.txt",
+};
+
+const ENGLISH: Language = Language {
+    name: "english",
+    alphabet: "QWERTYUIOPASDFGHJKLZXCVBNM",
+    full: "en-full-words.txt",
+    common: "en-common-words.txt",
+    easy: "en-common-words.txt",
+    profanities: None,
+    daily: "en-daily-words.txt",
+};
+
+fn main() {
+    let mut languages = vec![FINNISH];
+    if env::var_os("CARGO_FEATURE_SWEDISH").is_some() {
+        languages.push(SWEDISH);
+    }
+    if env::var_os("CARGO_FEATURE_ENGLISH").is_some() {
+        languages.push(ENGLISH);
+    }
+
+    let out_dir = env::var("OUT_DIR").expect("OUT_DIR is set by cargo");
+    // Only the releases need the daily words of the coming days, the development builds are
+    // often made with partial lists
+    let is_release = env::var("PROFILE").is_ok_and(|profile| profile == "release");
+
+    let mut errors = Vec::new();
+    for language in &languages {
+        let source = generate(language, is_release, &mut errors);
+        let path = Path::new(&out_dir).join(format!("{}-words.rs", language.name));
+        fs::write(&path, source).expect("Unable to write the generated word lists");
+    }
+
+    if !errors.is_empty() {
+        panic!("Invalid word lists:\n{}", errors.join("\n"));
+    }
+}
+
+fn generate(language: &Language, is_release: bool, errors: &mut Vec<String>) -> String {
+    let mut source = String::from("// Generated by build.rs from the word list files\n");
+
+    read_words(language.full, language.alphabet, None, errors);
+
+    let mut lists = vec![("COMMON", language.common), ("EASY", language.easy)];
+    if let Some(profanities) = language.profanities {
+        lists.push(("PROFANITIES", profanities));
+    }
+
+    for (name, filename) in lists {
+        let words = read_words(filename, language.alphabet, None, errors);
+        let _ = writeln!(
+            source,
+            "pub const {}: Partitions = &{:?};",
+            name,
+            partition(words)
+        );
+    }
+    if language.profanities.is_none() {
+        source.push_str("pub const PROFANITIES: Partitions = &[];\n");
+    }
+
+    let daily = read_words(
+        language.daily,
+        language.alphabet,
+        Some(DAILY_WORD_LEN),
+        errors,
+    );
+    check_daily_length(language.daily, daily.len(), is_release, errors);
+    let daily = daily
+        .iter()
+        .enumerate()
+        .map(|(index, word)| cipher::apply_daily_key(index, word.as_bytes()))
+        .collect::<Vec<_>>();
+    source.push_str("pub const DAILY: &[&[u8]] = &[");
+    for word in &daily {
+        source.push_str("b\"");
+        for byte in word {
+            let _ = write!(source, "\\x{:02x}", byte);
+        }
+        source.push_str("\",");
+    }
+    source.push_str("];\n");
+
+    source
+}
+
+fn read_words(
+    filename: &str,
+    alphabet: &str,
+    length: Option<usize>,
+    errors: &mut Vec<String>,
+) -> Vec<String> {
+    println!("cargo:rerun-if-changed={}", filename);
+
+    let data = match fs::read_to_string(filename) {
+        Ok(data) => data,
+        Err(error) => {
+            errors.push(format!(
+                "{}: {}, see the word lists in README.md",
+                filename, error
+            ));
+            return Vec::new();
+        }
+    };
+
+    let mut problems = Vec::new();
+    let mut seen = HashSet::new();
+    for (index, word) in data.lines().enumerate() {
+        let line = index + 1;
+        if word.is_empty() {
+            problems.push(format!("{}:{}: empty line", filename, line));
+        } else if let Some(character) = word
+            .chars()
+            .find(|character| !alphabet.contains(*character))
+        {
+            problems.push(format!(
+                "{}:{}: {:?} has the character {:?}",
+                filename, line, word, character
+            ));
+        } else if let Some(length) = length.filter(|length| word.chars().count() != *length) {
+            problems.push(format!(
+                "{}:{}: {:?} isn't {} letters long",
+                filename, line, word, length
+            ));
+        } else if !seen.insert(word) {
+            problems.push(format!("{}:{}: {:?} is a duplicate", filename, line, word));
+        }
+    }
+
+    if problems.len() > MAX_REPORTED {
+        let more = problems.len() - MAX_REPORTED;
+        problems.truncate(MAX_REPORTED);
+        problems.push(format!("{}: {} more problems", filename, more));
+    }
+    errors.extend(problems);
+
+    data.lines().map(str::to_owned).collect()
+}
+
+/// The words as sorted lists by their length, each list front coded into a single string.
+/// A string per word would cost a pointer and a length for each of them in the binary.
+fn partition(words: Vec<String>) -> Vec<(usize, String)> {
+    let mut partitions: BTreeMap<usize, Vec<String>> = BTreeMap::new();
+    for word in words {
+        partitions
+            .entry(word.chars().count())
+            .or_default()
+            .push(word);
+    }
+
+    partitions
+        .into_iter()
+        .map(|(length, mut words)| {
+            words.sort();
+            (length, front_code(&words))
+        })
+        .collect()
+}
+
+/// Every word as the number of letters it shares with the previous word, as a base 36 digit,
+/// followed by the rest of its letters. The sorted words of a length share two or three letters
+/// on average, and no separators are needed as the lengths are known.
+fn front_code(words: &[String]) -> String {
+    let mut encoded = String::new();
+    let mut previous: Vec<char> = Vec::new();
+
+    for word in words {
+        let word = word.chars().collect::<Vec<_>>();
+        let shared = previous
+            .iter()
+            .zip(&word)
+            .take_while(|(previous, current)| previous == current)
+            .count();
+
+        encoded
+            .push(std::char::from_digit(shared as u32, 36).expect("Word too long to front code"));
+        encoded.extend(&word[shared..]);
+        previous = word;
+    }
+
+    encoded
+}
+
+fn check_daily_length(filename: &str, length: usize, is_release: bool, errors: &mut Vec<String>) {
+    let today = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |duration| duration.as_secs() / 86_400);
+    let required = today.saturating_sub(DAILY_EPOCH_DAYS) + DAILY_MARGIN_DAYS + 1;
+
+    if (length as u64) < required {
+        let message = format!(
+            "{}: {} daily words, {} are needed to reach {} days past today",
+            filename, length, required, DAILY_MARGIN_DAYS
+        );
+
+        if is_release {
+            errors.push(message);
+        } else {
+            println!("cargo:warning={}", message);
+        }
+    }
+}
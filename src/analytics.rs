@@ -0,0 +1,108 @@
+use std::cell::Cell;
+use std::rc::Rc;
+
+use serde::Serialize;
+use web_sys::window;
+
+use crate::events::{GameEvent, Subscriber};
+
+// Collector of the anonymous usage events. Left unset, the analytics setting stays hidden:
+// `SANULI_ANALYTICS_URL=https://collect.example.com/sanuli trunk build`
+const ANALYTICS_URL: Option<&str> = option_env!("SANULI_ANALYTICS_URL");
+// Events collected before they are sent, unless a game ends first
+const BATCH_SIZE: usize = 20;
+
+/// The only data sent, without any identifiers, timestamps or words
+#[derive(Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+enum AnalyticsEvent {
+    GameStarted {
+        mode: &'static str,
+        word_length: usize,
+    },
+    GameFinished {
+        mode: &'static str,
+        word_length: usize,
+        guesses: usize,
+        is_winner: bool,
+    },
+}
+
+#[derive(Serialize)]
+struct Batch<'a> {
+    events: &'a [AnalyticsEvent],
+}
+
+pub fn is_configured() -> bool {
+    ANALYTICS_URL.is_some()
+}
+
+/// Subscriber sending the events of the game in batches, while the player has opted in
+pub struct Analytics {
+    is_enabled: Rc<Cell<bool>>,
+    queue: Vec<AnalyticsEvent>,
+}
+
+impl Analytics {
+    pub fn new(is_enabled: Rc<Cell<bool>>) -> Self {
+        Self {
+            is_enabled,
+            queue: Vec::new(),
+        }
+    }
+
+    /// Sends the queued events. They are dropped when offline or if sending fails.
+    fn flush(&mut self) {
+        let events = std::mem::take(&mut self.queue);
+        let (url, navigator) = match (ANALYTICS_URL, window()) {
+            (Some(url), Some(window)) => (url, window.navigator()),
+            _ => return,
+        };
+
+        if events.is_empty() || !navigator.on_line() {
+            return;
+        }
+
+        if let Ok(body) = serde_json::to_string(&Batch { events: &events }) {
+            // Beacons are delivered even if the page is closed right after the game
+            let _res = navigator.send_beacon_with_opt_str(url, Some(&body));
+        }
+    }
+}
+
+impl Subscriber for Analytics {
+    fn on_event(&mut self, event: &GameEvent) {
+        if !self.is_enabled.get() || !is_configured() {
+            self.queue.clear();
+            return;
+        }
+
+        let is_finished = matches!(event, GameEvent::GameFinished { .. });
+        match event {
+            GameEvent::GameStarted { mode, word_length } => {
+                self.queue.push(AnalyticsEvent::GameStarted {
+                    mode: mode.name(),
+                    word_length: *word_length,
+                });
+            }
+            GameEvent::GameFinished {
+                mode,
+                word_length,
+                is_winner,
+                guesses,
+            } => {
+                self.queue.push(AnalyticsEvent::GameFinished {
+                    mode: mode.name(),
+                    word_length: *word_length,
+                    guesses: *guesses,
+                    is_winner: *is_winner,
+                });
+            }
+            GameEvent::GuessRevealed { .. } | GameEvent::GameSwitched { .. } => return,
+        }
+
+        if is_finished || self.queue.len() >= BATCH_SIZE {
+            self.flush();
+        }
+    }
+}
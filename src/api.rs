@@ -0,0 +1,136 @@
+use std::fmt;
+
+use serde::{de::DeserializeOwned, Serialize};
+use wasm_bindgen::{JsCast, JsValue};
+use wasm_bindgen_futures::JsFuture;
+use web_sys::{window, Request, RequestInit, Response};
+
+// Backend for the online features. Left unset, ie. in local builds, the
+// features relying on it stay hidden:
+// `SANULI_API_URL=https://api.example.com trunk build --features voting`
+const API_URL: Option<&str> = option_env!("SANULI_API_URL");
+
+#[derive(Clone, Debug)]
+pub enum ApiError {
+    NotConfigured,
+    Network,
+    Status(u16),
+    Parse,
+}
+
+impl fmt::Display for ApiError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ApiError::NotConfigured => write!(f, "no backend configured"),
+            ApiError::Network => write!(f, "request failed"),
+            ApiError::Status(status) => write!(f, "unexpected status {}", status),
+            ApiError::Parse => write!(f, "invalid response"),
+        }
+    }
+}
+
+impl From<JsValue> for ApiError {
+    fn from(_: JsValue) -> Self {
+        ApiError::Network
+    }
+}
+
+impl From<serde_json::Error> for ApiError {
+    fn from(_: serde_json::Error) -> Self {
+        ApiError::Parse
+    }
+}
+
+pub fn is_configured() -> bool {
+    API_URL.is_some()
+}
+
+/// The address of the path on the backend
+pub fn url(path: &str) -> Result<String, ApiError> {
+    let base = API_URL.ok_or(ApiError::NotConfigured)?;
+    Ok(format!(
+        "{}/{}",
+        base.trim_end_matches('/'),
+        path.trim_start_matches('/')
+    ))
+}
+
+/// A request to the backend or to any other endpoint answering with JSON
+#[derive(Clone)]
+pub struct HttpRequest {
+    pub method: &'static str,
+    pub url: String,
+    // JSON
+    pub body: Option<String>,
+}
+
+impl HttpRequest {
+    pub fn get(url: String) -> Self {
+        Self {
+            method: "GET",
+            url,
+            body: None,
+        }
+    }
+
+    pub fn with_body<B: Serialize>(
+        method: &'static str,
+        url: String,
+        body: &B,
+    ) -> Result<Self, ApiError> {
+        Ok(Self {
+            method,
+            url,
+            body: Some(serde_json::to_string(body)?),
+        })
+    }
+}
+
+/// Sends the request, returning the body of the response
+pub async fn fetch(request: &HttpRequest) -> Result<String, ApiError> {
+    let window = window().ok_or(ApiError::Network)?;
+
+    let init = RequestInit::new();
+    init.set_method(request.method);
+    if let Some(body) = &request.body {
+        init.set_body(&JsValue::from_str(body));
+    }
+
+    let fetch_request = Request::new_with_str_and_init(&request.url, &init)?;
+    if request.body.is_some() {
+        fetch_request
+            .headers()
+            .set("Content-Type", "application/json")?;
+    }
+    fetch_request.headers().set("Accept", "application/json")?;
+
+    let response: Response = JsFuture::from(window.fetch_with_request(&fetch_request))
+        .await?
+        .dyn_into()?;
+    if !response.ok() {
+        return Err(ApiError::Status(response.status()));
+    }
+
+    JsFuture::from(response.text()?)
+        .await?
+        .as_string()
+        .ok_or(ApiError::Parse)
+}
+
+pub async fn get<T: DeserializeOwned>(path: &str) -> Result<T, ApiError> {
+    let body = fetch(&HttpRequest::get(url(path)?)).await?;
+
+    Ok(serde_json::from_str(&body)?)
+}
+
+/// Posts the body without reading the response, which is often empty for a stored submission
+pub async fn submit<B: Serialize>(path: &str, body: &B) -> Result<(), ApiError> {
+    fetch(&HttpRequest::with_body("POST", url(path)?, body)?)
+        .await
+        .map(|_| ())
+}
+
+/// Percent-encodes a value interpolated into a path, eg. an id from a feed
+pub fn path_segment(value: &str) -> String {
+    String::from(js_sys::encode_uri_component(value))
+}
@@ -0,0 +1,59 @@
+//! Plays games without the browser to benchmark solver strategies and the word lists:
+//!
+//! ```bash
+//! cargo run --release --bin simulate common-words.txt 5 1000
+//! ```
+//!
+//! plays 1000 words of length 5 picked from the list with every strategy, guessing only words
+//! of the same list.
+
+// The scoring of the app is left out of the simulations
+#[allow(dead_code)]
+#[path = "../engine.rs"]
+mod engine;
+#[path = "../simulation.rs"]
+mod simulation;
+
+use std::fs;
+
+use simulation::{FirstCandidate, RandomCandidate, Simulator, Strategy, MAX_GUESSES};
+
+// The strategies guess the same words on every run
+const STRATEGY_SEED: u64 = 1;
+
+fn main() {
+    let mut args = std::env::args().skip(1);
+    let filename = args.next().expect("No path to word list file given");
+    let word_length = args
+        .next()
+        .map_or(5, |arg| arg.parse().expect("Invalid word length"));
+    let games = args
+        .next()
+        .map_or(1000, |arg| arg.parse().expect("Invalid number of games"));
+
+    let data = fs::read_to_string(filename).expect("Unable to read word list file");
+    let words = data
+        .lines()
+        .map(|word| word.trim().chars().collect::<Vec<_>>())
+        .filter(|word| word.len() == word_length)
+        .collect::<Vec<_>>();
+
+    let simulator = Simulator::new(words, MAX_GUESSES);
+    let strategies: Vec<Box<dyn Strategy>> = vec![
+        Box::new(FirstCandidate),
+        Box::new(RandomCandidate::new(STRATEGY_SEED)),
+    ];
+
+    for mut strategy in strategies {
+        let summary = simulator.benchmark(strategy.as_mut(), games);
+
+        println!(
+            "{}: {} / {} solved, {:.2} guesses on average, distribution {:?}",
+            strategy.name(),
+            summary.solved(),
+            summary.played(),
+            summary.average_guesses(),
+            summary.distribution
+        );
+    }
+}
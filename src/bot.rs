@@ -0,0 +1,101 @@
+//! Opponent of the single player race, guessing the same word on a board of its own. The bot
+//! only ever shows the colors of its rows, like the opponent of an online race.
+
+use gloo_timers::callback::Interval;
+use yew::Callback;
+
+use crate::game;
+use crate::manager::{TileState, Word};
+use crate::race::RaceStatus;
+use crate::rng::GameRng;
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum BotStrength {
+    // Remembers only the colors of its latest row
+    Easy,
+    // Picks any word fitting the colors so far
+    Normal,
+    // Picks the word ruling out the most of the others
+    Hard,
+}
+
+impl BotStrength {
+    /// Time the bot thinks over a guess
+    fn interval_ms(&self) -> u32 {
+        match self {
+            BotStrength::Easy => 20_000,
+            BotStrength::Normal => 15_000,
+            BotStrength::Hard => 10_000,
+        }
+    }
+}
+
+pub struct Bot {
+    pub strength: BotStrength,
+    pub status: RaceStatus,
+    pub patterns: Vec<Vec<TileState>>,
+    word: Word,
+    // Sorted, the candidates are picked from with the seeded generator
+    words: Vec<Word>,
+    candidates: Vec<Word>,
+    max_guesses: usize,
+    // Dropped once the bot has solved the word or run out of guesses
+    interval: Option<Interval>,
+}
+
+impl Bot {
+    /// Starts guessing the word, `ontick` is called whenever the next guess is due
+    pub fn start(
+        strength: BotStrength,
+        word: Word,
+        mut words: Vec<Word>,
+        max_guesses: usize,
+        ontick: Callback<()>,
+    ) -> Self {
+        words.sort();
+        let interval = Interval::new(strength.interval_ms(), move || ontick.emit(()));
+
+        Self {
+            strength,
+            status: RaceStatus::Racing,
+            patterns: Vec::new(),
+            word,
+            candidates: words.clone(),
+            words,
+            max_guesses,
+            interval: Some(interval),
+        }
+    }
+
+    /// Makes the next guess, returning whether it solved the word
+    pub fn step(&mut self, rng: &GameRng) -> bool {
+        if self.status != RaceStatus::Racing || self.patterns.len() >= self.max_guesses {
+            return false;
+        }
+
+        let guess = match self.strength {
+            BotStrength::Hard => game::solver_guess(&self.candidates),
+            BotStrength::Easy | BotStrength::Normal => rng.choose(&self.candidates).cloned(),
+        }
+        .unwrap_or_else(|| self.word.clone());
+
+        let pattern = game::score_guess(&guess, &self.word);
+        if self.strength == BotStrength::Easy {
+            self.candidates = self.words.clone();
+        }
+        self.candidates
+            .retain(|word| *word != guess && game::matches_pattern(&guess, word, &pattern));
+        self.patterns.push(pattern);
+
+        let is_solved = guess == self.word;
+        if is_solved || self.patterns.len() == self.max_guesses {
+            self.stop();
+        }
+        is_solved
+    }
+
+    /// Ends the race for the bot, the board stays as it was
+    pub fn stop(&mut self) {
+        self.interval = None;
+    }
+}
@@ -0,0 +1,55 @@
+//! Attribution used in shared results. Forks can set these at build time, ie.
+//! `SANULI_BRAND_NAME=Sanaseppo SANULI_BRAND_URL=https://example.com trunk build`
+
+use crate::i18n::tf;
+
+const BRAND_NAME: Option<&str> = option_env!("SANULI_BRAND_NAME");
+const BRAND_URL: Option<&str> = option_env!("SANULI_BRAND_URL");
+
+pub const BRAND: BrandConfig = BrandConfig {
+    name: match BRAND_NAME {
+        Some(name) => name,
+        None => "Sanuli",
+    },
+    url: match BRAND_URL {
+        Some(url) => url,
+        None => "https://sanuli.fi",
+    },
+};
+
+pub struct BrandConfig {
+    pub name: &'static str,
+    pub url: &'static str,
+}
+
+impl BrandConfig {
+    /// The url without the protocol, short enough to fit on a share image
+    pub fn display_url(&self) -> &'static str {
+        self.url
+            .trim_start_matches("https://")
+            .trim_start_matches("http://")
+            .trim_end_matches('/')
+    }
+
+    /// Footer lines rendered at the bottom of a share image
+    pub fn footer_lines(
+        &self,
+        daily_index: Option<usize>,
+        profile_name: Option<&str>,
+    ) -> Vec<String> {
+        let mut lines = Vec::with_capacity(3);
+
+        match daily_index {
+            Some(index) => lines.push(tf("Päivän {} #{}", &[&self.name.to_lowercase(), &index])),
+            None => lines.push(self.name.to_owned()),
+        }
+
+        if let Some(name) = profile_name.filter(|name| !name.is_empty()) {
+            lines.push(tf("Pelaaja: {}", &[&name]));
+        }
+
+        lines.push(self.display_url().to_owned());
+
+        lines
+    }
+}
@@ -0,0 +1,149 @@
+use chrono::Utc;
+use serde_json::{json, Map, Value};
+use web_sys::window;
+
+use crate::game::Game;
+use crate::manager::Manager;
+use crate::storage;
+use crate::version;
+
+/// Snapshot of the whole app for a bug report, as pretty printed JSON. The words to guess are
+/// replaced with their hashes everywhere, so that attaching a report never spoils a puzzle.
+pub fn snapshot(manager: &Manager) -> String {
+    let user_agent = window()
+        .and_then(|window| window.navigator().user_agent().ok())
+        .unwrap_or_default();
+
+    let mut report = json!({
+        "version": version::current(),
+        "user_agent": user_agent,
+        "created_at": Utc::now().to_rfc3339(),
+        "game": manager.game.as_ref().map(|game| game_snapshot(game.as_ref())),
+        "settings": serde_json::to_value(manager).unwrap_or(Value::Null),
+        "storage": storage_snapshot(),
+    });
+    redact_words(&mut report);
+
+    serde_json::to_string_pretty(&report).unwrap_or_default()
+}
+
+fn game_snapshot(game: &dyn Game) -> Value {
+    let boards = game
+        .boards()
+        .iter()
+        .map(|board| {
+            json!({
+                "guesses": board.guesses,
+                "current_guess": board.current_guess,
+                "is_guessing": board.is_guessing,
+            })
+        })
+        .collect::<Vec<_>>();
+
+    // The positions of the known states are written out, JSON only has string keys
+    let knowledge = game
+        .knowledge()
+        .iter()
+        .map(|knowledge| {
+            let states = knowledge
+                .known_states()
+                .iter()
+                .map(|((character, index), state)| {
+                    (format!("{}{}", character, index), json!(state))
+                })
+                .collect::<Map<_, _>>();
+            let counts = knowledge
+                .known_counts()
+                .iter()
+                .map(|(character, count)| (character.to_string(), json!(count)))
+                .collect::<Map<_, _>>();
+
+            json!({ "states": states, "counts": counts })
+        })
+        .collect::<Vec<_>>();
+
+    json!({
+        "mode": game.game_mode(),
+        "word_list": game.word_list(),
+        "word_length": game.word_length(),
+        "max_guesses": game.max_guesses(),
+        "word": game.word(),
+        "boards": boards,
+        "knowledge": knowledge,
+        "message": game.message(),
+        "is_winner": game.is_winner(),
+        "is_unknown": game.is_unknown(),
+        "is_reset": game.is_reset(),
+    })
+}
+
+// The other profiles have nothing to do with the reported game
+fn storage_snapshot() -> Map<String, Value> {
+    storage::keys()
+        .into_iter()
+        .filter_map(|key| {
+            let value: Value = storage::get(&key).ok()?;
+            Some((key, value))
+        })
+        .collect()
+}
+
+fn redact_words(value: &mut Value) {
+    match value {
+        Value::Object(object) => {
+            for (key, value) in object.iter_mut() {
+                match (key.as_str(), value) {
+                    ("word", value) => redact(value),
+                    // Both words of a double daily
+                    ("words", Value::Array(words)) => words.iter_mut().for_each(redact),
+                    ("words", value) => redact(value),
+                    (_, value) => redact_words(value),
+                }
+            }
+        }
+        Value::Array(values) => values.iter_mut().for_each(redact_words),
+        _ => {}
+    }
+}
+
+fn redact(word: &mut Value) {
+    *word = Value::String(format!("redacted:{:016x}", hash(&word.to_string())));
+}
+
+/// FNV-1a, enough to tell whether two reports have the same word
+fn hash(text: &str) -> u64 {
+    text.bytes().fold(0xcbf29ce484222325, |hash, byte| {
+        (hash ^ u64::from(byte)).wrapping_mul(0x100000001b3)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn words_are_redacted_at_any_depth() {
+        let mut report = json!({
+            "game": { "word": ["K", "I", "S", "S", "A"], "guesses": [["K", "A", "L", "A", "T"]] },
+            "storage": {
+                "double_daily_history": [{ "words": [["K", "I", "S", "S", "A"], ["K", "A", "L", "A", "T"]] }],
+            },
+        });
+        redact_words(&mut report);
+
+        let redacted = report["game"]["word"].as_str().unwrap();
+        assert!(redacted.starts_with("redacted:"));
+        assert_eq!(
+            report["game"]["guesses"],
+            json!([["K", "A", "L", "A", "T"]])
+        );
+        assert_eq!(
+            report["storage"]["double_daily_history"][0]["words"][0],
+            redacted
+        );
+        assert_ne!(
+            report["storage"]["double_daily_history"][0]["words"][1],
+            redacted
+        );
+    }
+}
@@ -0,0 +1,81 @@
+//! The keystream encrypting the daily words by their dates and the FNV-1a hash of the word
+//! lists. Free of dependencies, shared by the app and build.rs, which includes this file with
+//! `#[path = "src/cipher.rs"] mod cipher;`.
+
+// Days from 1970-01-01 to 2022-01-07, the date of the first daily word
+pub const DAILY_EPOCH_DAYS: u64 = 18_999;
+// Mixed into the keys of the daily words
+const DAILY_KEY: u64 = 0x5341_4e55_4c49_0001;
+
+/// The key of a daily word is derived from its date as the days since 1970. Anyone reading the
+/// source can derive the keys too, the encryption only keeps the coming words from being spoiled
+/// by a glance at the binary.
+fn daily_keystream(day: u64) -> impl Iterator<Item = u8> {
+    // SplitMix64, eight bytes of the key per step
+    let mut state = day ^ DAILY_KEY;
+    std::iter::repeat_with(move || {
+        state = state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    })
+    .flat_map(u64::to_le_bytes)
+}
+
+/// Encrypts or decrypts the daily word of the index with the key of its date, the keystream is
+/// XORed the same way both ways
+pub fn apply_daily_key(index: usize, bytes: &[u8]) -> Vec<u8> {
+    bytes
+        .iter()
+        .zip(daily_keystream(DAILY_EPOCH_DAYS + index as u64))
+        .map(|(byte, key)| byte ^ key)
+        .collect()
+}
+
+/// FNV-1a over the words, each followed by a newline
+pub fn list_hash<'a>(words: impl IntoIterator<Item = &'a [u8]>) -> u64 {
+    let mut hash: u64 = 0xcbf2_9ce4_8422_2325;
+    for byte in words
+        .into_iter()
+        .flat_map(|word| word.iter().copied().chain(Some(b'\n')))
+    {
+        hash ^= byte as u64;
+        hash = hash.wrapping_mul(0x0100_0000_01b3);
+    }
+    hash
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // 2022-01-08, the second daily word
+    const INDEX: usize = 1;
+
+    #[test]
+    fn daily_word_decrypts_with_the_key_of_its_date() {
+        let encrypted = apply_daily_key(INDEX, "KISSA".as_bytes());
+
+        assert_ne!(encrypted, b"KISSA");
+        assert_eq!(apply_daily_key(INDEX, &encrypted), b"KISSA");
+        assert_ne!(apply_daily_key(INDEX + 1, &encrypted), b"KISSA");
+    }
+
+    #[test]
+    fn daily_word_is_encrypted_the_same_on_every_build() {
+        assert_eq!(
+            apply_daily_key(INDEX, "KISSA".as_bytes()),
+            [0x7e, 0xf1, 0xeb, 0x9b, 0xc1]
+        );
+    }
+
+    #[test]
+    fn list_hash_is_fnv_1a_of_the_lines() {
+        assert_eq!(list_hash([]), 0xcbf2_9ce4_8422_2325);
+        assert_eq!(
+            list_hash(["KISSA".as_bytes(), "KALA".as_bytes()]),
+            0x4362_debd_0a9c_a717
+        );
+    }
+}
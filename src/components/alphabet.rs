@@ -0,0 +1,32 @@
+use yew::prelude::*;
+
+use crate::i18n::t;
+use crate::manager::TileState;
+
+#[derive(Properties, PartialEq)]
+pub struct AlphabetProps {
+    // Every letter of the puzzle language in the alphabetical order
+    pub letters: Vec<(char, TileState)>,
+}
+
+/// Compact strip of the whole alphabet colored by what is known of each letter, readable where
+/// the colors of the keyboard are cramped
+#[function_component(Alphabet)]
+pub fn alphabet(props: &AlphabetProps) -> Html {
+    html! {
+        <div class="alphabet" role="list" aria-label={t("Aakkoset")}>
+            {
+                props.letters.iter().map(|(letter, state)| {
+                    let label = format!("{} {}", letter, state.description());
+
+                    html! {
+                        <span class={classes!("alphabet-letter", state.to_string())} role="listitem"
+                            aria-label={label.trim_end().to_owned()}>
+                            {letter}
+                        </span>
+                    }
+                }).collect::<Html>()
+            }
+        </div>
+    }
+}
@@ -0,0 +1,115 @@
+use chrono::{DateTime, Utc};
+use serde::Deserialize;
+use yew::prelude::*;
+
+use crate::api::{self, ApiError};
+use crate::i18n::t;
+use crate::storage;
+
+const FEED_PATH: &str = "announcements";
+const DISMISSED_KEY: &str = "dismissed_announcements";
+
+/// News of the game, like word list changes or new game modes
+#[derive(Clone, PartialEq, Deserialize)]
+pub struct Announcement {
+    pub id: String,
+    pub message: String,
+    #[serde(default)]
+    pub url: Option<String>,
+    #[serde(default)]
+    pub expires_at: Option<DateTime<Utc>>,
+}
+
+impl Announcement {
+    fn is_expired(&self) -> bool {
+        matches!(self.expires_at, Some(expires_at) if Utc::now() >= expires_at)
+    }
+}
+
+pub enum Msg {
+    FeedLoaded(Result<Vec<Announcement>, ApiError>),
+    Dismiss(String),
+}
+
+pub struct AnnouncementBanner {
+    announcements: Vec<Announcement>,
+    dismissed: Vec<String>,
+}
+
+impl AnnouncementBanner {
+    fn current(&self) -> Option<&Announcement> {
+        self.announcements.iter().find(|announcement| {
+            !announcement.is_expired() && !self.dismissed.contains(&announcement.id)
+        })
+    }
+}
+
+impl Component for AnnouncementBanner {
+    type Message = Msg;
+    type Properties = ();
+
+    fn create(ctx: &Context<Self>) -> Self {
+        ctx.link()
+            .send_future(async { Msg::FeedLoaded(api::get(FEED_PATH).await) });
+
+        Self {
+            announcements: Vec::new(),
+            dismissed: storage::get(DISMISSED_KEY).unwrap_or_default(),
+        }
+    }
+
+    fn update(&mut self, _ctx: &Context<Self>, msg: Self::Message) -> bool {
+        match msg {
+            Msg::FeedLoaded(Ok(announcements)) => {
+                // Forget the dismissals of the announcements no longer in the feed
+                self.dismissed.retain(|id| {
+                    announcements
+                        .iter()
+                        .any(|announcement| &announcement.id == id)
+                });
+                let _res = storage::set(DISMISSED_KEY, &self.dismissed);
+                self.announcements = announcements;
+            }
+            Msg::FeedLoaded(Err(err)) => {
+                log::info!("Announcements not available: {}", err);
+                return false;
+            }
+            Msg::Dismiss(id) => {
+                self.dismissed.push(id);
+                let _res = storage::set(DISMISSED_KEY, &self.dismissed);
+            }
+        }
+
+        true
+    }
+
+    fn view(&self, ctx: &Context<Self>) -> Html {
+        let announcement = match self.current() {
+            Some(announcement) => announcement,
+            None => return html! {},
+        };
+
+        let id = announcement.id.clone();
+        let dismiss = ctx.link().callback(move |e: MouseEvent| {
+            e.prevent_default();
+            Msg::Dismiss(id.clone())
+        });
+
+        html! {
+            <div class="announcement" role="status">
+                <span>{&announcement.message}</span>
+                {
+                    if let Some(url) = &announcement.url {
+                        html! { <a class="link" href={url.clone()} target="_blank">{t("Lue lisää")}</a> }
+                    } else {
+                        html! {}
+                    }
+                }
+                <span class="announcement-close" role="button" tabindex="0" aria-label={t("Sulje ikkuna")}
+                    onmousedown={dismiss}>
+                    {"✖"}
+                </span>
+            </div>
+        }
+    }
+}
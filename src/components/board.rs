@@ -1,6 +1,14 @@
 use yew::prelude::*;
 
-use crate::manager::TileState;
+use crate::components::message::dictionary_url;
+use crate::i18n::{t, tf};
+use crate::manager::{AnimationSpeed, TileState};
+
+// Durations at the normal animation speed
+const SLIDE_DURATION_MS: u32 = 1000;
+// The board of six rows has the longest way to slide
+const LONG_SLIDE_DURATION_MS: u32 = 1200;
+pub const FLIP_DURATION_MS: u32 = 600;
 
 #[derive(Properties, PartialEq)]
 pub struct Props {
@@ -11,60 +19,82 @@ pub struct Props {
     pub guesses: Vec<Vec<(char, TileState)>>,
     pub previous_guesses: Vec<Vec<(char, TileState)>>,
     pub current_guess: usize,
+    pub cursor: usize,
     pub max_guesses: usize,
     pub word_length: usize,
+
+    // Selects the tile of the current row under the cursor
+    pub onselect: Callback<usize>,
+    // The rows of a blind game turn over together once it has ended
+    #[prop_or_default]
+    pub is_flipping: bool,
+    // The row revealed by the latest guess turns over
+    #[prop_or_default]
+    pub revealing_row: Option<usize>,
+    #[prop_or_default]
+    pub animation_speed: AnimationSpeed,
+    // The guessed words open in the dictionary once the game has ended
+    #[prop_or_default]
+    pub is_linked: bool,
+}
+
+fn slide_duration_ms(rows: usize) -> u32 {
+    if rows >= 6 {
+        LONG_SLIDE_DURATION_MS
+    } else {
+        SLIDE_DURATION_MS
+    }
 }
 
 #[function_component(Board)]
 pub fn board(props: &Props) -> Html {
+    // The previous board slides out of the way of the new one after a reset
+    let slide_duration_ms = props
+        .animation_speed
+        .duration_ms(slide_duration_ms(props.previous_guesses.len()))
+        .filter(|_| props.is_reset);
+    let flip_duration_ms = props.animation_speed.duration_ms(FLIP_DURATION_MS);
+
     html! {
         <>
             {
-                if !props.previous_guesses.is_empty() && props.is_reset {
-                    html! {
+                match slide_duration_ms {
+                    Some(duration_ms) if !props.previous_guesses.is_empty() => html! {
                         <PreviousBoard
                             guesses={props.previous_guesses.clone()}
                             max_guesses={props.max_guesses}
                             word_length={props.word_length}
+                            duration_ms={duration_ms}
                         />
-                    }
-                } else {
-                    html! {}
+                    },
+                    _ => html! {},
                 }
             }
             <div class={classes!(
-                props.is_reset.then(|| "slide-in"),
-                props.is_reset.then(|| format!("slide-in-{}", props.previous_guesses.len())),
-                format!("board-{}", props.max_guesses))}>{
+                slide_duration_ms.map(|_| "slide-in"),
+                slide_duration_ms.map(|_| format!("slide-in-{}", props.previous_guesses.len())),
+                format!("board-{}", props.max_guesses))}
+                style={slide_duration_ms.map(|duration_ms| format!("animation-duration: {}ms", duration_ms))}>{
                     props.guesses.iter().enumerate().map(|(row, guess)| {
                         let is_current_row = row == props.current_guess && props.is_guessing;
+                        let is_word = guess.len() == props.word_length
+                            && guess.iter().all(|(character, _)| *character != ' ');
+                        let link = (props.is_linked && !props.is_hidden && is_word)
+                            .then(|| guess.iter().map(|(character, _)| character).collect::<String>());
 
+                        // Only the current row takes the callback, so that the other rows keep
+                        // equal props and skip rendering while typing
                         html! {
-                            <div class={format!("row-{}", props.word_length)}>
-                                {
-                                    (0..props.word_length).map(|tile_index| {
-                                        let (character, tile_state) = guess
-                                            .get(tile_index)
-                                            .unwrap_or(&(' ', TileState::Unknown));
-
-                                        html! {
-                                            <div class={classes!(
-                                                "tile",
-                                                tile_state.to_string(),
-                                                is_current_row.then(|| Some("current"))
-                                            )}>
-                                                {
-                                                    if props.is_hidden {
-                                                        ' '
-                                                    } else {
-                                                        *character
-                                                    }
-                                                }
-                                            </div>
-                                        }
-                                    }).collect::<Html>()
-                                }
-                            </div>
+                            <BoardRow
+                                key={row}
+                                guess={guess.clone()}
+                                word_length={props.word_length}
+                                is_hidden={props.is_hidden}
+                                flip_duration_ms={flip_duration_ms.filter(|_| props.is_flipping || props.revealing_row == Some(row))}
+                                cursor={is_current_row.then_some(props.cursor)}
+                                onselect={is_current_row.then(|| props.onselect.clone())}
+                                link={link}
+                            />
                         }
                     }).collect::<Html>()
                 }
@@ -73,17 +103,129 @@ pub fn board(props: &Props) -> Html {
     }
 }
 
+#[derive(Properties, PartialEq)]
+struct BoardRowProps {
+    guess: Vec<(char, TileState)>,
+    word_length: usize,
+    is_hidden: bool,
+    // Set while the row turns over
+    flip_duration_ms: Option<u32>,
+    // Set on the current row only
+    cursor: Option<usize>,
+    onselect: Option<Callback<usize>>,
+    // The guessed word, set on the rows opening in the dictionary
+    link: Option<String>,
+}
+
+/// A row of the board, rendered again only when its own props change
+#[function_component(BoardRow)]
+fn board_row(props: &BoardRowProps) -> Html {
+    let is_current_row = props.onselect.is_some();
+
+    let tiles = html! {
+        <>
+            {
+                (0..props.word_length).map(|tile_index| {
+                    let (character, tile_state) = props.guess
+                        .get(tile_index)
+                        .unwrap_or(&(' ', TileState::Unknown));
+                    let is_cursor = props.cursor == Some(tile_index);
+
+                    let label = match (*character, props.is_hidden) {
+                        (' ', _) => t("tyhjä").to_owned(),
+                        (_, true) => tile_state.description().to_owned(),
+                        _ => format!("{} {}", character, tile_state.description()).trim_end().to_owned(),
+                    };
+
+                    let onselect = props.onselect.clone();
+                    let onmousedown = Callback::from(move |e: MouseEvent| {
+                        if let Some(onselect) = &onselect {
+                            e.prevent_default();
+                            onselect.emit(tile_index);
+                        }
+                    });
+
+                    html! {
+                        <div class={classes!(
+                            "tile",
+                            tile_state.to_string(),
+                            props.flip_duration_ms.map(|_| "flip"),
+                            is_current_row.then(|| Some("current")),
+                            is_cursor.then(|| Some("cursor"))
+                        )} style={props.flip_duration_ms.map(|duration_ms| format!("animation-duration: {}ms", duration_ms))}
+                            role="img" aria-label={label} onmousedown={onmousedown}>
+                            {
+                                if props.is_hidden {
+                                    ' '
+                                } else {
+                                    *character
+                                }
+                            }
+                        </div>
+                    }
+                }).collect::<Html>()
+            }
+        </>
+    };
+
+    match &props.link {
+        Some(word) => html! {
+            <a class={classes!(format!("row-{}", props.word_length), "row-link")} href={dictionary_url(word)}
+                target="_blank" rel="noopener" aria-label={tf("Avaa {} sanakirjassa", &[word])}>
+                {tiles}
+            </a>
+        },
+        None => html! {
+            <div class={format!("row-{}", props.word_length)}>{tiles}</div>
+        },
+    }
+}
+
+#[derive(Properties, PartialEq)]
+pub struct BoardThumbnailProps {
+    pub patterns: Vec<Vec<TileState>>,
+    pub max_guesses: usize,
+    pub word_length: usize,
+}
+
+/// Color only miniature of a finished board, light enough to render dozens at once
+#[function_component(BoardThumbnail)]
+pub fn board_thumbnail(props: &BoardThumbnailProps) -> Html {
+    html! {
+        <div class="thumbnail" style={format!(
+            "grid-template-columns: repeat({}, 1fr); grid-template-rows: repeat({}, 1fr);",
+            props.word_length,
+            props.max_guesses
+        )}>
+            {
+                (0..props.max_guesses).flat_map(|row| {
+                    (0..props.word_length).map(move |tile_index| (row, tile_index))
+                }).map(|(row, tile_index)| {
+                    let tile_state = props.patterns
+                        .get(row)
+                        .and_then(|pattern| pattern.get(tile_index))
+                        .unwrap_or(&TileState::Unknown);
+
+                    html! { <div class={classes!("thumbnail-tile", tile_state.to_string())} /> }
+                }).collect::<Html>()
+            }
+        </div>
+    }
+}
+
 #[derive(Properties, PartialEq)]
 pub struct PreviousBoardProps {
     pub guesses: Vec<Vec<(char, TileState)>>,
     pub max_guesses: usize,
     pub word_length: usize,
+    pub duration_ms: u32,
 }
 
 #[function_component(PreviousBoard)]
 pub fn previous_board(props: &PreviousBoardProps) -> Html {
     html! {
-        <div class={classes!("slide-out", format!("slide-out-{}", props.guesses.len()), format!("board-{}", props.max_guesses))}>
+        <div class={classes!("slide-out", format!("slide-out-{}", props.guesses.len()), format!("board-{}", props.max_guesses))}
+            style={format!("animation-duration: {}ms", props.duration_ms)}>
             { props.guesses.iter().map(|guess| {
                 html! {
                     <div class={format!("row-{}", props.word_length)}>
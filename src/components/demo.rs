@@ -0,0 +1,187 @@
+use std::rc::Rc;
+
+use web_sys::HtmlInputElement;
+use yew::prelude::*;
+
+use crate::game::Game;
+use crate::i18n::{t, Language};
+use crate::manager::{TileState, WordLists};
+use crate::sanuli::Sanuli;
+
+const DEMO_GUESSES: usize = 3;
+
+/// The fixed word of the example, the guesses are checked against the word lists of the language
+fn demo_word(language: Language) -> &'static str {
+    match language {
+        Language::Finnish => "KISSA",
+        Language::Swedish => "HUSET",
+        Language::English => "PLANT",
+    }
+}
+
+pub enum Msg {
+    Input(String),
+    Submit,
+    Restart,
+}
+
+/// The word lists as a prop, compared only by their address as comparing the words would take
+/// ages
+#[derive(Clone)]
+pub struct SharedWordLists(pub Rc<WordLists>);
+
+impl PartialEq for SharedWordLists {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+#[derive(Properties, PartialEq)]
+pub struct DemoBoardProps {
+    pub word_lists: SharedWordLists,
+}
+
+/// A playable example with a game of its own, so that trying it out doesn't touch the game or
+/// the statistics of the player
+pub struct DemoBoard {
+    game: Sanuli,
+    input: String,
+}
+
+impl DemoBoard {
+    fn new_game(word_lists: &Rc<WordLists>) -> Sanuli {
+        Sanuli::practice(
+            demo_word(word_lists.language),
+            DEMO_GUESSES,
+            Rc::clone(word_lists),
+        )
+    }
+
+    fn current_guess(&self) -> usize {
+        self.game.boards()[0].current_guess
+    }
+}
+
+impl Component for DemoBoard {
+    type Message = Msg;
+    type Properties = DemoBoardProps;
+
+    fn create(ctx: &Context<Self>) -> Self {
+        Self {
+            game: Self::new_game(&ctx.props().word_lists.0),
+            input: String::new(),
+        }
+    }
+
+    fn update(&mut self, ctx: &Context<Self>, msg: Self::Message) -> bool {
+        match msg {
+            Msg::Input(input) => {
+                self.input = input
+                    .to_uppercase()
+                    .chars()
+                    .filter(|character| character.is_alphabetic())
+                    .take(self.game.word_length())
+                    .collect();
+            }
+            Msg::Submit => {
+                if !self.game.is_guessing() {
+                    return false;
+                }
+
+                for _ in 0..self.game.word_length() {
+                    self.game.pop_character();
+                }
+                for character in self.input.chars() {
+                    self.game.push_character(character);
+                }
+                let current_guess = self.current_guess();
+                self.game.submit_guess();
+
+                if self.current_guess() != current_guess || !self.game.is_guessing() {
+                    self.input = String::new();
+                }
+            }
+            Msg::Restart => {
+                self.game = Self::new_game(&ctx.props().word_lists.0);
+                self.input = String::new();
+            }
+        }
+
+        true
+    }
+
+    fn changed(&mut self, ctx: &Context<Self>) -> bool {
+        self.game = Self::new_game(&ctx.props().word_lists.0);
+        true
+    }
+
+    fn view(&self, ctx: &Context<Self>) -> Html {
+        let link = ctx.link();
+        let word_length = self.game.word_length();
+        let is_guessing = self.game.is_guessing();
+        let board = self.game.boards().remove(0);
+
+        let oninput = link.batch_callback(|e: InputEvent| {
+            e.target_dyn_into::<HtmlInputElement>()
+                .map(|input| Msg::Input(input.value()))
+        });
+        let onkeydown = link.batch_callback(|e: KeyboardEvent| {
+            // Kept from the keyboard shortcuts of the game
+            e.stop_propagation();
+            (e.key() == "Enter").then_some(Msg::Submit)
+        });
+        let submit = link.callback(|e: MouseEvent| {
+            e.prevent_default();
+            Msg::Submit
+        });
+        let restart = link.callback(|e: MouseEvent| {
+            e.prevent_default();
+            Msg::Restart
+        });
+
+        html! {
+            <div class="demo">
+                {board.guesses.iter().enumerate().map(|(row, guess)| {
+                    // The current row shows the letters being typed
+                    let is_current_row = is_guessing && row == board.current_guess;
+                    let letters = if is_current_row {
+                        self.input.chars().map(|character| (character, TileState::Unknown)).collect()
+                    } else {
+                        guess.clone()
+                    };
+
+                    html! {
+                        <div class={classes!(format!("row-{}", word_length), "example")}>
+                            {(0..word_length).map(|index| {
+                                let (character, tile_state) = letters
+                                    .get(index)
+                                    .copied()
+                                    .unwrap_or((' ', TileState::Unknown));
+                                html! {
+                                    <div class={classes!("tile", tile_state.to_string())}>{character}</div>
+                                }
+                            }).collect::<Html>()}
+                        </div>
+                    }
+                }).collect::<Html>()}
+                <p class="demo-message">{self.game.message()}</p>
+                if is_guessing {
+                    <div class="select-container">
+                        <input
+                            class="demo-input"
+                            type="text"
+                            value={self.input.clone()}
+                            placeholder={t("Kokeile arvata")}
+                            aria-label={t("Esimerkin arvaus")}
+                            {oninput}
+                            {onkeydown}
+                        />
+                        <button class="select" onmousedown={submit}>{t("Arvaa")}</button>
+                    </div>
+                } else {
+                    <button class="select" onmousedown={restart}>{t("Uusi yritys")}</button>
+                }
+            </div>
+        }
+    }
+}
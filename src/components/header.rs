@@ -1,10 +1,17 @@
 use yew::prelude::*;
 
+use crate::i18n::t;
+use crate::manager::Flame;
+
 #[derive(Properties, Clone, PartialEq)]
 pub struct Props {
     pub on_toggle_menu_cb: Callback<MouseEvent>,
     pub on_toggle_help_cb: Callback<MouseEvent>,
     pub title: String,
+    pub streak_flame: Option<(usize, Flame)>,
+    // Points of the current mode when scoring is on
+    #[prop_or_default]
+    pub points: Option<u64>,
 }
 
 #[function_component(Header)]
@@ -27,9 +34,33 @@ pub fn header(props: &Props) -> Html {
 
     html! {
         <header>
-            <nav onclick={onclick_help} class="title-icon">{"?"}</nav>
-                <h1 class="title">{&props.title}</h1>
-            <nav onclick={onclick_menu} class="title-icon">{"≡"}</nav>
+            <nav onclick={onclick_help} class="title-icon" tabindex="0" role="button" aria-label={t("Ohjeet")}>{"?"}</nav>
+                <h1 class="title">
+                    {&props.title}
+                    {
+                        if let Some((streak, flame)) = props.streak_flame {
+                            html! {
+                                <span class={classes!("flame", flame.to_string())} title={t("Päivän sanulien putki")}>
+                                    {format!("🔥{}", streak)}
+                                </span>
+                            }
+                        } else {
+                            html! {}
+                        }
+                    }
+                    {
+                        if let Some(points) = props.points {
+                            html! {
+                                <span class="points" title={t("Pelimuodon pisteet")}>
+                                    {format!("⭐{}", points)}
+                                </span>
+                            }
+                        } else {
+                            html! {}
+                        }
+                    }
+                </h1>
+            <nav onclick={onclick_menu} class="title-icon" tabindex="0" role="button" aria-label={t("Valikko")}>{"≡"}</nav>
         </header>
     }
 }
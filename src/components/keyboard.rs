@@ -1,14 +1,18 @@
+use gloo_timers::callback::Timeout;
 use std::collections::HashMap;
+use wasm_bindgen::JsCast;
+use web_sys::{Element, HtmlElement};
 use yew::prelude::*;
 
-use crate::manager::{GameMode, KeyState, TileState};
+use crate::i18n::{t, Language};
+use crate::keyboard_layout::{Key, KeyboardLayout};
+use crate::manager::{GameMode, KeyCount, KeyState, TileState};
 use crate::Msg;
 
 use crate::components::message::Message;
 
-const KEYBOARD_0: [char; 10] = ['Q', 'W', 'E', 'R', 'T', 'Y', 'U', 'I', 'O', 'P'];
-const KEYBOARD_1: [char; 11] = ['A', 'S', 'D', 'F', 'G', 'H', 'J', 'K', 'L', 'Ö', 'Ä'];
-const KEYBOARD_2: [char; 7] = ['Z', 'X', 'C', 'V', 'B', 'N', 'M'];
+// Holding ⌫ this long clears the whole row
+const LONG_PRESS_MS: u32 = 500;
 
 #[derive(Properties, PartialEq)]
 pub struct Props {
@@ -18,9 +22,10 @@ pub struct Props {
     pub is_winner: bool,
     pub is_guessing: bool,
     pub is_hidden: bool,
+    pub is_undo_allowed: bool,
+    pub has_next_word: bool,
 
-    pub is_emojis_copied: bool,
-    pub is_link_copied: bool,
+    pub is_replay_available: bool,
 
     pub game_mode: GameMode,
 
@@ -29,18 +34,161 @@ pub struct Props {
     pub last_guess: String,
 
     pub keyboard: HashMap<char, KeyState>,
+    pub counts: HashMap<char, KeyCount>,
+    pub puzzle_language: Language,
+    pub keyboard_layout: KeyboardLayout,
+    pub is_voice_input: bool,
+    pub is_listening: bool,
+}
+
+/// Moves the focus between the buttons of the keyboard rows with the arrow keys. Only the
+/// focused button is reachable with Tab, so the keyboard is a single stop in the tab order.
+fn onkeydown_roving(
+    focused: UseStateHandle<(usize, usize)>,
+    row_lengths: Vec<usize>,
+) -> Callback<KeyboardEvent> {
+    Callback::from(move |e: KeyboardEvent| {
+        let button = match e
+            .target()
+            .and_then(|target| target.dyn_into::<Element>().ok())
+        {
+            Some(button) if button.matches(".keyboard-button").unwrap_or(false) => button,
+            _ => return,
+        };
+
+        let (row, column) = clamp(*focused, &row_lengths);
+        let (row, column) = match e.key().as_str() {
+            "ArrowLeft" => (row, column.saturating_sub(1)),
+            "ArrowRight" => (row, column + 1),
+            "ArrowUp" => (row.saturating_sub(1), column),
+            "ArrowDown" => (row + 1, column),
+            "Home" => (row, 0),
+            "End" => (row, usize::MAX),
+            _ => return,
+        };
+        let (row, column) = clamp((row, column), &row_lengths);
+
+        // Keeps the arrow keys from moving the cursor of the board
+        e.prevent_default();
+        focused.set((row, column));
+
+        let next = button
+            .closest(".keyboard")
+            .ok()
+            .flatten()
+            .and_then(|keyboard| keyboard.query_selector_all(".keyboard-row").ok())
+            .and_then(|rows| rows.item(row as u32))
+            .and_then(|row| row.dyn_into::<Element>().ok())
+            .and_then(|row| row.query_selector_all("button").ok())
+            .and_then(|buttons| buttons.item(column as u32))
+            .and_then(|next| next.dyn_into::<HtmlElement>().ok());
+
+        if let Some(next) = next {
+            let _result = next.focus();
+        }
+    })
+}
+
+fn clamp((row, column): (usize, usize), row_lengths: &[usize]) -> (usize, usize) {
+    let row = row.min(row_lengths.len() - 1);
+    (row, column.min(row_lengths[row].saturating_sub(1)))
 }
 
 #[function_component(Keyboard)]
 pub fn keyboard(props: &Props) -> Html {
+    let rows = props.keyboard_layout.rows(props.puzzle_language);
+    let row_lengths = rows
+        .iter()
+        .map(|row| {
+            row.iter()
+                .filter(|key| key.is_button(props.is_voice_input))
+                .count()
+        })
+        .collect::<Vec<_>>();
+
+    let focused_handle = use_state(|| (0, 0));
+    let focused = clamp(*focused_handle, &row_lengths);
+    let onkeydown = onkeydown_roving(focused_handle, row_lengths);
+    let tabindex = |position: (usize, usize)| if focused == position { "0" } else { "-1" };
     let callback = props.callback.clone();
     let onbackspace = Callback::from(move |e: MouseEvent| {
         e.prevent_default();
         callback.emit(Msg::Backspace);
     });
 
+    // The first letter is removed on the press, the rest of the row once held long enough
+    let press_timer = use_mut_ref(|| None::<Timeout>);
+    let onbackspace_press = {
+        let press_timer = press_timer.clone();
+        let callback = props.callback.clone();
+        Callback::from(move |_: PointerEvent| {
+            let callback = callback.clone();
+            *press_timer.borrow_mut() = Some(Timeout::new(LONG_PRESS_MS, move || {
+                callback.emit(Msg::ClearRow)
+            }));
+        })
+    };
+    let onbackspace_release = Callback::from(move |_: PointerEvent| {
+        press_timer.borrow_mut().take();
+    });
+
+    let view_key = |key: &Key, position: (usize, usize)| -> Html {
+        match key {
+            Key::Letter(character) => {
+                let character = *character;
+                let callback = props.callback.clone();
+                let onkeypress = Callback::from(move |e: MouseEvent| {
+                    e.prevent_default();
+                    callback.emit(Msg::KeyPress(character));
+                });
+
+                let key_state = props
+                    .keyboard
+                    .get(&character)
+                    .unwrap_or(&KeyState::Single(TileState::Unknown));
+
+                html! {
+                    <KeyboardButton character={character} is_hidden={props.is_hidden} onkeypress={onkeypress} key_state={*key_state}
+                        count={props.counts.get(&character).copied()} tabindex={tabindex(position)}/>
+                }
+            }
+            Key::Backspace => html! {
+                <button data-nosnippet="" class={classes!("keyboard-button", "keyboard-button-backspace")}
+                    tabindex={tabindex(position)} aria-label={t("Poista kirjain")} onmousedown={onbackspace.clone()}
+                    onpointerdown={onbackspace_press.clone()} onpointerup={onbackspace_release.clone()}
+                    onpointerleave={onbackspace_release.clone()} onpointercancel={onbackspace_release.clone()}>
+                    { "⌫" }
+                </button>
+            },
+            Key::Voice if props.is_voice_input => {
+                let callback = props.callback.clone();
+                let onmousedown = Callback::from(move |e: MouseEvent| {
+                    e.prevent_default();
+                    callback.emit(Msg::ToggleDictation);
+                });
+
+                html! {
+                    <button data-nosnippet="" title={t("Sanele sana")} tabindex={tabindex(position)}
+                        class={classes!("keyboard-button", "keyboard-button-voice", props.is_listening.then(|| "keyboard-button-listening"))}
+                        onmousedown={onmousedown}>
+                        { "🎤" }
+                    </button>
+                }
+            }
+            Key::Voice => html! {
+                <>
+                    <div class="spacer" />
+                    <div class="spacer" />
+                    <div class="spacer" />
+                </>
+            },
+            Key::Submit => view_submit(props, tabindex(position)),
+            Key::Spacer => html! { <div class="spacer" /> },
+        }
+    };
+
     html! {
-        <div class="keyboard">
+        <div class="keyboard" onkeydown={onkeydown}>
             {
                 if props.message.is_empty() && !props.is_hidden {
                     html! {}
@@ -52,8 +200,8 @@ pub fn keyboard(props: &Props) -> Html {
                             is_winner={props.is_winner}
                             is_guessing={props.is_guessing}
                             is_hidden={props.is_hidden}
-                            is_emojis_copied={props.is_emojis_copied}
-                            is_link_copied={props.is_link_copied}
+                            is_undo_allowed={props.is_undo_allowed}
+                            is_replay_available={props.is_replay_available}
                             last_guess={props.last_guess.clone()}
                             word={props.word.clone()}
                             game_mode={props.game_mode}
@@ -63,127 +211,121 @@ pub fn keyboard(props: &Props) -> Html {
                 }
             }
 
-            <div class="keyboard-row">
-                {
-                    KEYBOARD_0.iter().map(|key| {
-                        let callback = props.callback.clone();
-                        let onkeypress = Callback::from(move |e: MouseEvent| {
-                            e.prevent_default();
-                            callback.emit(Msg::KeyPress(*key));
-                        });
-
-                        let key_state = props.keyboard.get(key).unwrap_or(&KeyState::Single(TileState::Unknown));
-
-                        html! {
-                            <KeyboardButton character={*key} is_hidden={props.is_hidden} onkeypress={onkeypress} key_state={*key_state}/>
-                        }
-                    }).collect::<Html>()
-                }
-                <button data-nosnippet="" class={classes!("keyboard-button", "keyboard-button-backspace")} onmousedown={onbackspace}>
-                    { "⌫" }
-                </button>
-            </div>
-            <div class="keyboard-row">
-                <div class="spacer" />
-                {
-                    KEYBOARD_1.iter().map(|key| {
-                        let callback = props.callback.clone();
-                        let onkeypress = Callback::from(move |e: MouseEvent| {
-                            e.prevent_default();
-                            callback.emit(Msg::KeyPress(*key));
-                        });
-
-                        let key_state = props.keyboard.get(key).unwrap_or(&KeyState::Single(TileState::Unknown));
-
-                        html! {
-                            <KeyboardButton character={*key} is_hidden={props.is_hidden} onkeypress={onkeypress} key_state={*key_state}/>
-                        }
-                    }).collect::<Html>()
-                }
-            </div>
-            <div class="keyboard-row">
-                <div class="spacer" />
-                <div class="spacer" />
-                <div class="spacer" />
-                {
-                    KEYBOARD_2.iter().map(|key| {
-                        let callback = props.callback.clone();
-                        let onkeypress = Callback::from(move |e: MouseEvent| {
-                            e.prevent_default();
-                            callback.emit(Msg::KeyPress(*key));
-                        });
-
-                        let key_state = props.keyboard.get(key).unwrap_or(&KeyState::Single(TileState::Unknown));
-
-                        html! {
-                            <KeyboardButton character={*key} is_hidden={props.is_hidden} onkeypress={onkeypress} key_state={*key_state}/>
-                        }
-                    }).collect::<Html>()
-                }
-                {
-                    if props.is_guessing {
-                        let callback = props.callback.clone();
-                        let onmousedown = Callback::from(move |e: MouseEvent| {
-                            e.prevent_default();
-                            callback.emit(Msg::Guess);
-                        });
-
-                        html! {
-                            <button data-nosnippet="" class={classes!("keyboard-button", "keyboard-button-submit")}
-                                onmousedown={onmousedown}>
-                                { "ARVAA" }
-                            </button>
-                        }
-                    } else if matches!(props.game_mode, GameMode::DailyWord(_) | GameMode::Shared) {
-                        let callback = props.callback.clone();
-                        let onmousedown = Callback::from(move |e: MouseEvent| {
-                            e.prevent_default();
-                            callback.emit(Msg::ChangePreviousGameMode);
-                        });
-
-                        html! {
-                            <button data-nosnippet="" class={classes!("keyboard-button", "keyboard-button-submit", "correct")}
-                                onmousedown={onmousedown}>
-                                { "TAKAISIN" }
-                            </button>
-                        }
-                    } else {
-                        let callback = props.callback.clone();
-                        let onmousedown = Callback::from(move |e: MouseEvent| {
-                            e.prevent_default();
-                            callback.emit(Msg::NextWord);
-                        });
-
-                        html! {
-                            <button data-nosnippet="" class={classes!("keyboard-button", "keyboard-button-submit", "correct")}
-                                onmousedown={onmousedown}>
-                                { "UUSI?" }
-                            </button>
+            {
+                rows.iter().enumerate().map(|(row, keys)| {
+                    // The position of a key among the buttons of its row
+                    let mut column = 0;
+                    let keys = keys.iter().map(|key| {
+                        let html = view_key(key, (row, column));
+                        if key.is_button(props.is_voice_input) {
+                            column += 1;
                         }
+                        html
+                    }).collect::<Html>();
+
+                    html! {
+                        <div class="keyboard-row">{keys}</div>
                     }
-                }
-                <div class="spacer" />
-                <div class="spacer" />
-            </div>
+                }).collect::<Html>()
+            }
         </div>
     }
 }
 
+/// Submits the guess, or once the game has ended, moves on to the next word or back
+fn view_submit(props: &Props, tabindex: &'static str) -> Html {
+    if props.is_guessing {
+        let callback = props.callback.clone();
+        let onmousedown = Callback::from(move |e: MouseEvent| {
+            e.prevent_default();
+            callback.emit(Msg::Guess);
+        });
+
+        html! {
+            <button data-nosnippet="" tabindex={tabindex} class={classes!("keyboard-button", "keyboard-button-submit")}
+                onmousedown={onmousedown}>
+                {t("ARVAA")}
+            </button>
+        }
+    } else if !props.has_next_word
+        || matches!(
+            props.game_mode,
+            GameMode::DailyWord(_)
+                | GameMode::EveningWord(_)
+                | GameMode::Race(_)
+                | GameMode::Shared
+        )
+    {
+        let callback = props.callback.clone();
+        let onmousedown = Callback::from(move |e: MouseEvent| {
+            e.prevent_default();
+            callback.emit(Msg::ChangePreviousGameMode);
+        });
+
+        html! {
+            <button data-nosnippet="" tabindex={tabindex} class={classes!("keyboard-button", "keyboard-button-submit", "correct")}
+                onmousedown={onmousedown}>
+                {t("TAKAISIN")}
+            </button>
+        }
+    } else {
+        let callback = props.callback.clone();
+        let onmousedown = Callback::from(move |e: MouseEvent| {
+            e.prevent_default();
+            callback.emit(Msg::NextWord);
+        });
+
+        html! {
+            <button data-nosnippet="" tabindex={tabindex} class={classes!("keyboard-button", "keyboard-button-submit", "correct")}
+                onmousedown={onmousedown}>
+                {t("UUSI?")}
+            </button>
+        }
+    }
+}
+
 #[derive(Properties, PartialEq)]
 pub struct KeyboardButtonProps {
     pub onkeypress: Callback<MouseEvent>,
     pub character: char,
     pub is_hidden: bool,
     pub key_state: KeyState,
+    pub count: Option<KeyCount>,
+    // Roving tabindex of the keyboard, "0" for the focusable button
+    pub tabindex: &'static str,
 }
 
 #[function_component(KeyboardButton)]
 pub fn keyboard_button(props: &KeyboardButtonProps) -> Html {
+    // Only the games of a single board have the counts of the letters
+    let is_exhausted = props.count.is_some_and(|count| count.is_exhausted);
+    let badge = match props.count {
+        Some(count) if count.count >= 2 => html! {
+            <span class="key-count" aria-hidden="true">{format!("×{}", count.count)}</span>
+        },
+        _ => html! {},
+    };
+
     if !props.is_hidden {
         match props.key_state {
             KeyState::Single(state) => {
                 html! {
-                    <button data-nosnippet="" class={classes!("keyboard-button", state.to_string())} onmousedown={props.onkeypress.clone()}>
+                    <button data-nosnippet="" class={classes!("keyboard-button", state.to_string(), is_exhausted.then_some("key-exhausted"))} tabindex={props.tabindex} onmousedown={props.onkeypress.clone()}>
+                        { props.character }
+                        { badge }
+                    </button>
+                }
+            }
+            KeyState::Double(states) => {
+                let background = format!(
+                    "background: linear-gradient(to right, var(--{left}) 50%, var(--{right}) 50%);",
+                    left = states[0],
+                    right = states[1],
+                );
+
+                html! {
+                    <button data-nosnippet="" class={"keyboard-button"} style={background} tabindex={props.tabindex}
+                        onmousedown={props.onkeypress.clone()}>
                         { props.character }
                     </button>
                 }
@@ -198,7 +340,7 @@ pub fn keyboard_button(props: &KeyboardButtonProps) -> Html {
                 );
 
                 html! {
-                    <button data-nosnippet="" class={"keyboard-button"} style={background.clone()}
+                    <button data-nosnippet="" class={"keyboard-button"} style={background.clone()} tabindex={props.tabindex}
                         onmousedown={props.onkeypress.clone()}>
                         { props.character }
                     </button>
@@ -207,7 +349,7 @@ pub fn keyboard_button(props: &KeyboardButtonProps) -> Html {
         }
     } else {
         html! {
-            <button data-nosnippet="" class={classes!("keyboard-button", "unknown")}>
+            <button data-nosnippet="" class={classes!("keyboard-button", "unknown")} tabindex={props.tabindex}>
                 { props.character }
             </button>
         }
@@ -0,0 +1,185 @@
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+use yew::prelude::*;
+
+use crate::api::{self, ApiError, HttpRequest};
+use crate::i18n::{t, tf};
+use crate::storage;
+use crate::transport::{HttpMessage, HttpTransport, Transport};
+
+/// Anonymous result of a daily word, the client id is random and only ties the resubmissions
+/// of the same player together
+#[derive(Clone, PartialEq, Serialize)]
+pub struct LeaderboardResult {
+    pub client_id: String,
+    // None for a word that wasn't solved
+    pub guesses: Option<usize>,
+    pub duration_secs: Option<i64>,
+}
+
+/// Results of everyone for the day, `counts[i]` solved the word with `i + 1` guesses and the
+/// last count failed
+#[derive(Clone, PartialEq, Deserialize)]
+pub struct Distribution {
+    pub counts: Vec<usize>,
+}
+
+impl Distribution {
+    /// Share of the other players with a worse result
+    fn percentile(&self, index: usize) -> usize {
+        let total = self.counts.iter().sum::<usize>().max(1);
+        let worse = self.counts.iter().skip(index + 1).sum::<usize>();
+        worse * 100 / total
+    }
+}
+
+/// Messages of the leaderboard backend, a submitted result is answered with `Submitted` and a
+/// loaded distribution with `Loaded`
+#[derive(Clone)]
+pub enum LeaderboardMessage {
+    Submit(NaiveDate, LeaderboardResult),
+    Load(NaiveDate),
+    Submitted(NaiveDate),
+    Loaded(Distribution),
+    Failed(ApiError),
+}
+
+fn leaderboard_path(date: NaiveDate) -> String {
+    format!("leaderboard/{}", date)
+}
+
+impl HttpMessage for LeaderboardMessage {
+    fn request(&self) -> Option<HttpRequest> {
+        match self {
+            LeaderboardMessage::Submit(date, result) => {
+                let url = api::url(&format!("{}/results", leaderboard_path(*date))).ok()?;
+                HttpRequest::with_body("POST", url, result).ok()
+            }
+            LeaderboardMessage::Load(date) => {
+                Some(HttpRequest::get(api::url(&leaderboard_path(*date)).ok()?))
+            }
+            _ => None,
+        }
+    }
+
+    fn reply(self, response: Result<String, ApiError>) -> Self {
+        match (self, response) {
+            // The response of a stored submission is often empty
+            (LeaderboardMessage::Submit(date, _), Ok(_)) => LeaderboardMessage::Submitted(date),
+            (LeaderboardMessage::Load(_), Ok(body)) => match serde_json::from_str(&body) {
+                Ok(distribution) => LeaderboardMessage::Loaded(distribution),
+                Err(err) => LeaderboardMessage::Failed(err.into()),
+            },
+            (_, Err(err)) => LeaderboardMessage::Failed(err),
+            // Only the requests are sent
+            (message, Ok(_)) => message,
+        }
+    }
+}
+
+#[derive(Properties, PartialEq)]
+pub struct Props {
+    pub date: NaiveDate,
+    pub max_guesses: usize,
+    pub result: LeaderboardResult,
+}
+
+pub enum Msg {
+    Received(LeaderboardMessage),
+}
+
+pub struct LeaderboardPanel {
+    distribution: Option<Distribution>,
+    transport: Box<dyn Transport<LeaderboardMessage>>,
+}
+
+fn submitted_key(date: NaiveDate) -> String {
+    format!("leaderboard|{}", date)
+}
+
+impl Component for LeaderboardPanel {
+    type Message = Msg;
+    type Properties = Props;
+
+    fn create(ctx: &Context<Self>) -> Self {
+        let date = ctx.props().date;
+        let transport = Box::new(HttpTransport::new(ctx.link().callback(Msg::Received)));
+
+        if storage::get::<bool>(submitted_key(date)).is_ok() {
+            transport.send(&LeaderboardMessage::Load(date));
+        } else {
+            transport.send(&LeaderboardMessage::Submit(
+                date,
+                ctx.props().result.clone(),
+            ));
+        }
+
+        Self {
+            distribution: None,
+            transport,
+        }
+    }
+
+    fn update(&mut self, _ctx: &Context<Self>, msg: Self::Message) -> bool {
+        match msg {
+            Msg::Received(LeaderboardMessage::Submitted(date)) => {
+                let _res = storage::set(submitted_key(date), true);
+                self.transport.send(&LeaderboardMessage::Load(date));
+                false
+            }
+            Msg::Received(LeaderboardMessage::Loaded(distribution)) => {
+                self.distribution = Some(distribution);
+                true
+            }
+            Msg::Received(LeaderboardMessage::Failed(err)) => {
+                log::info!("Leaderboard not available: {}", err);
+                false
+            }
+            Msg::Received(_) => false,
+        }
+    }
+
+    fn view(&self, ctx: &Context<Self>) -> Html {
+        let distribution = match &self.distribution {
+            Some(distribution) if !distribution.counts.is_empty() => distribution,
+            _ => return html! {},
+        };
+
+        let props = ctx.props();
+        let own_index = match props.result.guesses {
+            Some(guesses) => guesses - 1,
+            None => distribution.counts.len() - 1,
+        };
+        let max_count = distribution
+            .counts
+            .iter()
+            .copied()
+            .max()
+            .unwrap_or(0)
+            .max(1);
+
+        html! {
+            <div class="leaderboard">
+                <label class="label">{tf("Parempi tulos kuin {} % pelaajista", &[&distribution.percentile(own_index)])}</label>
+                {distribution.counts.iter().enumerate().map(|(index, count)| {
+                    let label = if index + 1 > props.max_guesses {
+                        "X".to_owned()
+                    } else {
+                        format!("{}", index + 1)
+                    };
+
+                    html! {
+                        <div class="leaderboard-row">
+                            <span class="leaderboard-label">{label}</span>
+                            <div class={classes!("leaderboard-bar", (index == own_index).then(|| "leaderboard-own"))}
+                                style={format!("width: {}%;", count * 100 / max_count)}>
+                                {count}
+                            </div>
+                        </div>
+                    }
+                }).collect::<Html>()}
+                <p class="leaderboard-info">{t("Tulokset lähetetään nimettöminä.")}</p>
+            </div>
+        }
+    }
+}
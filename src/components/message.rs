@@ -1,5 +1,6 @@
 use yew::prelude::*;
 
+use crate::i18n::t;
 use crate::manager::GameMode;
 use crate::Msg as GameMsg;
 
@@ -7,6 +8,11 @@ const FORMS_LINK_TEMPLATE_ADD: &str = "https://docs.google.com/forms/d/e/1FAIpQL
 const FORMS_LINK_TEMPLATE_DEL: &str = "https://docs.google.com/forms/d/e/1FAIpQLSfH8gs4sq-Ynn8iGOvlc99J_zOG2rJEC4m8V0kCgF_en3RHFQ/viewform?usp=pp_url&entry.461337706=Poistoa&entry.560255602=";
 const DICTIONARY_LINK_TEMPLATE: &str = "https://www.kielitoimistonsanakirja.fi/#/";
 
+/// The entry of a word in Kielitoimiston sanakirja
+pub fn dictionary_url(word: &str) -> String {
+    format!("{}{}?searchMode=all", DICTIONARY_LINK_TEMPLATE, word)
+}
+
 #[derive(Properties, Clone, PartialEq)]
 pub struct MessageProps {
     pub message: String,
@@ -14,9 +20,9 @@ pub struct MessageProps {
     pub is_winner: bool,
     pub is_guessing: bool,
     pub is_hidden: bool,
+    pub is_undo_allowed: bool,
 
-    pub is_emojis_copied: bool,
-    pub is_link_copied: bool,
+    pub is_replay_available: bool,
 
     pub word: String,
     pub last_guess: String,
@@ -45,11 +51,11 @@ pub fn message(props: &MessageProps) -> Html {
                     html! {
                         <>
                             <a class="link" href={"javascript:void(0)"} onclick={reset_game}>
-                                {"Kokeile ratkaista"}
+                                {t("Kokeile ratkaista")}
                             </a>
                             {" | "}
                             <a class="link" href={"javascript:void(0)"} onclick={reveal_hidden_tiles}>
-                                {"Paljasta"}
+                                {t("Paljasta")}
                             </a>
                         </>
                     }
@@ -57,8 +63,8 @@ pub fn message(props: &MessageProps) -> Html {
                     html! {
                         <SubMessage
                             is_winner={props.is_winner}
-                            is_emojis_copied={props.is_emojis_copied}
-                            is_link_copied={props.is_link_copied}
+                            is_undo_allowed={props.is_undo_allowed}
+                            is_replay_available={props.is_replay_available}
                             word={props.word.clone()}
                             game_mode={props.game_mode}
                             callback={props.callback.clone()}
@@ -68,7 +74,7 @@ pub fn message(props: &MessageProps) -> Html {
                     let last_guess = props.last_guess.to_lowercase();
                     html! {
                         <a class="link" href={format!("{}{}", FORMS_LINK_TEMPLATE_ADD, last_guess)}
-                            target="_blank">{ "Ehdota lisäystä?" }
+                            target="_blank">{t("Ehdota lisäystä?")}
                         </a>
                     }
                 } else {
@@ -83,8 +89,8 @@ pub fn message(props: &MessageProps) -> Html {
 #[derive(Properties, Clone, PartialEq)]
 pub struct SubMessageProps {
     pub is_winner: bool,
-    pub is_emojis_copied: bool,
-    pub is_link_copied: bool,
+    pub is_undo_allowed: bool,
+    pub is_replay_available: bool,
     pub word: String,
     pub game_mode: GameMode,
     pub callback: Callback<GameMsg>,
@@ -104,39 +110,58 @@ fn sub_message(props: &SubMessageProps) -> Html {
         e.prevent_default();
         callback.emit(GameMsg::ShareLink);
     });
+    let callback = props.callback.clone();
+    let show_qr_code = Callback::from(move |e: MouseEvent| {
+        e.prevent_default();
+        callback.emit(GameMsg::ShowQrCode);
+    });
+    let callback = props.callback.clone();
+    let share_image = Callback::from(move |e: MouseEvent| {
+        e.prevent_default();
+        callback.emit(GameMsg::ShareImage);
+    });
+    let callback = props.callback.clone();
+    let share_replay = Callback::from(move |e: MouseEvent| {
+        e.prevent_default();
+        callback.emit(GameMsg::ShareReplay);
+    });
+    let callback = props.callback.clone();
+    let undo_guess = Callback::from(move |e: MouseEvent| {
+        e.prevent_default();
+        callback.emit(GameMsg::UndoGuess);
+    });
 
     if props.game_mode == GameMode::Quadruple {
-        return html!{}   
+        return html! {};
+    }
+
+    // The two words of a double daily only share their combined result
+    if let GameMode::DoubleDaily(_) = props.game_mode {
+        return html! {
+            <a class="link" href={"javascript:void(0)"} onclick={share_emojis}>
+                {t("Kopioi tulos")}
+            </a>
+        };
     }
 
     html! {
         <>
-            <a class="link" href={format!("{}{}?searchMode=all", DICTIONARY_LINK_TEMPLATE, word)}
-                target="_blank">{ "Sanakirja" }
+            <a class="link" href={dictionary_url(&word)}
+                target="_blank">{t("Sanakirja")}
             </a>
             {" | "}
             <a class="link" href={"javascript:void(0)"} onclick={share_link}>
-                {
-                    if !props.is_link_copied {
-                        {"Kopioi linkki"}
-                    } else {
-                        {"Kopioitu!"}
-                    }
-                }
+                {t("Kopioi linkki")}
             </a>
+            {" | "}
+            <a class="link" href={"javascript:void(0)"} onclick={show_qr_code}>{t("QR-koodi")}</a>
             {
-                if matches!(props.game_mode, GameMode::DailyWord(_)) {
+                if matches!(props.game_mode, GameMode::DailyWord(_) | GameMode::EveningWord(_) | GameMode::Weekly(_) | GameMode::Tournament(_) | GameMode::Pressure | GameMode::Relay | GameMode::Marathon) {
                     html! {
                         <>
                             {" | "}
                             <a class="link" href={"javascript:void(0)"} onclick={share_emojis}>
-                                {
-                                    if !props.is_emojis_copied {
-                                        {"Kopioi tulos"}
-                                    } else {
-                                        {"Kopioitu!"}
-                                    }
-                                }
+                                {t("Kopioi tulos")}
                             </a>
                         </>
                     }
@@ -145,7 +170,39 @@ fn sub_message(props: &SubMessageProps) -> Html {
                         <>
                             {" | "}
                             <a class="link" href={format!("{}{}", FORMS_LINK_TEMPLATE_DEL, word)}
-                                target="_blank">{ "Ehdota poistoa?" }
+                                target="_blank">{t("Ehdota poistoa?")}
+                            </a>
+                        </>
+                    }
+                } else {
+                    html! {}
+                }
+            }
+            {" | "}
+            <a class="link" href={"javascript:void(0)"} onclick={share_image}>
+                {t("Lataa kuva")}
+            </a>
+            {
+                if props.is_replay_available {
+                    html! {
+                        <>
+                            {" | "}
+                            <a class="link" href={"javascript:void(0)"} onclick={share_replay}>
+                                {t("Kopioi uusinta")}
+                            </a>
+                        </>
+                    }
+                } else {
+                    html! {}
+                }
+            }
+            {
+                if props.is_undo_allowed {
+                    html! {
+                        <>
+                            {" | "}
+                            <a class="link" href={"javascript:void(0)"} onclick={undo_guess}>
+                                {t("Kumoa arvaus")}
                             </a>
                         </>
                     }
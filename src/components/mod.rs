@@ -1,5 +1,21 @@
+pub mod alphabet;
+#[cfg(feature = "announcements")]
+pub mod announcement;
+pub mod board;
+pub mod demo;
 pub mod header;
 pub mod keyboard;
+#[cfg(feature = "leaderboard")]
+pub mod leaderboard;
 pub mod message;
-pub mod board;
-pub mod modal;
\ No newline at end of file
+pub mod modal;
+pub mod race;
+pub mod replay;
+pub mod reverse;
+pub mod swipe;
+#[cfg(feature = "sync")]
+pub mod sync;
+pub mod toast;
+pub mod tutorial;
+#[cfg(feature = "voting")]
+pub mod voting;
@@ -1,12 +1,99 @@
-use chrono::Local;
+use std::collections::BTreeMap;
+
+use chrono::{Local, TimeZone};
+use rand::seq::SliceRandom;
+use web_sys::HtmlInputElement;
 use yew::prelude::*;
 
-use crate::manager::{GameMode, Theme, WordList};
+use crate::analytics;
+use crate::bot::BotStrength;
+use crate::brand::BRAND;
+use crate::components::board::BoardThumbnail;
+use crate::components::demo::{DemoBoard, SharedWordLists};
+use crate::components::replay::Replay;
+use crate::components::reverse::ReverseBoard;
+use crate::game;
+use crate::i18n::{t, tf, Language};
+use crate::keyboard_layout::KeyboardLayout;
+use crate::manager::{
+    puzzle_languages, AnimationSpeed, DailyWordHistory, GameMode, HistoryDensity, KeyboardHints,
+    LetterAccuracy, ModePoints, OpenerStatistics, RelayStatistics, ReplayRecord, Statistics, Theme,
+    TileState, UiScale, UnfinishedGame, WordList, THEMES,
+};
+use crate::notifications;
+use crate::qr::QrCode;
+use crate::race;
+use crate::rollover::{self, Rollover};
+use crate::sanuli::Sanuli;
+use crate::share::{ShareOptions, SHARE_OPTIONS};
+use crate::shortcuts::{Action, KeyBindings, ALTERNATIVE_KEYS, SHORTCUTS};
+use crate::spectate;
+use crate::speech;
+use crate::storage;
+use crate::tournament::TOURNAMENT_WORDS;
+use crate::version;
+use crate::year_review::YearSummary;
 use crate::Msg;
 
 const FORMS_LINK_TEMPLATE_ADD: &str = "https://docs.google.com/forms/d/e/1FAIpQLSfH8gs4sq-Ynn8iGOvlc99J_zOG2rJEC4m8V0kCgF_en3RHFQ/viewform?usp=pp_url&entry.461337706=Lis%C3%A4yst%C3%A4&entry.560255602=";
 const CHANGELOG_URL: &str = "https://github.com/Cadiac/sanuli/blob/master/CHANGELOG.md";
-const VERSION: &str = "v1.14";
+const RECENT_LIST_LENGTH: usize = 7;
+const RECENT_RELEASES: usize = 3;
+const OPENERS_SHOWN: usize = 3;
+// An opener is only called the best after this many games with it
+const MIN_OPENER_GAMES: usize = 3;
+const TRICKY_LETTERS_SHOWN: usize = 5;
+// Keys free on the common layouts lacking Å, Ä and Ö, and the letters of the Nordic layouts
+const SUBSTITUTE_KEYS: [char; 7] = [';', '\'', '[', ']', '\\', 'Ø', 'Æ'];
+// Hours offered for the daily word reminder
+const DAILY_REMINDER_HOURS: [u32; 4] = [9, 12, 18, 21];
+const ROOM_CODE_LENGTH: usize = 4;
+// Letters easy to tell apart when the code is read out
+const ROOM_CODE_LETTERS: &[u8] = b"ACDEFHJKLMNPRTUVY";
+
+#[cfg(feature = "voting")]
+fn voting_panel() -> Html {
+    if crate::api::is_configured() {
+        html! { <crate::components::voting::VotingPanel /> }
+    } else {
+        html! {}
+    }
+}
+
+#[cfg(not(feature = "voting"))]
+fn voting_panel() -> Html {
+    html! {}
+}
+
+#[cfg(feature = "sync")]
+fn sync_panel() -> Html {
+    html! { <crate::components::sync::SyncPanel /> }
+}
+
+#[cfg(not(feature = "sync"))]
+fn sync_panel() -> Html {
+    html! {}
+}
+
+#[cfg(feature = "leaderboard")]
+fn is_leaderboard_available() -> bool {
+    crate::api::is_configured()
+}
+
+#[cfg(not(feature = "leaderboard"))]
+fn is_leaderboard_available() -> bool {
+    false
+}
+
+#[cfg(feature = "definitions")]
+fn is_definition_available() -> bool {
+    crate::api::is_configured()
+}
+
+#[cfg(not(feature = "definitions"))]
+fn is_definition_available() -> bool {
+    false
+}
 
 macro_rules! onmousedown {
     ( $cb:ident, $msg:expr ) => {{
@@ -18,87 +105,169 @@ macro_rules! onmousedown {
     }};
 }
 
+/// The modal shown over the game. Only one is open at a time.
+#[derive(Clone, PartialEq)]
+pub enum ActiveModal {
+    Help,
+    Menu,
+    Shortcuts,
+    Changelog,
+    QrCode(String),
+    Replay(ReplayRecord),
+    // The question of an action waiting for a confirmation
+    Confirm(String),
+    Race,
+    BotRace,
+    Tournament,
+    // Link of the own game for the spectators
+    Spectate(String),
+    History,
+    Tutorial,
+    // The game left mid-progress on the previous visit
+    Resume(UnfinishedGame),
+    // The computer guesses the word of the player
+    Reverse,
+    YearReview(i32),
+}
+
+impl ActiveModal {
+    pub fn name(&self) -> &'static str {
+        match self {
+            ActiveModal::Help => "help",
+            ActiveModal::Menu => "menu",
+            ActiveModal::Shortcuts => "shortcuts",
+            ActiveModal::Changelog => "changelog",
+            ActiveModal::QrCode(_) => "qr-code",
+            ActiveModal::Replay(_) => "replay",
+            ActiveModal::Confirm(_) => "confirm",
+            ActiveModal::Race => "race",
+            ActiveModal::BotRace => "bot-race",
+            ActiveModal::Tournament => "tournament",
+            ActiveModal::Spectate(_) => "spectate",
+            ActiveModal::History => "history",
+            ActiveModal::Tutorial => "tutorial",
+            ActiveModal::Resume(_) => "resume",
+            ActiveModal::Reverse => "reverse",
+            ActiveModal::YearReview(_) => "year-review",
+        }
+    }
+}
+
+#[derive(Properties, PartialEq)]
+pub struct ModalProps {
+    pub callback: Callback<Msg>,
+    #[prop_or_default]
+    pub children: Children,
+}
+
+/// Frame of the modals, closed with the close button, Esc or a click outside of it
+#[function_component(Modal)]
+pub fn modal(props: &ModalProps) -> Html {
+    let callback = props.callback.clone();
+    let close_modal = onmousedown!(callback, Msg::CloseModal);
+
+    let callback = props.callback.clone();
+    let onkeydown = Callback::from(move |e: KeyboardEvent| {
+        if e.key() == "Escape" {
+            // Handled here instead of the shortcut listener
+            e.prevent_default();
+            callback.emit(Msg::CloseModal);
+        }
+    });
+
+    html! {
+        <>
+            <div class="modal-backdrop" onmousedown={close_modal.clone()} />
+            <div class="modal" tabindex="-1" onkeydown={onkeydown}>
+                <span onmousedown={close_modal} class="modal-close" tabindex="0" role="button" aria-label={t("Sulje ikkuna")}>{"✖"}</span>
+                { for props.children.iter() }
+            </div>
+        </>
+    }
+}
+
 #[derive(Properties, Clone, PartialEq)]
 pub struct HelpModalProps {
     pub theme: Theme,
+    pub word_lists: SharedWordLists,
     pub callback: Callback<Msg>,
 }
 
 #[function_component(HelpModal)]
 pub fn help_modal(props: &HelpModalProps) -> Html {
     let callback = props.callback.clone();
-    let toggle_help = onmousedown!(callback, Msg::ToggleHelp);
+    let start_tutorial = onmousedown!(callback, Msg::StartTutorial);
 
     html! {
-        <div class="modal">
-            <span onmousedown={toggle_help} class="modal-close">{"✖"}</span>
-            <p>{"Arvaa kätketty "}<i>{"sanuli"}</i>{" kuudella yrityksellä."}</p>
-            <p>{"Jokaisen yrityksen jälkeen arvatut kirjaimet vaihtavat väriään."}</p>
+        <Modal callback={props.callback.clone()}>
+            <p>{t("Arvaa kätketty ")}<i>{t("sanuli")}</i>{t(" kuudella yrityksellä.")}</p>
+            <p>{t("Jokaisen yrityksen jälkeen arvatut kirjaimet vaihtavat väriään.")}</p>
 
-            <div class="row-5 example">
-                <div class={classes!("tile", "correct")}>{"K"}</div>
-                <div class={classes!("tile", "absent")}>{"O"}</div>
-                <div class={classes!("tile", "present")}>{"I"}</div>
-                <div class={classes!("tile", "absent")}>{"R"}</div>
-                <div class={classes!("tile", "absent")}>{"A"}</div>
-            </div>
+            <p>{t("Kokeile itse: arvaa esimerkin sana kolmella yrityksellä. Kokeilu ei vaikuta peliisi eikä tilastoihisi.")}</p>
+            <DemoBoard word_lists={props.word_lists.clone()} />
 
             <p>
                 {
                     html! {
-                        if props.theme == Theme::Colorblind {
-                            <span class="present">{"Sininen"}</span>
+                        if props.theme.is_orange_and_blue() {
+                            <span class="present">{t("Sininen")}</span>
                         } else {
-                            <span class="present">{"Keltainen"}</span>
+                            <span class="present">{t("Keltainen")}</span>
                         }
                     }
                 }
-                {": kirjain löytyy kätketystä sanasta, mutta on arvauksessa väärällä paikalla."}
+                {t(": kirjain löytyy kätketystä sanasta, mutta on arvauksessa väärällä paikalla.")}
             </p>
             <p>
                 {
                     html! {
-                        if props.theme == Theme::Colorblind {
-                            <span class="correct">{"Oranssi"}</span>
+                        if props.theme.is_orange_and_blue() {
+                            <span class="correct">{t("Oranssi")}</span>
                         } else {
-                            <span class="correct">{"Vihreä"}</span>
+                            <span class="correct">{t("Vihreä")}</span>
                         }
                     }
                 }
-                {": kirjain on arvauksessa oikealla paikalla."}
+                {t(": kirjain on arvauksessa oikealla paikalla.")}
+            </p>
+            <p><span class="absent">{t("Harmaa")}</span>{t(": kirjain ei löydy sanasta.")}</p>
+            <p>
+                <a class="link" href={"javascript:void(0)"} onmousedown={start_tutorial}>{t("Harjoittele värejä")}</a>
             </p>
-            <p><span class="absent">{"Harmaa"}</span>{": kirjain ei löydy sanasta."}</p>
 
             <p>
-                {"Arvattaviin sanoihin käytetyn sanulistan vaikeusasteen voi valita asetuksista. Sanulistojen pohjana on käytetty
-                Kotimaisten kielten keskuksen (Kotus) julkaiseman "}
-                <a class="link" href="https://creativecommons.org/licenses/by/3.0/deed.fi" target="_blank">{"\"CC Nimeä 3.0 Muokkaamaton\""}</a>
-                {"-lisensoidun nykysuomen sanalistan sanoja."}
+                {t("Arvattaviin sanoihin käytetyn sanulistan vaikeusasteen voi valita asetuksista. Sanulistojen pohjana on käytetty Kotimaisten kielten keskuksen (Kotus) julkaiseman ")}
+                <a class="link" href="https://creativecommons.org/licenses/by/3.0/deed.fi" target="_blank">{t("\"CC Nimeä 3.0 Muokkaamaton\"")}</a>
+                {t("-lisensoidun nykysuomen sanalistan sanoja.")}
             </p>
 
-            <p><b>{"Tavallinen"}</b>{" lista sisältää täydestä listasta poimitut yleisimmät sanat ilman harvinaisempia laina- ja murresanoja tai muita erikoisuuksia."}</p>
-            <p><b>{"Helppo"}</b>{" lista on tavallisesta vielä hieman helpotettu versio, jossa jäljellä ovat vain yleiset arkikielen sanat ilman vanhahtavia sanoja,
-                puhekieltä tai rumia sanuleja. Näin lista sopii kaikenikäisille. \"Helppo\" kuusikirjaimisten sanulien lista on kuitenkin vielä kesken."}</p>
-            <p><b>{"Vaikea"}</b>{" lista on täysi lista pelin hyväksymiä sanoja. Tälle listalle on myös lisätty jonkin verran käyttäjien uusia ehdotuksia,
-                puhekielisyyksiä, murresanoja sekä muita erikoisuuksia, eikä poistoja ole tehty kuin vain jos sanulit eivät selvästi ole oikeita sanoja."}</p>
+            <p><b>{t("Tavallinen")}</b>{t(" lista sisältää täydestä listasta poimitut yleisimmät sanat ilman harvinaisempia laina- ja murresanoja tai muita erikoisuuksia.")}</p>
+            <p><b>{t("Helppo")}</b>{t(" lista on tavallisesta vielä hieman helpotettu versio, jossa jäljellä ovat vain yleiset arkikielen sanat ilman vanhahtavia sanoja, puhekieltä tai rumia sanuleja. Näin lista sopii kaikenikäisille. \"Helppo\" kuusikirjaimisten sanulien lista on kuitenkin vielä kesken.")}</p>
+            <p><b>{t("Vaikea")}</b>{t(" lista on täysi lista pelin hyväksymiä sanoja. Tälle listalle on myös lisätty jonkin verran käyttäjien uusia ehdotuksia, puhekielisyyksiä, murresanoja sekä muita erikoisuuksia, eikä poistoja ole tehty kuin vain jos sanulit eivät selvästi ole oikeita sanoja.")}</p>
             <p>
-                {"Sanulit ovat yleensä perusmuodossa, mutta eivät välttämättä täysin pelkkää kirjakieltä. Yhdyssanojakin on seassa."}
+                {t("Sanulit ovat yleensä perusmuodossa, mutta eivät välttämättä täysin pelkkää kirjakieltä. Yhdyssanojakin on seassa.")}
             </p>
             <p>
-                {"Päivän sanulit tulevat omalta listaltaan, joka on jotain tavallisen ja vaikean listan väliltä. Sanuli on aina sama kaikille pelaajille tiettynä päivänä."}
+                {t("Päivän sanulit tulevat omalta listaltaan, joka on jotain tavallisen ja vaikean listan väliltä. Sanuli on aina sama kaikille pelaajille tiettynä päivänä.")}
             </p>
             <p>
-                {"Sanuliketjussa jos arvaat sanulin, on se suoraan ensimmäinen arvaus seuraavaan peliin. Näin joudut sopeutumaan vaihtuviin alkuarvauksiin, ja peli on hieman vaikeampi."}
+                {t("Sanuliketjussa jos arvaat sanulin, on se suoraan ensimmäinen arvaus seuraavaan peliin. Näin joudut sopeutumaan vaihtuviin alkuarvauksiin, ja peli on hieman vaikeampi.")}
             </p>
             <p>
-                {"Nelulissa ratkaiset samalla kertaa neljää eri sanulia samoilla arvauksilla. Tavoite on saada kaikki neljä sanulia ratkaistua yhdeksällä arvauksella."}
+                {t("Zen-pelissä voit pelata rauhassa ilman putkia. Arvauksia voi perua, ja tulokset pidetään erillään muista tilastoistasi.")}
             </p>
             <p>
-                {"Sanulistoja muokkailen aina välillä käyttäjien ehdotusten perusteella, ja voit jättää omat ehdotuksesi sanuleihin "}
-                <a class="link" href={FORMS_LINK_TEMPLATE_ADD}>{"täällä"}</a>
-                {". Kiitos kaikille ehdotuksia jättäneille ja sanulistojen kasaamisessa auttaneille henkilöille!"}
+                {t("Aikapaineessa jokaiselle arvaukselle on rajattu aika, joka lyhenee rivi riviltä minuutista kymmeneen sekuntiin. Jos aika loppuu kesken, menetät rivin.")}
             </p>
-        </div>
+            <p>
+                {t("Nelulissa ratkaiset samalla kertaa neljää eri sanulia samoilla arvauksilla. Tavoite on saada kaikki neljä sanulia ratkaistua yhdeksällä arvauksella.")}
+            </p>
+            <p>
+                {t("Sanulistoja muokkailen aina välillä käyttäjien ehdotusten perusteella, ja voit jättää omat ehdotuksesi sanuleihin ")}
+                <a class="link" href={FORMS_LINK_TEMPLATE_ADD}>{t("täällä")}</a>
+                {t(". Kiitos kaikille ehdotuksia jättäneille ja sanulistojen kasaamisessa auttaneille henkilöille!")}
+            </p>
+        </Modal>
     }
 }
 
@@ -110,17 +279,75 @@ pub struct MenuModalProps {
     pub current_word_list: WordList,
     pub allow_profanities: bool,
     pub theme: Theme,
+    pub share_theme: Option<Theme>,
+    pub share_options: ShareOptions,
+    pub ui_scale: UiScale,
+    pub animation_speed: AnimationSpeed,
+    pub keyboard_hints: KeyboardHints,
+    pub keyboard_layout: KeyboardLayout,
+    pub scoring: bool,
+    // Points of the current mode, None while scoring is off
+    pub mode_points: Option<ModePoints>,
+    pub show_candidates: bool,
+    pub show_letter_frequencies: bool,
+    pub show_alphabet: bool,
+    pub swipe_gestures: bool,
+    pub pressure_time_limit: Option<u32>,
 
     pub max_streak: usize,
+    pub streak_freezes: usize,
     pub total_played: usize,
     pub total_solved: usize,
+    pub zen_statistics: Statistics,
+    pub pressure_statistics: Statistics,
+    pub relay_statistics: RelayStatistics,
+    pub marathon_best: usize,
+    pub bot_statistics: Statistics,
+    pub average_daily_duration: Option<i64>,
+    pub openers: Vec<OpenerStatistics>,
+    pub letter_accuracy: Vec<LetterAccuracy>,
+    pub recent_daily_words: Vec<DailyWordHistory>,
+    pub history_density: HistoryDensity,
+    pub language: Language,
+    pub puzzle_language: Language,
+    pub key_substitutions: BTreeMap<char, char>,
+    pub key_bindings: KeyBindings,
+    pub voice_input: bool,
+    pub evening_word: bool,
+    pub daily_hard_mode: bool,
+    pub rollover: Rollover,
+    pub leaderboard: bool,
+    pub analytics: bool,
+    pub daily_reminder: Option<u32>,
 }
 
 #[function_component(MenuModal)]
 pub fn menu_modal(props: &MenuModalProps) -> Html {
     let callback = props.callback.clone();
-    let today = Local::now().naive_local().date();
-    let toggle_menu = onmousedown!(callback, Msg::ToggleMenu);
+    let today = rollover::today();
+    let toggle_shortcuts = onmousedown!(callback, Msg::ToggleShortcuts);
+    let toggle_changelog = onmousedown!(callback, Msg::ToggleChangelog);
+    let toggle_history = onmousedown!(callback, Msg::ToggleHistory);
+    let toggle_reverse = onmousedown!(callback, Msg::ToggleReverse);
+    let toggle_year_review = onmousedown!(callback, Msg::ToggleYearReview);
+    let export_bug_report = onmousedown!(callback, Msg::ExportBugReport);
+    let toggle_race = onmousedown!(callback, Msg::ToggleRace);
+    let toggle_bot_race = onmousedown!(callback, Msg::ToggleBotRace);
+    let toggle_tournament = onmousedown!(callback, Msg::ToggleTournament);
+    let start_broadcast = onmousedown!(callback, Msg::StartBroadcast);
+    let change_evening_word_yes = onmousedown!(callback, Msg::ChangeEveningWord(true));
+    let change_evening_word_no = onmousedown!(callback, Msg::ChangeEveningWord(false));
+    let change_daily_hard_mode_yes = onmousedown!(callback, Msg::ChangeDailyHardMode(true));
+    let change_daily_hard_mode_no = onmousedown!(callback, Msg::ChangeDailyHardMode(false));
+    let change_rollover_local = onmousedown!(callback, Msg::ChangeRollover(Rollover::Local));
+    let change_rollover_finnish = onmousedown!(callback, Msg::ChangeRollover(Rollover::Finnish));
+    let change_leaderboard_yes = onmousedown!(callback, Msg::ChangeLeaderboard(true));
+    let change_leaderboard_no = onmousedown!(callback, Msg::ChangeLeaderboard(false));
+    let change_analytics_yes = onmousedown!(callback, Msg::ChangeAnalytics(true));
+    let change_analytics_no = onmousedown!(callback, Msg::ChangeAnalytics(false));
+    let change_voice_input_yes = onmousedown!(callback, Msg::ChangeVoiceInput(true));
+    let change_voice_input_no = onmousedown!(callback, Msg::ChangeVoiceInput(false));
+    let change_daily_reminder_off = onmousedown!(callback, Msg::ChangeDailyReminder(None));
 
     let change_word_length_5 = onmousedown!(callback, Msg::ChangeWordLength(5));
     let change_word_length_6 = onmousedown!(callback, Msg::ChangeWordLength(6));
@@ -129,8 +356,41 @@ pub fn menu_modal(props: &MenuModalProps) -> Html {
     let change_game_mode_relay = onmousedown!(callback, Msg::ChangeGameMode(GameMode::Relay));
     let change_game_mode_daily =
         onmousedown!(callback, Msg::ChangeGameMode(GameMode::DailyWord(today)));
+    let change_game_mode_weekly = onmousedown!(
+        callback,
+        Msg::ChangeGameMode(GameMode::Weekly(Sanuli::get_week_start(today)))
+    );
+    let change_game_mode_evening =
+        onmousedown!(callback, Msg::ChangeGameMode(GameMode::EveningWord(today)));
+    let change_game_mode_double_daily =
+        onmousedown!(callback, Msg::ChangeGameMode(GameMode::DoubleDaily(today)));
     let change_game_mode_quadruple =
         onmousedown!(callback, Msg::ChangeGameMode(GameMode::Quadruple));
+    let change_game_mode_zen = onmousedown!(callback, Msg::ChangeGameMode(GameMode::Zen));
+    let change_game_mode_pressure = onmousedown!(callback, Msg::ChangeGameMode(GameMode::Pressure));
+    let change_game_mode_deduction =
+        onmousedown!(callback, Msg::ChangeGameMode(GameMode::Deduction));
+    let change_game_mode_marathon = onmousedown!(callback, Msg::ChangeGameMode(GameMode::Marathon));
+    let change_game_mode_blind = onmousedown!(callback, Msg::ChangeGameMode(GameMode::Blind));
+    let change_game_mode_definition =
+        onmousedown!(callback, Msg::ChangeGameMode(GameMode::Definition));
+
+    let change_show_candidates_yes = onmousedown!(callback, Msg::ChangeShowCandidates(true));
+    let change_show_candidates_no = onmousedown!(callback, Msg::ChangeShowCandidates(false));
+    let change_show_letter_frequencies_yes =
+        onmousedown!(callback, Msg::ChangeShowLetterFrequencies(true));
+    let change_show_letter_frequencies_no =
+        onmousedown!(callback, Msg::ChangeShowLetterFrequencies(false));
+    let change_show_alphabet_yes = onmousedown!(callback, Msg::ChangeShowAlphabet(true));
+    let change_show_alphabet_no = onmousedown!(callback, Msg::ChangeShowAlphabet(false));
+    let change_swipe_gestures_yes = onmousedown!(callback, Msg::ChangeSwipeGestures(true));
+    let change_swipe_gestures_no = onmousedown!(callback, Msg::ChangeSwipeGestures(false));
+    let change_pressure_time_limit_shrinking =
+        onmousedown!(callback, Msg::ChangePressureTimeLimit(None));
+    let change_pressure_time_limit_30 =
+        onmousedown!(callback, Msg::ChangePressureTimeLimit(Some(30)));
+    let change_pressure_time_limit_15 =
+        onmousedown!(callback, Msg::ChangePressureTimeLimit(Some(15)));
 
     let change_word_list_easy = onmousedown!(callback, Msg::ChangeWordList(WordList::Easy));
     let change_word_list_common = onmousedown!(callback, Msg::ChangeWordList(WordList::Common));
@@ -139,57 +399,98 @@ pub fn menu_modal(props: &MenuModalProps) -> Html {
     let change_allow_profanities_yes = onmousedown!(callback, Msg::ChangeAllowProfanities(true));
     let change_allow_profanities_no = onmousedown!(callback, Msg::ChangeAllowProfanities(false));
 
-    let change_theme_dark = onmousedown!(callback, Msg::ChangeTheme(Theme::Dark));
-    let change_theme_colorblind = onmousedown!(callback, Msg::ChangeTheme(Theme::Colorblind));
+    let change_share_theme_auto = onmousedown!(callback, Msg::ChangeShareTheme(None));
+    let change_ui_scale_small = onmousedown!(callback, Msg::ChangeUiScale(UiScale::Small));
+    let change_ui_scale_normal = onmousedown!(callback, Msg::ChangeUiScale(UiScale::Normal));
+    let change_ui_scale_large = onmousedown!(callback, Msg::ChangeUiScale(UiScale::Large));
+    let change_animation_speed_off =
+        onmousedown!(callback, Msg::ChangeAnimationSpeed(AnimationSpeed::Off));
+    let change_animation_speed_fast =
+        onmousedown!(callback, Msg::ChangeAnimationSpeed(AnimationSpeed::Fast));
+    let change_animation_speed_normal =
+        onmousedown!(callback, Msg::ChangeAnimationSpeed(AnimationSpeed::Normal));
+
+    let change_keyboard_layout_qwerty =
+        onmousedown!(callback, Msg::ChangeKeyboardLayout(KeyboardLayout::Qwerty));
+    let change_keyboard_layout_alphabetical = onmousedown!(
+        callback,
+        Msg::ChangeKeyboardLayout(KeyboardLayout::Alphabetical)
+    );
+
+    let change_scoring_yes = onmousedown!(callback, Msg::ChangeScoring(true));
+    let change_scoring_no = onmousedown!(callback, Msg::ChangeScoring(false));
 
-    let is_hide_settings = matches!(props.game_mode, GameMode::DailyWord(_) | GameMode::Shared);
+    let change_keyboard_hints_full =
+        onmousedown!(callback, Msg::ChangeKeyboardHints(KeyboardHints::Full));
+    let change_keyboard_hints_light =
+        onmousedown!(callback, Msg::ChangeKeyboardHints(KeyboardHints::Light));
+    let change_keyboard_hints_off =
+        onmousedown!(callback, Msg::ChangeKeyboardHints(KeyboardHints::Off));
+
+    let change_history_density_list =
+        onmousedown!(callback, Msg::ChangeHistoryDensity(HistoryDensity::List));
+    let change_history_density_thumbnails = onmousedown!(
+        callback,
+        Msg::ChangeHistoryDensity(HistoryDensity::Thumbnails)
+    );
+
+    let change_language_finnish = onmousedown!(callback, Msg::ChangeLanguage(Language::Finnish));
+    let change_language_swedish = onmousedown!(callback, Msg::ChangeLanguage(Language::Swedish));
+    let change_language_english = onmousedown!(callback, Msg::ChangeLanguage(Language::English));
+
+    let is_hide_settings = props.game_mode.is_dated() || props.game_mode == GameMode::Shared;
+    let substituted_letters = props
+        .puzzle_language
+        .alphabet()
+        .filter(|letter| !letter.is_ascii())
+        .copied()
+        .collect::<Vec<char>>();
 
     html! {
-        <div class="modal">
-            <span onmousedown={toggle_menu} class="modal-close">{"✖"}</span>
+        <Modal callback={props.callback.clone()}>
             {if !is_hide_settings {
                 html! {
                     <>
                         <div>
-                            <label class="label">{"Sanulien pituus:"}</label>
+                            <label class="label">{t("Sanulien pituus:")}</label>
                             <div class="select-container">
                                 <button class={classes!("select", (props.word_length == 5).then(|| Some("select-active")))}
                                     onmousedown={change_word_length_5}>
-                                    {"5 merkkiä"}
+                                    {t("5 merkkiä")}
                                 </button>
                                 <button class={classes!("select", (props.word_length == 6).then(|| Some("select-active")))}
                                     onmousedown={change_word_length_6}>
-                                    {"6 merkkiä"}
+                                    {t("6 merkkiä")}
                                 </button>
                             </div>
                         </div>
                         <div>
-                            <label class="label">{"Sanulista:"}</label>
+                            <label class="label">{t("Sanulista:")}</label>
                             <div class="select-container">
                                 <button class={classes!("select", (props.current_word_list == WordList::Easy).then(|| Some("select-active")))}
                                     onmousedown={change_word_list_easy}>
-                                    {"Helppo"}
+                                    {t("Helppo")}
                                 </button>
                                 <button class={classes!("select", (props.current_word_list == WordList::Common).then(|| Some("select-active")))}
                                     onmousedown={change_word_list_common}>
-                                    {"Tavallinen"}
+                                    {t("Tavallinen")}
                                 </button>
                                 <button class={classes!("select", (props.current_word_list == WordList::Full).then(|| Some("select-active")))}
                                     onmousedown={change_word_list_full}>
-                                    {"Vaikea"}
+                                    {t("Vaikea")}
                                 </button>
                             </div>
                         </div>
                         <div>
-                            <label class="label">{"Rumat sanulit:"}</label>
+                            <label class="label">{t("Rumat sanulit:")}</label>
                             <div class="select-container">
                                 <button class={classes!("select", (!props.allow_profanities).then(|| Some("select-active")))}
                                     onmousedown={change_allow_profanities_no}>
-                                    {"Ei"}
+                                    {t("Ei")}
                                 </button>
                                 <button class={classes!("select", (props.allow_profanities).then(|| Some("select-active")))}
                                     onmousedown={change_allow_profanities_yes}>
-                                    {"Kyllä"}
+                                    {t("Kyllä")}
                                 </button>
                             </div>
                         </div>
@@ -199,49 +500,1443 @@ pub fn menu_modal(props: &MenuModalProps) -> Html {
                 html! {}
             }}
             <div>
-                <label class="label">{"Pelimuoto:"}</label>
+                <label class="label">{t("Pelimuoto:")}</label>
                 <div class="select-container">
                     <button class={classes!("select", (props.game_mode == GameMode::Classic).then(|| Some("select-active")))}
                         onmousedown={change_game_mode_classic}>
-                        {"Peruspeli"}
+                        {t("Peruspeli")}
                     </button>
                     <button class={classes!("select", (props.game_mode == GameMode::Relay).then(|| Some("select-active")))}
                         onmousedown={change_game_mode_relay}>
-                        {"Sanuliketju"}
+                        {t("Sanuliketju")}
                     </button>
                     <button class={classes!("select", (props.game_mode == GameMode::Quadruple).then(|| Some("select-active")))}
                         onmousedown={change_game_mode_quadruple}>
                         {"Neluli"}
                     </button>
+                    <button class={classes!("select", (props.game_mode == GameMode::Zen).then(|| Some("select-active")))}
+                        onmousedown={change_game_mode_zen}>
+                        {"Zen"}
+                    </button>
+                    <button class={classes!("select", (props.game_mode == GameMode::Pressure).then(|| Some("select-active")))}
+                        onmousedown={change_game_mode_pressure}>
+                        {t("Aikapaine")}
+                    </button>
+                    <button class={classes!("select", (props.game_mode == GameMode::Deduction).then(|| Some("select-active")))}
+                        onmousedown={change_game_mode_deduction}>
+                        {t("Päättely")}
+                    </button>
+                    <button class={classes!("select", (props.game_mode == GameMode::Marathon).then(|| Some("select-active")))}
+                        onmousedown={change_game_mode_marathon}>
+                        {t("Maraton")}
+                    </button>
+                    <button class={classes!("select", (props.game_mode == GameMode::Blind).then(|| Some("select-active")))}
+                        onmousedown={change_game_mode_blind}>
+                        {t("Sokko")}
+                    </button>
+                    if is_definition_available() {
+                        <button class={classes!("select", (props.game_mode == GameMode::Definition).then(|| Some("select-active")))}
+                            onmousedown={change_game_mode_definition}>
+                            {t("Määritelmä")}
+                        </button>
+                    }
                     <button class={classes!("select", matches!(props.game_mode, GameMode::DailyWord(_)).then(|| Some("select-active")))}
                         onclick={change_game_mode_daily}>
-                        {"Päivän sanuli"}
+                        {t("Päivän sanuli")}
+                    </button>
+                    <button class={classes!("select", matches!(props.game_mode, GameMode::DoubleDaily(_)).then(|| Some("select-active")))}
+                        onclick={change_game_mode_double_daily}>
+                        {t("Tuplasanuli")}
+                    </button>
+                    <button class={classes!("select", matches!(props.game_mode, GameMode::Weekly(_)).then(|| Some("select-active")))}
+                        onclick={change_game_mode_weekly}>
+                        {t("Viikkohaaste")}
+                    </button>
+                    {if props.evening_word {
+                        html! {
+                            <button class={classes!("select", matches!(props.game_mode, GameMode::EveningWord(_)).then(|| Some("select-active")))}
+                                onclick={change_game_mode_evening}>
+                                {t("Iltasanuli")}
+                            </button>
+                        }
+                    } else {
+                        html! {}
+                    }}
+                </div>
+            </div>
+            <div>
+                <label class="label">{t("Toinen päivän sanuli illaksi:")}</label>
+                <div class="select-container">
+                    <button class={classes!("select", (!props.evening_word).then(|| Some("select-active")))}
+                        onmousedown={change_evening_word_no}>
+                        {t("Ei")}
+                    </button>
+                    <button class={classes!("select", props.evening_word.then(|| Some("select-active")))}
+                        onmousedown={change_evening_word_yes}>
+                        {t("Kyllä")}
+                    </button>
+                </div>
+            </div>
+            <div>
+                <label class="label">{t("Päivän sanuli vaikeana:")}</label>
+                <div class="select-container">
+                    <button class={classes!("select", (!props.daily_hard_mode).then(|| Some("select-active")))}
+                        onmousedown={change_daily_hard_mode_no}>
+                        {t("Ei")}
+                    </button>
+                    <button class={classes!("select", props.daily_hard_mode.then(|| Some("select-active")))}
+                        onmousedown={change_daily_hard_mode_yes}>
+                        {t("Kyllä")}
+                    </button>
+                </div>
+                <p class="setting-description">{t("Paljastettujen vihjeiden on oltava mukana seuraavissa arvauksissa. Vaihto koskee vain arvaamatonta päivän sanulia.")}</p>
+            </div>
+            <div>
+                <label class="label">{t("Päivän sanuli vaihtuu:")}</label>
+                <div class="select-container">
+                    <button class={classes!("select", (props.rollover == Rollover::Local).then(|| Some("select-active")))}
+                        onmousedown={change_rollover_local}>
+                        {t("Oman ajan keskiyöllä")}
+                    </button>
+                    <button class={classes!("select", (props.rollover == Rollover::Finnish).then(|| Some("select-active")))}
+                        onmousedown={change_rollover_finnish}>
+                        {t("Suomen ajan keskiyöllä")}
+                    </button>
+                </div>
+                <p class="setting-description">{t("Suomen ajalla päivän sanuli on sama kuin Suomessa pelaavilla, vaikka olisit matkoilla.")}</p>
+            </div>
+            {if props.game_mode == GameMode::Zen {
+                html! {
+                    <div>
+                        <label class="label">{t("Näytä mahdolliset sanulit:")}</label>
+                        <div class="select-container">
+                            <button class={classes!("select", (!props.show_candidates).then(|| Some("select-active")))}
+                                onmousedown={change_show_candidates_no}>
+                                {t("Ei")}
+                            </button>
+                            <button class={classes!("select", (props.show_candidates).then(|| Some("select-active")))}
+                                onmousedown={change_show_candidates_yes}>
+                                {t("Kyllä")}
+                            </button>
+                        </div>
+                    </div>
+                }
+            } else {
+                html! {}
+            }}
+            {if props.game_mode == GameMode::Pressure {
+                html! {
+                    <div>
+                        <label class="label">{t("Aikaa arvaukselle:")}</label>
+                        <div class="select-container">
+                            <button class={classes!("select", props.pressure_time_limit.is_none().then(|| Some("select-active")))}
+                                onmousedown={change_pressure_time_limit_shrinking}>
+                                {t("Kiristyvä")}
+                            </button>
+                            <button class={classes!("select", (props.pressure_time_limit == Some(30)).then(|| Some("select-active")))}
+                                onmousedown={change_pressure_time_limit_30}>
+                                {"30 s"}
+                            </button>
+                            <button class={classes!("select", (props.pressure_time_limit == Some(15)).then(|| Some("select-active")))}
+                                onmousedown={change_pressure_time_limit_15}>
+                                {"15 s"}
+                            </button>
+                        </div>
+                    </div>
+                }
+            } else {
+                html! {}
+            }}
+            <div>
+                <label class="label">{t("Näytä kirjainten yleisyys:")}</label>
+                <div class="select-container">
+                    <button class={classes!("select", (!props.show_letter_frequencies).then(|| Some("select-active")))}
+                        onmousedown={change_show_letter_frequencies_no}>
+                        {t("Ei")}
+                    </button>
+                    <button class={classes!("select", props.show_letter_frequencies.then(|| Some("select-active")))}
+                        onmousedown={change_show_letter_frequencies_yes}>
+                        {t("Kyllä")}
+                    </button>
+                </div>
+            </div>
+            <div>
+                <label class="label">{t("Näytä aakkoset näppäimistön yllä:")}</label>
+                <div class="select-container">
+                    <button class={classes!("select", (!props.show_alphabet).then(|| Some("select-active")))}
+                        onmousedown={change_show_alphabet_no}>
+                        {t("Ei")}
+                    </button>
+                    <button class={classes!("select", props.show_alphabet.then(|| Some("select-active")))}
+                        onmousedown={change_show_alphabet_yes}>
+                        {t("Kyllä")}
                     </button>
                 </div>
             </div>
             <div>
-                <label class="label">{"Omat tilastosi:"}</label>
+                <label class="label">{t("Pyyhkäisyeleet:")}</label>
+                <div class="select-container">
+                    <button class={classes!("select", (!props.swipe_gestures).then(|| Some("select-active")))}
+                        onmousedown={change_swipe_gestures_no}>
+                        {t("Ei")}
+                    </button>
+                    <button class={classes!("select", props.swipe_gestures.then(|| Some("select-active")))}
+                        onmousedown={change_swipe_gestures_yes}>
+                        {t("Kyllä")}
+                    </button>
+                </div>
+                <p class="setting-description">{t("Pyyhkäise laudalla vasemmalle poistaaksesi kirjaimen, oikealle arvataksesi ja alas aloittaaksesi uuden pelin.")}</p>
+            </div>
+            <div>
+                <label class="label">{t("Näppäimistön vihjeet:")}</label>
+                <div class="select-container">
+                    <button class={classes!("select", (props.keyboard_hints == KeyboardHints::Full).then(|| Some("select-active")))}
+                        onmousedown={change_keyboard_hints_full}>
+                        {t("Täysi")}
+                    </button>
+                    <button class={classes!("select", (props.keyboard_hints == KeyboardHints::Light).then(|| Some("select-active")))}
+                        onmousedown={change_keyboard_hints_light}>
+                        {t("Kevyt")}
+                    </button>
+                    <button class={classes!("select", (props.keyboard_hints == KeyboardHints::Off).then(|| Some("select-active")))}
+                        onmousedown={change_keyboard_hints_off}>
+                        {t("Ei vihjeitä")}
+                    </button>
+                </div>
+                {if props.keyboard_hints == KeyboardHints::Off {
+                    html! {
+                        <p class="setting-description">{t("Näppäimistö pysyy värittömänä, vain ruudukko värittyy. Jaettu tulos merkitään 🧠-merkillä.")}</p>
+                    }
+                } else {
+                    html! {}
+                }}
+            </div>
+            <div>
+                <label class="label">{
+                    match props.keyboard_hints {
+                        KeyboardHints::Full => t("Omat tilastosi:"),
+                        KeyboardHints::Light => t("Omat tilastosi (kevyet vihjeet):"),
+                        KeyboardHints::Off => t("Omat tilastosi (ei vihjeitä):"),
+                    }
+                }</label>
                 <ul>
-                    <li class="statistics">{format!("Pisin putki: {}", props.max_streak)}</li>
-                    <li class="statistics">{format!("Pelatut sanulit: {}", props.total_played)}</li>
-                    <li class="statistics">{format!("Ratkaistut sanulit: {}", props.total_solved)}</li>
+                    <li class="statistics">{tf("Pisin putki: {}", &[&props.max_streak])}</li>
+                    <li class="statistics">{tf("Lomapäivät: {}", &[&props.streak_freezes])}</li>
+                    <li class="statistics">{tf("Pelatut sanulit: {}", &[&props.total_played])}</li>
+                    <li class="statistics">{tf("Ratkaistut sanulit: {}", &[&props.total_solved])}</li>
+                    <li class="statistics">{tf("Zen-sanulit: {} / {} ratkaistu", &[&props.zen_statistics.total_solved, &props.zen_statistics.total_played])}</li>
+                    <li class="statistics">{tf("Aikapaine: {} / {} ratkaistu, pisin putki {}", &[&props.pressure_statistics.total_solved, &props.pressure_statistics.total_played, &props.pressure_statistics.max_streak])}</li>
+                    <li class="statistics">{tf("Sanuliketju: ketju {}, pisin ketju {}, ketjun arvaukset {}", &[&props.relay_statistics.chain, &props.relay_statistics.longest_chain, &props.relay_statistics.chain_guesses])}</li>
+                    <li class="statistics">{tf("Maraton: ennätys {} sanaa", &[&props.marathon_best])}</li>
+                    <li class="statistics">{tf("Kilpa bottia vastaan: {} / {} voitettu", &[&props.bot_statistics.total_solved, &props.bot_statistics.total_played])}</li>
+                    {if let Some(points) = props.mode_points {
+                        html! {
+                            <li class="statistics">{tf("Pisteet ({}): {} yhteensä, paras peli {}", &[&game_mode_label(&props.game_mode), &points.total, &points.best])}</li>
+                        }
+                    } else {
+                        html! {}
+                    }}
+                    {if let Some(secs) = props.average_daily_duration {
+                        html! {
+                            <li class="statistics">{tf("Päivän sanulin keskimääräinen ratkaisuaika: {}", &[&game::format_duration(secs)])}</li>
+                        }
+                    } else {
+                        html! {}
+                    }}
                 </ul>
             </div>
+            {if !props.openers.is_empty() {
+                let best_opener = props
+                    .openers
+                    .iter()
+                    .filter(|opener| opener.games >= MIN_OPENER_GAMES)
+                    .max_by(|a, b| a.average_yield.total_cmp(&b.average_yield));
+
+                html! {
+                    <div>
+                        <label class="label">{t("Avaussanasi:")}</label>
+                        {if let Some(opener) = best_opener {
+                            html! {
+                                <p class="setting-description">
+                                    {tf("Paras avaussanasi on {}, keskimäärin {} vihreää tai keltaista ensimmäisellä rivillä.", &[&opener.word, &format!("{:.1}", opener.average_yield)])}
+                                </p>
+                            }
+                        } else {
+                            html! {}
+                        }}
+                        <ul>
+                            {props.openers.iter().take(OPENERS_SHOWN).map(|opener| {
+                                html! {
+                                    <li class="statistics">
+                                        {tf("{}: {} peliä, keskimäärin {} osumaa", &[&opener.word, &opener.games, &format!("{:.1}", opener.average_yield)])}
+                                    </li>
+                                }
+                            }).collect::<Html>()}
+                        </ul>
+                    </div>
+                }
+            } else {
+                html! {}
+            }}
+            {if !props.letter_accuracy.is_empty() {
+                html! {
+                    <div>
+                        <label class="label">{t("Sinulle hankalimmat kirjaimet:")}</label>
+                        <ul>
+                            {props.letter_accuracy.iter().take(TRICKY_LETTERS_SHOWN).map(|accuracy| {
+                                html! {
+                                    <li class="statistics">
+                                        {tf("{}: väärässä kohdassa {}, löytymättä {} / {} sanasta", &[&accuracy.letter, &accuracy.misplaced, &accuracy.missed, &accuracy.words])}
+                                    </li>
+                                }
+                            }).collect::<Html>()}
+                        </ul>
+                    </div>
+                }
+            } else {
+                html! {}
+            }}
+            {if is_leaderboard_available() {
+                html! {
+                    <div>
+                        <label class="label">{t("Lähetä päivän tulos nimettömänä tulostaulukkoon:")}</label>
+                        <div class="select-container">
+                            <button class={classes!("select", (!props.leaderboard).then(|| Some("select-active")))}
+                                onmousedown={change_leaderboard_no}>
+                                {t("Ei")}
+                            </button>
+                            <button class={classes!("select", props.leaderboard.then(|| Some("select-active")))}
+                                onmousedown={change_leaderboard_yes}>
+                                {t("Kyllä")}
+                            </button>
+                        </div>
+                    </div>
+                }
+            } else {
+                html! {}
+            }}
+            {if analytics::is_configured() {
+                html! {
+                    <div>
+                        <label class="label">{t("Lähetä nimettömiä käyttötilastoja:")}</label>
+                        <p class="setting-description">{t("Pelin aloitukset ja tulokset ilman tunnisteita tai arvattuja sanoja.")}</p>
+                        <div class="select-container">
+                            <button class={classes!("select", (!props.analytics).then(|| Some("select-active")))}
+                                onmousedown={change_analytics_no}>
+                                {t("Ei")}
+                            </button>
+                            <button class={classes!("select", props.analytics.then(|| Some("select-active")))}
+                                onmousedown={change_analytics_yes}>
+                                {t("Kyllä")}
+                            </button>
+                        </div>
+                    </div>
+                }
+            } else {
+                html! {}
+            }}
+            {voting_panel()}
+            {if !props.recent_daily_words.is_empty() {
+                html! {
+                    <div>
+                        <label class="label">{t("Viimeisimmät päivän sanulit:")}</label>
+                        <div class="select-container">
+                            <button class={classes!("select", (props.history_density == HistoryDensity::List).then(|| Some("select-active")))}
+                                onmousedown={change_history_density_list}>
+                                {t("Lista")}
+                            </button>
+                            <button class={classes!("select", (props.history_density == HistoryDensity::Thumbnails).then(|| Some("select-active")))}
+                                onmousedown={change_history_density_thumbnails}>
+                                {t("Pienoiskuvat")}
+                            </button>
+                        </div>
+                        {if props.history_density == HistoryDensity::Thumbnails {
+                            html! {
+                                <div class="thumbnails">
+                                    {props.recent_daily_words.iter().map(|history| {
+                                        let date = history.date;
+                                        let show_replay = onmousedown!(callback, Msg::ShowReplay(date));
+
+                                        html! {
+                                            <div class="thumbnail-container" onmousedown={show_replay} tabindex="0" role="button"
+                                                title={history.date.format("%-d.%-m.%Y").to_string()}>
+                                                <BoardThumbnail
+                                                    patterns={history.patterns()}
+                                                    max_guesses={history.guesses.len()}
+                                                    word_length={history.word.len()}
+                                                />
+                                            </div>
+                                        }
+                                    }).collect::<Html>()}
+                                </div>
+                            }
+                        } else {
+                            html! {
+                                <ul>
+                                    {props.recent_daily_words.iter().take(RECENT_LIST_LENGTH).map(|history| {
+                                        let date = history.date;
+                                        let show_replay = onmousedown!(callback, Msg::ShowReplay(date));
+                                        let result = if history.is_winner {
+                                            format!("{}", history.current_guess + 1)
+                                        } else {
+                                            "X".to_owned()
+                                        };
+
+                                        let duration = history
+                                            .duration_secs()
+                                            .map(|secs| format!(" ⏱️ {}", game::format_duration(secs)))
+                                            .unwrap_or_default();
+
+                                        html! {
+                                            <li class="statistics">
+                                                {format!(
+                                                    "{}: {}/{}{}{} ",
+                                                    date.format("%-d.%-m.%Y"),
+                                                    result,
+                                                    history.guesses.len(),
+                                                    if history.is_hard_mode { "*" } else { "" },
+                                                    duration
+                                                )}
+                                                <a class="link" href={"javascript:void(0)"} role="button" onmousedown={show_replay}>{t("Katso")}</a>
+                                            </li>
+                                        }
+                                    }).collect::<Html>()}
+                                </ul>
+                            }
+                        }}
+                    </div>
+                }
+            } else {
+                html! {}
+            }}
+            <div>
+                <label class="label">{t("Teema:")}</label>
+                <div class="select-container">
+                    {THEMES.iter().map(|&theme| {
+                        let change_theme = onmousedown!(callback, Msg::ChangeTheme(theme));
+                        html! {
+                            <button class={classes!("select", (props.theme == theme).then(|| Some("select-active")))}
+                                onmousedown={change_theme}>
+                                {t(theme.name())}
+                            </button>
+                        }
+                    }).collect::<Html>()}
+                </div>
+            </div>
+            <div>
+                <label class="label">{t("Jaetun tuloksen värit:")}</label>
+                <div class="select-container">
+                    <button class={classes!("select", props.share_theme.is_none().then(|| Some("select-active")))}
+                        onmousedown={change_share_theme_auto}>
+                        {t("Teeman mukaan")}
+                    </button>
+                    {THEMES.iter().map(|&theme| {
+                        let change_share_theme = onmousedown!(callback, Msg::ChangeShareTheme(Some(theme)));
+                        html! {
+                            <button class={classes!("select", (props.share_theme == Some(theme)).then(|| Some("select-active")))}
+                                title={theme.share_emojis().concat()}
+                                onmousedown={change_share_theme}>
+                                {t(theme.name())}
+                            </button>
+                        }
+                    }).collect::<Html>()}
+                </div>
+            </div>
+            <div>
+                <label class="label">{t("Jaetun tuloksen tiedot:")}</label>
+                <div class="select-container">
+                    {SHARE_OPTIONS.iter().map(|&(option, label)| {
+                        let toggle_share_option = onmousedown!(callback, Msg::ToggleShareOption(option));
+                        html! {
+                            <button class={classes!("select", props.share_options.is_enabled(option).then(|| Some("select-active")))}
+                                onmousedown={toggle_share_option}>
+                                {t(label)}
+                            </button>
+                        }
+                    }).collect::<Html>()}
+                </div>
+            </div>
             <div>
-                <label class="label">{"Teema:"}</label>
+                <label class="label">{t("Koko:")}</label>
                 <div class="select-container">
-                    <button class={classes!("select", (props.theme == Theme::Dark).then(|| Some("select-active")))}
-                        onmousedown={change_theme_dark}>
-                        {"Oletus"}
+                    <button class={classes!("select", (props.ui_scale == UiScale::Small).then(|| Some("select-active")))}
+                        onmousedown={change_ui_scale_small}>
+                        {t("Pieni")}
                     </button>
-                    <button class={classes!("select", (props.theme == Theme::Colorblind).then(|| Some("select-active")))}
-                        onmousedown={change_theme_colorblind}>
-                        {"Värisokeille"}
+                    <button class={classes!("select", (props.ui_scale == UiScale::Normal).then(|| Some("select-active")))}
+                        onmousedown={change_ui_scale_normal}>
+                        {t("Normaali")}
+                    </button>
+                    <button class={classes!("select", (props.ui_scale == UiScale::Large).then(|| Some("select-active")))}
+                        onmousedown={change_ui_scale_large}>
+                        {t("Suuri")}
                     </button>
                 </div>
+                <p class="setting-description">{t("Ruutujen, näppäimistön ja tekstien koko.")}</p>
+            </div>
+            <div>
+                <label class="label">{t("Animaatiot:")}</label>
+                <div class="select-container">
+                    <button class={classes!("select", (props.animation_speed == AnimationSpeed::Off).then(|| Some("select-active")))}
+                        onmousedown={change_animation_speed_off}>
+                        {t("Pois")}
+                    </button>
+                    <button class={classes!("select", (props.animation_speed == AnimationSpeed::Fast).then(|| Some("select-active")))}
+                        onmousedown={change_animation_speed_fast}>
+                        {t("Nopeat")}
+                    </button>
+                    <button class={classes!("select", (props.animation_speed == AnimationSpeed::Normal).then(|| Some("select-active")))}
+                        onmousedown={change_animation_speed_normal}>
+                        {t("Normaalit")}
+                    </button>
+                </div>
+            </div>
+            <div>
+                <label class="label">{t("Näppäimistö:")}</label>
+                <div class="select-container">
+                    <button class={classes!("select", (props.keyboard_layout == KeyboardLayout::Qwerty).then(|| Some("select-active")))}
+                        onmousedown={change_keyboard_layout_qwerty}>
+                        {"QWERTY"}
+                    </button>
+                    <button class={classes!("select", (props.keyboard_layout == KeyboardLayout::Alphabetical).then(|| Some("select-active")))}
+                        onmousedown={change_keyboard_layout_alphabetical}>
+                        {t("Aakkosjärjestys")}
+                    </button>
+                </div>
+            </div>
+            <div>
+                <label class="label">{t("Pisteet:")}</label>
+                <div class="select-container">
+                    <button class={classes!("select", (!props.scoring).then(|| Some("select-active")))}
+                        onmousedown={change_scoring_no}>
+                        {t("Ei")}
+                    </button>
+                    <button class={classes!("select", (props.scoring).then(|| Some("select-active")))}
+                        onmousedown={change_scoring_yes}>
+                        {t("Kyllä")}
+                    </button>
+                </div>
+                <p class="setting-description">{t("Pisteitä saa vähistä arvauksista, nopeasta ratkaisusta, putkesta ja vaikeasta tilasta. Pisteet pidetään pelimuodoittain.")}</p>
+            </div>
+            <div>
+                <label class="label">{t("Kieli:")}</label>
+                <div class="select-container">
+                    <button class={classes!("select", (props.language == Language::Finnish).then(|| Some("select-active")))}
+                        onmousedown={change_language_finnish}>
+                        {"Suomi"}
+                    </button>
+                    <button class={classes!("select", (props.language == Language::Swedish).then(|| Some("select-active")))}
+                        onmousedown={change_language_swedish}>
+                        {"Svenska"}
+                    </button>
+                    <button class={classes!("select", (props.language == Language::English).then(|| Some("select-active")))}
+                        onmousedown={change_language_english}>
+                        {"English"}
+                    </button>
+                </div>
+            </div>
+            {
+                if puzzle_languages().len() > 1 {
+                    html! {
+                        <div>
+                            <label class="label">{t("Sanulien kieli:")}</label>
+                            <div class="select-container">
+                                {puzzle_languages().into_iter().map(|language| {
+                                    let change_puzzle_language = onmousedown!(callback, Msg::ChangePuzzleLanguage(language));
+                                    html! {
+                                        <button class={classes!("select", (props.puzzle_language == language).then(|| Some("select-active")))}
+                                            onmousedown={change_puzzle_language}>
+                                            {language.name()}
+                                        </button>
+                                    }
+                                }).collect::<Html>()}
+                            </div>
+                        </div>
+                    }
+                } else {
+                    html! {}
+                }
+            }
+            {if !substituted_letters.is_empty() {
+                html! {
+                    <div>
+                        <label class="label">{t("Korvaavat näppäimet:")}</label>
+                        <p class="input-mapping-info">{t("Jos näppäimistöstäsi puuttuu kirjaimia, voit kirjoittaa ne näillä näppäimillä. AltGr-yhdistelmät toimivat aina.")}</p>
+                        {substituted_letters.iter().map(|letter| {
+                            let letter = *letter;
+                            let current = props.key_substitutions.iter()
+                                .find(|(_, substitute)| **substitute == letter)
+                                .map(|(key, _)| *key);
+                            let clear_substitution = onmousedown!(callback, Msg::ChangeKeySubstitution(letter, None));
+
+                            html! {
+                                <div class="select-container">
+                                    <span class="input-mapping-letter">{letter}</span>
+                                    <button class={classes!("select", current.is_none().then(|| Some("select-active")))}
+                                        onmousedown={clear_substitution}>
+                                        {t("Ei")}
+                                    </button>
+                                    {SUBSTITUTE_KEYS.iter().map(|key| {
+                                        let key = *key;
+                                        let change_substitution = onmousedown!(callback, Msg::ChangeKeySubstitution(letter, Some(key)));
+                                        html! {
+                                            <button class={classes!("select", (current == Some(key)).then(|| Some("select-active")))}
+                                                onmousedown={change_substitution}>
+                                                {key}
+                                            </button>
+                                        }
+                                    }).collect::<Html>()}
+                                </div>
+                            }
+                        }).collect::<Html>()}
+                    </div>
+                }
+            } else {
+                html! {}
+            }}
+            {if speech::is_supported() {
+                html! {
+                    <div>
+                        <label class="label">{t("Puhesyöte:")}</label>
+                        <div class="select-container">
+                            <button class={classes!("select", (!props.voice_input).then(|| Some("select-active")))}
+                                onmousedown={change_voice_input_no}>
+                                {t("Ei")}
+                            </button>
+                            <button class={classes!("select", props.voice_input.then(|| Some("select-active")))}
+                                onmousedown={change_voice_input_yes}>
+                                {t("Kyllä")}
+                            </button>
+                        </div>
+                    </div>
+                }
+            } else {
+                html! {}
+            }}
+            {if notifications::is_supported() {
+                html! {
+                    <div>
+                        <label class="label">{t("Muistuta päivän sanulista:")}</label>
+                        <div class="select-container">
+                            <button class={classes!("select", props.daily_reminder.is_none().then(|| Some("select-active")))}
+                                onmousedown={change_daily_reminder_off}>
+                                {t("Ei")}
+                            </button>
+                            {DAILY_REMINDER_HOURS.iter().map(|hour| {
+                                let change_daily_reminder = onmousedown!(callback, Msg::ChangeDailyReminder(Some(*hour)));
+
+                                html! {
+                                    <button class={classes!("select", (props.daily_reminder == Some(*hour)).then(|| Some("select-active")))}
+                                        onmousedown={change_daily_reminder}>
+                                        {format!("{}:00", hour)}
+                                    </button>
+                                }
+                            }).collect::<Html>()}
+                        </div>
+                    </div>
+                }
+            } else {
+                html! {}
+            }}
+            <div>
+                <label class="label">{t("Lisänäppäimet:")}</label>
+                {[(Action::Backspace, "⌫"), (Action::Enter, "Enter")].into_iter().map(|(action, label)| {
+                    let current = props.key_bindings.key(action);
+                    let clear_binding = onmousedown!(callback, Msg::ChangeKeyBinding(action, None));
+
+                    html! {
+                        <div class="select-container">
+                            <span class="input-mapping-letter">{label}</span>
+                            <button class={classes!("select", current.is_none().then(|| Some("select-active")))}
+                                onmousedown={clear_binding}>
+                                {t("Ei")}
+                            </button>
+                            {ALTERNATIVE_KEYS.iter().map(|(key, key_label)| {
+                                let change_binding = onmousedown!(callback, Msg::ChangeKeyBinding(action, Some(key.to_string())));
+                                html! {
+                                    <button class={classes!("select", (current == Some(*key)).then(|| Some("select-active")))}
+                                        onmousedown={change_binding}>
+                                        {key_label}
+                                    </button>
+                                }
+                            }).collect::<Html>()}
+                        </div>
+                    }
+                }).collect::<Html>()}
+            </div>
+            <ProfilePicker callback={props.callback.clone()} />
+            {sync_panel()}
+            <SyncCodePanel callback={props.callback.clone()} />
+            <div>
+                <button class="select" onmousedown={toggle_history}>
+                    {t("Pelihistoria")}
+                </button>
+            </div>
+            <div>
+                <button class="select" onmousedown={toggle_year_review}>
+                    {tf("{}-vuosi", &[&BRAND.name])}
+                </button>
+            </div>
+            <div>
+                <button class="select" onmousedown={toggle_reverse}>
+                    {t("Tietokone arvaa")}
+                </button>
+            </div>
+            <div>
+                <button class="select" onmousedown={toggle_shortcuts}>
+                    {t("Pikanäppäimet")}
+                </button>
+            </div>
+            {if race::is_configured() {
+                html! {
+                    <div>
+                        <button class="select" onmousedown={toggle_race}>
+                            {t("Kilpa verkossa")}
+                        </button>
+                    </div>
+                }
+            } else {
+                html! {}
+            }}
+            <div>
+                <button class="select" onmousedown={toggle_bot_race}>
+                    {t("Kilpa bottia vastaan")}
+                </button>
+            </div>
+            <div>
+                <button class="select" onmousedown={toggle_tournament}>
+                    {t("Turnaus")}
+                </button>
+            </div>
+            {if spectate::is_configured() {
+                html! {
+                    <div>
+                        <button class="select" onmousedown={start_broadcast}>
+                            {t("Jaa peli katsojille")}
+                        </button>
+                    </div>
+                }
+            } else {
+                html! {}
+            }}
+            <div>
+                <button class="select" onmousedown={toggle_changelog}>
+                    {t("Mitä uutta")}
+                </button>
+            </div>
+            <div>
+                <button class="select" onmousedown={export_bug_report}>
+                    {t("Lataa tiedot vikailmoitukseen")}
+                </button>
             </div>
             <div class="version">
-                <a class="version" href={CHANGELOG_URL} target="_blank">{ VERSION }</a>
+                <a class="version" href={CHANGELOG_URL} target="_blank">{ version::current() }</a>
+            </div>
+        </Modal>
+    }
+}
+
+#[derive(Properties, Clone, PartialEq)]
+pub struct ShortcutsModalProps {
+    pub callback: Callback<Msg>,
+    pub key_substitutions: BTreeMap<char, char>,
+    pub key_bindings: KeyBindings,
+}
+
+#[function_component(ShortcutsModal)]
+pub fn shortcuts_modal(props: &ShortcutsModalProps) -> Html {
+    html! {
+        <Modal callback={props.callback.clone()}>
+            <label class="label">{t("Pikanäppäimet")}</label>
+            <ul class="shortcuts">
+                {SHORTCUTS.iter().map(|shortcut| {
+                    html! {
+                        <li class="statistics">
+                            <kbd>{shortcut.label}</kbd>{" "}{t(shortcut.description)}
+                        </li>
+                    }
+                }).collect::<Html>()}
+                {SHORTCUTS.iter().filter_map(|shortcut| {
+                    let key = props.key_bindings.key(shortcut.action)?;
+                    let label = ALTERNATIVE_KEYS.iter().find(|(alternative, _)| *alternative == key)?.1;
+
+                    Some(html! {
+                        <li class="statistics">
+                            <kbd>{label}</kbd>{" "}{t(shortcut.description)}
+                        </li>
+                    })
+                }).collect::<Html>()}
+                {props.key_substitutions.iter().map(|(key, letter)| {
+                    html! {
+                        <li class="statistics">
+                            <kbd>{key}</kbd>{" "}{tf("Kirjoita {}", &[letter])}
+                        </li>
+                    }
+                }).collect::<Html>()}
+            </ul>
+        </Modal>
+    }
+}
+
+#[derive(Properties, Clone, PartialEq)]
+pub struct ChangelogModalProps {
+    pub callback: Callback<Msg>,
+}
+
+#[function_component(ChangelogModal)]
+pub fn changelog_modal(props: &ChangelogModalProps) -> Html {
+    html! {
+        <Modal callback={props.callback.clone()}>
+            <label class="label">{t("Mitä uutta")}</label>
+            {version::releases().into_iter().take(RECENT_RELEASES).map(|release| {
+                html! {
+                    <div class="changelog">
+                        <h3>{release.title}</h3>
+                        {release.sections.into_iter().map(|(heading, items)| {
+                            html! {
+                                <>
+                                    <h4>{heading}</h4>
+                                    <ul>
+                                        {items.into_iter().map(|item| html! { <li>{item}</li> }).collect::<Html>()}
+                                    </ul>
+                                </>
+                            }
+                        }).collect::<Html>()}
+                    </div>
+                }
+            }).collect::<Html>()}
+            <a class="link" href={CHANGELOG_URL} target="_blank">{t("Koko muutosloki")}</a>
+        </Modal>
+    }
+}
+
+#[derive(Properties, Clone, PartialEq)]
+pub struct ConfirmModalProps {
+    pub callback: Callback<Msg>,
+    pub question: String,
+}
+
+#[function_component(ConfirmModal)]
+pub fn confirm_modal(props: &ConfirmModalProps) -> Html {
+    let callback = props.callback.clone();
+    let confirm = onmousedown!(callback, Msg::Confirm);
+    let cancel = onmousedown!(callback, Msg::CloseModal);
+
+    html! {
+        <Modal callback={props.callback.clone()}>
+            <p>{&props.question}</p>
+            <div class="select-container">
+                <button class="select" onmousedown={cancel}>{t("Peruuta")}</button>
+                <button class={classes!("select", "select-active")} onmousedown={confirm}>{t("Jatka")}</button>
+            </div>
+        </Modal>
+    }
+}
+
+#[derive(Properties, Clone, PartialEq)]
+pub struct ResumeModalProps {
+    pub callback: Callback<Msg>,
+    pub unfinished_game: UnfinishedGame,
+}
+
+#[function_component(ResumeModal)]
+pub fn resume_modal(props: &ResumeModalProps) -> Html {
+    let callback = props.callback.clone();
+    let resume = onmousedown!(callback, Msg::CloseModal);
+    let start_new = onmousedown!(callback, Msg::StartNewGame);
+
+    let unfinished_game = &props.unfinished_game;
+    let streak = match unfinished_game.game_mode {
+        GameMode::Relay => tf("Ketju: {}", &[&unfinished_game.streak]),
+        _ => tf("Putki: {}", &[&unfinished_game.streak]),
+    };
+
+    html! {
+        <Modal callback={props.callback.clone()}>
+            <h3 class="title">{t("Keskeneräinen peli")}</h3>
+            <p>
+                {tf("Arvauksia käytetty {}/{}.", &[&unfinished_game.guesses_used, &unfinished_game.max_guesses])}
+                if unfinished_game.streak > 0 {
+                    {" "}{streak}
+                }
+            </p>
+            <div class="select-container">
+                <button class="select" onmousedown={start_new}>{t("Aloita uusi")}</button>
+                <button class={classes!("select", "select-active")} onmousedown={resume}>{t("Jatka keskeneräistä peliä")}</button>
+            </div>
+        </Modal>
+    }
+}
+
+#[derive(Properties, Clone, PartialEq)]
+pub struct RaceModalProps {
+    pub callback: Callback<Msg>,
+    // Room waited in for an opponent
+    pub waiting_room: Option<String>,
+}
+
+fn random_room_code() -> String {
+    let mut rng = rand::thread_rng();
+    (0..ROOM_CODE_LENGTH)
+        .map(|_| *ROOM_CODE_LETTERS.choose(&mut rng).unwrap() as char)
+        .collect()
+}
+
+#[function_component(RaceModal)]
+pub fn race_modal(props: &RaceModalProps) -> Html {
+    let callback = props.callback.clone();
+    let room = use_state(random_room_code);
+
+    if let Some(waiting_room) = &props.waiting_room {
+        let leave = onmousedown!(callback, Msg::LeaveRace);
+
+        return html! {
+            <Modal callback={props.callback.clone()}>
+                <p>{tf("Odotetaan vastustajaa huoneeseen {}…", &[waiting_room])}</p>
+                <div class="select-container">
+                    <button class="select" onmousedown={leave}>{t("Poistu")}</button>
+                </div>
+            </Modal>
+        };
+    }
+
+    let oninput = {
+        let room = room.clone();
+        Callback::from(move |e: InputEvent| {
+            if let Some(input) = e.target_dyn_into::<HtmlInputElement>() {
+                room.set(input.value().trim().to_uppercase());
+            }
+        })
+    };
+    let join = {
+        let room = room.clone();
+        Callback::from(move |e: MouseEvent| {
+            e.prevent_default();
+            if !room.is_empty() {
+                callback.emit(Msg::JoinRace((*room).clone()));
+            }
+        })
+    };
+
+    html! {
+        <Modal callback={props.callback.clone()}>
+            <p>{t("Kilpaile kaveria vastaan samasta sanulista. Jaa huoneen koodi, kilpa alkaa kun molemmat ovat liittyneet.")}</p>
+            <label class="label" for="race-room">{t("Huoneen koodi:")}</label>
+            <div class="select-container">
+                <input id="race-room" class="race-room" value={(*room).clone()} {oninput} />
+                <button class={classes!("select", "select-active")} onmousedown={join}>{t("Liity")}</button>
+            </div>
+        </Modal>
+    }
+}
+
+#[derive(Properties, Clone, PartialEq)]
+pub struct SpectateModalProps {
+    pub callback: Callback<Msg>,
+    pub link: String,
+}
+
+#[function_component(SpectateModal)]
+pub fn spectate_modal(props: &SpectateModalProps) -> Html {
+    let callback = props.callback.clone();
+    let stop = onmousedown!(callback, Msg::StopBroadcast);
+
+    html! {
+        <Modal callback={props.callback.clone()}>
+            <label class="label" for="spectate-link">{t("Jaa peli katsojille")}</label>
+            <p>{t("Linkin avanneet näkevät pelisi etenemisen. Arvausten kirjaimet paljastuvat vasta pelin päätyttyä.")}</p>
+            <input id="spectate-link" class="spectate-link" readonly=true value={props.link.clone()} />
+            <div class="select-container">
+                <button class="select" onmousedown={stop}>{t("Lopeta jakaminen")}</button>
+            </div>
+        </Modal>
+    }
+}
+
+#[derive(Properties, Clone, PartialEq)]
+pub struct QrCodeModalProps {
+    pub callback: Callback<Msg>,
+    pub link: String,
+}
+
+#[function_component(QrCodeModal)]
+pub fn qr_code_modal(props: &QrCodeModalProps) -> Html {
+    let code = match QrCode::encode(&props.link) {
+        Some(code) => code,
+        None => {
+            return html! {
+                <Modal callback={props.callback.clone()}>
+                    <p>{t("Linkki on liian pitkä QR-koodiksi.")}</p>
+                </Modal>
+            }
+        }
+    };
+
+    // Leave the quiet zone of four modules around the code
+    let extent = (code.size() + 8).to_string();
+    let view_box = format!("-4 -4 {} {}", extent, extent);
+
+    html! {
+        <Modal callback={props.callback.clone()}>
+            <label class="label">{t("Haasta kaveri")}</label>
+            <p>{t("Skannaa koodi puhelimella pelataksesi saman sanulin.")}</p>
+            <svg class="qr-code" viewBox={view_box} shape-rendering="crispEdges">
+                <rect x="-4" y="-4" width={extent.clone()} height={extent.clone()} fill="#ffffff" />
+                <path d={code.svg_path()} fill="#000000" />
+            </svg>
+        </Modal>
+    }
+}
+
+#[derive(Properties, Clone, PartialEq)]
+pub struct ReplayModalProps {
+    pub callback: Callback<Msg>,
+    pub replay: ReplayRecord,
+}
+
+#[function_component(ReplayModal)]
+pub fn replay_modal(props: &ReplayModalProps) -> Html {
+    let title = match props.replay.game_mode {
+        GameMode::DailyWord(date) => tf("Päivän sanuli {}", &[&date.format("%-d.%-m.%Y")]),
+        GameMode::EveningWord(date) => tf("Iltasanuli {}", &[&date.format("%-d.%-m.%Y")]),
+        _ => t("Uusinta").to_owned(),
+    };
+
+    html! {
+        <Modal callback={props.callback.clone()}>
+            <label class="label">{title}</label>
+            <Replay
+                guesses={props.replay.scored_guesses()}
+                max_guesses={props.replay.max_guesses}
+                word_length={props.replay.word_length()}
+            />
+        </Modal>
+    }
+}
+
+fn game_mode_label(game_mode: &GameMode) -> String {
+    match game_mode {
+        GameMode::Classic => t("Peruspeli").to_owned(),
+        GameMode::Relay => t("Sanuliketju").to_owned(),
+        GameMode::DailyWord(_) => t("Päivän sanuli").to_owned(),
+        GameMode::EveningWord(_) => t("Iltasanuli").to_owned(),
+        GameMode::Weekly(_) => t("Viikkohaaste").to_owned(),
+        GameMode::Race(_) => t("Kilpasanuli").to_owned(),
+        GameMode::Shared => t("Jaettu sanuli").to_owned(),
+        GameMode::Quadruple => "Neluli".to_owned(),
+        GameMode::DoubleDaily(_) => t("Tuplasanuli").to_owned(),
+        GameMode::Zen => "Zen".to_owned(),
+        GameMode::Pressure => t("Aikapaine").to_owned(),
+        GameMode::Deduction => t("Päättely").to_owned(),
+        GameMode::Marathon => t("Maraton").to_owned(),
+        GameMode::Blind => t("Sokko").to_owned(),
+        GameMode::Definition => t("Määritelmä").to_owned(),
+        GameMode::Tournament(_) => t("Turnaus").to_owned(),
+    }
+}
+
+#[derive(Properties, Clone, PartialEq)]
+pub struct BotRaceModalProps {
+    pub callback: Callback<Msg>,
+}
+
+#[function_component(BotRaceModal)]
+pub fn bot_race_modal(props: &BotRaceModalProps) -> Html {
+    let callback = props.callback.clone();
+    let start_easy = onmousedown!(callback, Msg::StartBotRace(BotStrength::Easy));
+    let start_normal = onmousedown!(callback, Msg::StartBotRace(BotStrength::Normal));
+    let start_hard = onmousedown!(callback, Msg::StartBotRace(BotStrength::Hard));
+
+    html! {
+        <Modal callback={props.callback.clone()}>
+            <p>{t("Kilpaile bottia vastaan samasta sanulista. Botti arvaa omalle laudalleen sitä nopeammin, mitä vaikeampi se on, ja näet sen arvauksista vain värit.")}</p>
+            <label class="label">{t("Botin taso:")}</label>
+            <div class="select-container">
+                <button class="select" onmousedown={start_easy}>{t("Helppo")}</button>
+                <button class="select" onmousedown={start_normal}>{t("Keskitaso")}</button>
+                <button class="select" onmousedown={start_hard}>{t("Vaikea")}</button>
+            </div>
+        </Modal>
+    }
+}
+
+#[derive(Properties, Clone, PartialEq)]
+pub struct TournamentModalProps {
+    pub callback: Callback<Msg>,
+    // Code of the tournament being played
+    pub current_code: Option<String>,
+}
+
+#[function_component(TournamentModal)]
+pub fn tournament_modal(props: &TournamentModalProps) -> Html {
+    let callback = props.callback.clone();
+    let code = use_state(String::new);
+
+    let oninput = {
+        let code = code.clone();
+        Callback::from(move |e: InputEvent| {
+            if let Some(input) = e.target_dyn_into::<HtmlInputElement>() {
+                code.set(input.value().trim().to_uppercase());
+            }
+        })
+    };
+    let join = {
+        let code = code.clone();
+        let callback = callback.clone();
+        Callback::from(move |e: MouseEvent| {
+            e.prevent_default();
+            if !code.is_empty() {
+                callback.emit(Msg::JoinTournament((*code).clone()));
+            }
+        })
+    };
+    let start_new = onmousedown!(callback, Msg::StartTournament);
+
+    html! {
+        <Modal callback={props.callback.clone()}>
+            <p>{tf("Turnauksen koodi määrää sarjan {} sanaa, jotka jokainen pelaa omalla laitteellaan. Kopioi lopuksi tuloksesi arvauksineen ja aikoineen ryhmän keskusteluun.", &[&TOURNAMENT_WORDS])}</p>
+            {if let Some(current_code) = &props.current_code {
+                html! {
+                    <p>{tf("Pelaat turnausta {}. Jaa koodi kavereillesi.", &[current_code])}</p>
+                }
+            } else {
+                html! {}
+            }}
+            <label class="label" for="tournament-code">{t("Turnauksen koodi:")}</label>
+            <div class="select-container">
+                <input id="tournament-code" class="race-room" value={(*code).clone()} {oninput} />
+                <button class={classes!("select", "select-active")} onmousedown={join}>{t("Liity")}</button>
+            </div>
+            <div class="select-container">
+                <button class="select" onmousedown={start_new}>{t("Uusi turnaus")}</button>
+            </div>
+        </Modal>
+    }
+}
+
+#[derive(Properties, Clone, PartialEq)]
+pub struct YearReviewModalProps {
+    pub summary: YearSummary,
+    // Years with a daily word played, the latest first
+    pub years: Vec<i32>,
+    pub callback: Callback<Msg>,
+}
+
+#[function_component(YearReviewModal)]
+pub fn year_review_modal(props: &YearReviewModalProps) -> Html {
+    let callback = props.callback.clone();
+    let summary = &props.summary;
+    let year = summary.year;
+    let share_text = onmousedown!(callback, Msg::ShareYearReview(year));
+    let download_image = onmousedown!(callback, Msg::DownloadYearReview(year));
+
+    html! {
+        <Modal callback={props.callback.clone()}>
+            <label class="label">{summary.title()}</label>
+            {if props.years.len() > 1 {
+                html! {
+                    <div class="select-container">
+                        {props.years.iter().copied().map(|year| {
+                            let show_year = onmousedown!(callback, Msg::ShowYearReview(year));
+                            html! {
+                                <button class={classes!("select", (year == summary.year).then_some("select-active"))}
+                                    onmousedown={show_year}>
+                                    {year}
+                                </button>
+                            }
+                        }).collect::<Html>()}
+                    </div>
+                }
+            } else {
+                html! {}
+            }}
+            {if summary.played == 0 {
+                html! {
+                    <p>{tf("Et pelannut vuonna {} yhtään päivän sanaa.", &[&summary.year])}</p>
+                }
+            } else {
+                html! {
+                    <>
+                        <ul>
+                            <li class="statistics">{tf("Päivän sanoja pelattu: {}", &[&summary.played])}</li>
+                            <li class="statistics">{tf("Voitettu: {} / {} ({} %)", &[&summary.won, &summary.played, &summary.win_rate().unwrap_or(0)])}</li>
+                            <li class="statistics">{tf("Pisin putki: {}", &[&summary.longest_streak])}</li>
+                            {if let Some(average_guesses) = summary.average_guesses {
+                                html! { <li class="statistics">{tf("Arvauksia keskimäärin: {}", &[&format!("{:.1}", average_guesses)])}</li> }
+                            } else {
+                                html! {}
+                            }}
+                            {if let Some(letter) = summary.tricky_letter {
+                                html! { <li class="statistics">{tf("Vaikein kirjain: {}", &[&letter])}</li> }
+                            } else {
+                                html! {}
+                            }}
+                        </ul>
+                        <div class="year-review-days" aria-hidden="true">
+                            {summary.days.iter().map(|month| html! {
+                                <div class="year-review-month">
+                                    {month.iter().map(|day| {
+                                        let state = match day {
+                                            Some(true) => TileState::Correct,
+                                            Some(false) => TileState::Absent,
+                                            None => TileState::Unknown,
+                                        };
+                                        html! { <span class={classes!("year-review-day", state.to_string())} /> }
+                                    }).collect::<Html>()}
+                                </div>
+                            }).collect::<Html>()}
+                        </div>
+                        <div class="select-container">
+                            <button class="select" onmousedown={share_text}>{t("Kopioi teksti")}</button>
+                            <button class="select" onmousedown={download_image}>{t("Lataa kuva")}</button>
+                        </div>
+                    </>
+                }
+            }}
+        </Modal>
+    }
+}
+
+#[derive(Properties, PartialEq)]
+pub struct ReverseModalProps {
+    pub word_lists: SharedWordLists,
+    pub callback: Callback<Msg>,
+}
+
+#[function_component(ReverseModal)]
+pub fn reverse_modal(props: &ReverseModalProps) -> Html {
+    html! {
+        <Modal callback={props.callback.clone()}>
+            <label class="label">{t("Tietokone arvaa")}</label>
+            <ReverseBoard word_lists={props.word_lists.clone()} />
+        </Modal>
+    }
+}
+
+#[derive(Properties, Clone, PartialEq)]
+pub struct HistoryModalProps {
+    pub callback: Callback<Msg>,
+    pub replays: Vec<ReplayRecord>,
+}
+
+/// The finished games of all the modes, newest first, filtered by the mode and the result
+#[function_component(HistoryModal)]
+pub fn history_modal(props: &HistoryModalProps) -> Html {
+    let callback = props.callback.clone();
+    // Name of the game mode shown, None shows all of them
+    let mode_filter = use_state(|| None::<&'static str>);
+    // Whether the won or the lost games are shown, None shows both
+    let result_filter = use_state(|| None::<bool>);
+
+    let mut modes: Vec<&GameMode> = Vec::new();
+    for replay in props.replays.iter().rev() {
+        if !modes
+            .iter()
+            .any(|mode| mode.name() == replay.game_mode.name())
+        {
+            modes.push(&replay.game_mode);
+        }
+    }
+
+    let replays = props
+        .replays
+        .iter()
+        .rev()
+        .filter(|replay| mode_filter.is_none_or(|mode| replay.game_mode.name() == mode))
+        .filter(|replay| result_filter.is_none_or(|is_winner| replay.is_winner == is_winner))
+        .collect::<Vec<_>>();
+
+    let select_mode = |mode: Option<&'static str>| {
+        let mode_filter = mode_filter.clone();
+        Callback::from(move |e: MouseEvent| {
+            e.prevent_default();
+            mode_filter.set(mode);
+        })
+    };
+    let select_result = |is_winner: Option<bool>| {
+        let result_filter = result_filter.clone();
+        Callback::from(move |e: MouseEvent| {
+            e.prevent_default();
+            result_filter.set(is_winner);
+        })
+    };
+
+    html! {
+        <Modal callback={props.callback.clone()}>
+            <label class="label">{t("Pelihistoria")}</label>
+            <div class="select-container">
+                <button class={classes!("select", mode_filter.is_none().then(|| Some("select-active")))}
+                    onmousedown={select_mode(None)}>
+                    {t("Kaikki")}
+                </button>
+                {modes.iter().map(|mode| {
+                    html! {
+                        <button class={classes!("select", (*mode_filter == Some(mode.name())).then(|| Some("select-active")))}
+                            onmousedown={select_mode(Some(mode.name()))}>
+                            {game_mode_label(mode)}
+                        </button>
+                    }
+                }).collect::<Html>()}
+            </div>
+            <div class="select-container">
+                <button class={classes!("select", result_filter.is_none().then(|| Some("select-active")))}
+                    onmousedown={select_result(None)}>
+                    {t("Kaikki")}
+                </button>
+                <button class={classes!("select", (*result_filter == Some(true)).then(|| Some("select-active")))}
+                    onmousedown={select_result(Some(true))}>
+                    {t("Ratkaistut")}
+                </button>
+                <button class={classes!("select", (*result_filter == Some(false)).then(|| Some("select-active")))}
+                    onmousedown={select_result(Some(false))}>
+                    {t("Ratkaisematta")}
+                </button>
+            </div>
+            {if replays.is_empty() {
+                html! { <p class="setting-description">{t("Ei pelattuja pelejä.")}</p> }
+            } else {
+                html! {
+                    <ul class="history">
+                        {replays.iter().map(|replay| {
+                            let record = (*replay).clone();
+                            let show_replay = onmousedown!(callback, Msg::ShowReplayRecord(record.clone()));
+                            let result = if replay.is_winner {
+                                format!("{}", replay.guesses.len())
+                            } else {
+                                "X".to_owned()
+                            };
+                            let duration = replay
+                                .duration_secs
+                                .map(|secs| format!(", {}", game::format_duration(secs)))
+                                .unwrap_or_default();
+
+                            html! {
+                                <li class="statistics">
+                                    {format!(
+                                        "{} {} ({}): {} {}/{}{} ",
+                                        Local.timestamp(replay.finished_at, 0).format("%-d.%-m.%Y"),
+                                        game_mode_label(&replay.game_mode),
+                                        replay.word_length(),
+                                        replay.word.iter().collect::<String>(),
+                                        result,
+                                        replay.max_guesses,
+                                        duration
+                                    )}
+                                    <a class="link" href={"javascript:void(0)"} role="button" onmousedown={show_replay}>{t("Katso")}</a>
+                                </li>
+                            }
+                        }).collect::<Html>()}
+                    </ul>
+                }
+            }}
+        </Modal>
+    }
+}
+
+#[derive(Properties, Clone, PartialEq)]
+pub struct ProfilePickerProps {
+    pub callback: Callback<Msg>,
+}
+
+/// Local profiles for the players sharing a device, each with its own games and statistics
+#[function_component(ProfilePicker)]
+fn profile_picker(props: &ProfilePickerProps) -> Html {
+    let callback = props.callback.clone();
+    let name = use_state(String::new);
+
+    let active_profile = storage::active_profile();
+    let switch_to_default = onmousedown!(callback, Msg::SwitchProfile(None));
+
+    let oninput = {
+        let name = name.clone();
+        Callback::from(move |e: InputEvent| {
+            if let Some(input) = e.target_dyn_into::<HtmlInputElement>() {
+                name.set(input.value());
+            }
+        })
+    };
+    let add_profile = {
+        let name = name.clone();
+        let callback = callback.clone();
+        Callback::from(move |e: MouseEvent| {
+            e.prevent_default();
+            if !name.trim().is_empty() {
+                callback.emit(Msg::AddProfile((*name).clone()));
+            }
+        })
+    };
+
+    html! {
+        <div>
+            <label class="label">{t("Profiili:")}</label>
+            <div class="select-container">
+                <button class={classes!("select", active_profile.is_none().then(|| Some("select-active")))}
+                    onmousedown={switch_to_default}>
+                    {t("Oletus")}
+                </button>
+                {storage::profiles().into_iter().map(|profile| {
+                    let is_active = active_profile.as_ref() == Some(&profile);
+                    let switched = profile.clone();
+                    let switch_profile = onmousedown!(callback, Msg::SwitchProfile(Some(switched.clone())));
+
+                    html! {
+                        <button class={classes!("select", is_active.then(|| Some("select-active")))}
+                            onmousedown={switch_profile}>
+                            {profile}
+                        </button>
+                    }
+                }).collect::<Html>()}
+            </div>
+            <div class="select-container">
+                <input class="profile-name" maxlength={storage::MAX_PROFILE_NAME_LENGTH.to_string()}
+                    placeholder={t("Uusi profiili")} value={(*name).clone()} {oninput} />
+                <button class="select" onmousedown={add_profile}>{t("Lisää")}</button>
+            </div>
+        </div>
+    }
+}
+
+#[derive(Properties, Clone, PartialEq)]
+pub struct SyncCodePanelProps {
+    pub callback: Callback<Msg>,
+}
+
+/// Moves the progress to another device as a code to copy and paste
+#[function_component(SyncCodePanel)]
+fn sync_code_panel(props: &SyncCodePanelProps) -> Html {
+    let callback = props.callback.clone();
+    let code = use_state(String::new);
+
+    let copy_sync_code = onmousedown!(callback, Msg::CopySyncCode);
+
+    let oninput = {
+        let code = code.clone();
+        Callback::from(move |e: InputEvent| {
+            if let Some(input) = e.target_dyn_into::<HtmlInputElement>() {
+                code.set(input.value());
+            }
+        })
+    };
+    let import_sync_code = {
+        let code = code.clone();
+        Callback::from(move |e: MouseEvent| {
+            e.prevent_default();
+            if !code.trim().is_empty() {
+                callback.emit(Msg::ImportSyncCode((*code).clone()));
+            }
+        })
+    };
+
+    html! {
+        <div>
+            <label class="label" for="sync-code">{t("Siirtokoodi:")}</label>
+            <p class="setting-description">{t("Kopioi koodi ja liitä se toisella laitteella siirtääksesi pelit, tilastot ja asetukset.")}</p>
+            <div class="select-container">
+                <button class="select" onmousedown={copy_sync_code}>{t("Kopioi siirtokoodi")}</button>
+            </div>
+            <div class="select-container">
+                <input id="sync-code" class="sync-code" value={(*code).clone()} {oninput} />
+                <button class="select" onmousedown={import_sync_code}>{t("Tuo")}</button>
             </div>
         </div>
     }
@@ -0,0 +1,39 @@
+use yew::prelude::*;
+
+use crate::components::board::BoardThumbnail;
+use crate::i18n::t;
+use crate::manager::TileState;
+use crate::race::RaceStatus;
+
+#[derive(Properties, PartialEq)]
+pub struct OpponentBoardProps {
+    pub patterns: Vec<Vec<TileState>>,
+    pub status: RaceStatus,
+    pub max_guesses: usize,
+    pub word_length: usize,
+    #[prop_or_default]
+    pub is_bot: bool,
+}
+
+/// Colors only view of the opponent's board, updated as their rows are revealed
+#[function_component(OpponentBoard)]
+pub fn opponent_board(props: &OpponentBoardProps) -> Html {
+    let status = match (props.status, props.is_bot) {
+        (RaceStatus::Waiting | RaceStatus::Racing, false) => t("Vastustaja"),
+        (RaceStatus::Waiting | RaceStatus::Racing, true) => t("Botti"),
+        (RaceStatus::Won, _) => t("Voitit kilvan!"),
+        (RaceStatus::Lost, false) => t("Vastustaja voitti"),
+        (RaceStatus::Lost, true) => t("Botti voitti"),
+    };
+
+    html! {
+        <div class="race-opponent">
+            <BoardThumbnail
+                patterns={props.patterns.clone()}
+                max_guesses={props.max_guesses}
+                word_length={props.word_length}
+            />
+            <span>{status}</span>
+        </div>
+    }
+}
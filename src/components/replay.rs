@@ -0,0 +1,165 @@
+use gloo_timers::callback::Interval;
+use yew::prelude::*;
+
+use crate::i18n::t;
+use crate::manager::TileState;
+
+const TICK_MS: u32 = 150;
+
+pub enum Msg {
+    Tick,
+    Restart,
+}
+
+#[derive(Properties, PartialEq)]
+pub struct Props {
+    pub guesses: Vec<Vec<(char, TileState)>>,
+    pub max_guesses: usize,
+    pub word_length: usize,
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum Playback {
+    Typing { row: usize, letters: usize },
+    Revealing { row: usize, ticks: usize },
+    Finished,
+}
+
+pub struct Replay {
+    playback: Playback,
+    interval: Option<Interval>,
+}
+
+impl Replay {
+    fn start(ctx: &Context<Self>) -> Interval {
+        let link = ctx.link().clone();
+        Interval::new(TICK_MS, move || link.send_message(Msg::Tick))
+    }
+
+    fn next_playback(&self, guesses: &[Vec<(char, TileState)>], word_length: usize) -> Playback {
+        match self.playback {
+            Playback::Typing { row, letters } if letters < guesses[row].len() => Playback::Typing {
+                row,
+                letters: letters + 1,
+            },
+            Playback::Typing { row, .. } => Playback::Revealing { row, ticks: 0 },
+            // Give the flip animation of the row time to finish before moving on
+            Playback::Revealing { row, ticks } if ticks < word_length => Playback::Revealing {
+                row,
+                ticks: ticks + 1,
+            },
+            Playback::Revealing { row, .. } if row + 1 < guesses.len() => Playback::Typing {
+                row: row + 1,
+                letters: 0,
+            },
+            Playback::Revealing { .. } | Playback::Finished => Playback::Finished,
+        }
+    }
+}
+
+impl Component for Replay {
+    type Message = Msg;
+    type Properties = Props;
+
+    fn create(ctx: &Context<Self>) -> Self {
+        Self {
+            playback: Playback::Typing { row: 0, letters: 0 },
+            interval: Some(Self::start(ctx)),
+        }
+    }
+
+    fn update(&mut self, ctx: &Context<Self>, msg: Self::Message) -> bool {
+        match msg {
+            Msg::Tick => {
+                let props = ctx.props();
+                let guesses = props
+                    .guesses
+                    .iter()
+                    .filter(|guess| !guess.is_empty())
+                    .cloned()
+                    .collect::<Vec<_>>();
+
+                if guesses.is_empty() {
+                    self.playback = Playback::Finished;
+                } else {
+                    self.playback = self.next_playback(&guesses, props.word_length);
+                }
+
+                if self.playback == Playback::Finished {
+                    self.interval = None;
+                }
+            }
+            Msg::Restart => {
+                self.playback = Playback::Typing { row: 0, letters: 0 };
+                self.interval = Some(Self::start(ctx));
+            }
+        }
+
+        true
+    }
+
+    fn view(&self, ctx: &Context<Self>) -> Html {
+        let props = ctx.props();
+        let restart = ctx.link().callback(|e: MouseEvent| {
+            e.prevent_default();
+            Msg::Restart
+        });
+
+        html! {
+            <>
+                <div class="replay">
+                    {
+                        (0..props.max_guesses).map(|row| {
+                            let guess = props.guesses.get(row).cloned().unwrap_or_default();
+
+                            let (visible_letters, is_revealed, is_flipping) = match self.playback {
+                                Playback::Typing { row: current, letters } if row == current => (letters, false, false),
+                                Playback::Typing { row: current, .. } | Playback::Revealing { row: current, .. } if row > current => (0, false, false),
+                                Playback::Revealing { row: current, .. } if row == current => (guess.len(), true, true),
+                                _ => (guess.len(), true, false),
+                            };
+
+                            html! {
+                                <div class={format!("row-{}", props.word_length)}>
+                                    {
+                                        (0..props.word_length).map(|tile_index| {
+                                            let (character, tile_state) = guess
+                                                .get(tile_index)
+                                                .filter(|_| tile_index < visible_letters)
+                                                .copied()
+                                                .unwrap_or((' ', TileState::Unknown));
+
+                                            html! {
+                                                <div
+                                                    class={classes!(
+                                                        "tile",
+                                                        is_revealed.then(|| tile_state.to_string()),
+                                                        is_flipping.then(|| "flip")
+                                                    )}
+                                                    style={format!("animation-delay: {}ms", tile_index * 100)}
+                                                >
+                                                    { character }
+                                                </div>
+                                            }
+                                        }).collect::<Html>()
+                                    }
+                                </div>
+                            }
+                        }).collect::<Html>()
+                    }
+                </div>
+                {
+                    if self.playback == Playback::Finished {
+                        html! {
+                            <div class="replay-controls">
+                                <a class="link" href={"javascript:void(0)"} onclick={restart}>{t("Katso uudelleen")}</a>
+                            </div>
+                        }
+                    } else {
+                        html! {}
+                    }
+                }
+            </>
+        }
+    }
+}
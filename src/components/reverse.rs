@@ -0,0 +1,197 @@
+use yew::prelude::*;
+
+use crate::components::demo::SharedWordLists;
+use crate::game::{self, DEFAULT_MAX_GUESSES, DEFAULT_WORD_LENGTH};
+use crate::i18n::{t, tf};
+use crate::manager::{TileState, Word, WordList};
+
+pub enum Msg {
+    // Cycles the color of a tile of the current guess
+    Toggle(usize),
+    Submit,
+    Restart,
+}
+
+#[derive(PartialEq)]
+enum Status {
+    Guessing,
+    Solved,
+    // No word fits the colors given
+    Inconsistent,
+    OutOfGuesses,
+}
+
+#[derive(Properties, PartialEq)]
+pub struct ReverseBoardProps {
+    pub word_lists: SharedWordLists,
+}
+
+/// The computer guesses a word the player thinks of, narrowing the candidates down with the
+/// colors the player gives to its guesses
+pub struct ReverseBoard {
+    // Sorted, so that the same colors always lead to the same guesses
+    candidates: Vec<Word>,
+    rows: Vec<Vec<(char, TileState)>>,
+    status: Status,
+}
+
+impl ReverseBoard {
+    fn new(word_lists: &SharedWordLists) -> Self {
+        let mut candidates = word_lists
+            .0
+            .answer_words(WordList::Common, DEFAULT_WORD_LENGTH);
+        candidates.sort();
+
+        let mut board = Self {
+            candidates,
+            rows: Vec::new(),
+            status: Status::Guessing,
+        };
+        board.guess_next();
+        board
+    }
+
+    fn guess_next(&mut self) {
+        match game::solver_guess(&self.candidates) {
+            Some(word) => self
+                .rows
+                .push(word.iter().map(|c| (*c, TileState::Absent)).collect()),
+            None => self.status = Status::Inconsistent,
+        }
+    }
+
+    fn submit(&mut self) {
+        let row = match self.rows.last() {
+            Some(row) => row,
+            None => return,
+        };
+        if row.iter().all(|(_, state)| *state == TileState::Correct) {
+            self.status = Status::Solved;
+            return;
+        }
+
+        let guess = row.iter().map(|(c, _)| *c).collect::<Vec<_>>();
+        let pattern = row.iter().map(|(_, state)| *state).collect::<Vec<_>>();
+        self.candidates
+            .retain(|word| game::matches_pattern(&guess, word, &pattern));
+
+        if self.candidates.is_empty() {
+            self.status = Status::Inconsistent;
+        } else if self.rows.len() == DEFAULT_MAX_GUESSES {
+            self.status = Status::OutOfGuesses;
+        } else {
+            self.guess_next();
+        }
+    }
+
+    fn message(&self) -> String {
+        match self.status {
+            Status::Guessing => tf("Sanoja jäljellä: {}", &[&self.candidates.len()]),
+            Status::Solved => tf("Arvasin sanasi {}. arvauksella!", &[&self.rows.len()]),
+            Status::Inconsistent => {
+                t("Mikään sana ei sovi antamiisi väreihin. Huijasitko?").to_owned()
+            }
+            Status::OutOfGuesses => tf(
+                "En arvannut sanaasi. Vaihtoehtoja oli vielä {}.",
+                &[&self.candidates.len()],
+            ),
+        }
+    }
+}
+
+impl Component for ReverseBoard {
+    type Message = Msg;
+    type Properties = ReverseBoardProps;
+
+    fn create(ctx: &Context<Self>) -> Self {
+        Self::new(&ctx.props().word_lists)
+    }
+
+    fn update(&mut self, ctx: &Context<Self>, msg: Self::Message) -> bool {
+        match msg {
+            Msg::Toggle(index) => {
+                if self.status != Status::Guessing {
+                    return false;
+                }
+
+                if let Some((_, state)) = self.rows.last_mut().and_then(|row| row.get_mut(index)) {
+                    *state = match state {
+                        TileState::Absent => TileState::Present,
+                        TileState::Present => TileState::Correct,
+                        _ => TileState::Absent,
+                    };
+                }
+            }
+            Msg::Submit => {
+                if self.status != Status::Guessing {
+                    return false;
+                }
+
+                self.submit();
+            }
+            Msg::Restart => *self = Self::new(&ctx.props().word_lists),
+        }
+
+        true
+    }
+
+    fn changed(&mut self, ctx: &Context<Self>) -> bool {
+        *self = Self::new(&ctx.props().word_lists);
+        true
+    }
+
+    fn view(&self, ctx: &Context<Self>) -> Html {
+        let link = ctx.link();
+        let current_row = self.rows.len().saturating_sub(1);
+        let is_guessing = self.status == Status::Guessing;
+
+        let submit = link.callback(|e: MouseEvent| {
+            e.prevent_default();
+            Msg::Submit
+        });
+        let restart = link.callback(|e: MouseEvent| {
+            e.prevent_default();
+            Msg::Restart
+        });
+
+        html! {
+            <div class="reverse">
+                <p>{t("Keksi viisikirjaiminen sana, niin tietokone yrittää arvata sen. Napauta arvauksen kirjaimia, kunnes niiden värit vastaavat sanaasi.")}</p>
+                {self.rows.iter().enumerate().map(|(row, guess)| {
+                    let is_current_row = is_guessing && row == current_row;
+
+                    html! {
+                        <div class={classes!(format!("row-{}", DEFAULT_WORD_LENGTH), "example")}>
+                            {guess.iter().enumerate().map(|(index, (character, tile_state))| {
+                                let onmousedown = link.callback(move |e: MouseEvent| {
+                                    e.prevent_default();
+                                    Msg::Toggle(index)
+                                });
+
+                                if is_current_row {
+                                    html! {
+                                        <button class={classes!("tile", "reverse-tile", tile_state.to_string())}
+                                            aria-label={format!("{} {}", character, tile_state.description())}
+                                            {onmousedown}>
+                                            {character}
+                                        </button>
+                                    }
+                                } else {
+                                    html! {
+                                        <div class={classes!("tile", tile_state.to_string())}>{character}</div>
+                                    }
+                                }
+                            }).collect::<Html>()}
+                        </div>
+                    }
+                }).collect::<Html>()}
+                <p class="demo-message" role="status">{self.message()}</p>
+                if is_guessing {
+                    <button class="select" onmousedown={submit}>{t("Värit valmiit")}</button>
+                } else {
+                    <button class="select" onmousedown={restart}>{t("Uusi sana")}</button>
+                }
+            </div>
+        }
+    }
+}
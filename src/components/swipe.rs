@@ -0,0 +1,92 @@
+use chrono::Utc;
+use yew::prelude::*;
+
+use crate::Msg;
+
+// Shorter movements are taps, like selecting a tile
+const MIN_SWIPE_DISTANCE: i32 = 50;
+// Slower movements are taken for scrolling or hesitation
+const MAX_SWIPE_DURATION_MS: i64 = 600;
+
+#[derive(Properties, PartialEq)]
+pub struct SwipeAreaProps {
+    pub class: Classes,
+    pub is_enabled: bool,
+    pub is_guessing: bool,
+    pub callback: Callback<Msg>,
+    #[prop_or_default]
+    pub children: Children,
+}
+
+/// The board area taking swipes: left removes a letter, right guesses the word and down
+/// continues to the next game once the game has ended
+#[function_component(SwipeArea)]
+pub fn swipe_area(props: &SwipeAreaProps) -> Html {
+    // Position and time of the touch in progress
+    let start = use_mut_ref(|| None::<(i32, i32, i64)>);
+
+    if !props.is_enabled {
+        return html! {
+            <div class={props.class.clone()}>{for props.children.iter()}</div>
+        };
+    }
+
+    let ontouchstart = {
+        let start = start.clone();
+        Callback::from(move |e: TouchEvent| {
+            // A second finger is a pinch, not a swipe
+            let touches = e.touches();
+            *start.borrow_mut() = touches
+                .get(0)
+                .filter(|_| touches.length() == 1)
+                .map(|touch| {
+                    (
+                        touch.client_x(),
+                        touch.client_y(),
+                        Utc::now().timestamp_millis(),
+                    )
+                });
+        })
+    };
+
+    let is_guessing = props.is_guessing;
+    let callback = props.callback.clone();
+    let ontouchend = Callback::from(move |e: TouchEvent| {
+        let (start_x, start_y, started_at) = match start.borrow_mut().take() {
+            Some(start) => start,
+            None => return,
+        };
+        let touch = match e.changed_touches().get(0) {
+            Some(touch) => touch,
+            None => return,
+        };
+        if Utc::now().timestamp_millis() - started_at > MAX_SWIPE_DURATION_MS {
+            return;
+        }
+
+        let dx = touch.client_x() - start_x;
+        let dy = touch.client_y() - start_y;
+        let msg = if dx.abs() >= MIN_SWIPE_DISTANCE && dx.abs() > dy.abs() * 2 {
+            match (dx < 0, is_guessing) {
+                (true, true) => Some(Msg::Backspace),
+                (false, true) => Some(Msg::Guess),
+                _ => None,
+            }
+        } else if dy >= MIN_SWIPE_DISTANCE && dy > dx.abs() * 2 && !is_guessing {
+            Some(Msg::Enter)
+        } else {
+            None
+        };
+
+        if let Some(msg) = msg {
+            e.prevent_default();
+            callback.emit(msg);
+        }
+    });
+
+    html! {
+        <div class={classes!(props.class.clone(), "swipeable")} {ontouchstart} {ontouchend}>
+            {for props.children.iter()}
+        </div>
+    }
+}
@@ -0,0 +1,79 @@
+use web_sys::{window, HtmlInputElement};
+use yew::prelude::*;
+
+use crate::i18n::t;
+use crate::sync;
+
+#[derive(Clone, Copy, PartialEq)]
+enum Status {
+    Idle,
+    Syncing,
+    Synced,
+    Failed,
+}
+
+/// Address of the synced save, and a button to sync with it right away
+#[function_component(SyncPanel)]
+pub fn sync_panel() -> Html {
+    let url = use_state(|| sync::sync_url().unwrap_or_default());
+    let status = use_state(|| Status::Idle);
+
+    let oninput = {
+        let url = url.clone();
+        Callback::from(move |e: InputEvent| {
+            if let Some(input) = e.target_dyn_into::<HtmlInputElement>() {
+                url.set(input.value());
+            }
+        })
+    };
+    let sync_now = {
+        let url = url.clone();
+        let status = status.clone();
+        Callback::from(move |e: MouseEvent| {
+            e.prevent_default();
+
+            // An empty address turns the sync off
+            sync::set_sync_url(Some(&url));
+            let url = match sync::sync_url() {
+                Some(url) => url,
+                None => return status.set(Status::Idle),
+            };
+
+            status.set(Status::Syncing);
+            let status = status.clone();
+            sync::sync(
+                &url,
+                Callback::from(move |result| match result {
+                    Ok(true) => {
+                        if let Some(window) = window() {
+                            let _result = window.location().reload();
+                        }
+                    }
+                    Ok(false) => status.set(Status::Synced),
+                    Err(err) => {
+                        log::info!("Syncing the progress failed: {}", err);
+                        status.set(Status::Failed);
+                    }
+                }),
+            );
+        })
+    };
+
+    html! {
+        <div>
+            <label class="label" for="sync-url">{t("Synkronoi edistyminen:")}</label>
+            <p class="setting-description">{t("Tallennus kulkee laitteelta toiselle osoitteen kautta, joka vastaa GET- ja PUT-pyyntöihin, esim. WebDAV-palvelimen tiedosto. Uudempi tallennus voittaa, päivän sanulit yhdistetään.")}</p>
+            <div class="select-container">
+                <input id="sync-url" class="sync-url" type="url" value={(*url).clone()} {oninput} />
+                <button class="select" onmousedown={sync_now} disabled={*status == Status::Syncing}>
+                    {t("Synkronoi")}
+                </button>
+            </div>
+            {match *status {
+                Status::Idle | Status::Syncing => html! {},
+                Status::Synced => html! { <p class="setting-description">{t("Edistyminen synkronoitu.")}</p> },
+                Status::Failed => html! { <p class="setting-description">{t("Synkronointi epäonnistui.")}</p> },
+            }}
+        </div>
+    }
+}
@@ -0,0 +1,51 @@
+use yew::prelude::*;
+
+use crate::Msg;
+
+// How long a toast is shown before it dismisses itself
+pub const TOAST_DURATION_MS: u32 = 3000;
+// Older toasts are dropped when more are queued at once
+pub const MAX_TOASTS: usize = 3;
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum ToastKind {
+    Info,
+    Error,
+}
+
+/// Short lived feedback, like "Linkki kopioitu!", kept apart from the message line of the game
+#[derive(Clone, PartialEq)]
+pub struct Toast {
+    pub id: usize,
+    pub message: String,
+    pub kind: ToastKind,
+}
+
+#[derive(Properties, PartialEq)]
+pub struct ToastsProps {
+    pub toasts: Vec<Toast>,
+    pub callback: Callback<Msg>,
+}
+
+#[function_component(Toasts)]
+pub fn toasts(props: &ToastsProps) -> Html {
+    html! {
+        <div class="toasts" aria-live="polite">
+            {props.toasts.iter().map(|toast| {
+                let id = toast.id;
+                let callback = props.callback.clone();
+                let dismiss = Callback::from(move |e: MouseEvent| {
+                    e.prevent_default();
+                    callback.emit(Msg::DismissToast(id));
+                });
+
+                html! {
+                    <div key={id} class={classes!("toast-message", (toast.kind == ToastKind::Error).then(|| "toast-error"))}
+                        onmousedown={dismiss}>
+                        {&toast.message}
+                    </div>
+                }
+            }).collect::<Html>()}
+        </div>
+    }
+}
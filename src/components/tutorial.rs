@@ -0,0 +1,186 @@
+use yew::prelude::*;
+
+use crate::components::modal::Modal;
+use crate::i18n::{t, tf, Language};
+use crate::manager::{Theme, TileState};
+use crate::tutorial;
+use crate::Msg;
+
+#[derive(Clone, Copy, PartialEq)]
+enum Step {
+    // The practice guess
+    Guessing,
+    // The colors of the practice guess are explained
+    Reading,
+    Solving,
+    Solved,
+}
+
+#[derive(Properties, PartialEq)]
+pub struct TutorialModalProps {
+    // Language of the practice words
+    pub language: Language,
+    pub theme: Theme,
+    pub callback: Callback<Msg>,
+}
+
+/// The practice puzzle walking through typing, submitting and reading the colors
+#[function_component(TutorialModal)]
+pub fn tutorial_modal(props: &TutorialModalProps) -> Html {
+    let (practice_guess, word) = tutorial::words(props.language);
+    let word_length = word.chars().count();
+
+    let step = use_state(|| Step::Guessing);
+    let typed = use_state(Vec::<char>::new);
+    let rows = use_state(Vec::<Vec<(char, TileState)>>::new);
+
+    let target = match *step {
+        Step::Guessing => practice_guess,
+        _ => word,
+    };
+    let is_typed = typed.iter().collect::<String>() == target;
+
+    let letter_buttons = tutorial::letters(props.language)
+        .into_iter()
+        .map(|letter| {
+            let typed = typed.clone();
+            let onmousedown = Callback::from(move |e: MouseEvent| {
+                e.prevent_default();
+                if typed.len() < word_length {
+                    let mut letters = (*typed).clone();
+                    letters.push(letter);
+                    typed.set(letters);
+                }
+            });
+
+            html! {
+                <button class="keyboard-button" onmousedown={onmousedown}>{letter}</button>
+            }
+        })
+        .collect::<Html>();
+
+    let backspace = {
+        let typed = typed.clone();
+        Callback::from(move |e: MouseEvent| {
+            e.prevent_default();
+            let mut letters = (*typed).clone();
+            letters.pop();
+            typed.set(letters);
+        })
+    };
+    let submit = {
+        let typed = typed.clone();
+        let rows = rows.clone();
+        let step = step.clone();
+        Callback::from(move |e: MouseEvent| {
+            e.prevent_default();
+            if !is_typed {
+                return;
+            }
+
+            let mut guesses = (*rows).clone();
+            guesses.push(tutorial::score(&typed, word));
+            rows.set(guesses);
+            typed.set(Vec::new());
+            step.set(match *step {
+                Step::Guessing => Step::Reading,
+                _ => Step::Solved,
+            });
+        })
+    };
+    let next = {
+        let step = step.clone();
+        Callback::from(move |e: MouseEvent| {
+            e.prevent_default();
+            step.set(Step::Solving);
+        })
+    };
+
+    let callback = props.callback.clone();
+    let complete = Callback::from(move |e: MouseEvent| {
+        e.prevent_default();
+        callback.emit(Msg::CompleteTutorial);
+    });
+
+    let (present, correct) = if props.theme.is_orange_and_blue() {
+        (t("Sininen"), t("Oranssi"))
+    } else {
+        (t("Keltainen"), t("Vihreä"))
+    };
+
+    let instruction = match *step {
+        Step::Guessing | Step::Solving if is_typed => html! {
+            <p>{t("Lähetä arvaus painamalla ARVAA.")}</p>
+        },
+        Step::Guessing | Step::Solving if typed.len() == word_length => html! {
+            <p>{tf("Harjoituksen sana on {}. Poista kirjaimia painamalla ⌫.", &[&target])}</p>
+        },
+        Step::Guessing => html! {
+            <p>{tf("Kirjoita arvaus {} painamalla kirjaimia.", &[&practice_guess])}</p>
+        },
+        Step::Reading => html! {
+            <>
+                <p>{t("Arvauksen jälkeen kirjaimet vaihtavat väriään:")}</p>
+                <p>
+                    <span class="correct">{correct}</span>
+                    {t(": kirjain on arvauksessa oikealla paikalla.")}
+                </p>
+                <p>
+                    <span class="present">{present}</span>
+                    {t(": kirjain löytyy kätketystä sanasta, mutta on arvauksessa väärällä paikalla.")}
+                </p>
+                <p><span class="absent">{t("Harmaa")}</span>{t(": kirjain ei löydy sanasta.")}</p>
+                <button class="select" onmousedown={next}>{t("Jatka")}</button>
+            </>
+        },
+        Step::Solving => html! {
+            <p>{tf("Värien perusteella kätketty sana voisi olla {}. Kirjoita se ja lähetä.", &[&word])}</p>
+        },
+        Step::Solved => html! {
+            <>
+                <p>{t("Löysit kätketyn sanan! Oikeassa pelissä sanaa ei kerrota, mutta värit auttavat samalla tavalla. Arvauksia on kuusi.")}</p>
+                <button class="select" onmousedown={complete.clone()}>{t("Aloita peli")}</button>
+            </>
+        },
+    };
+
+    let is_typing = matches!(*step, Step::Guessing | Step::Solving);
+
+    html! {
+        <Modal callback={props.callback.clone()}>
+            <h3 class="title">{t("Harjoitellaan")}</h3>
+            <div class="tutorial-board">
+                {rows.iter().map(|row| {
+                    html! {
+                        <div class={classes!(format!("row-{}", word_length), "example")}>
+                            {row.iter().map(|(character, tile_state)| html! {
+                                <div class={classes!("tile", tile_state.to_string())}>{*character}</div>
+                            }).collect::<Html>()}
+                        </div>
+                    }
+                }).collect::<Html>()}
+                if is_typing {
+                    <div class={classes!(format!("row-{}", word_length), "example")}>
+                        {(0..word_length).map(|index| html! {
+                            <div class="tile">{typed.get(index).copied().unwrap_or(' ')}</div>
+                        }).collect::<Html>()}
+                    </div>
+                }
+            </div>
+            {instruction}
+            if is_typing {
+                <div class="keyboard-row tutorial-keyboard">
+                    {letter_buttons}
+                    <button class="keyboard-button keyboard-button-backspace" onmousedown={backspace}>{"⌫"}</button>
+                    <button class={classes!("keyboard-button", "keyboard-button-submit", is_typed.then(|| "correct"))}
+                        onmousedown={submit}>
+                        {t("ARVAA")}
+                    </button>
+                </div>
+            }
+            if *step != Step::Solved {
+                <a class="link" href={"javascript:void(0)"} onmousedown={complete}>{t("Ohita harjoitus")}</a>
+            }
+        </Modal>
+    }
+}
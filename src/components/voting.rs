@@ -0,0 +1,182 @@
+use chrono::{DateTime, Local, Utc};
+use serde::{Deserialize, Serialize};
+use yew::prelude::*;
+
+use crate::api::{self, ApiError};
+use crate::i18n::{t, tf};
+use crate::storage;
+
+const FEED_PATH: &str = "voting/current";
+
+#[derive(Clone, PartialEq, Deserialize)]
+pub struct VoteCount {
+    pub word: String,
+    pub votes: usize,
+}
+
+/// Candidates for next week's themed daily words
+#[derive(Clone, PartialEq, Deserialize)]
+pub struct VotingFeed {
+    pub week: String,
+    pub theme: String,
+    pub candidates: Vec<String>,
+    pub closes_at: DateTime<Utc>,
+    #[serde(default)]
+    pub results: Vec<VoteCount>,
+}
+
+impl VotingFeed {
+    fn is_closed(&self) -> bool {
+        Utc::now() >= self.closes_at
+    }
+
+    fn vote_key(&self) -> String {
+        format!("vote|{}", self.week)
+    }
+}
+
+#[derive(Serialize)]
+struct Ballot<'a> {
+    word: &'a str,
+}
+
+pub enum Msg {
+    FeedLoaded(Result<VotingFeed, ApiError>),
+    Vote(String),
+    VoteSubmitted(Result<(), ApiError>),
+}
+
+enum Feed {
+    Loading,
+    Ready(VotingFeed),
+    Unavailable,
+}
+
+pub struct VotingPanel {
+    feed: Feed,
+    vote: Option<String>,
+    is_vote_failed: bool,
+}
+
+impl Component for VotingPanel {
+    type Message = Msg;
+    type Properties = ();
+
+    fn create(ctx: &Context<Self>) -> Self {
+        ctx.link()
+            .send_future(async { Msg::FeedLoaded(api::get(FEED_PATH).await) });
+
+        Self {
+            feed: Feed::Loading,
+            vote: None,
+            is_vote_failed: false,
+        }
+    }
+
+    fn update(&mut self, ctx: &Context<Self>, msg: Self::Message) -> bool {
+        match msg {
+            Msg::FeedLoaded(Ok(feed)) => {
+                self.vote = storage::get(feed.vote_key()).ok();
+                self.feed = Feed::Ready(feed);
+            }
+            Msg::FeedLoaded(Err(err)) => {
+                log::info!("Word of the week feed not available: {}", err);
+                self.feed = Feed::Unavailable;
+            }
+            Msg::Vote(word) => {
+                let feed = match &self.feed {
+                    Feed::Ready(feed) if self.vote.is_none() && !feed.is_closed() => feed,
+                    _ => return false,
+                };
+
+                let path = format!("voting/{}/votes", api::path_segment(&feed.week));
+                let _res = storage::set(feed.vote_key(), &word);
+                self.vote = Some(word.clone());
+                self.is_vote_failed = false;
+
+                ctx.link().send_future(async move {
+                    Msg::VoteSubmitted(api::submit(&path, &Ballot { word: &word }).await)
+                });
+            }
+            Msg::VoteSubmitted(Ok(())) => return false,
+            Msg::VoteSubmitted(Err(err)) => {
+                log::info!("Submitting the vote failed: {}", err);
+
+                // Forget the local vote so that it can be cast again
+                if let Feed::Ready(feed) = &self.feed {
+                    storage::delete(feed.vote_key());
+                }
+                self.vote = None;
+                self.is_vote_failed = true;
+            }
+        }
+
+        true
+    }
+
+    fn view(&self, ctx: &Context<Self>) -> Html {
+        let feed = match &self.feed {
+            Feed::Ready(feed) => feed,
+            Feed::Loading | Feed::Unavailable => return html! {},
+        };
+
+        html! {
+            <div class="voting">
+                <label class="label">{tf("Viikon teema: {}", &[&feed.theme])}</label>
+                {
+                    if feed.is_closed() {
+                        let total = feed.results.iter().map(|count| count.votes).sum::<usize>().max(1);
+
+                        html! {
+                            <ul>
+                                {feed.results.iter().map(|count| {
+                                    let is_own = self.vote.as_deref() == Some(count.word.as_str());
+                                    html! {
+                                        <li class={classes!("statistics", is_own.then(|| "voting-own"))}>
+                                            {format!("{}: {} % ({})", count.word, count.votes * 100 / total, count.votes)}
+                                        </li>
+                                    }
+                                }).collect::<Html>()}
+                            </ul>
+                        }
+                    } else if let Some(vote) = &self.vote {
+                        html! {
+                            <p class="voting-info">
+                                {tf(
+                                    "Äänestit sanulia {}. Tulokset julkaistaan {}.",
+                                    &[vote, &feed.closes_at.with_timezone(&Local).format("%-d.%-m. %H:%M")],
+                                )}
+                            </p>
+                        }
+                    } else {
+                        html! {
+                            <>
+                                <p class="voting-info">{t("Äänestä ensi viikon päivän sanulia:")}</p>
+                                <div class="select-container">
+                                    {feed.candidates.iter().map(|word| {
+                                        let candidate = word.clone();
+                                        let vote = ctx.link().callback(move |e: MouseEvent| {
+                                            e.prevent_default();
+                                            Msg::Vote(candidate.clone())
+                                        });
+
+                                        html! {
+                                            <button class="select" onmousedown={vote}>{word}</button>
+                                        }
+                                    }).collect::<Html>()}
+                                </div>
+                                {
+                                    if self.is_vote_failed {
+                                        html! { <p class="voting-info">{t("Äänen lähettäminen epäonnistui, yritä uudelleen.")}</p> }
+                                    } else {
+                                        html! {}
+                                    }
+                                }
+                            </>
+                        }
+                    }
+                }
+            </div>
+        }
+    }
+}
@@ -0,0 +1,62 @@
+//! Index of today's daily word as given by the backend, so that the players with skewed clocks
+//! get the same puzzle and a word can be swapped in an emergency. The backend answers `GET daily`
+//! with `{"index": 1012}`, the index in the daily list. The index is kept for the date it was
+//! fetched on, and `Sanuli::get_daily_word_index()` falls back to counting the days from the
+//! epoch for the other dates and until the answer arrives.
+
+use std::cell::Cell;
+
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+
+use crate::api::{self, ApiError};
+use crate::rollover;
+use crate::sanuli::Sanuli;
+use crate::storage;
+
+const DAILY_PATH: &str = "daily";
+// The last fetched index, used when offline
+const CACHE_KEY: &str = "daily_index";
+
+#[derive(Clone, Copy, Serialize, Deserialize)]
+struct DailyIndex {
+    // Today on the device when the index was fetched
+    date: NaiveDate,
+    index: usize,
+}
+
+#[derive(Deserialize)]
+struct DailyResponse {
+    index: usize,
+}
+
+thread_local! {
+    static DAILY_INDEX: Cell<Option<DailyIndex>> = Cell::new(storage::get(CACHE_KEY).ok());
+}
+
+pub fn index_of(date: NaiveDate) -> Option<usize> {
+    DAILY_INDEX
+        .with(Cell::get)
+        .filter(|daily_index| daily_index.date == date)
+        .map(|daily_index| daily_index.index)
+}
+
+/// Fetches the index of today's word, returning whether the word of today changed. Indexes past
+/// the end of the daily list of the build are ignored.
+pub async fn fetch(daily_words: usize) -> Result<bool, ApiError> {
+    let today = rollover::today();
+    let response: DailyResponse = api::get(DAILY_PATH).await?;
+    if response.index >= daily_words {
+        return Err(ApiError::Parse);
+    }
+
+    let is_changed = Sanuli::get_daily_word_index(today) != response.index;
+    let daily_index = DailyIndex {
+        date: today,
+        index: response.index,
+    };
+    DAILY_INDEX.with(|cached| cached.set(Some(daily_index)));
+    let _result = storage::set(CACHE_KEY, daily_index);
+
+    Ok(is_changed)
+}
@@ -0,0 +1,33 @@
+//! Definitions of the words of the daily list for the definition mode. The backend answers
+//! `GET definitions/{index}` with `{"definition": "..."}`, the index being the word's index in
+//! the daily list, so that the guessed word itself never shows up in the requests.
+
+use serde::Deserialize;
+
+use crate::api::{self, ApiError};
+
+const DEFINITIONS_PATH: &str = "definitions";
+
+#[derive(Deserialize)]
+struct DefinitionResponse {
+    definition: String,
+}
+
+/// The clue shown above the board of a definition game
+pub enum Definition {
+    Loading,
+    Loaded(String),
+    // Without a definition the game plays like a classic one
+    Failed,
+}
+
+pub async fn fetch(daily_index: usize) -> Result<String, ApiError> {
+    let response: DefinitionResponse =
+        api::get(&format!("{}/{}", DEFINITIONS_PATH, daily_index)).await?;
+    let definition = response.definition.trim();
+    if definition.is_empty() {
+        return Err(ApiError::Parse);
+    }
+
+    Ok(definition.to_owned())
+}
@@ -0,0 +1,272 @@
+//! The full word lists are deployed next to index.html instead of being embedded in the wasm
+//! module, they are the bulk of the download. A fetched list is cached along with its version
+//! when a signed delta file is deployed next to it, and later only the changes are fetched:
+//!
+//! ```text
+//! version 2024-06-01
+//! base 2024-05-01
+//! hash 9f3b0c1d2e4a5b6c
+//! +UUSIN
+//! -VANHA
+//! signature 5d1f...
+//! ```
+//!
+//! The delta turns the list of the `base` version into the `version` one, `hash` being
+//! `cipher::list_hash()` of the resulting words in sorted order. The last line is the hex of
+//! an Ed25519 signature of everything before it, made with the key whose public half is
+//! embedded at build time. A delta without a valid signature is ignored. A cached list of
+//! another version, or a result with another hash, falls back to fetching the whole list.
+
+use std::collections::BTreeSet;
+
+use js_sys::{Array, Uint8Array};
+use serde::{Deserialize, Serialize};
+use wasm_bindgen::{JsCast, JsValue};
+use wasm_bindgen_futures::JsFuture;
+use web_sys::{window, CryptoKey, Response};
+
+use crate::cipher;
+use crate::i18n::Language;
+use crate::storage;
+
+// Hex of the raw Ed25519 public key verifying the deltas. Left unset, the deltas are ignored
+// and the whole list is fetched every time:
+// `SANULI_DELTA_PUBLIC_KEY=3d4017c3... trunk build --release`
+const DELTA_PUBLIC_KEY: Option<&str> = option_env!("SANULI_DELTA_PUBLIC_KEY");
+
+fn path(language: Language) -> &'static str {
+    match language {
+        Language::Finnish => "/full-words.txt",
+        Language::Swedish => "/sv-full-words.txt",
+        Language::English => "/en-full-words.txt",
+    }
+}
+
+fn delta_path(language: Language) -> &'static str {
+    match language {
+        Language::Finnish => "/full-words.delta",
+        Language::Swedish => "/sv-full-words.delta",
+        Language::English => "/en-full-words.delta",
+    }
+}
+
+fn cache_key(language: Language) -> String {
+    format!("full_words|{}", language.code())
+}
+
+#[derive(Serialize, Deserialize)]
+struct CachedList {
+    version: String,
+    words: String,
+}
+
+struct Delta {
+    version: String,
+    base: String,
+    hash: u64,
+    added: Vec<String>,
+    removed: Vec<String>,
+}
+
+impl Delta {
+    fn parse(data: &str) -> Option<Self> {
+        let mut version = None;
+        let mut base = None;
+        let mut hash = None;
+        let mut added = Vec::new();
+        let mut removed = Vec::new();
+
+        for line in data.lines().map(str::trim).filter(|line| !line.is_empty()) {
+            if let Some(word) = line.strip_prefix('+') {
+                added.push(word.to_owned());
+            } else if let Some(word) = line.strip_prefix('-') {
+                removed.push(word.to_owned());
+            } else {
+                match line.split_once(' ')? {
+                    ("version", value) => version = Some(value.trim().to_owned()),
+                    ("base", value) => base = Some(value.trim().to_owned()),
+                    ("hash", value) => hash = u64::from_str_radix(value.trim(), 16).ok(),
+                    // Servers falling back to index.html for missing files
+                    _ => return None,
+                }
+            }
+        }
+
+        Some(Self {
+            version: version?,
+            base: base?,
+            hash: hash?,
+            added,
+            removed,
+        })
+    }
+
+    /// The words of the new version, None if they don't add up to the hash
+    fn apply(&self, words: &str) -> Option<String> {
+        let mut words = sorted_words(words);
+        for word in &self.removed {
+            words.remove(word.as_str());
+        }
+        words.extend(self.added.iter().map(String::as_str));
+
+        if hash(&words) != self.hash {
+            return None;
+        }
+        Some(words.into_iter().collect::<Vec<_>>().join("\n"))
+    }
+}
+
+/// Splits the delta into the signed part and the signature of its last line
+fn split_signature(data: &str) -> Option<(&str, Vec<u8>)> {
+    let data = data.trim_end();
+    let start = data.rfind('\n').map_or(0, |index| index + 1);
+    let signature = data[start..].trim().strip_prefix("signature ")?;
+
+    Some((&data[..start], decode_hex(signature.trim())?))
+}
+
+fn decode_hex(hex: &str) -> Option<Vec<u8>> {
+    if !hex.len().is_multiple_of(2) {
+        return None;
+    }
+
+    (0..hex.len())
+        .step_by(2)
+        .map(|index| u8::from_str_radix(hex.get(index..index + 2)?, 16).ok())
+        .collect()
+}
+
+/// Verifies the signature with the Web Crypto API, None if it's unavailable or the key is unset
+async fn verify(signed: &str, signature: &[u8]) -> Option<bool> {
+    let public_key = decode_hex(DELTA_PUBLIC_KEY?)?;
+    let subtle = window()?.crypto().ok()?.subtle();
+
+    let key_data = Uint8Array::from(public_key.as_slice());
+    let usages = Array::of1(&JsValue::from_str("verify"));
+    let key: CryptoKey = JsFuture::from(
+        subtle
+            .import_key_with_str("raw", &key_data, "Ed25519", false, &usages)
+            .ok()?,
+    )
+    .await
+    .ok()?
+    .dyn_into()
+    .ok()?;
+
+    JsFuture::from(
+        subtle
+            .verify_with_str_and_u8_array_and_u8_array(
+                "Ed25519",
+                &key,
+                signature,
+                signed.as_bytes(),
+            )
+            .ok()?,
+    )
+    .await
+    .ok()?
+    .as_bool()
+}
+
+async fn fetch_delta(language: Language) -> Option<Delta> {
+    let data = fetch_text(delta_path(language)).await?;
+    let (signed, signature) = split_signature(&data)?;
+
+    if verify(signed, &signature).await != Some(true) {
+        log::warn!("Word list delta isn't signed with the embedded key, ignoring it");
+        return None;
+    }
+    Delta::parse(signed)
+}
+
+fn sorted_words(words: &str) -> BTreeSet<&str> {
+    words
+        .lines()
+        .map(str::trim)
+        .filter(|word| !word.is_empty())
+        .collect()
+}
+
+fn hash(words: &BTreeSet<&str>) -> u64 {
+    cipher::list_hash(words.iter().map(|word| word.as_bytes()))
+}
+
+async fn fetch_text(path: &str) -> Option<String> {
+    let window = window()?;
+
+    let response: Response = JsFuture::from(window.fetch_with_str(path))
+        .await
+        .ok()?
+        .dyn_into()
+        .ok()?;
+    if !response.ok() {
+        return None;
+    }
+
+    JsFuture::from(response.text().ok()?)
+        .await
+        .ok()?
+        .as_string()
+}
+
+async fn fetch_full(language: Language) -> Option<String> {
+    let data = fetch_text(path(language)).await?;
+
+    // Servers falling back to index.html for missing files would otherwise reject every guess
+    let is_word_list = data
+        .lines()
+        .all(|word| word.trim().chars().all(char::is_uppercase));
+
+    Some(data).filter(|data| is_word_list && !data.trim().is_empty())
+}
+
+/// Fetches the full word list of the puzzle language, one word per line. Offline the cached
+/// list is used even if it's outdated.
+pub async fn fetch(language: Language) -> Option<String> {
+    let key = cache_key(language);
+    let cached = storage::cache_get::<CachedList>(&key);
+    let delta = fetch_delta(language).await;
+
+    if let (Some(cached), Some(delta)) = (&cached, &delta) {
+        if cached.version == delta.version {
+            return Some(cached.words.clone());
+        }
+
+        if cached.version == delta.base {
+            match delta.apply(&cached.words) {
+                Some(words) => {
+                    storage::cache_set(
+                        &key,
+                        CachedList {
+                            version: delta.version.clone(),
+                            words: words.clone(),
+                        },
+                    );
+                    return Some(words);
+                }
+                None => log::warn!(
+                    "Word list delta {} -> {} doesn't match its hash, fetching the whole list",
+                    delta.base,
+                    delta.version
+                ),
+            }
+        }
+    }
+
+    match fetch_full(language).await {
+        Some(words) => {
+            // Only a list matching the hash of the delta is known to be of its version
+            if let Some(delta) = delta.filter(|delta| hash(&sorted_words(&words)) == delta.hash) {
+                storage::cache_set(
+                    &key,
+                    CachedList {
+                        version: delta.version,
+                        words: words.clone(),
+                    },
+                );
+            }
+            Some(words)
+        }
+        None => cached.map(|cached| cached.words),
+    }
+}
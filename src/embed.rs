@@ -0,0 +1,236 @@
+//! API for pages embedding the game, and for scripts driving it on kiosks.
+//!
+//! Built with `--features embed`, the game installs a `window.sanuli` object:
+//!
+//! ```js
+//! const unsubscribe = window.sanuli.subscribe((state) => console.log(state.guesses));
+//! window.sanuli.setMode("daily");
+//! window.sanuli.submitGuess("kissa");
+//! // Once the game has ended, continues to the next word
+//! window.sanuli.startGame();
+//! // Repeats the same words and messages, eg. in end-to-end tests
+//! window.sanuli.setSeed(1234);
+//! unsubscribe();
+//! ```
+//!
+//! The subscribers are called with the state after every change, and once when subscribing.
+
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use js_sys::{Function, Object, Reflect};
+use serde::Serialize;
+use wasm_bindgen::{prelude::*, JsCast};
+use web_sys::window;
+use yew::html::Scope;
+
+use crate::game::Game;
+use crate::manager::GameMode;
+use crate::rollover;
+use crate::sanuli::Sanuli;
+use crate::{App, Msg};
+
+#[derive(Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EmbedState {
+    pub mode: &'static str,
+    pub word_length: usize,
+    pub max_guesses: usize,
+    // The submitted guesses of the first board
+    pub guesses: Vec<String>,
+    pub current_guess: String,
+    pub is_guessing: bool,
+    pub is_winner: bool,
+    pub message: String,
+}
+
+impl EmbedState {
+    pub fn from_game(game: &dyn Game) -> Self {
+        let board = &game.boards()[0];
+        let word = |guess: &Vec<(char, _)>| guess.iter().map(|(c, _)| *c).collect::<String>();
+        let submitted = if board.is_guessing {
+            board.current_guess
+        } else {
+            board.current_guess + 1
+        };
+
+        Self {
+            mode: game.game_mode().name(),
+            word_length: game.word_length(),
+            max_guesses: game.max_guesses(),
+            guesses: board.guesses.iter().take(submitted).map(word).collect(),
+            current_guess: if board.is_guessing {
+                board
+                    .guesses
+                    .get(board.current_guess)
+                    .map(word)
+                    .unwrap_or_default()
+            } else {
+                String::new()
+            },
+            is_guessing: game.is_guessing(),
+            is_winner: game.is_winner(),
+            message: game.message(),
+        }
+    }
+}
+
+/// The modes an embedding page can switch to, the dated ones are today's puzzles
+fn parse_mode(name: &str) -> Option<GameMode> {
+    let today = rollover::today();
+
+    match name {
+        "classic" => Some(GameMode::Classic),
+        "relay" => Some(GameMode::Relay),
+        "daily" => Some(GameMode::DailyWord(today)),
+        "evening" => Some(GameMode::EveningWord(today)),
+        "weekly" => Some(GameMode::Weekly(Sanuli::get_week_start(today))),
+        "quadruple" => Some(GameMode::Quadruple),
+        "double-daily" => Some(GameMode::DoubleDaily(today)),
+        "zen" => Some(GameMode::Zen),
+        "pressure" => Some(GameMode::Pressure),
+        "deduction" => Some(GameMode::Deduction),
+        "marathon" => Some(GameMode::Marathon),
+        "blind" => Some(GameMode::Blind),
+        #[cfg(feature = "definitions")]
+        "definition" => Some(GameMode::Definition),
+        _ => None,
+    }
+}
+
+type Subscribers = Rc<RefCell<Vec<Function>>>;
+
+/// The installed `window.sanuli` object, removed when dropped
+pub struct EmbedApi {
+    subscribers: Subscribers,
+    last_state: Option<EmbedState>,
+    // The last state as a JS object, passed to the new subscribers
+    current: Rc<RefCell<JsValue>>,
+    _closures: Vec<Closure<dyn Fn(JsValue) -> JsValue>>,
+}
+
+impl EmbedApi {
+    pub fn install(link: Scope<App>) -> Option<Self> {
+        let window = window()?;
+        let api = Object::new();
+        let subscribers: Subscribers = Rc::new(RefCell::new(Vec::new()));
+        let current = Rc::new(RefCell::new(JsValue::UNDEFINED));
+
+        let start_game = {
+            let link = link.clone();
+            Closure::wrap(Box::new(move |_: JsValue| {
+                link.send_message(Msg::StartGame);
+                JsValue::UNDEFINED
+            }) as Box<dyn Fn(JsValue) -> JsValue>)
+        };
+
+        let set_mode = {
+            let link = link.clone();
+            Closure::wrap(Box::new(move |name: JsValue| {
+                let mode = name.as_string().and_then(|name| parse_mode(&name));
+                let is_valid = mode.is_some();
+                if let Some(mode) = mode {
+                    link.send_message(Msg::ChangeGameMode(mode));
+                }
+                JsValue::from_bool(is_valid)
+            }) as Box<dyn Fn(JsValue) -> JsValue>)
+        };
+
+        let set_seed = {
+            let link = link.clone();
+            Closure::wrap(Box::new(move |seed: JsValue| {
+                let seed = seed
+                    .as_f64()
+                    .filter(|seed| *seed >= 0.0 && seed.fract() == 0.0);
+                let is_valid = seed.is_some();
+                if let Some(seed) = seed {
+                    link.send_message(Msg::SetSeed(seed as u64));
+                }
+                JsValue::from_bool(is_valid)
+            }) as Box<dyn Fn(JsValue) -> JsValue>)
+        };
+
+        let submit_guess = Closure::wrap(Box::new(move |word: JsValue| {
+            let word = word.as_string();
+            let is_valid = word.is_some();
+            if let Some(word) = word {
+                link.send_message(Msg::SubmitWord(word));
+            }
+            JsValue::from_bool(is_valid)
+        }) as Box<dyn Fn(JsValue) -> JsValue>);
+
+        let subscribe = {
+            let subscribers = subscribers.clone();
+            let current = current.clone();
+            Closure::wrap(Box::new(move |callback: JsValue| {
+                let callback = match callback.dyn_into::<Function>() {
+                    Ok(callback) => callback,
+                    Err(_) => return JsValue::UNDEFINED,
+                };
+                if !current.borrow().is_undefined() {
+                    let _res = callback.call1(&JsValue::NULL, &current.borrow());
+                }
+                subscribers.borrow_mut().push(callback.clone());
+
+                let subscribers = subscribers.clone();
+                Closure::once_into_js(move || {
+                    subscribers
+                        .borrow_mut()
+                        .retain(|subscriber| *subscriber != callback);
+                })
+            }) as Box<dyn Fn(JsValue) -> JsValue>)
+        };
+
+        let closures = vec![start_game, set_mode, set_seed, submit_guess, subscribe];
+        let names = [
+            "startGame",
+            "setMode",
+            "setSeed",
+            "submitGuess",
+            "subscribe",
+        ];
+        for (name, closure) in names.iter().zip(closures.iter()) {
+            Reflect::set(&api, &JsValue::from_str(name), closure.as_ref()).ok()?;
+        }
+        Reflect::set(&window, &JsValue::from_str("sanuli"), &api).ok()?;
+
+        Some(Self {
+            subscribers,
+            last_state: None,
+            current,
+            _closures: closures,
+        })
+    }
+
+    /// Passes the state to the subscribers, unless it is unchanged since the last call
+    pub fn notify(&mut self, state: EmbedState) {
+        if self.last_state.as_ref() == Some(&state) {
+            return;
+        }
+
+        let value = match serde_json::to_string(&state)
+            .ok()
+            .and_then(|json| js_sys::JSON::parse(&json).ok())
+        {
+            Some(value) => value,
+            None => return,
+        };
+
+        // Cloned, the subscribers may unsubscribe while called
+        let subscribers = self.subscribers.borrow().clone();
+        for subscriber in subscribers.iter() {
+            let _res = subscriber.call1(&JsValue::NULL, &value);
+        }
+
+        *self.current.borrow_mut() = value;
+        self.last_state = Some(state);
+    }
+}
+
+impl Drop for EmbedApi {
+    fn drop(&mut self) {
+        if let Some(window) = window() {
+            let _res = Reflect::delete_property(&window, &JsValue::from_str("sanuli"));
+        }
+    }
+}
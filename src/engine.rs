@@ -0,0 +1,208 @@
+//! Rules of the game without any dependencies on the browser, shared by the app and the native
+//! tools in `src/bin` and `benches`, which include this file with `#[path]` as `mod engine`.
+
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+/// Color of a tile of a revealed guess
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum Feedback {
+    Correct,
+    Present,
+    Absent,
+}
+
+/// Colors of the guess against the word. A letter guessed more often than the word has it is
+/// only present as many times as it is in the word, the correct positions first.
+pub fn score(guess: &[char], word: &[char]) -> Vec<Feedback> {
+    let mut feedback = vec![Feedback::Absent; guess.len()];
+    let mut unmatched: HashMap<char, usize> = HashMap::with_capacity(word.len());
+
+    for (index, (guessed, actual)) in guess.iter().zip(word.iter()).enumerate() {
+        if guessed == actual {
+            feedback[index] = Feedback::Correct;
+        } else {
+            *unmatched.entry(*actual).or_insert(0) += 1;
+        }
+    }
+
+    for (index, guessed) in guess.iter().enumerate() {
+        if feedback[index] == Feedback::Correct {
+            continue;
+        }
+
+        if let Some(count) = unmatched.get_mut(guessed) {
+            if *count > 0 {
+                *count -= 1;
+                feedback[index] = Feedback::Present;
+            }
+        }
+    }
+
+    feedback
+}
+
+// A won game earns this for every guess it had left, and once more for winning at all
+const POINTS_PER_GUESS_LEFT: u32 = 100;
+// Solves faster than this earn a part of the speed bonus, an instant solve all of it
+const SPEED_BONUS_SECS: i64 = 300;
+const MAX_SPEED_BONUS: u32 = 100;
+const POINTS_PER_STREAK_GAME: u32 = 10;
+const MAX_STREAK_BONUS: u32 = 100;
+
+/// What a finished game earns points for
+pub struct ScoredGame {
+    pub is_winner: bool,
+    pub guesses: usize,
+    pub max_guesses: usize,
+    pub duration_secs: Option<i64>,
+    // The streak including the game
+    pub streak: usize,
+    pub is_hard_mode: bool,
+}
+
+/// Points of a game by what they were earned for
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default, Serialize, Deserialize)]
+pub struct Points {
+    pub guesses: u32,
+    pub speed: u32,
+    pub streak: u32,
+    pub hard_mode: u32,
+}
+
+impl Points {
+    pub fn total(&self) -> u32 {
+        self.guesses + self.speed + self.streak + self.hard_mode
+    }
+}
+
+/// The lost games earn nothing. The fewer guesses the more points, with bonuses for a quick
+/// solve and a running streak, and half of the points of the guesses again in hard mode.
+pub fn points(game: &ScoredGame) -> Points {
+    if !game.is_winner {
+        return Points::default();
+    }
+
+    let guesses_left = game.max_guesses.saturating_sub(game.guesses) as u32;
+    let guesses = (guesses_left + 1) * POINTS_PER_GUESS_LEFT;
+    let speed = game.duration_secs.map_or(0, |secs| {
+        let secs_left = SPEED_BONUS_SECS - secs.clamp(0, SPEED_BONUS_SECS);
+        (secs_left * MAX_SPEED_BONUS as i64 / SPEED_BONUS_SECS) as u32
+    });
+    let streak = (game.streak.min(u32::MAX as usize) as u32)
+        .saturating_mul(POINTS_PER_STREAK_GAME)
+        .min(MAX_STREAK_BONUS);
+    let hard_mode = if game.is_hard_mode { guesses / 2 } else { 0 };
+
+    Points {
+        guesses,
+        speed,
+        streak,
+        hard_mode,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn won_game(guesses: usize) -> ScoredGame {
+        ScoredGame {
+            is_winner: true,
+            guesses,
+            max_guesses: 6,
+            duration_secs: None,
+            streak: 0,
+            is_hard_mode: false,
+        }
+    }
+
+    #[test]
+    fn lost_game_earns_nothing() {
+        let game = ScoredGame {
+            is_winner: false,
+            duration_secs: Some(0),
+            streak: 10,
+            is_hard_mode: true,
+            ..won_game(6)
+        };
+
+        assert_eq!(points(&game), Points::default());
+    }
+
+    #[test]
+    fn guesses_left_earn_points() {
+        assert_eq!(points(&won_game(1)).guesses, 600);
+        assert_eq!(points(&won_game(3)).guesses, 400);
+        assert_eq!(points(&won_game(6)).guesses, 100);
+    }
+
+    #[test]
+    fn guesses_over_the_maximum_earn_the_points_of_a_win() {
+        assert_eq!(points(&won_game(8)).guesses, 100);
+    }
+
+    #[test]
+    fn speed_bonus_is_clamped() {
+        let speed = |duration_secs| {
+            points(&ScoredGame {
+                duration_secs,
+                ..won_game(3)
+            })
+            .speed
+        };
+
+        assert_eq!(speed(None), 0);
+        assert_eq!(speed(Some(-5)), MAX_SPEED_BONUS);
+        assert_eq!(speed(Some(0)), MAX_SPEED_BONUS);
+        assert_eq!(speed(Some(SPEED_BONUS_SECS / 2)), MAX_SPEED_BONUS / 2);
+        assert_eq!(speed(Some(SPEED_BONUS_SECS)), 0);
+        assert_eq!(speed(Some(i64::MAX)), 0);
+    }
+
+    #[test]
+    fn streak_bonus_is_capped() {
+        let streak = |streak| {
+            points(&ScoredGame {
+                streak,
+                ..won_game(3)
+            })
+            .streak
+        };
+
+        assert_eq!(streak(0), 0);
+        assert_eq!(streak(5), 5 * POINTS_PER_STREAK_GAME);
+        assert_eq!(streak(11), MAX_STREAK_BONUS);
+        assert_eq!(streak(usize::MAX), MAX_STREAK_BONUS);
+    }
+
+    #[test]
+    fn hard_mode_earns_half_of_the_guess_points() {
+        let game = ScoredGame {
+            is_hard_mode: true,
+            ..won_game(3)
+        };
+        let points = points(&game);
+
+        assert_eq!(points.hard_mode, 200);
+        assert_eq!(points.total(), 600);
+    }
+
+    #[test]
+    fn repeated_letters_are_present_only_as_often_as_in_the_word() {
+        let word = "KALAT".chars().collect::<Vec<_>>();
+        let guess = "TAAKA".chars().collect::<Vec<_>>();
+
+        assert_eq!(
+            score(&guess, &word),
+            vec![
+                Feedback::Present,
+                Feedback::Correct,
+                Feedback::Present,
+                Feedback::Present,
+                Feedback::Absent,
+            ]
+        );
+    }
+}
@@ -0,0 +1,70 @@
+use crate::manager::GameMode;
+
+/// Events of the game engine, queued by the manager and passed on to the subscribers after every
+/// update of the app
+#[derive(Clone, PartialEq)]
+pub enum GameEvent {
+    // The first row of a word was revealed, or a relay continued with the carried over row
+    GameStarted {
+        mode: GameMode,
+        word_length: usize,
+    },
+    // A row was revealed, either guessed or lost to the timer
+    GuessRevealed {
+        mode: GameMode,
+        guesses: usize,
+    },
+    GameFinished {
+        mode: GameMode,
+        word_length: usize,
+        is_winner: bool,
+        guesses: usize,
+    },
+    // Another game became the active one
+    GameSwitched {
+        mode: GameMode,
+    },
+}
+
+impl GameEvent {
+    pub fn name(&self) -> &'static str {
+        match self {
+            GameEvent::GameStarted { .. } => "game_started",
+            GameEvent::GuessRevealed { .. } => "guess_revealed",
+            GameEvent::GameFinished { .. } => "game_finished",
+            GameEvent::GameSwitched { .. } => "game_switched",
+        }
+    }
+}
+
+pub trait Subscriber {
+    fn on_event(&mut self, event: &GameEvent);
+}
+
+/// Subscribers of the cross-cutting features, like analytics, that only need to know what
+/// happened in the game
+#[derive(Default)]
+pub struct EventBus {
+    subscribers: Vec<Box<dyn Subscriber>>,
+}
+
+impl EventBus {
+    pub fn subscribe(&mut self, subscriber: Box<dyn Subscriber>) {
+        self.subscribers.push(subscriber);
+    }
+
+    pub fn emit(&mut self, event: &GameEvent) {
+        for subscriber in self.subscribers.iter_mut() {
+            subscriber.on_event(event);
+        }
+    }
+}
+
+/// Logs the events of the development builds to the console
+pub struct EventLog;
+
+impl Subscriber for EventLog {
+    fn on_event(&mut self, event: &GameEvent) {
+        log::info!("Game event: {}", event.name());
+    }
+}
@@ -1,32 +1,180 @@
-use std::collections::HashMap;
+use std::collections::HashSet;
+use std::mem;
+use std::rc::Rc;
 
 use gloo_storage::errors::StorageError;
 
-pub type KnownStates = HashMap<(char, usize), CharacterState>;
-pub type KnownCounts = HashMap<char, CharacterCount>;
+use crate::engine::{self, Points};
+use crate::i18n::Language;
+use crate::rng::GameRng;
+use crate::share::ShareResult;
+use crate::storage::Transaction;
 
 use crate::manager::{
-    CharacterCount, CharacterState, GameMode, KeyState, Theme, TileState, WordList,
+    CharacterCount, CharacterState, GameMode, KeyCount, KeyState, KeyboardHints, Theme, TileState,
+    Word, WordList,
 };
 
+// Letters of every puzzle language, the keyboards use their subsets
+pub const ALPHABET_LEN: usize = 29;
+
+pub fn letter_index(character: char) -> Option<usize> {
+    match character {
+        'A'..='Z' => Some(character as usize - 'A' as usize),
+        'Å' => Some(26),
+        'Ä' => Some(27),
+        'Ö' => Some(28),
+        _ => None,
+    }
+}
+
+fn letter(index: usize) -> char {
+    match index {
+        26 => 'Å',
+        27 => 'Ä',
+        28 => 'Ö',
+        _ => (b'A' + index as u8) as char,
+    }
+}
+
+/// What is known of the word at a row: the state of every letter at every position, and the
+/// counts of the letters. Copied to the next row after every guess, so kept to flat arrays.
+#[derive(Clone, PartialEq)]
+pub struct Knowledge {
+    // Positions of the word, with the state of every letter of the alphabet
+    states: Vec<[CharacterState; ALPHABET_LEN]>,
+    counts: [Option<CharacterCount>; ALPHABET_LEN],
+    // Number of positions at which each letter is known to be correct, for the keyboard
+    correct: [usize; ALPHABET_LEN],
+}
+
+impl Default for Knowledge {
+    fn default() -> Self {
+        Self {
+            states: Vec::new(),
+            counts: [None; ALPHABET_LEN],
+            correct: [0; ALPHABET_LEN],
+        }
+    }
+}
+
+impl Knowledge {
+    pub fn state(&self, character: char, index: usize) -> CharacterState {
+        letter_index(character)
+            .and_then(|letter| Some(self.states.get(index)?[letter]))
+            .unwrap_or(CharacterState::Unknown)
+    }
+
+    fn set_state(&mut self, character: char, index: usize, state: CharacterState) {
+        let letter = match letter_index(character) {
+            Some(letter) => letter,
+            None => return,
+        };
+        if self.states.len() <= index {
+            self.states
+                .resize(index + 1, [CharacterState::Unknown; ALPHABET_LEN]);
+        }
+
+        let previous = mem::replace(&mut self.states[index][letter], state);
+        if previous == CharacterState::Correct {
+            self.correct[letter] -= 1;
+        }
+        if state == CharacterState::Correct {
+            self.correct[letter] += 1;
+        }
+    }
+
+    pub fn count(&self, character: char) -> Option<CharacterCount> {
+        letter_index(character).and_then(|letter| self.counts[letter])
+    }
+
+    fn set_count(&mut self, character: char, count: CharacterCount) {
+        if let Some(letter) = letter_index(character) {
+            self.counts[letter] = Some(count);
+        }
+    }
+
+    /// Number of positions at which the letter is known to be correct
+    pub fn correct_count(&self, character: char) -> usize {
+        letter_index(character).map_or(0, |letter| self.correct[letter])
+    }
+
+    /// The known states by the letter and its position, for the bug reports
+    pub fn known_states(&self) -> Vec<((char, usize), CharacterState)> {
+        self.states
+            .iter()
+            .enumerate()
+            .flat_map(|(index, states)| {
+                states
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, state)| **state != CharacterState::Unknown)
+                    .map(move |(letter, state)| ((self::letter(letter), index), *state))
+            })
+            .collect()
+    }
+
+    pub fn known_counts(&self) -> Vec<(char, CharacterCount)> {
+        self.counts
+            .iter()
+            .enumerate()
+            .filter_map(|(letter, count)| Some((self::letter(letter), (*count)?)))
+            .collect()
+    }
+}
+
 pub const SUCCESS_EMOJIS: [&str; 9] = ["🥳", "🤩", "🤗", "🎉", "😊", "😺", "😎", "👏", ":3"];
 pub const DEFAULT_WORD_LENGTH: usize = 5;
 pub const DEFAULT_MAX_GUESSES: usize = 6;
 pub const DEFAULT_ALLOW_PROFANITIES: bool = false;
+const DEDUCTION_ATTEMPTS: usize = 10;
+
+/// Storage key of a game. The keys of the Finnish games predate the other puzzle languages.
+pub fn game_key(
+    game_mode: &GameMode,
+    word_list: &WordList,
+    word_length: usize,
+    language: Language,
+) -> String {
+    let key = format!(
+        "game|{}|{}|{}",
+        serde_json::to_string(game_mode).unwrap(),
+        serde_json::to_string(word_list).unwrap(),
+        word_length
+    );
+
+    match language {
+        Language::Finnish => key,
+        language => format!("{}|{}", key, language.code()),
+    }
+}
 
 pub trait Game {
     fn title(&self) -> String;
     fn next_word(&mut self);
-    fn keyboard_tilestate(&self, key: &char) -> KeyState;
+    fn keyboard_tilestate(&self, key: &char, hints: KeyboardHints) -> KeyState;
+    // Only the games of a single board know the count of a letter
+    fn keyboard_count(&self, _key: &char, _hints: KeyboardHints) -> Option<KeyCount> {
+        None
+    }
     fn submit_guess(&mut self);
     fn push_character(&mut self, character: char);
     fn pop_character(&mut self);
-    fn share_emojis(&self, theme: Theme) -> Option<String>;
+    fn cursor(&self) -> usize;
+    fn move_cursor(&mut self, position: usize);
+    fn share_result(&self, theme: Theme) -> Option<ShareResult>;
     fn share_link(&self) -> Option<String>;
     fn reveal_hidden_tiles(&mut self);
+    fn undo_guess(&mut self);
+    fn expire_row(&mut self);
     fn reset(&mut self);
     fn refresh(&mut self);
-    fn persist(&self) -> Result<(), StorageError>;
+    fn persist_with(&self, transaction: &mut Transaction) -> Result<(), StorageError>;
+    fn persist(&self) -> Result<(), StorageError> {
+        let mut transaction = Transaction::new();
+        self.persist_with(&mut transaction)?;
+        transaction.commit()
+    }
     fn set_allow_profanities(&mut self, is_allowed: bool);
 
     fn game_mode(&self) -> &GameMode;
@@ -34,6 +182,10 @@ pub trait Game {
     fn word_length(&self) -> usize;
     fn max_guesses(&self) -> usize;
     fn word(&self) -> Vec<char>;
+    // The words of every board
+    fn words(&self) -> Vec<Vec<char>> {
+        vec![self.word()]
+    }
 
     fn last_guess(&self) -> String;
     fn boards(&self) -> Vec<Board>;
@@ -44,6 +196,52 @@ pub trait Game {
     fn is_hidden(&self) -> bool;
     fn is_winner(&self) -> bool;
     fn is_unknown(&self) -> bool;
+    fn is_undo_allowed(&self) -> bool;
+    fn deadline(&self) -> Option<i64>;
+    // Only a weekly challenge runs out of words
+    fn has_next_word(&self) -> bool {
+        true
+    }
+    // Continues a relay chain won on a board of another word length with the next word
+    fn continue_chain(
+        &mut self,
+        _previous_word: &[char],
+        _streak: usize,
+        _chain_scores: Vec<usize>,
+    ) {
+    }
+    // Time of the first typed letter of the word, in milliseconds
+    fn started_at(&self) -> Option<i64> {
+        None
+    }
+    fn challenge_scores(&self) -> Vec<usize> {
+        Vec::new()
+    }
+    fn chain_scores(&self) -> Vec<usize> {
+        Vec::new()
+    }
+    // Only a daily word without submitted guesses switches to or from hard mode
+    fn set_hard_mode(&mut self, _is_enabled: bool) {}
+    fn is_hard_mode(&self) -> bool {
+        false
+    }
+    // Points recorded when the game was finished, shown and shared instead of recomputing them
+    fn points(&self) -> Option<Points> {
+        None
+    }
+    fn set_points(&mut self, _points: Points) {}
+    // Whether the finish has been counted in the statistics, a practice game undone and finished
+    // again isn't counted twice
+    fn is_counted(&self) -> bool {
+        false
+    }
+    fn set_counted(&mut self) {}
+    // A fixed time limit of every row of a timed game, in seconds, instead of the shrinking one
+    fn set_row_time_limit(&mut self, _seconds: Option<u32>) {}
+    // The deductions of every board for the current row, included in the bug reports
+    fn knowledge(&self) -> Vec<Knowledge> {
+        Vec::new()
+    }
 
     fn message(&self) -> String;
     fn previous_guesses(&self) -> Vec<Vec<(char, TileState)>>;
@@ -69,21 +267,37 @@ impl PartialEq for dyn Game {
 pub struct Board {
     pub guesses: Vec<Vec<(char, TileState)>>,
     pub current_guess: usize,
+    pub cursor: usize,
     pub is_guessing: bool,
 }
 
 // Common game logic
 
+/// Duration in seconds as minutes and seconds, eg. "2:05"
+pub fn format_duration(secs: i64) -> String {
+    format!("{}:{:02}", secs / 60, secs % 60)
+}
+
+pub fn share_tile(state: &TileState, theme: Theme) -> &'static str {
+    let [correct, present, absent] = theme.share_emojis();
+    match state {
+        TileState::Correct => correct,
+        TileState::Present => present,
+        TileState::Absent => absent,
+        TileState::Unknown => "⬜",
+    }
+}
+
 pub fn known_count(
     character: &char,
     current_guess: usize,
     guess: &[(char, TileState)],
-    counts: &[KnownCounts],
+    knowledge: &[Knowledge],
     word: &[char],
 ) -> Option<CharacterCount> {
-    let known_count = counts[current_guess]
-        .get(character)
-        .unwrap_or(&CharacterCount::AtLeast(0));
+    let known_count = knowledge[current_guess]
+        .count(*character)
+        .unwrap_or(CharacterCount::AtLeast(0));
 
     // At most the same amount of characters are highlighted as there are in the word
     let count_in_word = word.iter().filter(|c| *c == character).count();
@@ -96,12 +310,12 @@ pub fn known_count(
     // Exact count should never change
     if let CharacterCount::AtLeast(count) = known_count {
         if count_in_guess > count_in_word {
-            if count_in_word >= *count {
+            if count_in_word >= count {
                 // The guess had more copies of the character than the word,
                 // the exact count is revealed
                 return Some(CharacterCount::Exactly(count_in_word));
             }
-        } else if count_in_guess == count_in_word || count_in_guess > *count {
+        } else if count_in_guess == count_in_word || count_in_guess > count {
             // One of:
             // 1) The count had the exact count but that isn't revealed yet
             // 2) Found more than before, but the exact count is still unknown
@@ -112,19 +326,14 @@ pub fn known_count(
     None
 }
 
-fn revealed_by_char(
-    guess: &[(char, TileState)],
-    current_guess: usize,
-    states: &[KnownStates],
-) -> HashMap<char, usize> {
-    let mut revealed_count_on_row: HashMap<char, usize> = HashMap::with_capacity(guess.len());
+fn revealed_by_char(guess: &[(char, TileState)], knowledge: &Knowledge) -> [usize; ALPHABET_LEN] {
+    let mut revealed_count_on_row = [0; ALPHABET_LEN];
 
     for (index, (character, _)) in guess.iter().enumerate() {
-        if let Some(CharacterState::Correct) = states[current_guess].get(&(*character, index)) {
-            revealed_count_on_row
-                .entry(*character)
-                .and_modify(|count| *count += 1)
-                .or_insert(1);
+        if knowledge.state(*character, index) == CharacterState::Correct {
+            if let Some(letter) = letter_index(*character) {
+                revealed_count_on_row[letter] += 1;
+            }
         }
     }
 
@@ -134,58 +343,48 @@ fn revealed_by_char(
 pub fn update_guess_tile_states(
     guess: &mut [(char, TileState)],
     guess_index: usize,
-    states: &[KnownStates],
-    counts: &[KnownCounts],
+    knowledge: &[Knowledge],
 ) {
-    let mut revealed_counts = revealed_by_char(guess, guess_index, states);
+    let knowledge = &knowledge[guess_index];
+    let mut revealed_counts = revealed_by_char(guess, knowledge);
 
     for (index, (character, tile_state)) in guess.iter_mut().enumerate() {
-        *tile_state = board_tile_state(
-            &mut revealed_counts,
-            guess_index,
-            states,
-            counts,
-            index,
-            character,
-        );
+        *tile_state = board_tile_state(&mut revealed_counts, knowledge, index, *character);
     }
 }
 
 pub fn board_tile_state(
-    revealed_counts: &mut HashMap<char, usize>,
-    current_guess: usize,
-    states: &[KnownStates],
-    counts: &[KnownCounts],
+    revealed_counts: &mut [usize; ALPHABET_LEN],
+    knowledge: &Knowledge,
     index: usize,
-    character: &char,
+    character: char,
 ) -> TileState {
-    match states[current_guess].get(&(*character, index)) {
-        Some(CharacterState::Correct) => {
-            return TileState::Correct;
-        }
-        Some(CharacterState::Absent) => {
-            let revealed = revealed_counts
-                .entry(*character)
-                .and_modify(|count| *count += 1)
-                .or_insert(1);
+    let letter = match letter_index(character) {
+        Some(letter) => letter,
+        // Never in the word, like the placeholders of the forfeited rows
+        None => return TileState::Absent,
+    };
 
-            let discovered_count = counts[current_guess]
-                .get(character)
-                .unwrap_or(&CharacterCount::AtLeast(0));
+    match knowledge.state(character, index) {
+        CharacterState::Correct => TileState::Correct,
+        CharacterState::Absent => {
+            revealed_counts[letter] += 1;
+
+            let discovered_count = knowledge
+                .count(character)
+                .unwrap_or(CharacterCount::AtLeast(0));
 
             match discovered_count {
                 CharacterCount::AtLeast(count) | CharacterCount::Exactly(count) => {
-                    if *revealed <= *count {
-                        return TileState::Present;
+                    if revealed_counts[letter] <= count {
+                        TileState::Present
                     } else {
-                        return TileState::Absent;
+                        TileState::Absent
                     }
                 }
             }
         }
-        _ => {
-            return TileState::Unknown;
-        }
+        CharacterState::Unknown => TileState::Unknown,
     }
 }
 
@@ -193,29 +392,22 @@ pub fn hint_tile_state(
     character: char,
     character_index: usize,
     guess_index: usize,
-    states: &[KnownStates],
-    counts: &[KnownCounts],
+    knowledge: &[Knowledge],
 ) -> TileState {
-    match states[guess_index].get(&(character, character_index)) {
-        Some(CharacterState::Correct) => TileState::Correct,
-        Some(CharacterState::Absent) => TileState::Absent,
-        _ => {
-            match counts[guess_index].get(&character) {
+    let knowledge = &knowledge[guess_index];
+
+    match knowledge.state(character, character_index) {
+        CharacterState::Correct => TileState::Correct,
+        CharacterState::Absent => TileState::Absent,
+        CharacterState::Unknown => {
+            match knowledge.count(character) {
                 Some(CharacterCount::Exactly(count)) => {
                     // We may know the exact count, but not the exact index of any characters..
-                    if *count == 0 {
+                    if count == 0 {
                         return TileState::Absent;
                     }
 
-                    let is_every_correct_found = states[guess_index]
-                        .iter()
-                        .filter(|((c, _i), state)| {
-                            c == &character && *state == &CharacterState::Correct
-                        })
-                        .count()
-                        == *count;
-
-                    if !is_every_correct_found {
+                    if knowledge.correct_count(character) != count {
                         return TileState::Present;
                     }
 
@@ -231,25 +423,63 @@ pub fn hint_tile_state(
 pub fn keyboard_tile_state(
     key: &char,
     current_guess: usize,
-    states: &[KnownStates],
-    counts: &[KnownCounts],
+    knowledge: &[Knowledge],
+    hints: KeyboardHints,
 ) -> TileState {
-    let is_correct = states[current_guess]
-        .iter()
-        .any(|((c, _index), state)| c == key && state == &CharacterState::Correct);
-    if is_correct {
+    let tile_state = known_keyboard_tile_state(*key, &knowledge[current_guess]);
+
+    match hints {
+        KeyboardHints::Full => tile_state,
+        KeyboardHints::Light => match tile_state {
+            // Only grey out the letters that are known to be absent
+            TileState::Absent => TileState::Absent,
+            _ => TileState::Unknown,
+        },
+        KeyboardHints::Off => TileState::Unknown,
+    }
+}
+
+/// The count of a letter known to occur twice or more, or known exactly with every occurrence
+/// found. Shown only with the full hints of the keyboard.
+pub fn keyboard_count(
+    key: &char,
+    current_guess: usize,
+    knowledge: &[Knowledge],
+    hints: KeyboardHints,
+) -> Option<KeyCount> {
+    if hints != KeyboardHints::Full {
+        return None;
+    }
+
+    let knowledge = knowledge.get(current_guess)?;
+    let is_exhausted = |count| count > 0 && knowledge.correct_count(*key) >= count;
+    match knowledge.count(*key)? {
+        CharacterCount::AtLeast(count) if count >= 2 => Some(KeyCount {
+            count,
+            is_exhausted: false,
+        }),
+        CharacterCount::Exactly(count) if count >= 2 || is_exhausted(count) => Some(KeyCount {
+            count,
+            is_exhausted: is_exhausted(count),
+        }),
+        _ => None,
+    }
+}
+
+fn known_keyboard_tile_state(key: char, knowledge: &Knowledge) -> TileState {
+    if knowledge.correct_count(key) > 0 {
         return TileState::Correct;
     }
 
-    match counts[current_guess].get(key) {
+    match knowledge.count(key) {
         Some(CharacterCount::AtLeast(count)) => {
-            if *count == 0 {
+            if count == 0 {
                 return TileState::Unknown;
             }
             TileState::Present
         }
         Some(CharacterCount::Exactly(count)) => {
-            if *count == 0 {
+            if count == 0 {
                 return TileState::Absent;
             }
             TileState::Present
@@ -258,36 +488,178 @@ pub fn keyboard_tile_state(
     }
 }
 
+/// Plain scoring of a guess against a word, without any previously known information
+/// Describes a revealed guess for the screen readers, eg. "K oikein, O ei sanassa, ..."
+pub fn describe_guess(guess: &[(char, TileState)]) -> String {
+    guess
+        .iter()
+        .map(|(character, tile_state)| format!("{} {}", character, tile_state.description()))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+pub fn score_guess(guess: &[char], word: &[char]) -> Vec<TileState> {
+    engine::score(guess, word)
+        .into_iter()
+        .map(TileState::from)
+        .collect()
+}
+
+/// The words that fit the colors of the revealed rows, with the number of them having each
+/// letter, the most common letters first
+pub fn letter_frequencies(
+    words: &[Word],
+    revealed: &[Vec<(char, TileState)>],
+) -> (usize, Vec<(char, usize)>) {
+    let rows = revealed
+        .iter()
+        .map(|row| {
+            let guess = row.iter().map(|(c, _)| *c).collect::<Vec<_>>();
+            let pattern = row.iter().map(|(_, state)| *state).collect::<Vec<_>>();
+            (guess, pattern)
+        })
+        .collect::<Vec<_>>();
+
+    let mut total = 0;
+    let mut counts = [0; ALPHABET_LEN];
+    for word in words {
+        if !rows
+            .iter()
+            .all(|(guess, pattern)| matches_pattern(guess, word, pattern))
+        {
+            continue;
+        }
+
+        total += 1;
+        let mut is_counted = [false; ALPHABET_LEN];
+        for index in word.iter().filter_map(|c| letter_index(*c)) {
+            if !is_counted[index] {
+                is_counted[index] = true;
+                counts[index] += 1;
+            }
+        }
+    }
+
+    let mut frequencies = counts
+        .iter()
+        .enumerate()
+        .filter(|(_, count)| **count > 0)
+        .map(|(index, count)| (letter(index), *count))
+        .collect::<Vec<_>>();
+    frequencies.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(&b.0)));
+
+    (total, frequencies)
+}
+
+/// Whether the guess gets exactly the colors of the pattern against the word
+pub fn matches_pattern(guess: &[char], word: &[char], pattern: &[TileState]) -> bool {
+    score_guess(guess, word) == pattern
+}
+
+/// Colors of the guesses of a made up game ending in the word, at most `max_rows` of them.
+/// Every guess is picked among the words that still fit the colors so far, like a player would.
+pub fn deduction_patterns(
+    words: &[Word],
+    word: &[char],
+    max_rows: usize,
+    rng: &GameRng,
+) -> Vec<Vec<TileState>> {
+    for _ in 0..DEDUCTION_ATTEMPTS {
+        let mut candidates = words.to_vec();
+        let mut patterns = Vec::new();
+
+        while let Some(guess) = rng.choose(&candidates).cloned() {
+            if *guess == *word || patterns.len() == max_rows {
+                break;
+            }
+
+            let pattern = score_guess(&guess, word);
+            candidates.retain(|candidate| score_guess(&guess, candidate) == pattern);
+            patterns.push(pattern);
+        }
+
+        // A lucky first guess leaves nothing to deduce
+        if !patterns.is_empty() {
+            return patterns;
+        }
+    }
+
+    Vec::new()
+}
+
+/// The next guess of the computer among the words still fitting the colors: the one whose
+/// different letters are in the most of them, so that every answer rules out many words
+pub fn solver_guess(candidates: &[Word]) -> Option<Word> {
+    let mut counts = [0; ALPHABET_LEN];
+    for word in candidates {
+        let mut is_counted = [false; ALPHABET_LEN];
+        for index in word.iter().filter_map(|c| letter_index(*c)) {
+            if !is_counted[index] {
+                is_counted[index] = true;
+                counts[index] += 1;
+            }
+        }
+    }
+
+    let coverage = |word: &Word| {
+        let mut is_counted = [false; ALPHABET_LEN];
+        word.iter()
+            .filter_map(|c| letter_index(*c))
+            .filter(|index| !mem::replace(&mut is_counted[*index], true))
+            .map(|index| counts[index])
+            .sum::<usize>()
+    };
+
+    // The last of the equal maximums in reverse is the first of the equally good words
+    candidates
+        .iter()
+        .rev()
+        .max_by_key(|word| coverage(word))
+        .map(Rc::clone)
+}
+
+/// Count the words that would have produced the same tiles for every guess as the actual word
+pub fn remaining_candidates(words: &HashSet<Word>, guesses: &[Vec<char>], word: &[char]) -> usize {
+    let patterns = guesses
+        .iter()
+        .map(|guess| score_guess(guess, word))
+        .collect::<Vec<_>>();
+
+    words
+        .iter()
+        .filter(|candidate| {
+            guesses
+                .iter()
+                .zip(patterns.iter())
+                .all(|(guess, pattern)| score_guess(guess, candidate) == *pattern)
+        })
+        .count()
+}
+
 pub fn update_known_information(
-    states: &mut [KnownStates],
-    counts: &mut [KnownCounts],
+    knowledge: &mut [Knowledge],
     guess: &mut [(char, TileState)],
     guess_index: usize,
     word: &[char],
     max_guesses: usize,
 ) {
     for (index, (character, _)) in guess.iter().enumerate() {
-        let known = states[guess_index]
-            .entry((*character, index))
-            .or_insert(CharacterState::Unknown);
-
         if word[index] == *character {
-            *known = CharacterState::Correct;
+            knowledge[guess_index].set_state(*character, index, CharacterState::Correct);
         } else {
-            *known = CharacterState::Absent;
+            knowledge[guess_index].set_state(*character, index, CharacterState::Absent);
 
-            if let Some(updated_count) = known_count(character, guess_index, guess, counts, word) {
-                counts[guess_index].insert(*character, updated_count);
+            if let Some(updated_count) = known_count(character, guess_index, guess, knowledge, word)
+            {
+                knowledge[guess_index].set_count(*character, updated_count);
             }
         }
     }
 
     // Copy the previous knowledge to the next guess
     if guess_index < max_guesses - 1 {
-        let next = guess_index + 1;
-        states[next] = states[guess_index].clone();
-        counts[next] = counts[guess_index].clone();
+        knowledge[guess_index + 1] = knowledge[guess_index].clone();
     }
 
-    update_guess_tile_states(guess, guess_index, states, counts);
+    update_guess_tile_states(guess, guess_index, knowledge);
 }
@@ -0,0 +1,853 @@
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+use web_sys::window;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Language {
+    Finnish,
+    Swedish,
+    English,
+}
+
+impl Default for Language {
+    fn default() -> Self {
+        Language::Finnish
+    }
+}
+
+impl Language {
+    pub fn code(&self) -> &'static str {
+        match self {
+            Language::Finnish => "fi",
+            Language::Swedish => "sv",
+            Language::English => "en",
+        }
+    }
+
+    pub fn locale(&self) -> &'static str {
+        match self {
+            Language::Finnish => "fi-FI",
+            Language::Swedish => "sv-SE",
+            Language::English => "en-US",
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Language::Finnish => "Suomi",
+            Language::Swedish => "Svenska",
+            Language::English => "English",
+        }
+    }
+
+    /// Keyboard rows of the puzzle alphabet
+    pub fn keyboard_rows(&self) -> [&'static [char]; 3] {
+        match self {
+            Language::Finnish => [
+                &['Q', 'W', 'E', 'R', 'T', 'Y', 'U', 'I', 'O', 'P'],
+                &['A', 'S', 'D', 'F', 'G', 'H', 'J', 'K', 'L', 'Ö', 'Ä'],
+                &['Z', 'X', 'C', 'V', 'B', 'N', 'M'],
+            ],
+            Language::Swedish => [
+                &['Q', 'W', 'E', 'R', 'T', 'Y', 'U', 'I', 'O', 'P', 'Å'],
+                &['A', 'S', 'D', 'F', 'G', 'H', 'J', 'K', 'L', 'Ö', 'Ä'],
+                &['Z', 'X', 'C', 'V', 'B', 'N', 'M'],
+            ],
+            Language::English => [
+                &['Q', 'W', 'E', 'R', 'T', 'Y', 'U', 'I', 'O', 'P'],
+                &['A', 'S', 'D', 'F', 'G', 'H', 'J', 'K', 'L'],
+                &['Z', 'X', 'C', 'V', 'B', 'N', 'M'],
+            ],
+        }
+    }
+
+    pub fn alphabet(&self) -> impl Iterator<Item = &'static char> {
+        self.keyboard_rows().into_iter().flatten()
+    }
+
+    fn catalog(&self) -> &'static [(&'static str, &'static str)] {
+        match self {
+            Language::Finnish => &[],
+            Language::Swedish => SWEDISH,
+            Language::English => ENGLISH,
+        }
+    }
+}
+
+thread_local! {
+    static CATALOG: RefCell<HashMap<&'static str, &'static str>> = RefCell::new(HashMap::new());
+}
+
+pub fn set_language(language: Language) {
+    CATALOG.with(|catalog| {
+        *catalog.borrow_mut() = language.catalog().iter().copied().collect();
+    });
+
+    if let Some(root) = window()
+        .and_then(|window| window.document())
+        .and_then(|document| document.document_element())
+    {
+        let _res = root.set_attribute("lang", language.code());
+    }
+}
+
+/// Translates the Finnish source text to the current language.
+/// Texts missing from the catalog are shown in Finnish.
+pub fn t(text: &'static str) -> &'static str {
+    CATALOG.with(|catalog| catalog.borrow().get(text).copied().unwrap_or(text))
+}
+
+/// Translates the text and fills its `{}` placeholders with the arguments in order
+pub fn tf(text: &'static str, args: &[&dyn fmt::Display]) -> String {
+    let mut parts = t(text).split("{}");
+    let mut result = parts.next().unwrap_or_default().to_owned();
+
+    for (index, part) in parts.enumerate() {
+        if let Some(arg) = args.get(index) {
+            result += &arg.to_string();
+        }
+        result += part;
+    }
+
+    result
+}
+
+const SWEDISH: &[(&str, &str)] = &[
+    // Game messages and titles
+    ("Liian vähän kirjaimia!", "För få bokstäver!"),
+    ("Ei sanulistalla.", "Finns inte i ordlistan."),
+    ("Aika loppui!", "Tiden tog slut!"),
+    ("Peli nollattu, arvaa sanuli!", "Spelet nollställt, gissa ordet!"),
+    ("Löysit päivän sanulin! {}", "Du hittade dagens ord! {}"),
+    ("Löysit sanan! {}", "Du hittade ordet! {}"),
+    ("Löysit sanulit! {}", "Du hittade orden! {}"),
+    ("Sana oli \"{}\"", "Ordet var \"{}\""),
+    ("Löytämättä jäi: \"{}\"", "Hittades inte: \"{}\""),
+    ("Päivän sanuli #{}", "Dagens ord #{}"),
+    ("Jaettu sanuli", "Delat ord"),
+    ("Aikapaine", "Tidspress"),
+    ("Aikapaine — Putki: {}", "Tidspress — Svit: {}"),
+    ("Sanuli — Putki: {}", "Sanuli — Svit: {}"),
+    ("Neluli — Putki: {}", "Neluli — Svit: {}"),
+    ("Päivän {} #{}", "Dagens {} #{}"),
+    ("Pelaaja: {}", "Spelare: {}"),
+    ("Päivän sanulien putki", "Svit av dagens ord"),
+    ("Ohjeet", "Instruktioner"),
+    ("Valikko", "Meny"),
+    ("Pikanäppäimet", "Kortkommandon"),
+    ("Arvaa sanaa", "Gissa ordet"),
+    ("Poista kirjain", "Radera bokstav"),
+    ("Siirrä kohdistinta vasemmalle", "Flytta markören åt vänster"),
+    ("Siirrä kohdistinta oikealle", "Flytta markören åt höger"),
+    ("Peru arvaus", "Ångra gissningen"),
+    ("Sulje ikkuna", "Stäng fönstret"),
+    ("Valikko, kun peli on päättynyt", "Meny, när spelet är slut"),
+    ("Uusi peli, kun peli on päättynyt", "Nytt spel, när spelet är slut"),
+    ("Kirjoita {}", "Skriv {}"),
+    ("Lisänäppäimet:", "Extra tangenter:"),
+    ("Puhesyöte:", "Röstinmatning:"),
+    ("Arvaa sanaa {}?", "Gissa ordet {}?"),
+    ("Sanele sana", "Diktera ordet"),
+    ("Mahdollisia sanuleja: {}", "Möjliga ord: {}"),
+    // Keyboard and message links
+    ("ARVAA", "GISSA"),
+    ("TAKAISIN", "TILLBAKA"),
+    ("UUSI?", "NY?"),
+    ("Kokeile ratkaista", "Försök lösa"),
+    ("Paljasta", "Avslöja"),
+    ("Ehdota lisäystä?", "Föreslå tillägg?"),
+    ("Ehdota poistoa?", "Föreslå borttagning?"),
+    ("Sanakirja", "Ordbok"),
+    ("Kopioi linkki", "Kopiera länk"),
+    ("Kopioi tulos", "Kopiera resultat"),
+    ("Kopioi uusinta", "Kopiera repris"),
+    ("QR-koodi", "QR-kod"),
+    ("Lataa kuva", "Ladda ner bild"),
+    ("Kumoa arvaus", "Ångra gissning"),
+    // Help
+    ("Arvaa kätketty ", "Gissa det dolda "),
+    ("sanuli", "ordet"),
+    (" kuudella yrityksellä.", " på sex försök."),
+    ("Jokaisen yrityksen jälkeen arvatut kirjaimet vaihtavat väriään.", "Efter varje försök byter de gissade bokstäverna färg."),
+    ("Sininen", "Blå"),
+    ("Keltainen", "Gul"),
+    ("Oranssi", "Orange"),
+    ("Vihreä", "Grön"),
+    ("Harmaa", "Grå"),
+    (": kirjain löytyy kätketystä sanasta, mutta on arvauksessa väärällä paikalla.", ": bokstaven finns i det dolda ordet men står på fel plats i gissningen."),
+    (": kirjain on arvauksessa oikealla paikalla.", ": bokstaven står på rätt plats i gissningen."),
+    (": kirjain ei löydy sanasta.", ": bokstaven finns inte i ordet."),
+    ("Arvattaviin sanoihin käytetyn sanulistan vaikeusasteen voi valita asetuksista. Sanulistojen pohjana on käytetty Kotimaisten kielten keskuksen (Kotus) julkaiseman ", "Svårighetsgraden för ordlistan kan väljas i inställningarna. Ordlistorna bygger på den av Institutet för de inhemska språken (Kotus) publicerade, under "),
+    ("\"CC Nimeä 3.0 Muokkaamaton\"", "\"CC Erkännande 3.0 Unported\""),
+    ("-lisensoidun nykysuomen sanalistan sanoja.", " licensierade ordlistan över modern finska."),
+    ("Tavallinen", "Vanlig"),
+    (" lista sisältää täydestä listasta poimitut yleisimmät sanat ilman harvinaisempia laina- ja murresanoja tai muita erikoisuuksia.", " lista innehåller de vanligaste orden ur den fullständiga listan, utan ovanligare lån- och dialektord eller andra specialiteter."),
+    ("Helppo", "Lätt"),
+    (" lista on tavallisesta vielä hieman helpotettu versio, jossa jäljellä ovat vain yleiset arkikielen sanat ilman vanhahtavia sanoja, puhekieltä tai rumia sanuleja. Näin lista sopii kaikenikäisille. \"Helppo\" kuusikirjaimisten sanulien lista on kuitenkin vielä kesken.", " lista är en ännu lite lättare version av den vanliga, med bara vanliga vardagsord utan ålderdomliga ord, talspråk eller fula ord. Därför passar listan alla åldrar. Den lätta listan med sexbokstavsord är dock ännu inte färdig."),
+    ("Vaikea", "Svår"),
+    (" lista on täysi lista pelin hyväksymiä sanoja. Tälle listalle on myös lisätty jonkin verran käyttäjien uusia ehdotuksia, puhekielisyyksiä, murresanoja sekä muita erikoisuuksia, eikä poistoja ole tehty kuin vain jos sanulit eivät selvästi ole oikeita sanoja.", " lista är hela listan av ord som spelet godkänner. Till den har även lagts en del förslag från användare, talspråk, dialektord och andra specialiteter, och ord har tagits bort endast om de uppenbart inte är riktiga ord."),
+    ("Sanulit ovat yleensä perusmuodossa, mutta eivät välttämättä täysin pelkkää kirjakieltä. Yhdyssanojakin on seassa.", "Orden är oftast i grundform, men inte nödvändigtvis rent skriftspråk. Det finns också sammansatta ord."),
+    ("Päivän sanulit tulevat omalta listaltaan, joka on jotain tavallisen ja vaikean listan väliltä. Sanuli on aina sama kaikille pelaajille tiettynä päivänä.", "Dagens ord kommer från en egen lista, som ligger någonstans mellan den vanliga och den svåra listan. Ordet är detsamma för alla spelare under en viss dag."),
+    ("Sanuliketjussa jos arvaat sanulin, on se suoraan ensimmäinen arvaus seuraavaan peliin. Näin joudut sopeutumaan vaihtuviin alkuarvauksiin, ja peli on hieman vaikeampi.", "I ordkedjan blir ett rätt gissat ord direkt den första gissningen i nästa spel. Då måste du anpassa dig till skiftande startgissningar, och spelet blir lite svårare."),
+    ("Zen-pelissä voit pelata rauhassa ilman putkia. Arvauksia voi perua, ja tulokset pidetään erillään muista tilastoistasi.", "I Zen-läget kan du spela i lugn och ro utan sviter. Gissningar kan ångras, och resultaten hålls åtskilda från din övriga statistik."),
+    ("Aikapaineessa jokaiselle arvaukselle on rajattu aika, joka lyhenee rivi riviltä minuutista kymmeneen sekuntiin. Jos aika loppuu kesken, menetät rivin.", "I tidspress har varje gissning en tidsgräns som krymper rad för rad från en minut till tio sekunder. Om tiden tar slut förlorar du raden."),
+    ("Nelulissa ratkaiset samalla kertaa neljää eri sanulia samoilla arvauksilla. Tavoite on saada kaikki neljä sanulia ratkaistua yhdeksällä arvauksella.", "I Neluli löser du fyra olika ord samtidigt med samma gissningar. Målet är att lösa alla fyra ord på nio gissningar."),
+    ("Sanulistoja muokkailen aina välillä käyttäjien ehdotusten perusteella, ja voit jättää omat ehdotuksesi sanuleihin ", "Jag justerar ordlistorna då och då utifrån användarnas förslag, och du kan lämna egna förslag "),
+    ("täällä", "här"),
+    (". Kiitos kaikille ehdotuksia jättäneille ja sanulistojen kasaamisessa auttaneille henkilöille!", ". Tack till alla som har lämnat förslag och hjälpt till att sammanställa ordlistorna!"),
+    // Menu
+    ("Sanulien pituus:", "Ordlängd:"),
+    ("5 merkkiä", "5 tecken"),
+    ("6 merkkiä", "6 tecken"),
+    ("Sanulista:", "Ordlista:"),
+    ("Rumat sanulit:", "Fula ord:"),
+    ("Ei", "Nej"),
+    ("Kyllä", "Ja"),
+    ("Pelimuoto:", "Spelläge:"),
+    ("Peruspeli", "Grundspel"),
+    ("Sanuliketju", "Ordkedja"),
+    ("Päivän sanuli", "Dagens ord"),
+    ("Näytä mahdolliset sanulit:", "Visa möjliga ord:"),
+    ("Näppäimistön vihjeet:", "Tangentbordets ledtrådar:"),
+    ("Täysi", "Fullständiga"),
+    ("Kevyt", "Lätta"),
+    ("Ei vihjeitä", "Inga ledtrådar"),
+    ("Omat tilastosi:", "Din statistik:"),
+    ("Omat tilastosi (kevyet vihjeet):", "Din statistik (lätta ledtrådar):"),
+    ("Omat tilastosi (ei vihjeitä):", "Din statistik (inga ledtrådar):"),
+    ("Pisin putki: {}", "Längsta svit: {}"),
+    ("Lomapäivät: {}", "Lovdagar: {}"),
+    ("Pelatut sanulit: {}", "Spelade ord: {}"),
+    ("Ratkaistut sanulit: {}", "Lösta ord: {}"),
+    ("Zen-sanulit: {} / {} ratkaistu", "Zen: {} / {} lösta"),
+    ("Aikapaine: {} / {} ratkaistu, pisin putki {}", "Tidspress: {} / {} lösta, längsta svit {}"),
+    ("Viimeisimmät päivän sanulit:", "Senaste dagens ord:"),
+    ("Pienoiskuvat", "Miniatyrer"),
+    ("Katso", "Visa"),
+    ("Teema:", "Tema:"),
+    ("Oletus", "Standard"),
+    ("Värisokeille", "För färgblinda"),
+    ("Kieli:", "Språk:"),
+    ("Sanulien kieli:", "Ordens språk:"),
+    ("Korvaavat näppäimet:", "Ersättande tangenter:"),
+    (
+        "Jos näppäimistöstäsi puuttuu kirjaimia, voit kirjoittaa ne näillä näppäimillä. AltGr-yhdistelmät toimivat aina.",
+        "Om ditt tangentbord saknar bokstäver kan du skriva dem med dessa tangenter. AltGr-kombinationer fungerar alltid.",
+    ),
+    // Modals and panels
+    ("Linkki on liian pitkä QR-koodiksi.", "Länken är för lång för en QR-kod."),
+    ("Haasta kaveri", "Utmana en vän"),
+    ("Skannaa koodi puhelimella pelataksesi saman sanulin.", "Skanna koden med telefonen för att spela samma ord."),
+    ("Päivän sanuli {}", "Dagens ord {}"),
+    ("Uusinta", "Repris"),
+    ("Katso uudelleen", "Se igen"),
+    ("Viikon teema: {}", "Veckans tema: {}"),
+    ("Äänestit sanulia {}. Tulokset julkaistaan {}.", "Du röstade på {}. Resultaten publiceras {}."),
+    ("Äänestä ensi viikon päivän sanulia:", "Rösta på nästa veckas dagens ord:"),
+    ("Äänen lähettäminen epäonnistui, yritä uudelleen.", "Det gick inte att skicka rösten, försök igen."),
+    ("oikein", "rätt"),
+    ("ei sanassa", "finns inte i ordet"),
+    ("väärässä paikassa", "på fel plats"),
+    ("tyhjä", "tom"),
+    ("Kirjain poistettu", "Bokstaven borttagen"),
+    ("Sanuli {}: {}", "Ord {}: {}"),
+    ("Sanulista on uusi versio.", "En ny version av spelet finns."),
+    ("Päivitä", "Uppdatera"),
+    ("Päivän sanuli odottaa!", "Dagens ord väntar!"),
+    ("Muistuta päivän sanulista:", "Påminn om dagens ord:"),
+    ("Lue lisää", "Läs mer"),
+    ("Mitä uutta", "Nyheter"),
+    ("Koko muutosloki", "Hela ändringsloggen"),
+    ("Tulos kopioitu!", "Resultatet kopierat!"),
+    ("Linkki kopioitu!", "Länken kopierad!"),
+    ("Uusinta kopioitu!", "Reprisen kopierad!"),
+    ("Kuva ladattu!", "Bilden hämtad!"),
+    ("Puheentunnistus ei käynnistynyt.", "Taligenkänningen startade inte."),
+    ("Sanaa ei tunnistettu.", "Ordet kändes inte igen."),
+    ("Keskeneräinen peli menetetään — jatketaanko?", "Spelet som pågår går förlorat — fortsätta?"),
+    ("Peruuta", "Avbryt"),
+    ("Jatka", "Fortsätt"),
+    ("Iltasanuli", "Kvällens ord"),
+    ("Iltasanuli #{}", "Kvällens ord #{}"),
+    ("Iltasanuli {}", "Kvällens ord {}"),
+    ("Toinen päivän sanuli illaksi:", "Ett andra dagens ord på kvällen:"),
+    ("Viikkohaaste", "Veckans utmaning"),
+    ("Viikkohaaste {} — sana {}/{}", "Veckans utmaning {} — ord {}/{}"),
+    ("viikkohaaste {}", "veckans utmaning {}"),
+    ("{} arvausta", "{} gissningar"),
+    ("Kilpasanuli", "Kapplöpning"),
+    ("Kilpa verkossa", "Kapplöpning online"),
+    ("Vastustaja", "Motståndare"),
+    ("Voitit kilvan!", "Du vann kapplöpningen!"),
+    ("Vastustaja voitti", "Motståndaren vann"),
+    ("Odotetaan vastustajaa huoneeseen {}…", "Väntar på en motståndare i rummet {}…"),
+    ("Poistu", "Lämna"),
+    ("Kilpaile kaveria vastaan samasta sanulista. Jaa huoneen koodi, kilpa alkaa kun molemmat ovat liittyneet.", "Tävla mot en vän om samma ord. Dela rummets kod, kapplöpningen börjar när båda har anslutit."),
+    ("Huoneen koodi:", "Rummets kod:"),
+    ("Liity", "Anslut"),
+    ("Vastustaja ratkaisi sanulin ensin!", "Motståndaren löste ordet först!"),
+    ("Vastustaja poistui kilvasta.", "Motståndaren lämnade kapplöpningen."),
+    ("Kilpaan liittyminen epäonnistui.", "Det gick inte att ansluta till kapplöpningen."),
+    ("Yhteys kilpaan katkesi.", "Anslutningen till kapplöpningen bröts."),
+    ("Parempi tulos kuin {} % pelaajista", "Bättre resultat än {} % av spelarna"),
+    ("Tulokset lähetetään nimettöminä.", "Resultaten skickas anonymt."),
+    ("Lähetä päivän tulos nimettömänä tulostaulukkoon:", "Skicka dagens resultat anonymt till topplistan:"),
+    ("Jaa peli katsojille", "Dela spelet med åskådare"),
+    ("Linkin avanneet näkevät pelisi etenemisen. Arvausten kirjaimet paljastuvat vasta pelin päätyttyä.", "De som öppnar länken ser hur ditt spel framskrider. Bokstäverna i gissningarna visas först när spelet är slut."),
+    ("Lopeta jakaminen", "Sluta dela"),
+    ("Katsojalinkin luominen epäonnistui.", "Det gick inte att skapa en åskådarlänk."),
+    ("Yhteys katsojiin katkesi.", "Anslutningen till åskådarna bröts."),
+    ("Yhteys pelaajaan katkesi.", "Anslutningen till spelaren bröts."),
+    ("Odotetaan pelaajaa…", "Väntar på spelaren…"),
+    ("Lopeta katselu", "Sluta titta"),
+    ("Peli on kesken, kirjaimet paljastuvat pelin päätyttyä.", "Spelet pågår, bokstäverna visas när spelet är slut."),
+    ("Peli on päättynyt.", "Spelet är slut."),
+    ("Lähetä nimettömiä käyttötilastoja:", "Skicka anonym användningsstatistik:"),
+    ("Pelin aloitukset ja tulokset ilman tunnisteita tai arvattuja sanoja.", "Påbörjade spel och resultat, utan identifierare eller gissade ord."),
+    ("Hups, jotain meni pieleen", "Hoppsan, något gick fel"),
+    ("Sanuli kohtasi odottamattoman virheen. Tallennettu peli ja tilastot ovat yleensä tallessa, ja sivun lataaminen uudelleen palauttaa ne.", "Sanuli stötte på ett oväntat fel. Det sparade spelet och statistiken finns oftast kvar och återställs när sidan laddas om."),
+    ("Yritä palauttaa tallennettu peli", "Försök återställa det sparade spelet"),
+    ("Jos virhe toistuu, voit aloittaa alusta. Tämä poistaa kaikki pelit, tilastot ja asetukset.", "Om felet upprepas kan du börja om från början. Det tar bort alla spel, all statistik och alla inställningar."),
+    ("Tyhjennä kaikki tiedot", "Töm alla data"),
+    ("Poistetaanko kaikki tiedot pysyvästi?", "Ta bort alla data permanent?"),
+    ("Lataa tiedot vikailmoitukseen", "Ladda ned data för en felrapport"),
+    ("Vikailmoituksen tiedot ladattu!", "Data för felrapporten nedladdad!"),
+    ("Ladataan sanakirjaa…", "Laddar ordlistan…"),
+    ("Sanakirja latautuu vielä, yritä hetken päästä.", "Ordlistan laddas fortfarande, försök igen om en stund."),
+    ("Sanuliketju — Ketju: {}", "Ordkedja — Kedja: {}"),
+    ("Sanuliketju: ketju {}, pisin ketju {}, ketjun arvaukset {}", "Ordkedja: kedja {}, längsta kedja {}, kedjans gissningar {}"),
+    ("ketju", "kedja"),
+    ("{} sanaa, {} arvausta", "{} ord, {} gissningar"),
+    ("Päivän sanuli vaikeana:", "Dagens ord på svår nivå:"),
+    ("Paljastettujen vihjeiden on oltava mukana seuraavissa arvauksissa. Vaihto koskee vain arvaamatonta päivän sanulia.", "Avslöjade ledtrådar måste användas i de följande gissningarna. Ändringen gäller bara ett ogissat dagens ord."),
+    ("Päivän sanuli #{} — Vaikea", "Dagens ord #{} — Svår"),
+    ("{}. kirjaimen täytyy olla {}", "Bokstav {} måste vara {}"),
+    ("Arvauksessa täytyy olla {}", "Gissningen måste innehålla {}"),
+    ("Tuplasanuli", "Dubbelord"),
+    ("Tuplasanuli #{}", "Dubbelord #{}"),
+    ("Päättely", "Deduktion"),
+    ("Päättelysanuli", "Deduktionsord"),
+    ("Värit eivät täsmää!", "Färgerna stämmer inte!"),
+    ("Päättely onnistui! {}", "Deduktionen lyckades! {}"),
+    ("Aikaa arvaukselle:", "Tid per gissning:"),
+    ("Kiristyvä", "Krympande"),
+    ("Maraton", "Maraton"),
+    ("Maraton — Sanat: {}, arvauksia jäljellä: {}", "Maraton — Ord: {}, gissningar kvar: {}"),
+    ("Maraton päättyi, {} sanaa! Sana oli \"{}\"", "Maratonet tog slut, {} ord! Ordet var \"{}\""),
+    ("maraton", "maraton"),
+    ("{} sanaa", "{} ord"),
+    ("Maraton: ennätys {} sanaa", "Maraton: rekord {} ord"),
+    ("Näppäimistö pysyy värittömänä, vain ruudukko värittyy. Jaettu tulos merkitään 🧠-merkillä.", "Tangentbordet förblir ofärgat, bara rutnätet färgas. Delade resultat markeras med 🧠."),
+    ("Sokko", "Blind"),
+    ("Sokkosanuli", "Blindord"),
+    ("Näytä kirjainten yleisyys:", "Visa bokstävernas frekvens:"),
+    ("Sanoja jäljellä {}", "Ord kvar {}"),
+    ("Aakkoset", "Alfabetet"),
+    ("Näytä aakkoset näppäimistön yllä:", "Visa alfabetet ovanför tangentbordet:"),
+    ("Pelihistoria", "Spelhistorik"),
+    ("Kaikki", "Alla"),
+    ("Ratkaistut", "Lösta"),
+    ("Ratkaisematta", "Olösta"),
+    ("Ei pelattuja pelejä.", "Inga spelade spel."),
+    ("Päivän sanulin keskimääräinen ratkaisuaika: {}", "Genomsnittlig lösningstid för dagens ord: {}"),
+    ("Avaussanasi:", "Dina öppningsord:"),
+    ("Paras avaussanasi on {}, keskimäärin {} vihreää tai keltaista ensimmäisellä rivillä.", "Ditt bästa öppningsord är {}, i snitt {} gröna eller gula på första raden."),
+    ("{}: {} peliä, keskimäärin {} osumaa", "{}: {} spel, i snitt {} träffar"),
+    ("Sinulle hankalimmat kirjaimet:", "Dina knepigaste bokstäver:"),
+    ("{}: väärässä kohdassa {}, löytymättä {} / {} sanasta", "{}: fel plats {}, aldrig hittad {} / {} ord"),
+    ("Profiili:", "Profil:"),
+    ("Uusi profiili", "Ny profil"),
+    ("Lisää", "Lägg till"),
+    ("Profiilin nimi on jo käytössä.", "Profilnamnet används redan."),
+    ("Synkronoi edistyminen:", "Synkronisera framsteg:"),
+    ("Tallennus kulkee laitteelta toiselle osoitteen kautta, joka vastaa GET- ja PUT-pyyntöihin, esim. WebDAV-palvelimen tiedosto. Uudempi tallennus voittaa, päivän sanulit yhdistetään.", "Sparningen flyttas mellan enheter via en adress som svarar på GET- och PUT-förfrågningar, t.ex. en fil på en WebDAV-server. Den nyare sparningen vinner, dagens ord slås ihop."),
+    ("Synkronoi", "Synkronisera"),
+    ("Edistyminen synkronoitu.", "Framstegen har synkroniserats."),
+    ("Synkronointi epäonnistui.", "Synkroniseringen misslyckades."),
+    ("Siirtokoodi:", "Överföringskod:"),
+    ("Kopioi koodi ja liitä se toisella laitteella siirtääksesi pelit, tilastot ja asetukset.", "Kopiera koden och klistra in den på en annan enhet för att flytta spel, statistik och inställningar."),
+    ("Kopioi siirtokoodi", "Kopiera överföringskod"),
+    ("Tuo", "Importera"),
+    ("Siirtokoodi kopioitu!", "Överföringskoden kopierad!"),
+    ("Siirtokoodi korvaa tämän laitteen pelit, tilastot ja asetukset — jatketaanko?", "Överföringskoden ersätter spelen, statistiken och inställningarna på den här enheten — fortsätta?"),
+    ("Siirtokoodi ei kelpaa.", "Överföringskoden är ogiltig."),
+    ("Siirtokoodi on eri versiosta, päivitä peli molemmilla laitteilla.", "Överföringskoden är från en annan version, uppdatera spelet på båda enheterna."),
+    ("Siirtokoodi on vaillinainen, kopioi se uudelleen kokonaan.", "Överföringskoden är ofullständig, kopiera hela koden igen."),
+    ("Siirtokoodin tallentaminen epäonnistui.", "Det gick inte att spara överföringskoden."),
+    ("Jaetun tuloksen värit:", "Färger i delat resultat:"),
+    ("Teeman mukaan", "Enligt temat"),
+    ("Jaetun tuloksen tiedot:", "Detaljer i det delade resultatet:"),
+    ("Pelimuoto", "Spelläge"),
+    ("Sanan pituus", "Ordlängd"),
+    ("Vaikea-merkintä", "Svårt läge-markering"),
+    ("Putki", "Svit"),
+    ("Ratkaisuaika", "Lösningstid"),
+    ("{} kirjainta", "{} bokstäver"),
+    ("Klassinen", "Klassisk"),
+    ("Pastelli", "Pastell"),
+    ("Pääte", "Terminal"),
+    ("Korkea kontrasti", "Hög kontrast"),
+    ("Koko:", "Storlek:"),
+    ("Pieni", "Liten"),
+    ("Normaali", "Normal"),
+    ("Suuri", "Stor"),
+    ("Ruutujen, näppäimistön ja tekstien koko.", "Storleken på rutorna, tangentbordet och texterna."),
+    ("Animaatiot:", "Animationer:"),
+    ("Pois", "Av"),
+    ("Nopeat", "Snabba"),
+    ("Normaalit", "Normala"),
+    ("Harjoittele värejä", "Öva på färgerna"),
+    ("Harjoitellaan", "Vi övar"),
+    ("Lähetä arvaus painamalla ARVAA.", "Skicka gissningen genom att trycka på GISSA."),
+    ("Harjoituksen sana on {}. Poista kirjaimia painamalla ⌫.", "Övningens ord är {}. Ta bort bokstäver genom att trycka på ⌫."),
+    ("Kirjoita arvaus {} painamalla kirjaimia.", "Skriv gissningen {} genom att trycka på bokstäverna."),
+    ("Arvauksen jälkeen kirjaimet vaihtavat väriään:", "Efter gissningen byter bokstäverna färg:"),
+    ("Värien perusteella kätketty sana voisi olla {}. Kirjoita se ja lähetä.", "Enligt färgerna kunde det dolda ordet vara {}. Skriv det och skicka."),
+    ("Löysit kätketyn sanan! Oikeassa pelissä sanaa ei kerrota, mutta värit auttavat samalla tavalla. Arvauksia on kuusi.", "Du hittade det dolda ordet! I det riktiga spelet berättas ordet inte, men färgerna hjälper på samma sätt. Du har sex gissningar."),
+    ("Aloita peli", "Börja spela"),
+    ("Ohita harjoitus", "Hoppa över övningen"),
+    ("Kokeile itse: arvaa esimerkin sana kolmella yrityksellä. Kokeilu ei vaikuta peliisi eikä tilastoihisi.", "Prova själv: gissa exemplets ord med tre försök. Försöket påverkar inte ditt spel eller din statistik."),
+    ("Kokeile arvata", "Prova att gissa"),
+    ("Esimerkin arvaus", "Exemplets gissning"),
+    ("Arvaa", "Gissa"),
+    ("Uusi yritys", "Nytt försök"),
+    ("Päivän sanuli vaihtuu:", "Dagens ord byts:"),
+    ("Oman ajan keskiyöllä", "Vid lokal midnatt"),
+    ("Suomen ajan keskiyöllä", "Vid midnatt i Finland"),
+    ("Suomen ajalla päivän sanuli on sama kuin Suomessa pelaavilla, vaikka olisit matkoilla.", "Med finsk tid är dagens ord detsamma som för spelarna i Finland, även när du reser."),
+    ("Keskeneräinen peli", "Ofärdigt spel"),
+    ("Arvauksia käytetty {}/{}.", "Gissningar använda {}/{}."),
+    ("Putki: {}", "Svit: {}"),
+    ("Ketju: {}", "Kedja: {}"),
+    ("Aloita uusi", "Börja ett nytt"),
+    ("Jatka keskeneräistä peliä", "Fortsätt det ofärdiga spelet"),
+    ("Selaimen tallennustila on täynnä, eikä peliä voitu tallentaa. Vapauta tilaa poistamalla profiileja tai muiden sivujen tietoja.", "Webbläsarens lagringsutrymme är fullt och spelet kunde inte sparas. Frigör utrymme genom att ta bort profiler eller andra webbplatsers data."),
+    ("Selain ei salli tallentamista, eikä peliä voitu tallentaa.", "Webbläsaren tillåter inte lagring och spelet kunde inte sparas."),
+    ("Peliä ei voitu tallentaa.", "Spelet kunde inte sparas."),
+    ("Ladataan…", "Laddar…"),
+    ("Tyhjennä rivi", "Töm raden"),
+    ("Rivi tyhjennetty", "Raden tömd"),
+    ("Pyyhkäisyeleet:", "Svepgester:"),
+    ("Pyyhkäise laudalla vasemmalle poistaaksesi kirjaimen, oikealle arvataksesi ja alas aloittaaksesi uuden pelin.", "Svep åt vänster på brädet för att ta bort en bokstav, åt höger för att gissa och nedåt för att börja ett nytt spel."),
+    ("Avaa {} sanakirjassa", "Öppna {} i ordboken"),
+    ("Määritelmä", "Definition"),
+    ("Määritelmäsanuli", "Definitionsord"),
+    ("Haetaan määritelmää…", "Hämtar definitionen…"),
+    ("Määritelmää ei saatu, arvaa ilman vihjettä.", "Definitionen kunde inte hämtas, gissa utan ledtråd."),
+    ("Tietokone arvaa", "Datorn gissar"),
+    ("Keksi viisikirjaiminen sana, niin tietokone yrittää arvata sen. Napauta arvauksen kirjaimia, kunnes niiden värit vastaavat sanaasi.", "Tänk på ett ord på fem bokstäver så försöker datorn gissa det. Tryck på bokstäverna i gissningen tills färgerna stämmer med ditt ord."),
+    ("Sanoja jäljellä: {}", "Ord kvar: {}"),
+    ("Arvasin sanasi {}. arvauksella!", "Jag gissade ditt ord på gissning {}!"),
+    ("Mikään sana ei sovi antamiisi väreihin. Huijasitko?", "Inget ord passar färgerna du gav. Fuskade du?"),
+    ("En arvannut sanaasi. Vaihtoehtoja oli vielä {}.", "Jag gissade inte ditt ord. Det fanns ännu {} alternativ."),
+    ("Värit valmiit", "Färgerna klara"),
+    ("Uusi sana", "Nytt ord"),
+    ("Kilpa bottia vastaan", "Kapplöpning mot boten"),
+    ("Kilpa bottia vastaan: {} / {} voitettu", "Kapplöpning mot boten: {} / {} vunna"),
+    ("Kilpaile bottia vastaan samasta sanulista. Botti arvaa omalle laudalleen sitä nopeammin, mitä vaikeampi se on, ja näet sen arvauksista vain värit.", "Tävla mot boten om samma ord. Boten gissar på sin egen spelplan, snabbare ju svårare den är, och du ser bara färgerna på dess gissningar."),
+    ("Botin taso:", "Botens nivå:"),
+    ("Keskitaso", "Medel"),
+    ("Botti", "Boten"),
+    ("Botti voitti", "Boten vann"),
+    ("Botti ratkaisi sanulin ensin!", "Boten löste ordet först!"),
+    ("Turnaus", "Turnering"),
+    ("Turnaus {} — sana {}/{}", "Turnering {} — ord {}/{}"),
+    ("turnaus {} ({}/{})", "turnering {} ({}/{})"),
+    ("{} arvausta, {}", "{} gissningar, {}"),
+    ("Turnauksen koodi määrää sarjan {} sanaa, jotka jokainen pelaa omalla laitteellaan. Kopioi lopuksi tuloksesi arvauksineen ja aikoineen ryhmän keskusteluun.", "Turneringens kod bestämmer en serie på {} ord som var och en spelar på sin egen enhet. Kopiera till sist ditt resultat med gissningar och tider till gruppchatten."),
+    ("Pelaat turnausta {}. Jaa koodi kavereillesi.", "Du spelar turneringen {}. Dela koden med dina vänner."),
+    ("Turnauksen koodi:", "Turneringens kod:"),
+    ("Uusi turnaus", "Ny turnering"),
+    ("Virheellinen turnauksen koodi.", "Ogiltig turneringskod."),
+    ("Näppäimistö:", "Tangentbord:"),
+    ("Aakkosjärjestys", "Alfabetisk"),
+    ("{}-vuosi {}", "{}-året {}"),
+    ("{}-vuosi", "{}-året"),
+    ("📅 Päivän sanoja pelattu: {}", "📅 Dagens ord spelade: {}"),
+    ("🏆 Voitettu: {} %", "🏆 Vunna: {} %"),
+    ("🔥 Pisin putki: {}", "🔥 Längsta svit: {}"),
+    ("🎯 Arvauksia keskimäärin: {}", "🎯 Gissningar i snitt: {}"),
+    ("🔤 Vaikein kirjain: {}", "🔤 Svåraste bokstaven: {}"),
+    ("Päivän sanoja pelattu: {}", "Dagens ord spelade: {}"),
+    ("Voitettu: {} / {} ({} %)", "Vunna: {} / {} ({} %)"),
+    ("Arvauksia keskimäärin: {}", "Gissningar i snitt: {}"),
+    ("Vaikein kirjain: {}", "Svåraste bokstaven: {}"),
+    ("Et pelannut vuonna {} yhtään päivän sanaa.", "Du spelade inga dagens ord år {}."),
+    ("Kopioi teksti", "Kopiera text"),
+    ("Pelimuodon pisteet", "Poäng i spelläget"),
+    ("Pisteet:", "Poäng:"),
+    ("Pisteitä saa vähistä arvauksista, nopeasta ratkaisusta, putkesta ja vaikeasta tilasta. Pisteet pidetään pelimuodoittain.", "Poäng ges för få gissningar, snabb lösning, svit och svårt läge. Poängen hålls per spelläge."),
+    ("Pisteet ({}): {} yhteensä, paras peli {}", "Poäng ({}): {} totalt, bästa spel {}"),
+];
+
+const ENGLISH: &[(&str, &str)] = &[
+    // Game messages and titles
+    ("Liian vähän kirjaimia!", "Not enough letters!"),
+    ("Ei sanulistalla.", "Not in word list."),
+    ("Aika loppui!", "Time's up!"),
+    ("Peli nollattu, arvaa sanuli!", "Game reset, guess the word!"),
+    ("Löysit päivän sanulin! {}", "You found the word of the day! {}"),
+    ("Löysit sanan! {}", "You found the word! {}"),
+    ("Löysit sanulit! {}", "You found the words! {}"),
+    ("Sana oli \"{}\"", "The word was \"{}\""),
+    ("Löytämättä jäi: \"{}\"", "Not found: \"{}\""),
+    ("Päivän sanuli #{}", "Word of the day #{}"),
+    ("Jaettu sanuli", "Shared word"),
+    ("Aikapaine", "Pressure"),
+    ("Aikapaine — Putki: {}", "Pressure — Streak: {}"),
+    ("Sanuli — Putki: {}", "Sanuli — Streak: {}"),
+    ("Neluli — Putki: {}", "Neluli — Streak: {}"),
+    ("Päivän {} #{}", "Daily {} #{}"),
+    ("Pelaaja: {}", "Player: {}"),
+    ("Päivän sanulien putki", "Word of the day streak"),
+    ("Ohjeet", "Help"),
+    ("Valikko", "Menu"),
+    ("Pikanäppäimet", "Keyboard shortcuts"),
+    ("Arvaa sanaa", "Guess the word"),
+    ("Poista kirjain", "Delete a letter"),
+    ("Siirrä kohdistinta vasemmalle", "Move the cursor left"),
+    ("Siirrä kohdistinta oikealle", "Move the cursor right"),
+    ("Peru arvaus", "Undo the guess"),
+    ("Sulje ikkuna", "Close the window"),
+    ("Valikko, kun peli on päättynyt", "Menu, once the game has ended"),
+    ("Uusi peli, kun peli on päättynyt", "New game, once the game has ended"),
+    ("Kirjoita {}", "Type {}"),
+    ("Lisänäppäimet:", "Extra keys:"),
+    ("Puhesyöte:", "Voice input:"),
+    ("Arvaa sanaa {}?", "Guess the word {}?"),
+    ("Sanele sana", "Dictate the word"),
+    ("Mahdollisia sanuleja: {}", "Possible words: {}"),
+    // Keyboard and message links
+    ("ARVAA", "GUESS"),
+    ("TAKAISIN", "BACK"),
+    ("UUSI?", "NEW?"),
+    ("Kokeile ratkaista", "Try to solve"),
+    ("Paljasta", "Reveal"),
+    ("Ehdota lisäystä?", "Suggest adding?"),
+    ("Ehdota poistoa?", "Suggest removal?"),
+    ("Sanakirja", "Dictionary"),
+    ("Kopioi linkki", "Copy link"),
+    ("Kopioi tulos", "Copy result"),
+    ("Kopioi uusinta", "Copy replay"),
+    ("QR-koodi", "QR code"),
+    ("Lataa kuva", "Download image"),
+    ("Kumoa arvaus", "Undo guess"),
+    // Help
+    ("Arvaa kätketty ", "Guess the hidden "),
+    ("sanuli", "word"),
+    (" kuudella yrityksellä.", " in six tries."),
+    ("Jokaisen yrityksen jälkeen arvatut kirjaimet vaihtavat väriään.", "After each guess the letters change color."),
+    ("Sininen", "Blue"),
+    ("Keltainen", "Yellow"),
+    ("Oranssi", "Orange"),
+    ("Vihreä", "Green"),
+    ("Harmaa", "Gray"),
+    (": kirjain löytyy kätketystä sanasta, mutta on arvauksessa väärällä paikalla.", ": the letter is in the hidden word, but in the wrong spot."),
+    (": kirjain on arvauksessa oikealla paikalla.", ": the letter is in the right spot."),
+    (": kirjain ei löydy sanasta.", ": the letter is not in the word."),
+    ("Arvattaviin sanoihin käytetyn sanulistan vaikeusasteen voi valita asetuksista. Sanulistojen pohjana on käytetty Kotimaisten kielten keskuksen (Kotus) julkaiseman ", "The difficulty of the word list can be chosen in the settings. The word lists are based on the list of contemporary Finnish words published by the Institute for the Languages of Finland (Kotus) under the "),
+    ("\"CC Nimeä 3.0 Muokkaamaton\"", "\"CC Attribution 3.0 Unported\""),
+    ("-lisensoidun nykysuomen sanalistan sanoja.", " license."),
+    ("Tavallinen", "Common"),
+    (" lista sisältää täydestä listasta poimitut yleisimmät sanat ilman harvinaisempia laina- ja murresanoja tai muita erikoisuuksia.", " list contains the most common words of the full list, without rarer loanwords, dialect words or other oddities."),
+    ("Helppo", "Easy"),
+    (" lista on tavallisesta vielä hieman helpotettu versio, jossa jäljellä ovat vain yleiset arkikielen sanat ilman vanhahtavia sanoja, puhekieltä tai rumia sanuleja. Näin lista sopii kaikenikäisille. \"Helppo\" kuusikirjaimisten sanulien lista on kuitenkin vielä kesken.", " list is a slightly easier version of the common list, with only everyday words and no archaic words, slang or profanities. This makes it suitable for all ages. The easy list of six letter words is still a work in progress though."),
+    ("Vaikea", "Hard"),
+    (" lista on täysi lista pelin hyväksymiä sanoja. Tälle listalle on myös lisätty jonkin verran käyttäjien uusia ehdotuksia, puhekielisyyksiä, murresanoja sekä muita erikoisuuksia, eikä poistoja ole tehty kuin vain jos sanulit eivät selvästi ole oikeita sanoja.", " list is the full list of words accepted by the game. Some player suggestions, colloquialisms, dialect words and other oddities have been added to it, and words have only been removed when they clearly aren't real words."),
+    ("Sanulit ovat yleensä perusmuodossa, mutta eivät välttämättä täysin pelkkää kirjakieltä. Yhdyssanojakin on seassa.", "The words are usually in their base form, but not necessarily strictly standard language. There are compound words too."),
+    ("Päivän sanulit tulevat omalta listaltaan, joka on jotain tavallisen ja vaikean listan väliltä. Sanuli on aina sama kaikille pelaajille tiettynä päivänä.", "The words of the day come from their own list, somewhere between the common and the hard list. The word is the same for every player on a given day."),
+    ("Sanuliketjussa jos arvaat sanulin, on se suoraan ensimmäinen arvaus seuraavaan peliin. Näin joudut sopeutumaan vaihtuviin alkuarvauksiin, ja peli on hieman vaikeampi.", "In the word chain a solved word becomes the first guess of the next game. You have to adapt to changing opening guesses, which makes the game a bit harder."),
+    ("Zen-pelissä voit pelata rauhassa ilman putkia. Arvauksia voi perua, ja tulokset pidetään erillään muista tilastoistasi.", "In Zen mode you can play in peace without streaks. Guesses can be undone, and the results are kept apart from your other statistics."),
+    ("Aikapaineessa jokaiselle arvaukselle on rajattu aika, joka lyhenee rivi riviltä minuutista kymmeneen sekuntiin. Jos aika loppuu kesken, menetät rivin.", "In pressure mode every guess has a time limit that shrinks row by row from a minute to ten seconds. If the time runs out, you lose the row."),
+    ("Nelulissa ratkaiset samalla kertaa neljää eri sanulia samoilla arvauksilla. Tavoite on saada kaikki neljä sanulia ratkaistua yhdeksällä arvauksella.", "In Neluli you solve four different words at once with the same guesses. The goal is to solve all four within nine guesses."),
+    ("Sanulistoja muokkailen aina välillä käyttäjien ehdotusten perusteella, ja voit jättää omat ehdotuksesi sanuleihin ", "I tweak the word lists every now and then based on player suggestions, and you can leave your own suggestions "),
+    ("täällä", "here"),
+    (". Kiitos kaikille ehdotuksia jättäneille ja sanulistojen kasaamisessa auttaneille henkilöille!", ". Thanks to everyone who has left suggestions and helped to put the word lists together!"),
+    // Menu
+    ("Sanulien pituus:", "Word length:"),
+    ("5 merkkiä", "5 letters"),
+    ("6 merkkiä", "6 letters"),
+    ("Sanulista:", "Word list:"),
+    ("Rumat sanulit:", "Profanities:"),
+    ("Ei", "No"),
+    ("Kyllä", "Yes"),
+    ("Pelimuoto:", "Game mode:"),
+    ("Peruspeli", "Classic"),
+    ("Sanuliketju", "Word chain"),
+    ("Päivän sanuli", "Word of the day"),
+    ("Näytä mahdolliset sanulit:", "Show possible words:"),
+    ("Näppäimistön vihjeet:", "Keyboard hints:"),
+    ("Täysi", "Full"),
+    ("Kevyt", "Light"),
+    ("Ei vihjeitä", "No hints"),
+    ("Omat tilastosi:", "Your statistics:"),
+    ("Omat tilastosi (kevyet vihjeet):", "Your statistics (light hints):"),
+    ("Omat tilastosi (ei vihjeitä):", "Your statistics (no hints):"),
+    ("Pisin putki: {}", "Longest streak: {}"),
+    ("Lomapäivät: {}", "Streak freezes: {}"),
+    ("Pelatut sanulit: {}", "Words played: {}"),
+    ("Ratkaistut sanulit: {}", "Words solved: {}"),
+    ("Zen-sanulit: {} / {} ratkaistu", "Zen: {} / {} solved"),
+    ("Aikapaine: {} / {} ratkaistu, pisin putki {}", "Pressure: {} / {} solved, longest streak {}"),
+    ("Viimeisimmät päivän sanulit:", "Recent words of the day:"),
+    ("Lista", "List"),
+    ("Pienoiskuvat", "Thumbnails"),
+    ("Katso", "Watch"),
+    ("Teema:", "Theme:"),
+    ("Oletus", "Default"),
+    ("Värisokeille", "Colorblind"),
+    ("Kieli:", "Language:"),
+    ("Sanulien kieli:", "Puzzle language:"),
+    ("Korvaavat näppäimet:", "Substitute keys:"),
+    (
+        "Jos näppäimistöstäsi puuttuu kirjaimia, voit kirjoittaa ne näillä näppäimillä. AltGr-yhdistelmät toimivat aina.",
+        "If your keyboard lacks some letters, you can type them with these keys. AltGr combinations always work.",
+    ),
+    // Modals and panels
+    ("Linkki on liian pitkä QR-koodiksi.", "The link is too long for a QR code."),
+    ("Haasta kaveri", "Challenge a friend"),
+    ("Skannaa koodi puhelimella pelataksesi saman sanulin.", "Scan the code with a phone to play the same word."),
+    ("Päivän sanuli {}", "Word of the day {}"),
+    ("Uusinta", "Replay"),
+    ("Katso uudelleen", "Watch again"),
+    ("Viikon teema: {}", "Theme of the week: {}"),
+    ("Äänestit sanulia {}. Tulokset julkaistaan {}.", "You voted for {}. The results will be published {}."),
+    ("Äänestä ensi viikon päivän sanulia:", "Vote for next week's word of the day:"),
+    ("Äänen lähettäminen epäonnistui, yritä uudelleen.", "Sending the vote failed, please try again."),
+    ("oikein", "correct"),
+    ("ei sanassa", "not in the word"),
+    ("väärässä paikassa", "in the wrong place"),
+    ("tyhjä", "empty"),
+    ("Kirjain poistettu", "Letter removed"),
+    ("Sanuli {}: {}", "Word {}: {}"),
+    ("Sanulista on uusi versio.", "A new version of the game is available."),
+    ("Päivitä", "Update"),
+    ("Päivän sanuli odottaa!", "The word of the day is waiting!"),
+    ("Muistuta päivän sanulista:", "Remind me of the word of the day:"),
+    ("Lue lisää", "Read more"),
+    ("Mitä uutta", "What's new"),
+    ("Koko muutosloki", "Full changelog"),
+    ("Tulos kopioitu!", "Result copied!"),
+    ("Linkki kopioitu!", "Link copied!"),
+    ("Uusinta kopioitu!", "Replay copied!"),
+    ("Kuva ladattu!", "Image downloaded!"),
+    ("Puheentunnistus ei käynnistynyt.", "Speech recognition didn't start."),
+    ("Sanaa ei tunnistettu.", "The word wasn't recognized."),
+    ("Keskeneräinen peli menetetään — jatketaanko?", "The game in progress will be lost — continue?"),
+    ("Peruuta", "Cancel"),
+    ("Jatka", "Continue"),
+    ("Iltasanuli", "Evening word"),
+    ("Iltasanuli #{}", "Evening word #{}"),
+    ("Iltasanuli {}", "Evening word {}"),
+    ("Toinen päivän sanuli illaksi:", "A second daily word for the evening:"),
+    ("Viikkohaaste", "Weekly challenge"),
+    ("Viikkohaaste {} — sana {}/{}", "Weekly challenge {} — word {}/{}"),
+    ("viikkohaaste {}", "weekly challenge {}"),
+    ("{} arvausta", "{} guesses"),
+    ("Kilpasanuli", "Race"),
+    ("Kilpa verkossa", "Online race"),
+    ("Vastustaja", "Opponent"),
+    ("Voitit kilvan!", "You won the race!"),
+    ("Vastustaja voitti", "The opponent won"),
+    ("Odotetaan vastustajaa huoneeseen {}…", "Waiting for an opponent in room {}…"),
+    ("Poistu", "Leave"),
+    ("Kilpaile kaveria vastaan samasta sanulista. Jaa huoneen koodi, kilpa alkaa kun molemmat ovat liittyneet.", "Race a friend on the same word. Share the room code, the race starts once both have joined."),
+    ("Huoneen koodi:", "Room code:"),
+    ("Liity", "Join"),
+    ("Vastustaja ratkaisi sanulin ensin!", "The opponent solved the word first!"),
+    ("Vastustaja poistui kilvasta.", "The opponent left the race."),
+    ("Kilpaan liittyminen epäonnistui.", "Joining the race failed."),
+    ("Yhteys kilpaan katkesi.", "The connection to the race was lost."),
+    ("Parempi tulos kuin {} % pelaajista", "Better than {} % of the players"),
+    ("Tulokset lähetetään nimettöminä.", "The results are sent anonymously."),
+    ("Lähetä päivän tulos nimettömänä tulostaulukkoon:", "Send the daily result anonymously to the leaderboard:"),
+    ("Jaa peli katsojille", "Share the game with spectators"),
+    ("Linkin avanneet näkevät pelisi etenemisen. Arvausten kirjaimet paljastuvat vasta pelin päätyttyä.", "Anyone opening the link sees your game progress. The letters of the guesses are revealed once the game has ended."),
+    ("Lopeta jakaminen", "Stop sharing"),
+    ("Katsojalinkin luominen epäonnistui.", "Creating the spectator link failed."),
+    ("Yhteys katsojiin katkesi.", "The connection to the spectators was lost."),
+    ("Yhteys pelaajaan katkesi.", "The connection to the player was lost."),
+    ("Odotetaan pelaajaa…", "Waiting for the player…"),
+    ("Lopeta katselu", "Stop watching"),
+    ("Peli on kesken, kirjaimet paljastuvat pelin päätyttyä.", "The game is in progress, the letters are revealed once it has ended."),
+    ("Peli on päättynyt.", "The game has ended."),
+    ("Lähetä nimettömiä käyttötilastoja:", "Send anonymous usage statistics:"),
+    ("Pelin aloitukset ja tulokset ilman tunnisteita tai arvattuja sanoja.", "Started games and results, without identifiers or guessed words."),
+    ("Hups, jotain meni pieleen", "Oops, something went wrong"),
+    ("Sanuli kohtasi odottamattoman virheen. Tallennettu peli ja tilastot ovat yleensä tallessa, ja sivun lataaminen uudelleen palauttaa ne.", "Sanuli ran into an unexpected error. The saved game and statistics are usually intact, and reloading the page restores them."),
+    ("Yritä palauttaa tallennettu peli", "Try to restore the saved game"),
+    ("Jos virhe toistuu, voit aloittaa alusta. Tämä poistaa kaikki pelit, tilastot ja asetukset.", "If the error keeps coming back, you can start over. This removes all games, statistics and settings."),
+    ("Tyhjennä kaikki tiedot", "Reset all data"),
+    ("Poistetaanko kaikki tiedot pysyvästi?", "Remove all data permanently?"),
+    ("Lataa tiedot vikailmoitukseen", "Download data for a bug report"),
+    ("Vikailmoituksen tiedot ladattu!", "Bug report data downloaded!"),
+    ("Ladataan sanakirjaa…", "Loading the dictionary…"),
+    ("Sanakirja latautuu vielä, yritä hetken päästä.", "The dictionary is still loading, try again in a moment."),
+    ("Sanuliketju — Ketju: {}", "Word chain — Chain: {}"),
+    ("Sanuliketju: ketju {}, pisin ketju {}, ketjun arvaukset {}", "Word chain: chain {}, longest chain {}, guesses in the chain {}"),
+    ("ketju", "chain"),
+    ("{} sanaa, {} arvausta", "{} words, {} guesses"),
+    ("Päivän sanuli vaikeana:", "Hard mode for the word of the day:"),
+    ("Paljastettujen vihjeiden on oltava mukana seuraavissa arvauksissa. Vaihto koskee vain arvaamatonta päivän sanulia.", "Revealed hints must be used in the following guesses. Only applies to a daily word without guesses."),
+    ("Päivän sanuli #{} — Vaikea", "Word of the day #{} — Hard"),
+    ("{}. kirjaimen täytyy olla {}", "Letter {} must be {}"),
+    ("Arvauksessa täytyy olla {}", "Guess must contain {}"),
+    ("Tuplasanuli", "Double daily"),
+    ("Tuplasanuli #{}", "Double daily #{}"),
+    ("Päättely", "Deduction"),
+    ("Päättelysanuli", "Deduction"),
+    ("Värit eivät täsmää!", "The colors don't match!"),
+    ("Päättely onnistui! {}", "Deduction solved! {}"),
+    ("Aikaa arvaukselle:", "Time per guess:"),
+    ("Kiristyvä", "Shrinking"),
+    ("Maraton", "Marathon"),
+    ("Maraton — Sanat: {}, arvauksia jäljellä: {}", "Marathon — Words: {}, guesses left: {}"),
+    ("Maraton päättyi, {} sanaa! Sana oli \"{}\"", "The marathon ended, {} words! The word was \"{}\""),
+    ("maraton", "marathon"),
+    ("{} sanaa", "{} words"),
+    ("Maraton: ennätys {} sanaa", "Marathon: best {} words"),
+    ("Näppäimistö pysyy värittömänä, vain ruudukko värittyy. Jaettu tulos merkitään 🧠-merkillä.", "The keyboard stays uncolored, only the board is colored. Shared results are marked with 🧠."),
+    ("Sokko", "Blind"),
+    ("Sokkosanuli", "Blind"),
+    ("Näytä kirjainten yleisyys:", "Show letter frequencies:"),
+    ("Sanoja jäljellä {}", "Words left {}"),
+    ("Aakkoset", "Alphabet"),
+    ("Näytä aakkoset näppäimistön yllä:", "Show the alphabet above the keyboard:"),
+    ("Pelihistoria", "Game history"),
+    ("Kaikki", "All"),
+    ("Ratkaistut", "Solved"),
+    ("Ratkaisematta", "Unsolved"),
+    ("Ei pelattuja pelejä.", "No games played yet."),
+    ("Päivän sanulin keskimääräinen ratkaisuaika: {}", "Average daily word solve time: {}"),
+    ("Avaussanasi:", "Your openers:"),
+    ("Paras avaussanasi on {}, keskimäärin {} vihreää tai keltaista ensimmäisellä rivillä.", "Your best opener is {}, with {} greens or yellows on the first row on average."),
+    ("{}: {} peliä, keskimäärin {} osumaa", "{}: {} games, {} hits on average"),
+    ("Sinulle hankalimmat kirjaimet:", "Trickiest letters for you:"),
+    ("{}: väärässä kohdassa {}, löytymättä {} / {} sanasta", "{}: misplaced {}, missed {} / {} words"),
+    ("Profiili:", "Profile:"),
+    ("Uusi profiili", "New profile"),
+    ("Lisää", "Add"),
+    ("Profiilin nimi on jo käytössä.", "The profile name is already taken."),
+    ("Synkronoi edistyminen:", "Sync progress:"),
+    ("Tallennus kulkee laitteelta toiselle osoitteen kautta, joka vastaa GET- ja PUT-pyyntöihin, esim. WebDAV-palvelimen tiedosto. Uudempi tallennus voittaa, päivän sanulit yhdistetään.", "The save moves between devices through an address answering GET and PUT requests, eg. a file on a WebDAV server. The newer save wins, the daily words are merged."),
+    ("Synkronoi", "Sync"),
+    ("Edistyminen synkronoitu.", "Progress synced."),
+    ("Synkronointi epäonnistui.", "Syncing failed."),
+    ("Siirtokoodi:", "Sync code:"),
+    ("Kopioi koodi ja liitä se toisella laitteella siirtääksesi pelit, tilastot ja asetukset.", "Copy the code and paste it on another device to move your games, statistics and settings."),
+    ("Kopioi siirtokoodi", "Copy sync code"),
+    ("Tuo", "Import"),
+    ("Siirtokoodi kopioitu!", "Sync code copied!"),
+    ("Siirtokoodi korvaa tämän laitteen pelit, tilastot ja asetukset — jatketaanko?", "The sync code replaces the games, statistics and settings on this device — continue?"),
+    ("Siirtokoodi ei kelpaa.", "The sync code is invalid."),
+    ("Siirtokoodi on eri versiosta, päivitä peli molemmilla laitteilla.", "The sync code is from another version, update the game on both devices."),
+    ("Siirtokoodi on vaillinainen, kopioi se uudelleen kokonaan.", "The sync code is incomplete, copy all of it again."),
+    ("Siirtokoodin tallentaminen epäonnistui.", "Saving the sync code failed."),
+    ("Jaetun tuloksen värit:", "Colors of the shared result:"),
+    ("Teeman mukaan", "Follow the theme"),
+    ("Jaetun tuloksen tiedot:", "Details of the shared result:"),
+    ("Pelimuoto", "Game mode"),
+    ("Sanan pituus", "Word length"),
+    ("Vaikea-merkintä", "Hard mode mark"),
+    ("Putki", "Streak"),
+    ("Ratkaisuaika", "Solve time"),
+    ("{} kirjainta", "{} letters"),
+    ("Klassinen", "Classic"),
+    ("Pastelli", "Pastel"),
+    ("Pääte", "Terminal"),
+    ("Korkea kontrasti", "High contrast"),
+    ("Koko:", "Size:"),
+    ("Pieni", "Small"),
+    ("Normaali", "Normal"),
+    ("Suuri", "Large"),
+    ("Ruutujen, näppäimistön ja tekstien koko.", "Size of the tiles, the keyboard and the texts."),
+    ("Animaatiot:", "Animations:"),
+    ("Pois", "Off"),
+    ("Nopeat", "Fast"),
+    ("Normaalit", "Normal"),
+    ("Harjoittele värejä", "Practice the colors"),
+    ("Harjoitellaan", "Let's practice"),
+    ("Lähetä arvaus painamalla ARVAA.", "Submit the guess by pressing GUESS."),
+    ("Harjoituksen sana on {}. Poista kirjaimia painamalla ⌫.", "The practice word is {}. Remove letters by pressing ⌫."),
+    ("Kirjoita arvaus {} painamalla kirjaimia.", "Type the guess {} by pressing the letters."),
+    ("Arvauksen jälkeen kirjaimet vaihtavat väriään:", "After the guess the letters change their color:"),
+    ("Värien perusteella kätketty sana voisi olla {}. Kirjoita se ja lähetä.", "Going by the colors, the hidden word could be {}. Type it and submit."),
+    ("Löysit kätketyn sanan! Oikeassa pelissä sanaa ei kerrota, mutta värit auttavat samalla tavalla. Arvauksia on kuusi.", "You found the hidden word! In the real game the word isn't told, but the colors help the same way. You have six guesses."),
+    ("Aloita peli", "Start playing"),
+    ("Ohita harjoitus", "Skip the practice"),
+    ("Kokeile itse: arvaa esimerkin sana kolmella yrityksellä. Kokeilu ei vaikuta peliisi eikä tilastoihisi.", "Try it yourself: guess the word of the example in three tries. Trying doesn't affect your game or your statistics."),
+    ("Kokeile arvata", "Try a guess"),
+    ("Esimerkin arvaus", "Guess of the example"),
+    ("Arvaa", "Guess"),
+    ("Uusi yritys", "Try again"),
+    ("Päivän sanuli vaihtuu:", "The daily word changes:"),
+    ("Oman ajan keskiyöllä", "At local midnight"),
+    ("Suomen ajan keskiyöllä", "At midnight in Finland"),
+    ("Suomen ajalla päivän sanuli on sama kuin Suomessa pelaavilla, vaikka olisit matkoilla.", "With Finnish time the daily word is the same as for the players in Finland, even while travelling."),
+    ("Keskeneräinen peli", "Unfinished game"),
+    ("Arvauksia käytetty {}/{}.", "Guesses used {}/{}."),
+    ("Putki: {}", "Streak: {}"),
+    ("Ketju: {}", "Chain: {}"),
+    ("Aloita uusi", "Start a new one"),
+    ("Jatka keskeneräistä peliä", "Continue the unfinished game"),
+    ("Selaimen tallennustila on täynnä, eikä peliä voitu tallentaa. Vapauta tilaa poistamalla profiileja tai muiden sivujen tietoja.", "The browser's storage is full and the game could not be saved. Free up space by removing profiles or the data of other sites."),
+    ("Selain ei salli tallentamista, eikä peliä voitu tallentaa.", "The browser doesn't allow storage and the game could not be saved."),
+    ("Peliä ei voitu tallentaa.", "The game could not be saved."),
+    ("Ladataan…", "Loading…"),
+    ("Tyhjennä rivi", "Clear the row"),
+    ("Rivi tyhjennetty", "Row cleared"),
+    ("Pyyhkäisyeleet:", "Swipe gestures:"),
+    ("Pyyhkäise laudalla vasemmalle poistaaksesi kirjaimen, oikealle arvataksesi ja alas aloittaaksesi uuden pelin.", "Swipe left on the board to remove a letter, right to guess and down to start a new game."),
+    ("Avaa {} sanakirjassa", "Open {} in the dictionary"),
+    ("Määritelmä", "Definition"),
+    ("Määritelmäsanuli", "Definition"),
+    ("Haetaan määritelmää…", "Fetching the definition…"),
+    ("Määritelmää ei saatu, arvaa ilman vihjettä.", "The definition could not be fetched, guess without a clue."),
+    ("Tietokone arvaa", "The computer guesses"),
+    ("Keksi viisikirjaiminen sana, niin tietokone yrittää arvata sen. Napauta arvauksen kirjaimia, kunnes niiden värit vastaavat sanaasi.", "Think of a five-letter word and the computer tries to guess it. Tap the letters of the guess until their colors match your word."),
+    ("Sanoja jäljellä: {}", "Words left: {}"),
+    ("Arvasin sanasi {}. arvauksella!", "I guessed your word on guess {}!"),
+    ("Mikään sana ei sovi antamiisi väreihin. Huijasitko?", "No word fits the colors you gave. Did you cheat?"),
+    ("En arvannut sanaasi. Vaihtoehtoja oli vielä {}.", "I didn't guess your word. There were still {} options."),
+    ("Värit valmiit", "Colors done"),
+    ("Uusi sana", "New word"),
+    ("Kilpa bottia vastaan", "Race against the bot"),
+    ("Kilpa bottia vastaan: {} / {} voitettu", "Races against the bot: {} / {} won"),
+    ("Kilpaile bottia vastaan samasta sanulista. Botti arvaa omalle laudalleen sitä nopeammin, mitä vaikeampi se on, ja näet sen arvauksista vain värit.", "Race the bot for the same word. The bot guesses on a board of its own, the faster the harder it is, and you only see the colors of its guesses."),
+    ("Botin taso:", "Bot level:"),
+    ("Keskitaso", "Medium"),
+    ("Botti", "Bot"),
+    ("Botti voitti", "The bot won"),
+    ("Botti ratkaisi sanulin ensin!", "The bot solved the word first!"),
+    ("Turnaus", "Tournament"),
+    ("Turnaus {} — sana {}/{}", "Tournament {} — word {}/{}"),
+    ("turnaus {} ({}/{})", "tournament {} ({}/{})"),
+    ("{} arvausta, {}", "{} guesses, {}"),
+    ("Turnauksen koodi määrää sarjan {} sanaa, jotka jokainen pelaa omalla laitteellaan. Kopioi lopuksi tuloksesi arvauksineen ja aikoineen ryhmän keskusteluun.", "The tournament code defines a series of {} words that everyone plays on their own device. At the end, copy your result with the guesses and the times to the group chat."),
+    ("Pelaat turnausta {}. Jaa koodi kavereillesi.", "You are playing the tournament {}. Share the code with your friends."),
+    ("Turnauksen koodi:", "Tournament code:"),
+    ("Uusi turnaus", "New tournament"),
+    ("Virheellinen turnauksen koodi.", "Invalid tournament code."),
+    ("Näppäimistö:", "Keyboard:"),
+    ("Aakkosjärjestys", "Alphabetical"),
+    ("{}-vuosi {}", "{} year {}"),
+    ("{}-vuosi", "{} year"),
+    ("📅 Päivän sanoja pelattu: {}", "📅 Daily words played: {}"),
+    ("🏆 Voitettu: {} %", "🏆 Won: {} %"),
+    ("🔥 Pisin putki: {}", "🔥 Longest streak: {}"),
+    ("🎯 Arvauksia keskimäärin: {}", "🎯 Average guesses: {}"),
+    ("🔤 Vaikein kirjain: {}", "🔤 Trickiest letter: {}"),
+    ("Päivän sanoja pelattu: {}", "Daily words played: {}"),
+    ("Voitettu: {} / {} ({} %)", "Won: {} / {} ({} %)"),
+    ("Arvauksia keskimäärin: {}", "Average guesses: {}"),
+    ("Vaikein kirjain: {}", "Trickiest letter: {}"),
+    ("Et pelannut vuonna {} yhtään päivän sanaa.", "You played no daily words in {}."),
+    ("Kopioi teksti", "Copy text"),
+    ("Pelimuodon pisteet", "Points in the game mode"),
+    ("Pisteet:", "Points:"),
+    ("Pisteitä saa vähistä arvauksista, nopeasta ratkaisusta, putkesta ja vaikeasta tilasta. Pisteet pidetään pelimuodoittain.", "Points are earned for few guesses, fast solves, streaks and hard mode. Points are kept per game mode."),
+    ("Pisteet ({}): {} yhteensä, paras peli {}", "Points ({}): {} in total, best game {}"),
+];
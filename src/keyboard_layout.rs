@@ -0,0 +1,81 @@
+//! Layouts of the on-screen keyboard. A layout arranges the letters of the puzzle language with
+//! the other keys into rows, which the keyboard component renders the same way for every layout
+//! and language.
+
+use serde::{Deserialize, Serialize};
+
+use crate::i18n::Language;
+
+// Finnish and Swedish both order the letters after Z as Å, Ä, Ö
+const ALPHABETICAL_ORDER: &str = "ABCDEFGHIJKLMNOPQRSTUVWXYZÅÄÖ";
+
+#[derive(PartialEq, Clone, Copy, Serialize, Deserialize)]
+pub enum KeyboardLayout {
+    // The keyboard of the puzzle language, see `Language::keyboard_rows()`
+    Qwerty,
+    Alphabetical,
+}
+
+impl Default for KeyboardLayout {
+    fn default() -> Self {
+        KeyboardLayout::Qwerty
+    }
+}
+
+#[derive(PartialEq, Clone, Copy)]
+pub enum Key {
+    Letter(char),
+    Backspace,
+    // Submits the guess, or moves on once the game has ended
+    Submit,
+    // The dictation button, or the room of it when dictation is off
+    Voice,
+    Spacer,
+}
+
+impl Key {
+    /// Whether the key is a button, reachable with the arrow keys
+    pub fn is_button(&self, is_voice_input: bool) -> bool {
+        match self {
+            Key::Letter(_) | Key::Backspace | Key::Submit => true,
+            Key::Voice => is_voice_input,
+            Key::Spacer => false,
+        }
+    }
+}
+
+impl KeyboardLayout {
+    pub fn rows(&self, language: Language) -> Vec<Vec<Key>> {
+        let language_rows = language.keyboard_rows();
+        let letter_rows: Vec<Vec<char>> = match self {
+            KeyboardLayout::Qwerty => language_rows.iter().map(|row| row.to_vec()).collect(),
+            KeyboardLayout::Alphabetical => {
+                let mut letters = language.alphabet().copied().collect::<Vec<_>>();
+                letters.sort_by_key(|letter| ALPHABETICAL_ORDER.chars().position(|c| c == *letter));
+
+                // The rows are as long as on the keyboard of the language
+                let mut letters = letters.into_iter();
+                language_rows
+                    .iter()
+                    .map(|row| letters.by_ref().take(row.len()).collect())
+                    .collect()
+            }
+        };
+        let letters = |row: &[char]| {
+            row.iter()
+                .map(|letter| Key::Letter(*letter))
+                .collect::<Vec<_>>()
+        };
+
+        vec![
+            [letters(&letter_rows[0]), vec![Key::Backspace]].concat(),
+            [vec![Key::Spacer], letters(&letter_rows[1])].concat(),
+            [
+                vec![Key::Voice],
+                letters(&letter_rows[2]),
+                vec![Key::Submit, Key::Spacer, Key::Spacer],
+            ]
+            .concat(),
+        ]
+    }
+}
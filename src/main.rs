@@ -1,99 +1,934 @@
 extern crate wee_alloc;
 
-use std::collections::HashMap;
-use wasm_bindgen::{prelude::Closure, JsCast};
-use web_sys::{window, Window};
+use chrono::{Datelike, Local, NaiveDate, Utc};
+use gloo_timers::callback::{Interval, Timeout};
+use std::cell::{Cell, RefCell};
+use std::collections::{BTreeMap, HashMap, VecDeque};
+use std::mem;
+use std::rc::Rc;
+use wasm_bindgen::{prelude::Closure, JsCast, JsValue};
+#[cfg(web_sys_unstable_apis)]
+use web_sys::ClipboardEvent;
+use web_sys::{window, HtmlElement, Window};
 use yew::prelude::*;
 
+mod analytics;
+#[cfg(feature = "api")]
+mod api;
+mod bot;
+mod brand;
+mod bug_report;
+mod cipher;
 mod components;
+mod dictionary;
 mod game;
+mod i18n;
+mod keyboard_layout;
 mod manager;
 mod neluli;
+mod notifications;
+mod qr;
+mod race;
+mod recovery;
+mod retention;
+mod rng;
+mod rollover;
 mod sanuli;
+mod share;
+mod share_image;
+mod shortcuts;
+mod spectate;
+mod speech;
+mod version;
+mod year_review;
+
+use analytics::Analytics;
+use bot::{Bot, BotStrength};
+use events::{EventBus, EventLog, GameEvent};
+use race::{Race, RaceMessage, RaceStatus};
+use rollover::Rollover;
+use share::ShareOption;
+use shortcuts::{Action, KeyBindings};
+use spectate::{Broadcast, SpectateMessage, SpectatedBoard, Spectating};
+#[cfg(feature = "daily-index")]
+mod daily_index;
+#[cfg(feature = "definitions")]
+mod definition;
+#[cfg(feature = "embed")]
+mod embed;
+mod engine;
+mod events;
+mod state_export;
+mod storage;
+#[cfg(feature = "sync")]
+mod sync;
+mod sync_code;
+mod tournament;
+mod transport;
+mod tutorial;
 
 use components::{
-    board::Board,
+    alphabet::Alphabet,
+    board::{Board, FLIP_DURATION_MS},
+    demo::SharedWordLists,
     header::Header,
     keyboard::Keyboard,
-    modal::{HelpModal, MenuModal},
+    modal::{
+        ActiveModal, BotRaceModal, ChangelogModal, ConfirmModal, HelpModal, HistoryModal,
+        MenuModal, QrCodeModal, RaceModal, ReplayModal, ResumeModal, ReverseModal, ShortcutsModal,
+        SpectateModal, TournamentModal, YearReviewModal,
+    },
+    race::OpponentBoard,
+    swipe::SwipeArea,
+    toast::{Toast, ToastKind, Toasts, MAX_TOASTS, TOAST_DURATION_MS},
+    tutorial::TutorialModal,
+};
+use i18n::{t, tf, Language};
+use keyboard_layout::KeyboardLayout;
+use manager::{
+    AnimationSpeed, GameMode, HistoryDensity, KeyCount, KeyState, KeyboardHints, Manager,
+    ReplayRecord, Theme, UiScale, WordList,
 };
-use manager::{GameMode, KeyState, Manager, Theme, WordList};
 
 // Use `wee_alloc` as the global allocator.
 #[global_allocator]
 static ALLOC: wee_alloc::WeeAlloc = wee_alloc::WeeAlloc::INIT;
 
-const ALLOWED_KEYS: [char; 28] = [
-    'Q', 'W', 'E', 'R', 'T', 'Y', 'U', 'I', 'O', 'P', 'A', 'S', 'D', 'F', 'G', 'H', 'J', 'K', 'L',
-    'Ö', 'Ä', 'Z', 'X', 'C', 'V', 'B', 'N', 'M',
-];
+const RECENT_DAILY_WORDS: usize = 30;
+// The most common letters of the remaining words shown in the frequency panel
+const LETTER_FREQUENCIES_SHOWN: usize = 10;
+
+/// How far the startup has come
+#[derive(Clone, Copy, PartialEq)]
+enum Phase {
+    // The first frame is painted with the settings only. The games are loaded after it, and
+    // the guesses wait for the first fetch of the full word list to finish.
+    Initializing,
+    Ready,
+}
 
 pub enum Msg {
+    // Loads the word lists and the games once the first frame has been painted
+    Rehydrate,
     KeyPress(char),
     Backspace,
+    // Empties the current row, from a long press of ⌫ or Ctrl+Backspace
+    ClearRow,
+    #[cfg(web_sys_unstable_apis)]
+    Paste(String),
+    MoveCursor(usize),
+    MoveCursorLeft,
+    MoveCursorRight,
     Enter,
+    Escape,
     Guess,
     NextWord,
+    // Replaces the game in progress with a new word
+    StartNewGame,
+    // Continues to the next word once the game has ended
+    StartGame,
+    // Replaces the current row with the word and guesses it
+    SubmitWord(String),
+    #[cfg(feature = "embed")]
+    SetSeed(u64),
+    #[cfg(feature = "daily-index")]
+    DailyIndexLoaded(Result<bool, api::ApiError>),
+    #[cfg(feature = "definitions")]
+    DefinitionLoaded(Vec<char>, Result<String, api::ApiError>),
     ToggleHelp,
     ToggleMenu,
+    ToggleShortcuts,
+    ToggleChangelog,
+    ToggleHistory,
+    ToggleYearReview,
+    ShowYearReview(i32),
+    ShareYearReview(i32),
+    DownloadYearReview(i32),
+    ToggleReverse,
+    StartTutorial,
+    CompleteTutorial,
+    // None switches to the default profile
+    SwitchProfile(Option<String>),
+    AddProfile(String),
+    CloseModal,
+    Confirm,
     ChangeGameMode(GameMode),
     ChangePreviousGameMode,
     ChangeWordLength(usize),
     ChangeWordList(WordList),
     ChangeAllowProfanities(bool),
     ChangeTheme(Theme),
+    ChangeShareTheme(Option<Theme>),
+    ToggleShareOption(ShareOption),
+    ChangeUiScale(UiScale),
+    ChangeAnimationSpeed(AnimationSpeed),
+    ChangeRollover(Rollover),
+    ChangeKeyboardHints(KeyboardHints),
+    ChangeKeyboardLayout(KeyboardLayout),
+    ChangeScoring(bool),
+    ChangeShowCandidates(bool),
+    ChangeShowAlphabet(bool),
+    ChangeSwipeGestures(bool),
+    ChangeShowLetterFrequencies(bool),
+    ChangePressureTimeLimit(Option<u32>),
+    ChangeHistoryDensity(HistoryDensity),
+    ChangeLanguage(Language),
+    ChangePuzzleLanguage(Language),
+    ChangeKeySubstitution(char, Option<char>),
+    ChangeKeyBinding(Action, Option<String>),
+    ChangeEveningWord(bool),
+    ChangeDailyHardMode(bool),
+    ChangeLeaderboard(bool),
+    ChangeAnalytics(bool),
+    ChangeVoiceInput(bool),
+    ChangeDailyReminder(Option<u32>),
+    NotificationPermission(bool),
+    ToggleDictation,
+    DictationResult(Vec<String>),
+    DictationEnded,
+    ConfirmDictation,
+    CancelDictation,
     ShareEmojis,
     ShareLink,
+    ShowQrCode,
+    ShareReplay,
+    CopySyncCode,
+    ImportSyncCode(String),
+    ShareImage,
+    ExportBugReport,
     RevealHiddenTiles,
     ResetGame,
+    UndoGuess,
+    ShowReplay(NaiveDate),
+    ShowReplayRecord(ReplayRecord),
+    Tick,
+    TimerTick,
+    CheckVersion,
+    VersionChecked(Option<String>),
+    DictionaryLoaded(Language, Option<String>),
+    Update,
+    DismissUpdate,
+    HistoryBack,
+    DismissToast(usize),
+    ToggleRace,
+    ToggleBotRace,
+    StartBotRace(BotStrength),
+    // The bot's next guess is due
+    BotTick,
+    ToggleTournament,
+    StartTournament,
+    JoinTournament(String),
+    JoinRace(String),
+    RaceMessage(RaceMessage),
+    RaceClosed,
+    LeaveRace,
+    StartBroadcast,
+    StopBroadcast,
+    BroadcastClosed,
+    SpectateMessage(SpectateMessage),
+    SpectateClosed,
+    StopSpectating,
+    RevealEnded,
+}
+
+// How often an open game checks for a new deployed version
+const VERSION_CHECK_INTERVAL_MS: u32 = 30 * 60 * 1000;
+// Keys typed during a reveal beyond these are dropped
+const MAX_BUFFERED_INPUTS: usize = 16;
+// Repeats of a submission within this are taken for the same intent, like a double click
+const DOUBLE_SUBMIT_MS: i64 = 500;
+
+/// The rows turning over after a guess
+struct Reveal {
+    // Row turning over on each board, None on the boards the guess didn't change
+    rows: Vec<Option<usize>>,
+    _timeout: Timeout,
 }
 
 pub struct App {
     manager: Manager,
-    is_help_visible: bool,
-    is_menu_visible: bool,
-    is_emojis_copied: bool,
-    is_link_copied: bool,
+    active_modal: Option<ActiveModal>,
+    // Held back by the confirmation dialog until the player accepts it
+    pending_confirmation: Option<Msg>,
+    is_abandon_confirmed: bool,
+    dictation: Option<speech::Dictation>,
+    // Dictated word waiting for a confirmation before it is guessed
+    dictated_word: Option<String>,
+    // Read out by the screen readers from the live region
+    announcement: String,
+    toasts: VecDeque<Toast>,
+    next_toast_id: usize,
+    // Shown once, the failed writes tend to repeat on every keypress
+    storage_failure: Option<storage::Failure>,
     keyboard_listener: Option<Closure<dyn Fn(KeyboardEvent)>>,
+    #[cfg(web_sys_unstable_apis)]
+    paste_listener: Option<Closure<dyn Fn(ClipboardEvent)>>,
+    // Shared with the keyboard listener
+    key_substitutions: Rc<RefCell<BTreeMap<char, char>>>,
+    key_bindings: Rc<RefCell<KeyBindings>>,
+    // Shared with the listeners, the game ignores keyboard input while a modal is open
+    is_modal_open: Rc<Cell<bool>>,
+    // Element focused before the modal was opened
+    focus_return: Option<HtmlElement>,
+    is_modal_focus_pending: bool,
+    _clock: Interval,
+    timer: Option<Interval>,
+    reveal: Option<Reveal>,
+    // Typed during the reveal, applied to the next row once it has ended
+    buffered_inputs: VecDeque<Msg>,
+    // When the latest guess or new word went through, until something is typed
+    submitted_at: Option<i64>,
+    _version_check: Interval,
+    visibility_listener: Option<Closure<dyn Fn()>>,
+    popstate_listener: Option<Closure<dyn Fn()>>,
+    // The open modals have an entry in the browser history, so that going back closes them
+    is_modal_in_history: bool,
+    is_update_available: bool,
+    phase: Phase,
+    is_dictionary_loading: bool,
+    race: Option<Race>,
+    // Opponent of the single player race
+    bot: Option<Bot>,
+    // The own game shown to the spectators
+    broadcast: Option<Broadcast>,
+    // The game of another player watched instead of playing
+    spectating: Option<Spectating>,
+    #[cfg(feature = "embed")]
+    embed: Option<embed::EmbedApi>,
+    // The clue of the definition game, with the word it was requested for
+    #[cfg(feature = "definitions")]
+    definition: Option<(Vec<char>, definition::Definition)>,
+    events: EventBus,
+    // Shared with the analytics subscriber
+    is_analytics_enabled: Rc<Cell<bool>>,
+}
+
+impl App {
+    fn revealing_row(&self, board_index: usize) -> Option<usize> {
+        *self.reveal.as_ref()?.rows.get(board_index)?
+    }
+
+    fn board_states(&self) -> Vec<(usize, bool)> {
+        self.manager
+            .game
+            .as_ref()
+            .map(|game| {
+                game.boards()
+                    .iter()
+                    .map(|board| (board.current_guess, board.is_guessing))
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    /// A second Enter or click of the submit button right after a guess or a new word, with
+    /// nothing typed in between, would guess again or skip the word that just started
+    fn is_repeated_submission(&self, msg: &Msg) -> bool {
+        let is_submission = matches!(
+            msg,
+            Msg::Enter
+                | Msg::Guess
+                | Msg::NextWord
+                | Msg::StartGame
+                | Msg::StartNewGame
+                | Msg::ChangePreviousGameMode
+        );
+
+        match self.submitted_at {
+            Some(submitted_at) if is_submission => {
+                self.reveal.is_some()
+                    || Utc::now().timestamp_millis() - submitted_at < DOUBLE_SUBMIT_MS
+            }
+            _ => false,
+        }
+    }
+
+    /// Starts the window of the repeated submissions if the guess went through
+    fn mark_submitted(&mut self, previous: &[(usize, bool)]) {
+        if self.board_states() != previous {
+            self.submitted_at = Some(Utc::now().timestamp_millis());
+        }
+    }
+
+    /// Turns over the rows revealed since `previous`, holding back the input until they have
+    fn start_reveal(&mut self, ctx: &Context<Self>, previous: &[(usize, bool)]) {
+        let duration_ms = match self.manager.animation_speed.duration_ms(FLIP_DURATION_MS) {
+            Some(duration_ms) => duration_ms,
+            None => return,
+        };
+
+        let rows = self
+            .board_states()
+            .iter()
+            .zip(previous)
+            .map(|(current, previous)| (current != previous).then_some(previous.0))
+            .collect::<Vec<_>>();
+        if rows.iter().all(Option::is_none) {
+            return;
+        }
+
+        let link = ctx.link().clone();
+        self.reveal = Some(Reveal {
+            rows,
+            _timeout: Timeout::new(duration_ms, move || link.send_message(Msg::RevealEnded)),
+        });
+    }
+
+    /// Announces the results of the rows revealed since `previous`, or the message of a rejected guess
+    fn announce_revealed(&mut self, previous: &[(usize, bool)]) {
+        let game = match &self.manager.game {
+            Some(game) => game,
+            None => return,
+        };
+
+        let boards = game.boards();
+        let is_quadruple = boards.len() > 1;
+        let mut announcements = boards
+            .iter()
+            .zip(previous)
+            .enumerate()
+            .filter(|(_, (board, (current_guess, is_guessing)))| {
+                *is_guessing && (board.current_guess != *current_guess || !board.is_guessing)
+            })
+            .filter_map(|(index, (board, (current_guess, _)))| {
+                let description = game::describe_guess(board.guesses.get(*current_guess)?);
+                if is_quadruple {
+                    Some(tf("Sanuli {}: {}", &[&(index + 1), &description]))
+                } else {
+                    Some(description)
+                }
+            })
+            .collect::<Vec<_>>();
+
+        let message = game.message();
+        if !message.is_empty() {
+            announcements.push(message);
+        }
+
+        self.announcement = announcements.join(". ");
+    }
+
+    fn open_modal(&self) -> Option<&'static str> {
+        self.active_modal.as_ref().map(ActiveModal::name)
+    }
+
+    /// Opens the modal, or closes it if it was already open
+    fn toggle_modal(&mut self, modal: ActiveModal) {
+        if self.active_modal.as_ref().map(ActiveModal::name) == Some(modal.name()) {
+            self.active_modal = None;
+        } else {
+            self.active_modal = Some(modal);
+        }
+    }
+
+    /// Moves the focus into an opened modal and back to where it was once the modals are closed
+    fn sync_modal_focus(&mut self, previous_modal: Option<&'static str>) {
+        let open_modal = self.open_modal();
+        self.is_modal_open.set(open_modal.is_some());
+
+        if open_modal == previous_modal {
+            return;
+        }
+
+        match (previous_modal, open_modal) {
+            (None, Some(modal)) => {
+                self.push_modal_history(modal);
+                self.focus_return = window()
+                    .and_then(|window| window.document())
+                    .and_then(|document| document.active_element())
+                    .and_then(|element| element.dyn_into::<HtmlElement>().ok());
+                self.is_modal_focus_pending = true;
+            }
+            (Some(_), Some(_)) => self.is_modal_focus_pending = true,
+            (_, None) => {
+                self.pop_modal_history();
+                if let Some(element) = self.focus_return.take() {
+                    let _res = element.focus();
+                }
+            }
+        }
+    }
+
+    fn push_modal_history(&mut self, modal: &str) {
+        if let Some(history) = window().and_then(|window| window.history().ok()) {
+            self.is_modal_in_history = history.push_state(&JsValue::from_str(modal), "").is_ok();
+        }
+    }
+
+    /// Removes the history entry of modals closed from the game, rather than with the back button
+    fn pop_modal_history(&mut self) {
+        if !self.is_modal_in_history {
+            return;
+        }
+
+        self.is_modal_in_history = false;
+        if let Some(history) = window().and_then(|window| window.history().ok()) {
+            let _res = history.back();
+        }
+    }
+
+    /// Whether the message would switch away from a game that can't be returned to unharmed
+    fn is_abandoning_game(&self, msg: &Msg) -> bool {
+        let is_switch = match msg {
+            Msg::ChangeWordLength(length) => *length != self.manager.current_word_length,
+            Msg::ChangeGameMode(mode) => *mode != self.manager.current_game_mode,
+            Msg::ChangeWordList(list) => *list != self.manager.current_word_list,
+            Msg::ChangePuzzleLanguage(language) => *language != self.manager.puzzle_language,
+            _ => false,
+        };
+
+        is_switch && !self.is_abandon_confirmed && self.manager.is_game_at_risk()
+    }
+
+    fn show_toast(&mut self, ctx: &Context<Self>, message: &str, kind: ToastKind) {
+        let id = self.next_toast_id;
+        self.next_toast_id += 1;
+
+        self.toasts.push_back(Toast {
+            id,
+            message: message.to_owned(),
+            kind,
+        });
+        while self.toasts.len() > MAX_TOASTS {
+            self.toasts.pop_front();
+        }
+
+        let link = ctx.link().clone();
+        Timeout::new(TOAST_DURATION_MS, move || {
+            link.send_message(Msg::DismissToast(id))
+        })
+        .forget();
+    }
+
+    /// Names the browser tab, and the history entries, after the game being played
+    fn sync_document_title(&self) {
+        let title = match &self.manager.game {
+            Some(game) => game.title(),
+            None => brand::BRAND.name.to_owned(),
+        };
+        set_document_title(&title);
+    }
+
+    fn on_race_message(&mut self, ctx: &Context<Self>, message: RaceMessage) {
+        let race = match &mut self.race {
+            Some(race) => race,
+            None => return,
+        };
+
+        match message {
+            RaceMessage::Start { seed } => {
+                race.status = RaceStatus::Racing;
+                race.opponent.clear();
+                self.manager.change_game_mode(GameMode::Race(seed));
+                self.active_modal = None;
+            }
+            RaceMessage::Progress { patterns } => race.opponent = patterns,
+            RaceMessage::Solved => {
+                if race.status == RaceStatus::Racing {
+                    race.status = RaceStatus::Lost;
+                    self.show_toast(
+                        ctx,
+                        t("Vastustaja ratkaisi sanulin ensin!"),
+                        ToastKind::Info,
+                    );
+                }
+            }
+            RaceMessage::Left => {
+                self.race = None;
+                self.show_toast(ctx, t("Vastustaja poistui kilvasta."), ToastKind::Info);
+            }
+            RaceMessage::Join { .. } => {}
+        }
+    }
+
+    /// Passes the events of the game to the app's own handling and to the subscribers
+    fn dispatch_events(&mut self, ctx: &Context<Self>) {
+        for event in self.manager.take_events() {
+            if let GameEvent::GuessRevealed {
+                mode: GameMode::Race(_),
+                ..
+            } = event
+            {
+                self.send_race_progress(ctx);
+                self.finish_bot_race(ctx);
+            }
+
+            self.events.emit(&event);
+        }
+    }
+
+    /// Loads the word lists and the games, and opens the modal the visit starts with
+    fn rehydrate(&mut self, ctx: &Context<Self>) {
+        self.manager = Manager::new();
+        *self.key_substitutions.borrow_mut() = self.manager.key_substitutions.clone();
+        *self.key_bindings.borrow_mut() = self.manager.key_bindings.clone();
+        self.is_analytics_enabled.set(self.manager.analytics);
+
+        // The new players practice first, until they complete or skip the tutorial
+        if version::is_first_visit() {
+            tutorial::start();
+        }
+        // The changes are shown once after an update, unless a shared replay is being opened
+        self.active_modal = match self.manager.shared_replay.take() {
+            Some(replay) => Some(ActiveModal::Replay(replay)),
+            None if version::take_is_updated() => Some(ActiveModal::Changelog),
+            None if tutorial::is_pending() => Some(ActiveModal::Tutorial),
+            // The review of the ended year is shown once, before the game left mid-progress,
+            // which is continued only if the player wants to
+            None => self
+                .manager
+                .take_due_year_review(rollover::today())
+                .map(ActiveModal::YearReview)
+                .or_else(|| self.manager.unfinished_game().map(ActiveModal::Resume)),
+        };
+
+        #[cfg(feature = "daily-index")]
+        if api::is_configured() {
+            let daily_words = self.manager.word_lists.daily_count();
+            ctx.link().send_future(async move {
+                Msg::DailyIndexLoaded(daily_index::fetch(daily_words).await)
+            });
+        }
+
+        // Later fetches, eg. after being offline, check the guesses against the common words
+        // until the full list arrives
+        self.fetch_dictionary(ctx);
+        if !self.is_dictionary_loading {
+            self.phase = Phase::Ready;
+        }
+    }
+
+    /// Fetches the clue once the definition game has a word without one
+    #[cfg(feature = "definitions")]
+    fn request_definition(&mut self, ctx: &Context<Self>) {
+        let word = match &self.manager.game {
+            Some(game)
+                if self.phase == Phase::Ready && *game.game_mode() == GameMode::Definition =>
+            {
+                game.word()
+            }
+            _ => return,
+        };
+        if self
+            .definition
+            .as_ref()
+            .is_some_and(|(requested, _)| *requested == word)
+        {
+            return;
+        }
+
+        self.definition = Some((word.clone(), definition::Definition::Loading));
+        match self.manager.word_lists.daily_index(&word) {
+            Some(index) if api::is_configured() => ctx.link().send_future(async move {
+                Msg::DefinitionLoaded(word, definition::fetch(index).await)
+            }),
+            _ => ctx.link().send_message(Msg::DefinitionLoaded(
+                word,
+                Err(api::ApiError::NotConfigured),
+            )),
+        }
+    }
+
+    #[cfg(feature = "definitions")]
+    fn definition_view(&self, game: &dyn game::Game) -> Html {
+        if *game.game_mode() != GameMode::Definition {
+            return html! {};
+        }
+
+        let word = game.word();
+        let text = match &self.definition {
+            Some((requested, definition)) if *requested == word => match definition {
+                definition::Definition::Loaded(text) => text.clone(),
+                definition::Definition::Failed => {
+                    t("Määritelmää ei saatu, arvaa ilman vihjettä.").to_owned()
+                }
+                definition::Definition::Loading => t("Haetaan määritelmää…").to_owned(),
+            },
+            _ => t("Haetaan määritelmää…").to_owned(),
+        };
+
+        html! {
+            <p class="definition" role="status">{text}</p>
+        }
+    }
+
+    #[cfg(not(feature = "definitions"))]
+    fn definition_view(&self, _game: &dyn game::Game) -> Html {
+        html! {}
+    }
+
+    /// Tells the player once if the game or the statistics could not be stored
+    fn report_storage_failure(&mut self, ctx: &Context<Self>) {
+        match storage::take_failure() {
+            Some(failure) if self.storage_failure != Some(failure) => {
+                self.storage_failure = Some(failure);
+                self.show_toast(ctx, t(failure.message()), ToastKind::Error);
+            }
+            _ => {}
+        }
+    }
+
+    /// Sends the colors of the revealed rows to the opponent, and claims the win once solved
+    fn send_race_progress(&mut self, ctx: &Context<Self>) {
+        let (race, game) = match (&mut self.race, &self.manager.game) {
+            (Some(race), Some(game))
+                if race.status != RaceStatus::Waiting
+                    && matches!(game.game_mode(), GameMode::Race(_)) =>
+            {
+                (race, game)
+            }
+            _ => return,
+        };
+
+        let board = &game.boards()[0];
+        let revealed = if board.is_guessing {
+            board.current_guess
+        } else {
+            board.current_guess + 1
+        };
+        let patterns = board
+            .guesses
+            .iter()
+            .take(revealed)
+            .map(|guess| guess.iter().map(|(_, state)| *state).collect())
+            .collect();
+        race.send(&RaceMessage::Progress { patterns });
+
+        if game.is_winner() && race.status == RaceStatus::Racing {
+            race.status = RaceStatus::Won;
+            race.send(&RaceMessage::Solved);
+            self.show_toast(ctx, t("Voitit kilvan!"), ToastKind::Info);
+        }
+    }
+
+    /// Settles the race against the bot once the player's game has ended
+    fn finish_bot_race(&mut self, ctx: &Context<Self>) {
+        let is_winner = match (&mut self.bot, &self.manager.game) {
+            (Some(bot), Some(game))
+                if bot.status == RaceStatus::Racing
+                    && matches!(game.game_mode(), GameMode::Race(_))
+                    && !game.is_guessing() =>
+            {
+                bot.status = if game.is_winner() {
+                    RaceStatus::Won
+                } else {
+                    RaceStatus::Lost
+                };
+                bot.stop();
+                game.is_winner()
+            }
+            _ => return,
+        };
+
+        self.manager.record_bot_race(is_winner);
+        if is_winner {
+            self.show_toast(ctx, t("Voitit kilvan!"), ToastKind::Info);
+        }
+    }
+
+    /// Fetches the full word list of the puzzle language unless it's already loaded or loading
+    fn fetch_dictionary(&mut self, ctx: &Context<Self>) {
+        if self.is_dictionary_loading || self.manager.word_lists.is_dictionary_loaded() {
+            return;
+        }
+
+        self.is_dictionary_loading = true;
+        let language = self.manager.puzzle_language;
+        ctx.link().send_future(async move {
+            Msg::DictionaryLoaded(language, dictionary::fetch(language).await)
+        });
+    }
+
+    /// Sends the board to the spectators after every change
+    fn sync_broadcast(&mut self) {
+        let board = self
+            .manager
+            .game
+            .as_ref()
+            .and_then(|game| SpectatedBoard::from_game(game.as_ref()));
+
+        if let (Some(broadcast), Some(board)) = (&mut self.broadcast, board) {
+            broadcast.send_board(board);
+        }
+    }
+
+    /// Passes the changed state to the subscribers of the embedding page
+    #[cfg(feature = "embed")]
+    fn sync_embed(&mut self) {
+        if let (Some(embed), Some(game)) = (&mut self.embed, &self.manager.game) {
+            embed.notify(embed::EmbedState::from_game(game.as_ref()));
+        }
+    }
+
+    /// Leaves the race room once the player has switched away from the race
+    fn sync_race(&mut self) {
+        let is_started = matches!(&self.race, Some(race) if race.status != RaceStatus::Waiting);
+        if is_started && !matches!(self.manager.current_game_mode, GameMode::Race(_)) {
+            self.race = None;
+        }
+        if !matches!(self.manager.current_game_mode, GameMode::Race(_)) {
+            self.bot = None;
+        }
+    }
+
+    /// Keeps the countdown of timed games running only while a row is on the clock
+    fn sync_timer(&mut self, ctx: &Context<Self>) {
+        match (self.manager.deadline(), &self.timer) {
+            (Some(_), None) => {
+                let link = ctx.link().clone();
+                self.timer = Some(Interval::new(250, move || {
+                    link.send_message(Msg::TimerTick)
+                }));
+            }
+            (None, Some(_)) => self.timer = None,
+            _ => {}
+        }
+    }
 }
 
 impl Component for App {
     type Message = Msg;
     type Properties = ();
 
-    fn create(_ctx: &Context<Self>) -> Self {
-        Self {
-            manager: Manager::new(),
-            is_help_visible: false,
-            is_menu_visible: false,
-            is_emojis_copied: false,
-            is_link_copied: false,
+    fn create(ctx: &Context<Self>) -> Self {
+        let manager = Manager::preload();
+        let key_substitutions = Rc::new(RefCell::new(manager.key_substitutions.clone()));
+        let key_bindings = Rc::new(RefCell::new(manager.key_bindings.clone()));
+        let is_modal_open = Rc::new(Cell::new(false));
+        let is_analytics_enabled = Rc::new(Cell::new(manager.analytics));
+
+        // Re-render every minute so time based views, like the streak flame, stay up to date
+        let link = ctx.link().clone();
+        let clock = Interval::new(60_000, move || link.send_message(Msg::Tick));
+
+        // The progress made on the other devices since the last visit
+        #[cfg(feature = "sync")]
+        sync::sync_in_background();
+
+        let link = ctx.link().clone();
+        let version_check = Interval::new(VERSION_CHECK_INTERVAL_MS, move || {
+            link.send_message(Msg::CheckVersion)
+        });
+
+        let mut app = Self {
+            manager,
+            active_modal: None,
+            pending_confirmation: None,
+            is_abandon_confirmed: false,
+            dictation: None,
+            dictated_word: None,
+            announcement: String::new(),
+            toasts: VecDeque::new(),
+            next_toast_id: 0,
+            storage_failure: None,
             keyboard_listener: None,
+            #[cfg(web_sys_unstable_apis)]
+            paste_listener: None,
+            key_substitutions,
+            key_bindings,
+            is_modal_focus_pending: false,
+            is_modal_open,
+            focus_return: None,
+            _clock: clock,
+            timer: None,
+            reveal: None,
+            buffered_inputs: VecDeque::new(),
+            submitted_at: None,
+            _version_check: version_check,
+            visibility_listener: None,
+            popstate_listener: None,
+            is_modal_in_history: false,
+            is_update_available: false,
+            phase: Phase::Initializing,
+            is_dictionary_loading: false,
+            race: None,
+            bot: None,
+            broadcast: None,
+            spectating: None,
+            #[cfg(feature = "embed")]
+            embed: embed::EmbedApi::install(ctx.link().clone()),
+            #[cfg(feature = "definitions")]
+            definition: None,
+            events: EventBus::default(),
+            is_analytics_enabled,
+        };
+        if let Some(channel) = spectate::channel_from_url() {
+            let link = ctx.link();
+            app.spectating = Spectating::watch(
+                &channel,
+                link.callback(Msg::SpectateMessage),
+                link.callback(|_| Msg::SpectateClosed),
+            );
+        }
+        if cfg!(debug_assertions) {
+            app.events.subscribe(Box::new(EventLog));
         }
+        app.events
+            .subscribe(Box::new(Analytics::new(app.is_analytics_enabled.clone())));
+        app.report_storage_failure(ctx);
+
+        app
     }
 
     fn rendered(&mut self, ctx: &Context<Self>, first_render: bool) {
+        if self.is_modal_focus_pending {
+            self.is_modal_focus_pending = false;
+
+            if let Some(modal) = window()
+                .and_then(|window| window.document())
+                .and_then(|document| document.query_selector(".modal").ok().flatten())
+                .and_then(|element| element.dyn_into::<HtmlElement>().ok())
+            {
+                let _res = modal.focus();
+            }
+        }
+
+        #[cfg(feature = "definitions")]
+        self.request_definition(ctx);
+
         if !first_render {
             return;
         }
 
+        // A zero timeout lets the browser paint the first frame before the slow part
+        let link = ctx.link().clone();
+        Timeout::new(0, move || link.send_message(Msg::Rehydrate)).forget();
+
         let window: Window = window().expect("window not available");
 
-        let cb = ctx.link().batch_callback(|e: KeyboardEvent| {
-            if e.key().chars().count() == 1 {
+        let key_substitutions = self.key_substitutions.clone();
+        let key_bindings = self.key_bindings.clone();
+        let is_modal_open = self.is_modal_open.clone();
+        let cb = ctx.link().batch_callback(move |e: KeyboardEvent| {
+            // AltGr combinations, like AltGr+P for Ö, are reported as Ctrl+Alt on Windows
+            let is_alt_graph = e.get_modifier_state("AltGraph");
+            let is_ctrl = (e.ctrl_key() || e.meta_key()) && !is_alt_graph;
+
+            if e.default_prevented() {
+                // Already handled by the focused component, like the arrow keys of the on-screen keyboard
+                None
+            } else if shortcuts::activate_focused_control(&e) {
+                None
+            } else if let Some(shortcut) = shortcuts::find(&e.key(), is_ctrl, is_modal_open.get()) {
+                e.prevent_default();
+                Some(shortcut.action.msg())
+            } else if is_modal_open.get() {
+                // Let the focused modal handle the keys
+                None
+            } else if let Some(action) = key_bindings.borrow().find(&e.key()).filter(|_| !is_ctrl) {
+                e.prevent_default();
+                Some(action.msg())
+            } else if e.key().chars().count() == 1 {
                 let key = e.key().to_uppercase().chars().next().unwrap();
-                if ALLOWED_KEYS.contains(&key) && !e.ctrl_key() && !e.alt_key() && !e.meta_key() {
+                let key = key_substitutions.borrow().get(&key).copied().unwrap_or(key);
+                let is_modified = (e.ctrl_key() || e.alt_key()) && !is_alt_graph;
+                // Letters outside the alphabet of the puzzle language are ignored on KeyPress
+                if key.is_alphabetic() && !is_modified && !e.meta_key() {
                     e.prevent_default();
                     Some(Msg::KeyPress(key))
                 } else {
                     None
                 }
-            } else if e.key() == "Backspace" {
-                e.prevent_default();
-                Some(Msg::Backspace)
-            } else if e.key() == "Enter" {
-                e.prevent_default();
-                Some(Msg::Enter)
             } else {
                 None
             }
@@ -106,9 +941,65 @@ impl Component for App {
             .add_event_listener_with_callback("keydown", listener.as_ref().unchecked_ref())
             .unwrap();
         self.keyboard_listener = Some(listener);
+
+        // Returning to the game, like switching back to its tab, checks for a new version
+        let link = ctx.link().clone();
+        let listener = Closure::<dyn Fn()>::wrap(Box::new(move || {
+            let is_visible = web_sys::window()
+                .and_then(|window| window.document())
+                .map(|document| !document.hidden())
+                .unwrap_or(false);
+            if is_visible {
+                link.send_message(Msg::CheckVersion);
+            } else {
+                // Leaving the game, possibly for another device
+                #[cfg(feature = "sync")]
+                sync::sync_in_background();
+            }
+        }));
+
+        window
+            .add_event_listener_with_callback("visibilitychange", listener.as_ref().unchecked_ref())
+            .unwrap();
+        self.visibility_listener = Some(listener);
+
+        let link = ctx.link().clone();
+        let listener =
+            Closure::<dyn Fn()>::wrap(Box::new(move || link.send_message(Msg::HistoryBack)));
+
+        window
+            .add_event_listener_with_callback("popstate", listener.as_ref().unchecked_ref())
+            .unwrap();
+        self.popstate_listener = Some(listener);
+
+        #[cfg(web_sys_unstable_apis)]
+        {
+            let is_modal_open = self.is_modal_open.clone();
+            let cb = ctx.link().batch_callback(move |e: ClipboardEvent| {
+                if is_modal_open.get() {
+                    return None;
+                }
+
+                let text = e.clipboard_data()?.get_data("text").ok()?;
+                e.prevent_default();
+                Some(Msg::Paste(text))
+            });
+
+            let listener =
+                Closure::<dyn Fn(ClipboardEvent)>::wrap(Box::new(move |e: ClipboardEvent| {
+                    cb.emit(e)
+                }));
+
+            window
+                .add_event_listener_with_callback("paste", listener.as_ref().unchecked_ref())
+                .unwrap();
+            self.paste_listener = Some(listener);
+        }
     }
 
     fn destroy(&mut self, _: &Context<Self>) {
+        set_document_title(brand::BRAND.name);
+
         // Remove the keyboard listener
         if let Some(listener) = self.keyboard_listener.take() {
             let window: Window = window().expect("window not available");
@@ -116,20 +1007,129 @@ impl Component for App {
                 .remove_event_listener_with_callback("keydown", listener.as_ref().unchecked_ref())
                 .unwrap();
         }
+
+        if let Some(listener) = self.popstate_listener.take() {
+            let window: Window = window().expect("window not available");
+            window
+                .remove_event_listener_with_callback("popstate", listener.as_ref().unchecked_ref())
+                .unwrap();
+        }
+
+        if let Some(listener) = self.visibility_listener.take() {
+            let window: Window = window().expect("window not available");
+            window
+                .remove_event_listener_with_callback(
+                    "visibilitychange",
+                    listener.as_ref().unchecked_ref(),
+                )
+                .unwrap();
+        }
+
+        #[cfg(web_sys_unstable_apis)]
+        if let Some(listener) = self.paste_listener.take() {
+            let window: Window = window().expect("window not available");
+            window
+                .remove_event_listener_with_callback("paste", listener.as_ref().unchecked_ref())
+                .unwrap();
+        }
     }
 
     fn update(&mut self, ctx: &Context<Self>, msg: Self::Message) -> bool {
+        let previous_modal = self.open_modal();
+
+        // Nothing is played nor stored before the games and the word lists have been loaded,
+        // apart from watching the game of another player
+        if self.phase == Phase::Initializing
+            && !matches!(
+                msg,
+                Msg::Rehydrate
+                    | Msg::DictionaryLoaded(..)
+                    | Msg::SpectateMessage(_)
+                    | Msg::SpectateClosed
+            )
+        {
+            return false;
+        }
+
+        if self.is_abandoning_game(&msg) {
+            self.pending_confirmation = Some(msg);
+            self.active_modal = Some(ActiveModal::Confirm(
+                t("Keskeneräinen peli menetetään — jatketaanko?").to_owned(),
+            ));
+            self.sync_modal_focus(previous_modal);
+            return true;
+        }
+
+        if self.is_repeated_submission(&msg) {
+            return false;
+        }
+        if matches!(msg, Msg::KeyPress(_) | Msg::Backspace | Msg::ClearRow) {
+            self.submitted_at = None;
+        }
+
+        if self.reveal.is_some()
+            && matches!(
+                msg,
+                Msg::KeyPress(_) | Msg::Backspace | Msg::ClearRow | Msg::Enter | Msg::Guess
+            )
+        {
+            if self.buffered_inputs.len() < MAX_BUFFERED_INPUTS {
+                self.buffered_inputs.push_back(msg);
+            }
+            return false;
+        }
+
         match msg {
-            Msg::KeyPress(c) => self.manager.push_character(c),
-            Msg::Backspace => self.manager.pop_character(),
+            Msg::Rehydrate => self.rehydrate(ctx),
+            Msg::KeyPress(c) => {
+                let is_guessing = matches!(&self.manager.game, Some(game) if game.is_guessing());
+
+                if !is_guessing {
+                    // The letters are free for shortcuts once the game has ended
+                    if let Some(shortcut) = shortcuts::find_game_over(c) {
+                        ctx.link().send_message(shortcut.action.msg());
+                    }
+                    return false;
+                } else if self.manager.puzzle_language.alphabet().any(|key| *key == c) {
+                    self.dictated_word = None;
+                    self.manager.push_character(c);
+                    self.announcement = c.to_string();
+                } else {
+                    return false;
+                }
+            }
+            Msg::Backspace => {
+                self.dictated_word = None;
+                self.manager.pop_character();
+                self.announcement = t("Kirjain poistettu").to_owned();
+            }
+            Msg::ClearRow => {
+                self.dictated_word = None;
+                self.manager.clear_row();
+                self.announcement = t("Rivi tyhjennetty").to_owned();
+            }
+            #[cfg(web_sys_unstable_apis)]
+            Msg::Paste(text) => self.manager.paste_word(&text),
+            Msg::MoveCursor(position) => self.manager.move_cursor(position),
+            Msg::MoveCursorLeft => self.manager.move_cursor_left(),
+            Msg::MoveCursorRight => self.manager.move_cursor_right(),
             Msg::Enter => {
                 let link = ctx.link();
+                self.dictated_word = None;
 
                 if let Some(game) = &self.manager.game {
                     if game.is_guessing() {
                         link.send_message(Msg::Guess);
                     } else {
-                        if matches!(game.game_mode(), GameMode::DailyWord(_) | GameMode::Shared) {
+                        if !game.has_next_word()
+                            || matches!(
+                                game.game_mode(),
+                                GameMode::DailyWord(_)
+                                    | GameMode::EveningWord(_)
+                                    | GameMode::Race(_)
+                                    | GameMode::Shared
+                            )
+                        {
                             link.send_message(Msg::ChangePreviousGameMode);
                         } else {
                             link.send_message(Msg::NextWord);
@@ -137,46 +1137,277 @@ impl Component for App {
                     }
                 }
             }
-            Msg::Guess => self.manager.submit_guess(),
+            Msg::Escape => {
+                if self.active_modal.take().is_none() {
+                    return false;
+                }
+            }
+            Msg::Guess => {
+                let previous = self.board_states();
+                self.manager.submit_guess();
+                self.announce_revealed(&previous);
+                self.start_reveal(ctx, &previous);
+                self.mark_submitted(&previous);
+            }
             Msg::NextWord => {
                 self.manager.next_word();
-                self.is_emojis_copied = false;
-                self.is_link_copied = false;
+                self.submitted_at = Some(Utc::now().timestamp_millis());
+            }
+            Msg::StartNewGame => {
+                self.manager.start_new_game();
+                self.submitted_at = Some(Utc::now().timestamp_millis());
+                self.active_modal = None;
+            }
+            Msg::StartGame => match &self.manager.game {
+                Some(game) if !game.is_guessing() => ctx.link().send_message(Msg::Enter),
+                _ => return false,
+            },
+            #[cfg(feature = "embed")]
+            Msg::SetSeed(seed) => self.manager.set_seed(seed),
+            #[cfg(feature = "daily-index")]
+            Msg::DailyIndexLoaded(result) => match result {
+                Ok(true) => self.manager.refresh_daily_word(),
+                Ok(false) => return false,
+                Err(err) => {
+                    log::info!("Fetching the daily index failed: {}", err);
+                    return false;
+                }
+            },
+            #[cfg(feature = "definitions")]
+            Msg::DefinitionLoaded(word, result) => {
+                if !self
+                    .definition
+                    .as_ref()
+                    .is_some_and(|(requested, _)| *requested == word)
+                {
+                    return false;
+                }
+
+                let definition = match result {
+                    Ok(text) => definition::Definition::Loaded(text),
+                    Err(err) => {
+                        log::info!("Fetching the definition failed: {}", err);
+                        definition::Definition::Failed
+                    }
+                };
+                self.definition = Some((word, definition));
+            }
+            Msg::SubmitWord(word) => {
+                self.dictated_word = None;
+                self.manager.paste_word(&word);
+                let previous = self.board_states();
+                self.manager.submit_guess();
+                self.announce_revealed(&previous);
+                self.start_reveal(ctx, &previous);
+                self.mark_submitted(&previous);
+            }
+            Msg::RevealEnded => {
+                self.reveal = None;
+                for msg in mem::take(&mut self.buffered_inputs) {
+                    self.update(ctx, msg);
+                }
+            }
+            Msg::ToggleHelp => self.toggle_modal(ActiveModal::Help),
+            Msg::ToggleMenu => self.toggle_modal(ActiveModal::Menu),
+            Msg::ToggleShortcuts => self.toggle_modal(ActiveModal::Shortcuts),
+            Msg::ToggleChangelog => self.toggle_modal(ActiveModal::Changelog),
+            Msg::ToggleHistory => self.toggle_modal(ActiveModal::History),
+            Msg::ToggleYearReview => {
+                let year = self
+                    .manager
+                    .latest_review_year()
+                    .unwrap_or_else(|| rollover::today().year());
+                self.toggle_modal(ActiveModal::YearReview(year));
+            }
+            Msg::ShowYearReview(year) => self.active_modal = Some(ActiveModal::YearReview(year)),
+            #[cfg_attr(not(web_sys_unstable_apis), allow(unused_variables))]
+            Msg::ShareYearReview(year) => {
+                let text = self.manager.year_summary(year).share_text();
+                #[cfg(web_sys_unstable_apis)]
+                {
+                    use web_sys::Navigator;
+
+                    let window: Window = window().expect("window not available");
+                    let navigator: Navigator = window.navigator();
+                    if let Some(clipboard) = navigator.clipboard() {
+                        let _promise = clipboard.write_text(text.as_str());
+                    }
+                }
+                self.show_toast(ctx, t("Tulos kopioitu!"), ToastKind::Info);
+            }
+            Msg::DownloadYearReview(year) => {
+                if let Some(image) = self.manager.year_review_image(year) {
+                    let filename = format!("{}-{}.png", brand::BRAND.name.to_lowercase(), year);
+                    share_image::download(&image, &filename);
+                    self.show_toast(ctx, t("Kuva ladattu!"), ToastKind::Info);
+                }
+            }
+            Msg::ToggleReverse => self.toggle_modal(ActiveModal::Reverse),
+            Msg::StartTutorial => self.active_modal = Some(ActiveModal::Tutorial),
+            Msg::CompleteTutorial => {
+                tutorial::complete();
+                self.active_modal = None;
             }
-            Msg::ToggleHelp => {
-                self.is_help_visible = !self.is_help_visible;
-                self.is_menu_visible = false;
+            Msg::CloseModal => {
+                self.active_modal = None;
+                self.pending_confirmation = None;
             }
-            Msg::ToggleMenu => {
-                self.is_menu_visible = !self.is_menu_visible;
-                self.is_help_visible = false;
+            Msg::Confirm => {
+                self.active_modal = None;
+                if let Some(msg) = self.pending_confirmation.take() {
+                    self.is_abandon_confirmed = true;
+                    self.update(ctx, msg);
+                    self.is_abandon_confirmed = false;
+                }
             }
             Msg::ChangeWordLength(new_length) => {
                 self.manager.change_word_length(new_length);
-                self.is_menu_visible = false;
-                self.is_help_visible = false;
+                self.active_modal = None;
             }
             Msg::ChangeGameMode(new_mode) => {
                 self.manager.change_game_mode(new_mode);
-                self.is_menu_visible = false;
-                self.is_help_visible = false;
+                self.active_modal = None;
             }
             Msg::ChangeWordList(new_list) => {
                 self.manager.change_word_list(new_list);
-                self.is_menu_visible = false;
-                self.is_help_visible = false;
+                self.active_modal = None;
             }
             Msg::ChangePreviousGameMode => {
                 self.manager.change_previous_game_mode();
-                self.is_emojis_copied = false;
-                self.is_link_copied = false;
+                self.submitted_at = Some(Utc::now().timestamp_millis());
             }
             Msg::ChangeAllowProfanities(is_allowed) => {
                 self.manager.change_allow_profanities(is_allowed);
-                self.is_menu_visible = false;
-                self.is_help_visible = false;
+                self.active_modal = None;
             }
             Msg::ChangeTheme(theme) => self.manager.change_theme(theme),
+            Msg::ChangeShareTheme(share_theme) => self.manager.change_share_theme(share_theme),
+            Msg::ToggleShareOption(option) => self.manager.toggle_share_option(option),
+            Msg::ChangeUiScale(ui_scale) => self.manager.change_ui_scale(ui_scale),
+            Msg::ChangeRollover(rollover) => self.manager.change_rollover(rollover),
+            Msg::ChangeAnimationSpeed(animation_speed) => {
+                self.manager.change_animation_speed(animation_speed)
+            }
+            Msg::ChangeKeyboardHints(hints) => self.manager.change_keyboard_hints(hints),
+            Msg::ChangeKeyboardLayout(layout) => self.manager.change_keyboard_layout(layout),
+            Msg::ChangeScoring(is_enabled) => self.manager.change_scoring(is_enabled),
+            Msg::ChangeShowCandidates(is_shown) => self.manager.change_show_candidates(is_shown),
+            Msg::ChangeShowAlphabet(is_shown) => self.manager.change_show_alphabet(is_shown),
+            Msg::ChangeSwipeGestures(is_enabled) => self.manager.change_swipe_gestures(is_enabled),
+            Msg::ChangeShowLetterFrequencies(is_shown) => {
+                self.manager.change_show_letter_frequencies(is_shown)
+            }
+            Msg::ChangePressureTimeLimit(seconds) => {
+                self.manager.change_pressure_time_limit(seconds)
+            }
+            Msg::ChangeHistoryDensity(density) => self.manager.change_history_density(density),
+            Msg::ChangeLanguage(language) => self.manager.change_language(language),
+            Msg::ChangePuzzleLanguage(language) => {
+                self.manager.change_puzzle_language(language);
+                self.fetch_dictionary(ctx);
+            }
+            Msg::ChangeKeySubstitution(letter, key) => {
+                self.manager.change_key_substitution(letter, key);
+                *self.key_substitutions.borrow_mut() = self.manager.key_substitutions.clone();
+            }
+            Msg::ChangeEveningWord(is_enabled) => self.manager.change_evening_word(is_enabled),
+            Msg::ChangeDailyHardMode(is_enabled) => self.manager.change_daily_hard_mode(is_enabled),
+            Msg::ChangeLeaderboard(is_enabled) => self.manager.change_leaderboard(is_enabled),
+            Msg::ChangeAnalytics(is_enabled) => {
+                self.manager.change_analytics(is_enabled);
+                self.is_analytics_enabled.set(is_enabled);
+            }
+            Msg::ChangeVoiceInput(is_enabled) => {
+                self.manager.change_voice_input(is_enabled);
+                self.dictation = None;
+            }
+            Msg::ChangeDailyReminder(hour) => {
+                self.manager.change_daily_reminder(hour);
+                if hour.is_some() && !notifications::is_permitted() {
+                    ctx.link().send_future(async {
+                        Msg::NotificationPermission(notifications::request_permission().await)
+                    });
+                }
+            }
+            Msg::NotificationPermission(is_granted) => {
+                if !is_granted {
+                    // Nothing to remind with, the setting is turned back off
+                    self.manager.change_daily_reminder(None);
+                }
+            }
+            Msg::ToggleDictation => {
+                if self.dictation.take().is_none() {
+                    let link = ctx.link();
+                    self.dictated_word = None;
+                    self.dictation = speech::Dictation::start(
+                        self.manager.puzzle_language,
+                        link.callback(Msg::DictationResult),
+                        link.callback(|_| Msg::DictationEnded),
+                    );
+                    if self.dictation.is_none() {
+                        self.show_toast(
+                            ctx,
+                            t("Puheentunnistus ei käynnistynyt."),
+                            ToastKind::Error,
+                        );
+                    }
+                }
+            }
+            Msg::DictationResult(alternatives) => {
+                self.dictation = None;
+                self.dictated_word = self.manager.dictate_word(&alternatives);
+                if self.dictated_word.is_none() {
+                    self.show_toast(ctx, t("Sanaa ei tunnistettu."), ToastKind::Error);
+                }
+            }
+            Msg::DictationEnded => self.dictation = None,
+            Msg::ConfirmDictation => {
+                self.dictated_word = None;
+                let previous = self.board_states();
+                self.manager.submit_guess();
+                self.announce_revealed(&previous);
+            }
+            Msg::CancelDictation => {
+                self.dictated_word = None;
+                self.manager.paste_word("");
+            }
+            Msg::ChangeKeyBinding(action, key) => {
+                self.manager.change_key_binding(action, key);
+                *self.key_bindings.borrow_mut() = self.manager.key_bindings.clone();
+            }
+            Msg::CopySyncCode => {
+                #[cfg(web_sys_unstable_apis)]
+                {
+                    let window: Window = window().expect("window not available");
+                    if let Some(clipboard) = window.navigator().clipboard() {
+                        let _promise = clipboard.write_text(&sync_code::export());
+                    }
+                }
+                self.show_toast(ctx, t("Siirtokoodi kopioitu!"), ToastKind::Info);
+            }
+            Msg::ImportSyncCode(code) => {
+                if !self.is_abandon_confirmed {
+                    self.pending_confirmation = Some(Msg::ImportSyncCode(code));
+                    self.active_modal = Some(ActiveModal::Confirm(
+                        t("Siirtokoodi korvaa tämän laitteen pelit, tilastot ja asetukset — jatketaanko?").to_owned(),
+                    ));
+                    self.sync_modal_focus(previous_modal);
+                    return true;
+                }
+
+                match sync_code::import(&code) {
+                    Ok(()) => {
+                        if let Some(window) = window() {
+                            let _result = window.location().reload();
+                        }
+                        return false;
+                    }
+                    Err(err) => {
+                        log::info!("Importing the sync code failed: {:?}", err);
+                        self.show_toast(ctx, t(err.message()), ToastKind::Error);
+                    }
+                }
+            }
             Msg::ShareEmojis => {
                 #[cfg(web_sys_unstable_apis)]
                 {
@@ -190,8 +1421,7 @@ impl Component for App {
                         }
                     }
                 }
-                self.is_emojis_copied = true;
-                self.is_link_copied = false;
+                self.show_toast(ctx, t("Tulos kopioitu!"), ToastKind::Info);
             }
             Msg::ShareLink => {
                 #[cfg(web_sys_unstable_apis)]
@@ -206,62 +1436,474 @@ impl Component for App {
                         }
                     }
                 }
-                self.is_link_copied = true;
-                self.is_emojis_copied = false;
+                self.show_toast(ctx, t("Linkki kopioitu!"), ToastKind::Info);
+            }
+            Msg::ShowQrCode => {
+                self.active_modal = self.manager.share_link().map(ActiveModal::QrCode)
+            }
+            Msg::ShareImage => {
+                if let Some(image) = self.manager.share_image() {
+                    let filename = format!("{}.png", brand::BRAND.name.to_lowercase());
+                    share_image::download(&image, &filename);
+                    self.show_toast(ctx, t("Kuva ladattu!"), ToastKind::Info);
+                }
+            }
+            Msg::ExportBugReport => {
+                let report = bug_report::snapshot(&self.manager);
+                let data_url = format!(
+                    "data:application/json;charset=utf-8,{}",
+                    js_sys::encode_uri_component(&report)
+                );
+                let filename = format!("{}-bug-report.json", brand::BRAND.name.to_lowercase());
+                share_image::download(&data_url, &filename);
+                self.show_toast(ctx, t("Vikailmoituksen tiedot ladattu!"), ToastKind::Info);
+            }
+            Msg::ShareReplay => {
+                #[cfg(web_sys_unstable_apis)]
+                {
+                    use web_sys::Navigator;
+
+                    if let Some(link) = self.manager.share_replay_link() {
+                        let window: Window = window().expect("window not available");
+                        let navigator: Navigator = window.navigator();
+                        if let Some(clipboard) = navigator.clipboard() {
+                            let _promise = clipboard.write_text(link.as_str());
+                        }
+                    }
+                }
+                self.show_toast(ctx, t("Uusinta kopioitu!"), ToastKind::Info);
             }
             Msg::RevealHiddenTiles => self.manager.reveal_hidden_tiles(),
             Msg::ResetGame => self.manager.reset_game(),
+            Msg::UndoGuess => self.manager.undo_guess(),
+            Msg::ShowReplay(date) => {
+                self.active_modal = self
+                    .manager
+                    .daily_word_history
+                    .get(&date)
+                    .map(ReplayRecord::from_daily_word_history)
+                    .map(ActiveModal::Replay);
+            }
+            Msg::ShowReplayRecord(replay) => self.active_modal = Some(ActiveModal::Replay(replay)),
+            Msg::Tick => {
+                // The day may change while the game is open
+                self.manager.spend_streak_freeze(rollover::today());
+                // Retried until the full word list has been loaded, eg. after being offline
+                self.fetch_dictionary(ctx);
+
+                // Reminders are only needed while the player is away from the game
+                let is_hidden = window()
+                    .and_then(|window| window.document())
+                    .map(|document| document.hidden())
+                    .unwrap_or(false);
+
+                if is_hidden
+                    && notifications::is_permitted()
+                    && self.manager.take_daily_reminder(Local::now().naive_local())
+                {
+                    notifications::show(brand::BRAND.name, t("Päivän sanuli odottaa!"));
+                }
+            }
+            Msg::CheckVersion => {
+                if !self.is_update_available {
+                    ctx.link().send_future(async {
+                        Msg::VersionChecked(version::fetch_deployed().await)
+                    });
+                }
+                return false;
+            }
+            Msg::VersionChecked(deployed) => match deployed {
+                Some(deployed) if deployed != version::current() => self.is_update_available = true,
+                _ => return false,
+            },
+            Msg::Update => {
+                // The letters of an unfinished guess would otherwise be lost on reload
+                self.manager.persist_game();
+                if let Some(window) = window() {
+                    let _result = window.location().reload();
+                }
+                return false;
+            }
+            Msg::DismissUpdate => self.is_update_available = false,
+            Msg::SwitchProfile(profile) => {
+                self.manager.persist_game();
+                // Everything is rehydrated from the keys of the profile
+                let _result = storage::switch_profile(profile.as_deref());
+                if let Some(window) = window() {
+                    let _result = window.location().reload();
+                }
+                return false;
+            }
+            Msg::AddProfile(name) => {
+                if storage::add_profile(&name) {
+                    ctx.link()
+                        .send_message(Msg::SwitchProfile(Some(name.trim().to_owned())));
+                } else {
+                    self.show_toast(ctx, t("Profiilin nimi on jo käytössä."), ToastKind::Error);
+                }
+            }
+            Msg::DictionaryLoaded(language, data) => {
+                self.is_dictionary_loading = false;
+                // Failing the first fetch falls back to the common words
+                self.phase = Phase::Ready;
+                match data {
+                    // The puzzle language may have changed during the fetch
+                    Some(data) if language == self.manager.puzzle_language => {
+                        self.manager.word_lists.set_dictionary(&data)
+                    }
+                    Some(_) => self.fetch_dictionary(ctx),
+                    None => return false,
+                }
+            }
+            Msg::ToggleRace => self.toggle_modal(ActiveModal::Race),
+            Msg::ToggleBotRace => self.toggle_modal(ActiveModal::BotRace),
+            Msg::StartBotRace(strength) => {
+                // Both races play the seeded word of the race mode
+                let seed = rand::random();
+                let word = match self.manager.word_lists.seeded_word(seed) {
+                    Some(word) => word,
+                    None => return false,
+                };
+                self.race = None;
+                self.manager.change_game_mode(GameMode::Race(seed));
+
+                let words = self
+                    .manager
+                    .word_lists
+                    .answer_words(WordList::Common, word.len());
+                self.bot = Some(Bot::start(
+                    strength,
+                    word,
+                    words,
+                    self.manager
+                        .game
+                        .as_ref()
+                        .map_or(game::DEFAULT_MAX_GUESSES, |game| game.max_guesses()),
+                    ctx.link().callback(|_| Msg::BotTick),
+                ));
+                self.active_modal = None;
+            }
+            Msg::BotTick => {
+                let bot = match &mut self.bot {
+                    Some(bot) => bot,
+                    None => return false,
+                };
+                if bot.step(&self.manager.word_lists.rng) {
+                    bot.status = RaceStatus::Lost;
+                    self.manager.record_bot_race(false);
+                    self.show_toast(ctx, t("Botti ratkaisi sanulin ensin!"), ToastKind::Info);
+                }
+            }
+            Msg::ToggleTournament => self.toggle_modal(ActiveModal::Tournament),
+            Msg::StartTournament => {
+                self.manager
+                    .change_game_mode(GameMode::Tournament(tournament::random_id()));
+                self.active_modal = None;
+            }
+            Msg::JoinTournament(code) => match tournament::parse(&code) {
+                Some(id) => {
+                    self.manager.change_game_mode(GameMode::Tournament(id));
+                    self.active_modal = None;
+                }
+                None => self.show_toast(ctx, t("Virheellinen turnauksen koodi."), ToastKind::Error),
+            },
+            Msg::JoinRace(room) => {
+                self.bot = None;
+                let link = ctx.link();
+                self.race = Race::join(
+                    &room,
+                    link.callback(Msg::RaceMessage),
+                    link.callback(|_| Msg::RaceClosed),
+                );
+                if self.race.is_none() {
+                    self.show_toast(ctx, t("Kilpaan liittyminen epäonnistui."), ToastKind::Error);
+                }
+            }
+            Msg::RaceMessage(message) => self.on_race_message(ctx, message),
+            Msg::RaceClosed => {
+                if self.race.take().is_some() {
+                    self.show_toast(ctx, t("Yhteys kilpaan katkesi."), ToastKind::Error);
+                }
+            }
+            Msg::LeaveRace => {
+                self.race = None;
+                self.active_modal = None;
+            }
+            Msg::StartBroadcast => {
+                if self.broadcast.is_none() {
+                    self.broadcast =
+                        Broadcast::start(ctx.link().callback(|_| Msg::BroadcastClosed));
+                }
+
+                match self
+                    .broadcast
+                    .as_ref()
+                    .and_then(|broadcast| broadcast.link())
+                {
+                    Some(link) => self.active_modal = Some(ActiveModal::Spectate(link)),
+                    None => {
+                        self.broadcast = None;
+                        self.show_toast(
+                            ctx,
+                            t("Katsojalinkin luominen epäonnistui."),
+                            ToastKind::Error,
+                        );
+                    }
+                }
+            }
+            Msg::StopBroadcast => {
+                self.broadcast = None;
+                self.active_modal = None;
+            }
+            Msg::BroadcastClosed => {
+                if self.broadcast.take().is_some() {
+                    self.show_toast(ctx, t("Yhteys katsojiin katkesi."), ToastKind::Error);
+                }
+            }
+            Msg::SpectateMessage(SpectateMessage::Board { board }) => match &mut self.spectating {
+                Some(spectating) => spectating.board = Some(board),
+                None => return false,
+            },
+            Msg::SpectateMessage(_) => return false,
+            Msg::SpectateClosed => {
+                if self.spectating.take().is_some() {
+                    self.show_toast(ctx, t("Yhteys pelaajaan katkesi."), ToastKind::Error);
+                }
+            }
+            Msg::StopSpectating => self.spectating = None,
+            Msg::DismissToast(id) => {
+                let count = self.toasts.len();
+                self.toasts.retain(|toast| toast.id != id);
+                if self.toasts.len() == count {
+                    return false;
+                }
+            }
+            Msg::HistoryBack => {
+                // Also fired by the history entry removed in pop_modal_history
+                if !self.is_modal_in_history {
+                    return false;
+                }
+
+                self.is_modal_in_history = false;
+                self.active_modal = None;
+            }
+            Msg::TimerTick => {
+                let previous = self.board_states();
+                self.manager.check_deadline();
+                if self.board_states() != previous {
+                    self.announce_revealed(&previous);
+                }
+            }
         };
 
+        self.dispatch_events(ctx);
+        self.report_storage_failure(ctx);
+        self.sync_timer(ctx);
+        self.sync_race();
+        self.sync_broadcast();
+        #[cfg(feature = "embed")]
+        self.sync_embed();
+        self.sync_modal_focus(previous_modal);
+        self.sync_document_title();
+
         true
     }
 
     fn view(&self, ctx: &Context<Self>) -> Html {
         let link = ctx.link();
+        if let Some(spectating) = &self.spectating {
+            return spectator_view(ctx, spectating);
+        }
+
+        if self.phase == Phase::Initializing {
+            return skeleton_view(self.manager.current_word_length);
+        }
+
+        let statistics = self.manager.statistics();
+        let recent_daily_words = self
+            .manager
+            .daily_word_history
+            .values()
+            .rev()
+            .take(RECENT_DAILY_WORDS)
+            .cloned()
+            .collect::<Vec<_>>();
         if let Some(game) = &self.manager.game {
-            let keyboard_state = ALLOWED_KEYS
-                .iter()
-                .map(|key| (*key, game.keyboard_tilestate(key)))
+            let keyboard_state = self
+                .manager
+                .puzzle_language
+                .alphabet()
+                .map(|key| {
+                    (
+                        *key,
+                        game.keyboard_tilestate(key, self.manager.keyboard_hints),
+                    )
+                })
                 .collect::<HashMap<char, KeyState>>();
+            let keyboard_counts = self
+                .manager
+                .puzzle_language
+                .alphabet()
+                .filter_map(|key| {
+                    Some((*key, game.keyboard_count(key, self.manager.keyboard_hints)?))
+                })
+                .collect::<HashMap<char, KeyCount>>();
 
             let last_guess = game.last_guess();
+            let mut message = game.message();
+            if let Some(secs) = self.manager.daily_duration() {
+                message += &format!(" ⏱️ {}", game::format_duration(secs));
+            }
 
             let boards = game.boards();
 
+            // The boards of several words have no single state for a letter
+            let mut alphabet = keyboard_state
+                .iter()
+                .filter_map(|(letter, state)| match state {
+                    KeyState::Single(state) => Some((*letter, *state)),
+                    _ => None,
+                })
+                .collect::<Vec<_>>();
+            alphabet.sort_by_key(|(letter, _)| game::letter_index(*letter));
+
             html! {
-                <div class={classes!("game", self.manager.theme.to_string())}>
+                // Keyed by the language so that every component is rendered again when it changes
+                <div class="game" key={self.manager.language.code()}>
                     <Header
                         on_toggle_help_cb={link.callback(|_| Msg::ToggleHelp)}
                         on_toggle_menu_cb={link.callback(|_| Msg::ToggleMenu)}
                         title={game.title()}
+                        streak_flame={self.manager.streak_flame(rollover::now())}
+                        points={self.manager.current_mode_points().map(|points| points.total)}
                     />
 
+                    <div class="sr-only" aria-live="polite" aria-atomic="true">{&self.announcement}</div>
+
+                    <Toasts toasts={self.toasts.iter().cloned().collect::<Vec<_>>()} callback={link.callback(move |msg| msg)} />
+
+                    {
+                        if self.is_update_available {
+                            html! {
+                                <div class="toast" role="status">
+                                    <span>{t("Sanulista on uusi versio.")}</span>
+                                    <button class="toast-button" onmousedown={link.callback(|e: MouseEvent| {
+                                        e.prevent_default();
+                                        Msg::Update
+                                    })}>
+                                        {t("Päivitä")}
+                                    </button>
+                                    <span class="toast-close" role="button" tabindex="0" aria-label={t("Sulje ikkuna")}
+                                        onmousedown={link.callback(|e: MouseEvent| {
+                                            e.prevent_default();
+                                            Msg::DismissUpdate
+                                        })}>
+                                        {"✖"}
+                                    </span>
+                                </div>
+                            }
+                        } else {
+                            html! {}
+                        }
+                    }
+
+                    {
+                        if !self.manager.word_lists.is_dictionary_loaded() {
+                            html! {
+                                <div class="dictionary-status" role="status">{t("Ladataan sanakirjaa…")}</div>
+                            }
+                        } else {
+                            html! {}
+                        }
+                    }
+
+                    {
+                        if let Some(deadline) = game.deadline() {
+                            let remaining = (deadline - Utc::now().timestamp_millis()).max(0);
+                            let seconds = (remaining + 999) / 1000;
+
+                            html! {
+                                <div class={classes!("timer", (seconds <= 5).then(|| "timer-urgent"))}>
+                                    {format!("⏱️ {} s", seconds)}
+                                </div>
+                            }
+                        } else {
+                            html! {}
+                        }
+                    }
+
+                    {announcement_banner()}
+
+                    {
+                        if let Some(candidates) = self.manager.remaining_candidates() {
+                            html! {
+                                <div class="candidates">{tf("Mahdollisia sanuleja: {}", &[&candidates])}</div>
+                            }
+                        } else {
+                            html! {}
+                        }
+                    }
+
+                    {
+                        if let Some((total, frequencies)) = self.manager.letter_frequencies() {
+                            let letters = frequencies
+                                .iter()
+                                .take(LETTER_FREQUENCIES_SHOWN)
+                                .map(|(letter, count)| format!("{} {} %", letter, count * 100 / total))
+                                .collect::<Vec<_>>();
+
+                            html! {
+                                <div class="letter-frequencies">
+                                    {tf("Sanoja jäljellä {}", &[&total])}
+                                    {if letters.is_empty() { String::new() } else { format!(": {}", letters.join(" · ")) }}
+                                </div>
+                            }
+                        } else {
+                            html! {}
+                        }
+                    }
+
+                    {self.definition_view(game.as_ref())}
+
                     {
                         match boards.len() {
                             1 => html! {
-                                <div class="board-container">
+                                <SwipeArea class={classes!("board-container")} is_enabled={self.manager.swipe_gestures}
+                                    is_guessing={game.is_guessing()} callback={link.callback(move |msg| msg)}>
                                     <Board
                                         guesses={boards[0].guesses.clone()}
                                         is_guessing={boards[0].is_guessing}
                                         current_guess={boards[0].current_guess}
+                                        cursor={boards[0].cursor}
+                                        onselect={link.callback(Msg::MoveCursor)}
                                         is_reset={game.is_reset()}
+                                        animation_speed={self.manager.animation_speed}
                                         is_hidden={game.is_hidden()}
+                                        is_flipping={*game.game_mode() == GameMode::Blind && !game.is_guessing()}
+                                        revealing_row={self.revealing_row(0)}
+                                        is_linked={!game.is_guessing()}
                                         previous_guesses={game.previous_guesses().clone()}
                                         max_guesses={game.max_guesses()}
                                         word_length={game.word_length()}
                                     />
-                                </div>
+                                </SwipeArea>
                             },
-                            4 => html! {
-                                <div class="quadruple-container">
-                                    <div class="quadruple-grid">
-                                        {game.boards().iter().map(|board| {
+                            2 | 4 => html! {
+                                <SwipeArea class={classes!("quadruple-container")} is_enabled={self.manager.swipe_gestures}
+                                    is_guessing={game.is_guessing()} callback={link.callback(move |msg| msg)}>
+                                    <div class={classes!("quadruple-grid", (boards.len() == 2).then(|| "double-grid"))}>
+                                        {game.boards().iter().enumerate().map(|(index, board)| {
                                             html! {
                                                 <Board
+                                                    revealing_row={self.revealing_row(index)}
+                                                    is_linked={!game.is_guessing()}
                                                     guesses={board.guesses.clone()}
                                                     is_guessing={board.is_guessing}
                                                     current_guess={board.current_guess}
+                                                    cursor={board.cursor}
+                                                    onselect={link.callback(Msg::MoveCursor)}
                                                     is_reset={game.is_reset()}
+                                                    animation_speed={self.manager.animation_speed}
                                                     is_hidden={game.is_hidden()}
                                                     previous_guesses={game.previous_guesses().clone()}
                                                     max_guesses={game.max_guesses()}
@@ -270,38 +1912,139 @@ impl Component for App {
                                             }
                                         }).collect::<Html>()}
                                     </div>
-                                </div>
+                                </SwipeArea>
                             },
                             _ => html! {}
                         }
                     }
 
+                    {
+                        match &self.race {
+                            Some(race) if race.status != RaceStatus::Waiting => html! {
+                                <OpponentBoard
+                                    patterns={race.opponent.clone()}
+                                    status={race.status}
+                                    max_guesses={game.max_guesses()}
+                                    word_length={game.word_length()}
+                                />
+                            },
+                            _ => html! {},
+                        }
+                    }
+
+                    {
+                        match &self.bot {
+                            Some(bot) => html! {
+                                <OpponentBoard
+                                    patterns={bot.patterns.clone()}
+                                    status={bot.status}
+                                    max_guesses={game.max_guesses()}
+                                    word_length={game.word_length()}
+                                    is_bot={true}
+                                />
+                            },
+                            None => html! {},
+                        }
+                    }
+
+                    {leaderboard_panel(&self.manager)}
+
+                    {
+                        if let Some(word) = &self.dictated_word {
+                            html! {
+                                <div class="dictation">
+                                    <span>{tf("Arvaa sanaa {}?", &[word])}</span>
+                                    <button class="select" onmousedown={link.callback(|e: MouseEvent| {
+                                        e.prevent_default();
+                                        Msg::ConfirmDictation
+                                    })}>
+                                        {t("Kyllä")}
+                                    </button>
+                                    <button class="select" onmousedown={link.callback(|e: MouseEvent| {
+                                        e.prevent_default();
+                                        Msg::CancelDictation
+                                    })}>
+                                        {t("Ei")}
+                                    </button>
+                                </div>
+                            }
+                        } else {
+                            html! {}
+                        }
+                    }
+
+                    {
+                        if self.manager.show_alphabet && !alphabet.is_empty() {
+                            html! { <Alphabet letters={alphabet} /> }
+                        } else {
+                            html! {}
+                        }
+                    }
+
                     <Keyboard
                         callback={link.callback(move |msg| msg)}
                         is_unknown={game.is_unknown()}
                         is_winner={game.is_winner()}
                         is_guessing={game.is_guessing()}
                         is_hidden={game.is_hidden()}
-                        is_emojis_copied={self.is_emojis_copied}
-                        is_link_copied={self.is_link_copied}
+                        is_undo_allowed={game.is_undo_allowed()}
+                        has_next_word={game.has_next_word()}
+                        is_replay_available={self.manager.last_replay().is_some()}
                         game_mode={game.game_mode().clone()}
-                        message={game.message()}
+                        message={message}
                         word={game.word().iter().collect::<String>()}
                         last_guess={last_guess}
                         keyboard={keyboard_state}
+                        counts={keyboard_counts}
+                        puzzle_language={self.manager.puzzle_language}
+                        keyboard_layout={self.manager.keyboard_layout}
+                        is_voice_input={self.manager.voice_input && speech::is_supported()}
+                        is_listening={self.dictation.is_some()}
                     />
 
                     {
-                        if self.is_help_visible {
-                            html! { <HelpModal theme={self.manager.theme} callback={link.callback(move |msg| msg)} /> }
-                        } else {
-                            html! {}
-                        }
-                    }
-
-                    {
-                        if self.is_menu_visible {
-                            html! {
+                        match &self.active_modal {
+                            Some(ActiveModal::Help) => html! { <HelpModal theme={self.manager.theme} word_lists={SharedWordLists(self.manager.word_lists.clone())} callback={link.callback(move |msg| msg)} /> },
+                            Some(ActiveModal::Tutorial) => html! {
+                                <TutorialModal
+                                    language={self.manager.puzzle_language}
+                                    theme={self.manager.theme}
+                                    callback={link.callback(move |msg| msg)}
+                                />
+                            },
+                            Some(ActiveModal::Shortcuts) => html! {
+                                <ShortcutsModal
+                                    callback={link.callback(move |msg| msg)}
+                                    key_substitutions={self.manager.key_substitutions.clone()}
+                                    key_bindings={self.manager.key_bindings.clone()}
+                                />
+                            },
+                            Some(ActiveModal::Changelog) => html! { <ChangelogModal callback={link.callback(move |msg| msg)} /> },
+                            Some(ActiveModal::History) => html! { <HistoryModal replays={self.manager.replays.clone()} callback={link.callback(move |msg| msg)} /> },
+                            Some(ActiveModal::YearReview(year)) => html! { <YearReviewModal summary={self.manager.year_summary(*year)} years={self.manager.review_years()} callback={link.callback(move |msg| msg)} /> },
+                            Some(ActiveModal::Reverse) => html! { <ReverseModal word_lists={SharedWordLists(self.manager.word_lists.clone())} callback={link.callback(move |msg| msg)} /> },
+                            Some(ActiveModal::Replay(replay)) => html! { <ReplayModal replay={replay.clone()} callback={link.callback(move |msg| msg)} /> },
+                            Some(ActiveModal::BotRace) => html! { <BotRaceModal callback={link.callback(move |msg| msg)} /> },
+                            Some(ActiveModal::Tournament) => html! {
+                                <TournamentModal
+                                    callback={link.callback(move |msg| msg)}
+                                    current_code={match self.manager.current_game_mode {
+                                        GameMode::Tournament(id) => Some(tournament::code(id)),
+                                        _ => None,
+                                    }}
+                                />
+                            },
+                            Some(ActiveModal::Race) => html! {
+                                <RaceModal
+                                    callback={link.callback(move |msg| msg)}
+                                    waiting_room={self.race.as_ref().filter(|race| race.status == RaceStatus::Waiting).map(|race| race.room.clone())}
+                                />
+                            },
+                            Some(ActiveModal::Resume(unfinished_game)) => html! { <ResumeModal unfinished_game={unfinished_game.clone()} callback={link.callback(move |msg| msg)} /> },
+                            Some(ActiveModal::Confirm(question)) => html! { <ConfirmModal question={question.clone()} callback={link.callback(move |msg| msg)} /> },
+                            Some(ActiveModal::Spectate(spectate_link)) => html! { <SpectateModal link={spectate_link.clone()} callback={link.callback(move |msg| msg)} /> },
+                            Some(ActiveModal::QrCode(qr_code_link)) => html! { <QrCodeModal link={qr_code_link.clone()} callback={link.callback(move |msg| msg)} /> },
+                            Some(ActiveModal::Menu) => html! {
                                 <MenuModal
                                     callback={link.callback(move |msg| msg)}
                                     game_mode={self.manager.current_game_mode}
@@ -309,13 +2052,47 @@ impl Component for App {
                                     current_word_list={self.manager.current_word_list}
                                     allow_profanities={self.manager.allow_profanities}
                                     theme={self.manager.theme}
-                                    max_streak={self.manager.max_streak}
-                                    total_played={self.manager.total_played}
-                                    total_solved={self.manager.total_solved}
+                                    share_theme={self.manager.share_theme}
+                                    share_options={self.manager.share_options}
+                                    ui_scale={self.manager.ui_scale}
+                                    animation_speed={self.manager.animation_speed}
+                                    keyboard_hints={self.manager.keyboard_hints}
+                                    keyboard_layout={self.manager.keyboard_layout}
+                                    scoring={self.manager.scoring}
+                                    mode_points={self.manager.current_mode_points()}
+                                    show_candidates={self.manager.show_candidates}
+                                    show_alphabet={self.manager.show_alphabet}
+                                    swipe_gestures={self.manager.swipe_gestures}
+                                    show_letter_frequencies={self.manager.show_letter_frequencies}
+                                    pressure_time_limit={self.manager.pressure_time_limit}
+                                    zen_statistics={self.manager.zen_statistics}
+                                    pressure_statistics={self.manager.pressure_statistics}
+                                    relay_statistics={self.manager.relay_statistics}
+                                    marathon_best={self.manager.marathon_best}
+                                    bot_statistics={self.manager.bot_statistics}
+                                    average_daily_duration={self.manager.average_daily_duration()}
+                                    openers={self.manager.opener_statistics()}
+                                    letter_accuracy={self.manager.letter_accuracy()}
+                                    recent_daily_words={recent_daily_words.clone()}
+                                    history_density={self.manager.history_density}
+                                    language={self.manager.language}
+                                    puzzle_language={self.manager.puzzle_language}
+                                    key_substitutions={self.manager.key_substitutions.clone()}
+                                    key_bindings={self.manager.key_bindings.clone()}
+                                    voice_input={self.manager.voice_input}
+                                    evening_word={self.manager.evening_word}
+                                    daily_hard_mode={self.manager.daily_hard_mode}
+                                    rollover={self.manager.rollover}
+                                    leaderboard={self.manager.leaderboard}
+                                    analytics={self.manager.analytics}
+                                    daily_reminder={self.manager.daily_reminder}
+                                    max_streak={statistics.max_streak}
+                                    streak_freezes={self.manager.streak_freezes}
+                                    total_played={statistics.total_played}
+                                    total_solved={statistics.total_solved}
                                 />
-                            }
-                        } else {
-                            html! {}
+                            },
+                            None => html! {},
                         }
                     }
                 </div>
@@ -329,16 +2106,195 @@ impl Component for App {
                     current_word_list={self.manager.current_word_list}
                     allow_profanities={self.manager.allow_profanities}
                     theme={self.manager.theme}
-                    max_streak={self.manager.max_streak}
-                    total_played={self.manager.total_played}
-                    total_solved={self.manager.total_solved}
+                    share_theme={self.manager.share_theme}
+                    share_options={self.manager.share_options}
+                    ui_scale={self.manager.ui_scale}
+                    animation_speed={self.manager.animation_speed}
+                    keyboard_hints={self.manager.keyboard_hints}
+                    keyboard_layout={self.manager.keyboard_layout}
+                    scoring={self.manager.scoring}
+                    mode_points={self.manager.current_mode_points()}
+                    show_candidates={self.manager.show_candidates}
+                    show_alphabet={self.manager.show_alphabet}
+                    swipe_gestures={self.manager.swipe_gestures}
+                    show_letter_frequencies={self.manager.show_letter_frequencies}
+                    pressure_time_limit={self.manager.pressure_time_limit}
+                    zen_statistics={self.manager.zen_statistics}
+                    pressure_statistics={self.manager.pressure_statistics}
+                    relay_statistics={self.manager.relay_statistics}
+                    marathon_best={self.manager.marathon_best}
+                    bot_statistics={self.manager.bot_statistics}
+                    average_daily_duration={self.manager.average_daily_duration()}
+                    openers={self.manager.opener_statistics()}
+                    letter_accuracy={self.manager.letter_accuracy()}
+                    recent_daily_words={recent_daily_words.clone()}
+                    history_density={self.manager.history_density}
+                    language={self.manager.language}
+                    puzzle_language={self.manager.puzzle_language}
+                    key_substitutions={self.manager.key_substitutions.clone()}
+                    key_bindings={self.manager.key_bindings.clone()}
+                    voice_input={self.manager.voice_input}
+                    evening_word={self.manager.evening_word}
+                    daily_hard_mode={self.manager.daily_hard_mode}
+                    rollover={self.manager.rollover}
+                    leaderboard={self.manager.leaderboard}
+                    analytics={self.manager.analytics}
+                    daily_reminder={self.manager.daily_reminder}
+                    max_streak={statistics.max_streak}
+                    streak_freezes={self.manager.streak_freezes}
+                    total_played={statistics.total_played}
+                    total_solved={statistics.total_solved}
                 />
             }
         }
     }
 }
 
+/// Board of the watched player, in place of the own game
+fn spectator_view(ctx: &Context<App>, spectating: &Spectating) -> Html {
+    let stop = ctx.link().callback(|e: MouseEvent| {
+        e.prevent_default();
+        Msg::StopSpectating
+    });
+
+    let board = match &spectating.board {
+        Some(board) => board,
+        None => {
+            return html! {
+                <div class="game spectator">
+                    <p class="spectator-status">{t("Odotetaan pelaajaa…")}</p>
+                    <button class="select" onmousedown={stop}>{t("Lopeta katselu")}</button>
+                </div>
+            }
+        }
+    };
+
+    html! {
+        <div class="game spectator">
+            <header>
+                <h1 class="title">{&board.title}</h1>
+            </header>
+            <div class="board-container">
+                <Board
+                    guesses={board.guesses.clone()}
+                    is_guessing={false}
+                    current_guess={board.guesses.len()}
+                    cursor={0}
+                    onselect={Callback::noop()}
+                    is_reset={false}
+                    is_hidden={false}
+                    previous_guesses={Vec::new()}
+                    max_guesses={board.max_guesses}
+                    word_length={board.word_length}
+                />
+            </div>
+            <p class="spectator-status">
+                {if board.is_guessing { t("Peli on kesken, kirjaimet paljastuvat pelin päätyttyä.") } else { t("Peli on päättynyt.") }}
+            </p>
+            <button class="select" onmousedown={stop}>{t("Lopeta katselu")}</button>
+        </div>
+    }
+}
+
+/// Empty board in the colors of the theme while the games and the word lists are loaded
+fn skeleton_view(word_length: usize) -> Html {
+    html! {
+        <div class="game">
+            <div class="board-container skeleton" aria-busy="true">
+                <Board
+                    guesses={vec![Vec::new(); game::DEFAULT_MAX_GUESSES]}
+                    is_guessing={false}
+                    current_guess={0}
+                    cursor={0}
+                    onselect={Callback::noop()}
+                    is_reset={false}
+                    is_hidden={false}
+                    previous_guesses={Vec::new()}
+                    max_guesses={game::DEFAULT_MAX_GUESSES}
+                    word_length={word_length}
+                />
+            </div>
+            <div class="dictionary-status" role="status">{t("Ladataan…")}</div>
+        </div>
+    }
+}
+
+fn set_document_title(title: &str) {
+    if let Some(document) = window().and_then(|window| window.document()) {
+        if document.title() != title {
+            document.set_title(title);
+        }
+    }
+}
+
+#[cfg(feature = "leaderboard")]
+fn leaderboard_panel(manager: &Manager) -> Html {
+    use components::leaderboard::{LeaderboardPanel, LeaderboardResult};
+
+    let (game, client_id) = match (&manager.game, &manager.leaderboard_client_id) {
+        (Some(game), Some(client_id)) if manager.leaderboard && api::is_configured() => {
+            (game, client_id)
+        }
+        _ => return html! {},
+    };
+
+    // The results are collected for today's word only
+    let date = match game.game_mode() {
+        GameMode::DailyWord(date) if *date == rollover::today() && !game.is_guessing() => *date,
+        _ => return html! {},
+    };
+
+    let guesses = game.boards()[0].current_guess + 1;
+    let result = LeaderboardResult {
+        client_id: client_id.clone(),
+        guesses: game.is_winner().then(|| guesses),
+        duration_secs: game
+            .started_at()
+            .map(|started_at| (Utc::now().timestamp_millis() - started_at) / 1000),
+    };
+
+    html! { <LeaderboardPanel date={date} max_guesses={game.max_guesses()} result={result} /> }
+}
+
+#[cfg(not(feature = "leaderboard"))]
+fn leaderboard_panel(_manager: &Manager) -> Html {
+    html! {}
+}
+
+#[cfg(feature = "announcements")]
+fn announcement_banner() -> Html {
+    if api::is_configured() {
+        html! { <components::announcement::AnnouncementBanner /> }
+    } else {
+        html! {}
+    }
+}
+
+#[cfg(not(feature = "announcements"))]
+fn announcement_banner() -> Html {
+    html! {}
+}
+
+fn register_service_worker() {
+    let navigator = match window() {
+        Some(window) => window.navigator(),
+        None => return,
+    };
+
+    // Service workers are only available on HTTPS and localhost
+    if !js_sys::Reflect::has(&navigator, &"serviceWorker".into()).unwrap_or(false) {
+        return;
+    }
+
+    let _promise = navigator.service_worker().register("/sw.js");
+}
+
 fn main() {
     wasm_logger::init(wasm_logger::Config::default());
+    recovery::install_panic_hook();
+    // Development builds served by `trunk serve` are left uncached
+    if !cfg!(debug_assertions) {
+        register_service_worker();
+    }
     yew::start_app::<App>();
 }
@@ -1,3 +1,6 @@
+use std::cell::RefCell;
+use std::collections::BTreeMap;
+use std::collections::BTreeSet;
 use std::collections::HashMap;
 use std::collections::HashSet;
 use std::fmt;
@@ -5,83 +8,357 @@ use std::mem;
 use std::rc::Rc;
 use std::str::FromStr;
 
-use chrono::{Local, NaiveDate};
-use gloo_storage::{errors::StorageError, LocalStorage, Storage};
+use chrono::{Datelike, Duration, NaiveDate, NaiveDateTime, Timelike, Utc};
+use gloo_storage::errors::StorageError;
 use serde::{Deserialize, Serialize};
 use wasm_bindgen::JsValue;
 use web_sys::{window, Window};
 
-use crate::game::Game;
-use crate::neluli::Neluli;
-use crate::sanuli::Sanuli;
+use crate::brand::BRAND;
+use crate::cipher;
+use crate::engine::{self, Feedback, Points, ScoredGame};
+use crate::events::GameEvent;
+use crate::game;
+use crate::game::{Board, Game};
+use crate::i18n::{self, Language};
+use crate::keyboard_layout::KeyboardLayout;
+use crate::neluli::{self, Neluli};
+use crate::retention;
+use crate::rng::GameRng;
+use crate::rollover::{self, Rollover};
+use crate::sanuli::{Sanuli, WEEKLY_CHALLENGE_WORDS};
+#[cfg(web_sys_unstable_apis)]
+use crate::share::{self, ShareContext};
+use crate::share::{ShareOption, ShareOptions};
+use crate::share_image;
+use crate::shortcuts::{Action, KeyBindings};
+use crate::storage::{self, Transaction};
+use crate::tournament::TOURNAMENT_WORDS;
+use crate::year_review::{self, YearSummary};
+
+// Sorted words of a list by their length, the words of a length front coded, see `decode_words`
+type Partitions = &'static [(usize, &'static str)];
+
+// Validated and generated from the word list files by build.rs
+mod finnish_words {
+    use super::Partitions;
+    include!(concat!(env!("OUT_DIR"), "/finnish-words.rs"));
+}
 
-const EASY_WORDS: &str = include_str!("../easy-words.txt");
-const COMMON_WORDS: &str = include_str!("../common-words.txt");
-const FULL_WORDS: &str = include_str!("../full-words.txt");
-const PROFANITIES: &str = include_str!("../profanities.txt");
+#[cfg(feature = "swedish")]
+mod swedish_words {
+    use super::Partitions;
+    include!(concat!(env!("OUT_DIR"), "/swedish-words.rs"));
+}
+
+#[cfg(feature = "english")]
+mod english_words {
+    use super::Partitions;
+    include!(concat!(env!("OUT_DIR"), "/english-words.rs"));
+}
 
 pub const DEFAULT_WORD_LENGTH: usize = 5;
 pub const DEFAULT_MAX_GUESSES: usize = 6;
 pub const DEFAULT_ALLOW_PROFANITIES: bool = false;
 pub const DAILY_WORD_LEN: usize = 5;
+pub const DOUBLE_DAILY_BOARDS: usize = 2;
+const DOUBLE_DAILY_SEEDS: u64 = 1 << 32;
+// Between the seeds of the double dailies and the fallback daily words
+const EVENING_WORD_SEEDS: u64 = 1 << 34;
+// Between the seeds of the double dailies and the tournaments, used past the end of the daily list
+const FALLBACK_DAILY_SEEDS: u64 = 1 << 36;
+// Far above the seeds of the double dailies
+const TOURNAMENT_SEEDS: u64 = 1 << 40;
+
+/// A word of the lists, shared by every list that has it and by the games it's picked for
+pub type Word = Rc<[char]>;
+
+/// Word lists of a single puzzle language
+#[derive(Default, PartialEq)]
+pub struct WordLists {
+    pub language: Language,
+    // Sets of the embedded lists
+    words: HashMap<(WordList, usize), HashSet<Word>>,
+    // The full list by the word length, fetched after the first paint, see `dictionary::fetch`.
+    // Every submitted guess is looked up in it.
+    dictionary: RefCell<Option<HashMap<usize, HashSet<Word>>>>,
+    // Encrypted by build.rs, see `decrypt_daily`
+    daily: &'static [&'static [u8]],
+    pub rng: GameRng,
+}
+
+impl WordLists {
+    pub fn get(&self, key: &(WordList, usize)) -> Option<&HashSet<Word>> {
+        self.words.get(key)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.words.is_empty()
+    }
+
+    pub fn is_dictionary_loaded(&self) -> bool {
+        self.dictionary.borrow().is_some()
+    }
+
+    /// Takes the fetched full list, one word per line
+    pub fn set_dictionary(&self, data: &str) {
+        // The words of the embedded lists are shared with the full list
+        let mut interned = self
+            .words
+            .values()
+            .flatten()
+            .cloned()
+            .collect::<HashSet<_>>();
+
+        let mut dictionary: HashMap<usize, HashSet<Word>> = HashMap::new();
+        for word in data.lines().map(str::trim).filter(|word| !word.is_empty()) {
+            let word = intern(&mut interned, word.chars().collect());
+            dictionary.entry(word.len()).or_default().insert(word);
+        }
+
+        *self.dictionary.borrow_mut() = Some(dictionary);
+    }
+
+    /// Whether the word is on the full list, None while the list is still loading
+    pub fn is_dictionary_word(&self, word: &[char]) -> Option<bool> {
+        self.dictionary.borrow().as_ref().map(|dictionary| {
+            dictionary
+                .get(&word.len())
+                .is_some_and(|words| words.contains(word))
+        })
+    }
+
+    /// Words to pick the answers from. The common words stand in for the full list until it has
+    /// been fetched.
+    pub fn answer_words(&self, word_list: WordList, word_length: usize) -> Vec<Word> {
+        if word_list == WordList::Full {
+            if let Some(words) = self
+                .dictionary
+                .borrow()
+                .as_ref()
+                .and_then(|dictionary| dictionary.get(&word_length))
+            {
+                return words.iter().cloned().collect();
+            }
+        }
+
+        let word_list = if word_list == WordList::Full {
+            WordList::Common
+        } else {
+            word_list
+        };
+
+        self.get(&(word_list, word_length))
+            .map(|words| words.iter().cloned().collect())
+            .unwrap_or_default()
+    }
+
+    #[cfg(feature = "daily-index")]
+    pub fn daily_count(&self) -> usize {
+        self.daily.len()
+    }
+
+    /// Falls back to a seeded common word past the end of the daily list
+    pub fn daily_word(&self, date: NaiveDate) -> Option<Word> {
+        let index = Sanuli::get_daily_word_index(date);
+        self.daily_entry(index)
+            .or_else(|| self.seeded_word(FALLBACK_DAILY_SEEDS + index as u64 * 2))
+    }
+
+    /// A random word of the daily list from before today, so that the definition mode never
+    /// spoils a daily word to come
+    pub fn definition_word(&self, today: NaiveDate) -> Option<Word> {
+        let played = Sanuli::get_daily_word_index(today).min(self.daily.len());
+        let index = *self.rng.choose(&(0..played).collect::<Vec<_>>())?;
+        self.daily_entry(index)
+    }
+
+    /// Index of the word in the daily list, only the words up to today are decrypted for it
+    #[cfg(feature = "definitions")]
+    pub fn daily_index(&self, word: &[char]) -> Option<usize> {
+        let played = Sanuli::get_daily_word_index(rollover::today()) + 1;
+        (0..played.min(self.daily.len()))
+            .find(|index| self.daily_entry(*index).as_deref() == Some(word))
+    }
 
-pub type WordLists = HashMap<(WordList, usize), HashSet<Vec<char>>>;
+    /// The evening words walk a seeded sequence of the common words of their own, so that they
+    /// never give away a daily word to come
+    pub fn evening_word(&self, date: NaiveDate) -> Option<Word> {
+        let index = Sanuli::get_daily_word_index(date) as u64;
+        self.seeded_word(EVENING_WORD_SEEDS + index)
+    }
+
+    fn daily_entry(&self, index: usize) -> Option<Word> {
+        decrypt_daily(index, self.daily.get(index)?)
+    }
+
+    /// The words of a double daily walk a seeded sequence of the common words parallel to the
+    /// daily list, far above the seeds of the weekly challenges
+    pub fn double_daily_word(&self, date: NaiveDate, board: usize) -> Option<Word> {
+        let index = Sanuli::get_daily_word_index(date) as u64;
+        self.seeded_word(DOUBLE_DAILY_SEEDS + index * DOUBLE_DAILY_BOARDS as u64 + board as u64)
+    }
+
+    /// The words of a weekly challenge are picked from the common words by the ISO week
+    pub fn weekly_word(&self, week_start: NaiveDate, round: usize) -> Option<Word> {
+        let week = week_start.iso_week();
+        let seed = (week.year() as u64 * 100 + week.week() as u64) * WEEKLY_CHALLENGE_WORDS as u64
+            + round as u64;
+
+        self.seeded_word(seed)
+    }
+
+    /// The words of a tournament walk a seeded sequence of the common words by its code
+    pub fn tournament_word(&self, id: u64, round: usize) -> Option<Word> {
+        self.seeded_word(TOURNAMENT_SEEDS + id * TOURNAMENT_WORDS as u64 + round as u64)
+    }
+
+    /// The same common word for every player with the same seed
+    pub fn seeded_word(&self, seed: u64) -> Option<Word> {
+        let mut words = self
+            .get(&(WordList::Common, DAILY_WORD_LEN))?
+            .iter()
+            .collect::<Vec<_>>();
+        words.sort();
+
+        // Multiplicative hashing scatters the consecutive seeds over the whole list
+        let index = seed.wrapping_mul(2_654_435_761) % words.len() as u64;
+
+        words.get(index as usize).map(|word| Rc::clone(word))
+    }
+}
+
+struct WordSources {
+    common: Partitions,
+    easy: Partitions,
+    profanities: Partitions,
+    daily: &'static [&'static [u8]],
+    // Hash of the encrypted daily list computed by build.rs
+}
+
+fn word_sources(language: Language) -> WordSources {
+    match language {
+        // Only the Finnish lists have the easy words and the profanities, the others use the common words as the easy ones
+        #[cfg(feature = "swedish")]
+        Language::Swedish => WordSources {
+            common: swedish_words::COMMON,
+            easy: swedish_words::EASY,
+            profanities: swedish_words::PROFANITIES,
+            daily: swedish_words::DAILY,
+        },
+        #[cfg(feature = "english")]
+        Language::English => WordSources {
+            common: english_words::COMMON,
+            easy: english_words::EASY,
+            profanities: english_words::PROFANITIES,
+            daily: english_words::DAILY,
+        },
+        _ => WordSources {
+            common: finnish_words::COMMON,
+            easy: finnish_words::EASY,
+            profanities: finnish_words::PROFANITIES,
+            daily: finnish_words::DAILY,
+        },
+    }
+}
+
+/// Puzzle languages whose word lists are built in, see the `swedish` and `english` features
+pub fn puzzle_languages() -> Vec<Language> {
+    let mut languages = vec![Language::Finnish];
+    if cfg!(feature = "swedish") {
+        languages.push(Language::Swedish);
+    }
+    if cfg!(feature = "english") {
+        languages.push(Language::English);
+    }
+    languages
+}
 
 #[derive(PartialEq, Copy, Clone)]
 pub enum KeyState {
     Quadruple([TileState; 4]),
+    Double([TileState; 2]),
     Single(TileState),
 }
 
-fn parse_all_words() -> Rc<WordLists> {
-    let mut word_lists: HashMap<(WordList, usize), HashSet<Vec<char>>> = HashMap::with_capacity(3);
-    for word in FULL_WORDS.lines() {
-        let chars = word.chars();
-        let word_length = chars.clone().count();
-        word_lists
-            .entry((WordList::Full, word_length))
-            .or_insert_with(HashSet::new)
-            .insert(chars.collect());
+/// Known number of a letter in the word, shown as a badge on its key
+#[derive(PartialEq, Copy, Clone)]
+pub struct KeyCount {
+    pub count: usize,
+    // Every occurrence of the letter is already in its place
+    pub is_exhausted: bool,
+}
+
+/// Words of a length front coded by build.rs: each word starts with the number of letters it
+/// shares with the previous word as a base 36 digit, followed by the rest of its letters
+fn decode_words(word_length: usize, encoded: &str, interned: &mut HashSet<Word>) -> HashSet<Word> {
+    let mut words = HashSet::new();
+    let mut word = Vec::with_capacity(word_length);
+    let mut chars = encoded.chars();
+
+    while let Some(shared) = chars.next().and_then(|digit| digit.to_digit(36)) {
+        let shared = (shared as usize).min(word.len());
+        word.truncate(shared);
+        word.extend(chars.by_ref().take(word_length - shared));
+        words.insert(intern(interned, word.clone()));
     }
 
-    // TODO: Only 5-letter easy words exist for now on this list; fake them from common list
-    for word in EASY_WORDS.lines() {
-        let chars = word.chars();
-        let word_length = chars.clone().count();
-        word_lists
-            .entry((WordList::Easy, word_length))
-            .or_insert_with(HashSet::new)
-            .insert(chars.collect());
+    words
+}
+
+/// The same shared word for every list that has it
+fn intern(interned: &mut HashSet<Word>, word: Vec<char>) -> Word {
+    if let Some(word) = interned.get(word.as_slice()) {
+        return Rc::clone(word);
     }
 
-    for word in COMMON_WORDS.lines() {
-        let chars = word.chars();
-        let word_length = chars.clone().count();
+    let word: Word = word.into();
+    interned.insert(Rc::clone(&word));
+    word
+}
 
-        if word_length == 6 {
-            // TODO: Fake 6-letter easy words from common words, get rid of this if the list is created
-            word_lists
-                .entry((WordList::Easy, 6))
-                .or_insert_with(HashSet::new)
-                .insert(chars.clone().collect());
-        }
+/// The daily word of the index, decrypted with the key of its date
+fn decrypt_daily(index: usize, encrypted: &[u8]) -> Option<Word> {
+    String::from_utf8(cipher::apply_daily_key(index, encrypted))
+        .ok()
+        .map(|word| word.chars().collect())
+}
 
-        word_lists
-            .entry((WordList::Common, word_length))
-            .or_insert_with(HashSet::new)
-            .insert(chars.collect());
+fn parse_all_words(language: Language) -> Rc<WordLists> {
+    let sources = word_sources(language);
+
+    let mut word_lists: HashMap<(WordList, usize), HashSet<Word>> = HashMap::with_capacity(3);
+    let mut interned = HashSet::new();
+    let lists = [
+        (WordList::Easy, sources.easy),
+        (WordList::Common, sources.common),
+        (WordList::Profanities, sources.profanities),
+    ];
+    for (word_list, partitions) in lists {
+        for (word_length, words) in partitions {
+            word_lists.insert(
+                (word_list, *word_length),
+                decode_words(*word_length, words, &mut interned),
+            );
+        }
     }
 
-    for word in PROFANITIES.lines() {
-        let chars = word.chars();
-        let word_length = chars.clone().count();
+    // TODO: Only 5-letter easy words exist for now on this list; fake 6-letter ones from common
+    // words, get rid of this if the list is created
+    if let Some(common) = word_lists.get(&(WordList::Common, 6)).cloned() {
         word_lists
-            .entry((WordList::Profanities, word_length))
-            .or_insert_with(HashSet::new)
-            .insert(chars.collect());
+            .entry((WordList::Easy, 6))
+            .or_default()
+            .extend(common);
     }
 
-    Rc::new(word_lists)
+    Rc::new(WordLists {
+        language,
+        words: word_lists,
+        dictionary: RefCell::default(),
+        daily: sources.daily,
+        rng: GameRng::default(),
+    })
 }
 
 #[derive(PartialEq, Eq, Hash, Clone, Copy, Serialize, Deserialize)]
@@ -104,8 +381,28 @@ pub enum GameMode {
     Classic,
     Relay,
     DailyWord(NaiveDate),
+    // Optional second official puzzle of the day
+    EveningWord(NaiveDate),
+    // Challenge of the ISO week starting on the date
+    Weekly(NaiveDate),
+    // Online race, both players get the word of the same seed
+    Race(u64),
     Shared,
     Quadruple,
+    // Two words of the day solved with a shared budget of guesses
+    DoubleDaily(NaiveDate),
+    Zen,
+    Pressure,
+    // The colors of a game are given with its word, the guesses have to be reconstructed
+    Deduction,
+    // Words back to back on a shared pool of guesses
+    Marathon,
+    // The colors of the guesses are shown only once the game has ended
+    Blind,
+    // A past word of the daily list is guessed with its dictionary definition as the clue
+    Definition,
+    // Series of words of a tournament code, played by every participant on their own
+    Tournament(u64),
 }
 
 impl Default for GameMode {
@@ -114,10 +411,99 @@ impl Default for GameMode {
     }
 }
 
+impl GameMode {
+    /// Name of the mode for the external APIs, without the dates or seeds
+    pub fn name(&self) -> &'static str {
+        match self {
+            GameMode::Classic => "classic",
+            GameMode::Relay => "relay",
+            GameMode::DailyWord(_) => "daily",
+            GameMode::EveningWord(_) => "evening",
+            GameMode::Weekly(_) => "weekly",
+            GameMode::Race(_) => "race",
+            GameMode::Shared => "shared",
+            GameMode::Quadruple => "quadruple",
+            GameMode::DoubleDaily(_) => "double-daily",
+            GameMode::Zen => "zen",
+            GameMode::Pressure => "pressure",
+            GameMode::Deduction => "deduction",
+            GameMode::Marathon => "marathon",
+            GameMode::Blind => "blind",
+            GameMode::Definition => "definition",
+            GameMode::Tournament(_) => "tournament",
+        }
+    }
+
+    /// Whether the word is fixed by a date or a seed, the same for every player, and played on the
+    /// daily list at its word length
+    pub fn is_dated(&self) -> bool {
+        matches!(
+            self,
+            GameMode::DailyWord(_)
+                | GameMode::EveningWord(_)
+                | GameMode::DoubleDaily(_)
+                | GameMode::Weekly(_)
+                | GameMode::Race(_)
+                | GameMode::Definition
+                | GameMode::Tournament(_)
+        )
+    }
+}
+
 #[derive(PartialEq, Clone, Copy, Serialize, Deserialize)]
 pub enum Theme {
+    // The classic colors
     Dark,
     Colorblind,
+    Pastel,
+    Terminal,
+    HighContrast,
+}
+
+pub const THEMES: [Theme; 5] = [
+    Theme::Dark,
+    Theme::Colorblind,
+    Theme::Pastel,
+    Theme::Terminal,
+    Theme::HighContrast,
+];
+
+impl Theme {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Theme::Dark => "Klassinen",
+            Theme::Colorblind => "Värisokeille",
+            Theme::Pastel => "Pastelli",
+            Theme::Terminal => "Pääte",
+            Theme::HighContrast => "Korkea kontrasti",
+        }
+    }
+
+    /// The emojis of the correct, present and absent letters in the shared results
+    pub fn share_emojis(&self) -> [&'static str; 3] {
+        match self {
+            Theme::Dark => ["🟩", "🟨", "⬛"],
+            Theme::Colorblind | Theme::HighContrast => ["🟧", "🟦", "⬛"],
+            Theme::Pastel => ["💚", "💛", "🖤"],
+            Theme::Terminal => ["🟢", "🟡", "⚫"],
+        }
+    }
+
+    /// Whether the correct and present letters are orange and blue instead of green and yellow
+    pub fn is_orange_and_blue(&self) -> bool {
+        matches!(self, Theme::Colorblind | Theme::HighContrast)
+    }
+
+    /// Sets the class of the theme on the root element, so that the page around the game has
+    /// the colors of the theme too
+    pub fn apply(&self) {
+        if let Some(root) = window()
+            .and_then(|window| window.document())
+            .and_then(|document| document.document_element())
+        {
+            root.set_class_name(&self.to_string());
+        }
+    }
 }
 
 impl Default for Theme {
@@ -126,7 +512,7 @@ impl Default for Theme {
     }
 }
 
-#[derive(Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Clone, Copy, PartialEq, Serialize, Deserialize)]
 pub enum CharacterState {
     Correct,
     Absent,
@@ -141,6 +527,16 @@ pub enum TileState {
     Unknown,
 }
 
+impl From<Feedback> for TileState {
+    fn from(feedback: Feedback) -> Self {
+        match feedback {
+            Feedback::Correct => TileState::Correct,
+            Feedback::Present => TileState::Present,
+            Feedback::Absent => TileState::Absent,
+        }
+    }
+}
+
 impl fmt::Display for TileState {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match self {
@@ -152,6 +548,139 @@ impl fmt::Display for TileState {
     }
 }
 
+impl TileState {
+    /// Spoken state of the tile for the screen readers
+    pub fn description(&self) -> &'static str {
+        match self {
+            TileState::Correct => i18n::t("oikein"),
+            TileState::Absent => i18n::t("ei sanassa"),
+            TileState::Present => i18n::t("väärässä paikassa"),
+            TileState::Unknown => "",
+        }
+    }
+}
+
+#[derive(PartialEq, Clone, Copy, Serialize, Deserialize)]
+pub enum HistoryDensity {
+    List,
+    Thumbnails,
+}
+
+impl Default for HistoryDensity {
+    fn default() -> Self {
+        HistoryDensity::List
+    }
+}
+
+#[derive(PartialEq, Clone, Copy, Serialize, Deserialize)]
+pub enum UiScale {
+    Small,
+    Normal,
+    Large,
+}
+
+impl Default for UiScale {
+    fn default() -> Self {
+        UiScale::Normal
+    }
+}
+
+impl UiScale {
+    fn factor(&self) -> f64 {
+        match self {
+            UiScale::Small => 0.85,
+            UiScale::Normal => 1.0,
+            UiScale::Large => 1.2,
+        }
+    }
+
+    /// Sets the scale on the root element, the sizes of the board and the keyboard are
+    /// multiplied by it in the stylesheet and the texts follow the root font size
+    pub fn apply(&self) {
+        if let Some(root) = window()
+            .and_then(|window| window.document())
+            .and_then(|document| document.document_element())
+        {
+            let style = format!(
+                "--ui-scale: {}; font-size: {}px",
+                self.factor(),
+                16.0 * self.factor()
+            );
+            let _res = root.set_attribute("style", &style);
+        }
+    }
+}
+
+#[derive(PartialEq, Clone, Copy, Serialize, Deserialize)]
+pub enum AnimationSpeed {
+    Off,
+    Fast,
+    Normal,
+}
+
+impl Default for AnimationSpeed {
+    fn default() -> Self {
+        AnimationSpeed::Normal
+    }
+}
+
+impl AnimationSpeed {
+    /// Duration of an animation taking `normal_ms` at the normal speed, None when the
+    /// animations are turned off and the end state is shown right away
+    pub fn duration_ms(&self, normal_ms: u32) -> Option<u32> {
+        match self {
+            AnimationSpeed::Off => None,
+            AnimationSpeed::Fast => Some(normal_ms / 2),
+            AnimationSpeed::Normal => Some(normal_ms),
+        }
+    }
+}
+
+#[derive(PartialEq, Eq, Hash, Clone, Copy, Serialize, Deserialize)]
+pub enum KeyboardHints {
+    Full,
+    Light,
+    Off,
+}
+
+impl Default for KeyboardHints {
+    fn default() -> Self {
+        KeyboardHints::Full
+    }
+}
+
+#[derive(Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+pub struct Statistics {
+    pub max_streak: usize,
+    pub total_played: usize,
+    pub total_solved: usize,
+}
+
+/// Consecutive wins of the relay mode, kept apart from the classic statistics
+#[derive(Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+pub struct RelayStatistics {
+    pub chain: usize,
+    pub longest_chain: usize,
+    // Guesses used on the words of the current chain, without the carried over first rows
+    pub chain_guesses: usize,
+}
+
+impl RelayStatistics {
+    fn update(&mut self, is_winner: bool, chain: usize, guesses: usize) {
+        if !is_winner {
+            self.chain = 0;
+            self.chain_guesses = 0;
+            return;
+        }
+
+        // Every word after the first one starts with the previous word on its first row
+        let is_carried_over = chain > 1;
+        self.chain = chain;
+        self.chain_guesses += guesses - usize::from(is_carried_over);
+        self.longest_chain = self.longest_chain.max(chain);
+    }
+}
+
 impl FromStr for Theme {
     type Err = ();
 
@@ -159,6 +688,9 @@ impl FromStr for Theme {
         match input {
             "dark" => Ok(Theme::Dark),
             "colorblind" => Ok(Theme::Colorblind),
+            "pastel" => Ok(Theme::Pastel),
+            "terminal" => Ok(Theme::Terminal),
+            "high-contrast" => Ok(Theme::HighContrast),
             _ => Err(()),
         }
     }
@@ -169,16 +701,370 @@ impl fmt::Display for Theme {
         match self {
             Theme::Dark => write!(f, "dark"),
             Theme::Colorblind => write!(f, "colorblind"),
+            Theme::Pastel => write!(f, "pastel"),
+            Theme::Terminal => write!(f, "terminal"),
+            Theme::HighContrast => write!(f, "high-contrast"),
         }
     }
 }
 
+#[derive(Clone, Copy, PartialEq)]
+pub enum Flame {
+    Hot,
+    Warm,
+    Cooling,
+    Cold,
+}
+
+impl fmt::Display for Flame {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Flame::Hot => write!(f, "flame-hot"),
+            Flame::Warm => write!(f, "flame-warm"),
+            Flame::Cooling => write!(f, "flame-cooling"),
+            Flame::Cold => write!(f, "flame-cold"),
+        }
+    }
+}
+
+#[derive(Clone, PartialEq, Serialize, Deserialize)]
+pub struct DailyWordHistory {
+    pub word: Vec<char>,
+    pub date: NaiveDate,
+    pub guesses: Vec<Vec<(char, TileState)>>,
+    pub current_guess: usize,
+    pub is_winner: bool,
+    #[serde(default)]
+    pub is_hard_mode: bool,
+    // Times of the first typed letter and the end of the game, in milliseconds
+    #[serde(default)]
+    pub started_at: Option<i64>,
+    #[serde(default)]
+    pub finished_at: Option<i64>,
+}
+
+impl DailyWordHistory {
+    /// The colors of the submitted guesses, without the letters
+    pub fn patterns(&self) -> Vec<Vec<TileState>> {
+        self.guesses
+            .iter()
+            .take(self.current_guess + 1)
+            .map(|guess| guess.iter().map(|(_, state)| *state).collect())
+            .collect()
+    }
+
+    pub fn from_game(date: NaiveDate, game: &dyn Game) -> Option<Self> {
+        let Board {
+            guesses,
+            current_guess,
+            is_guessing,
+            ..
+        } = game.boards().into_iter().next()?;
+
+        if is_guessing {
+            return None;
+        }
+
+        Some(Self {
+            word: game.word(),
+            date,
+            guesses,
+            current_guess,
+            is_winner: game.is_winner(),
+            is_hard_mode: game.is_hard_mode(),
+            started_at: game.started_at(),
+            finished_at: Some(Utc::now().timestamp_millis()),
+        })
+    }
+
+    /// Seconds taken to solve the word, unknown for the games finished before it was recorded
+    pub fn duration_secs(&self) -> Option<i64> {
+        Some((self.finished_at? - self.started_at?) / 1000)
+    }
+
+    /// Drops the letters and colors of the guesses, keeping the word and the result for the
+    /// statistics
+    fn compact(&mut self) -> bool {
+        !mem::take(&mut self.guesses).is_empty()
+    }
+}
+
+/// Combined result of the two boards of a double daily
+#[derive(Clone, PartialEq, Serialize, Deserialize)]
+pub struct DoubleDailyHistory {
+    pub words: Vec<Vec<char>>,
+    pub date: NaiveDate,
+    // Revealed rows of each board, a solved board stops at its word
+    pub patterns: Vec<Vec<Vec<TileState>>>,
+    pub guesses: usize,
+    pub is_winner: bool,
+}
+
+impl DoubleDailyHistory {
+    pub fn from_game(date: NaiveDate, game: &dyn Game) -> Option<Self> {
+        if game.is_guessing() {
+            return None;
+        }
+
+        let boards = game.boards();
+        let patterns = boards
+            .iter()
+            .map(|board| {
+                board
+                    .guesses
+                    .iter()
+                    .take(board.current_guess + 1)
+                    .map(|guess| guess.iter().map(|(_, state)| *state).collect())
+                    .collect()
+            })
+            .collect();
+
+        Some(Self {
+            words: game.words(),
+            date,
+            patterns,
+            guesses: boards.iter().map(|board| board.current_guess + 1).max()?,
+            is_winner: game.is_winner(),
+        })
+    }
+
+    /// Drops the colors of the boards, keeping the words and the result for the statistics
+    fn compact(&mut self) -> bool {
+        !mem::take(&mut self.patterns).is_empty()
+    }
+}
+
+// Long enough to look up the words of the past few weeks in the game history
+const MAX_REPLAYS: usize = 200;
+// Letters of fewer recorded words tell too little of the player
+const MIN_LETTER_WORDS: usize = 3;
+
+const DAILY_WINS_PER_STREAK_FREEZE: usize = 7;
+const MAX_STREAK_FREEZES: usize = 2;
+
+/// Compact record of a finished game, enough to replay it anywhere
 #[derive(Clone, PartialEq, Serialize, Deserialize)]
+pub struct ReplayRecord {
+    pub game_mode: GameMode,
+    pub word: Vec<char>,
+    pub guesses: Vec<Vec<char>>,
+    pub max_guesses: usize,
+    pub finished_at: i64,
+    pub is_winner: bool,
+    // Seconds from the first typed letter to the end of the game, None when unknown
+    #[serde(default)]
+    pub duration_secs: Option<i64>,
+}
+
+impl ReplayRecord {
+    pub fn from_game(game: &dyn Game) -> Option<Self> {
+        let boards = game.boards();
+        if boards.len() != 1 || boards[0].is_guessing {
+            return None;
+        }
+
+        Some(Self {
+            game_mode: *game.game_mode(),
+            word: game.word(),
+            guesses: boards[0]
+                .guesses
+                .iter()
+                .take(boards[0].current_guess + 1)
+                .map(|guess| guess.iter().map(|(c, _)| *c).collect())
+                .collect(),
+            max_guesses: game.max_guesses(),
+            finished_at: Utc::now().timestamp(),
+            is_winner: game.is_winner(),
+            duration_secs: game
+                .started_at()
+                .map(|started_at| (Utc::now().timestamp_millis() - started_at) / 1000),
+        })
+    }
+
+    pub fn from_daily_word_history(history: &DailyWordHistory) -> Self {
+        Self {
+            game_mode: GameMode::DailyWord(history.date),
+            word: history.word.clone(),
+            guesses: history
+                .guesses
+                .iter()
+                .take(history.current_guess + 1)
+                .map(|guess| guess.iter().map(|(c, _)| *c).collect())
+                .collect(),
+            max_guesses: history.guesses.len(),
+            finished_at: history.date.and_hms(0, 0, 0).timestamp(),
+            is_winner: history.is_winner,
+            duration_secs: history.duration_secs(),
+        }
+    }
+
+    pub fn word_length(&self) -> usize {
+        self.word.len()
+    }
+
+    /// The guesses with their tiles scored against the word
+    pub fn scored_guesses(&self) -> Vec<Vec<(char, TileState)>> {
+        self.guesses
+            .iter()
+            .map(|guess| {
+                guess
+                    .iter()
+                    .copied()
+                    .zip(game::score_guess(guess, &self.word))
+                    .collect()
+            })
+            .collect()
+    }
+
+    /// Serialize to a compact "word|guesses|max guesses|finished at|game mode" string
+    pub fn to_share_string(&self) -> String {
+        format!(
+            "{}|{}|{}|{}|{}",
+            self.word.iter().collect::<String>(),
+            self.guesses.iter().flatten().collect::<String>(),
+            self.max_guesses,
+            self.finished_at,
+            serde_json::to_string(&self.game_mode).unwrap_or_default(),
+        )
+    }
+
+    pub fn from_share_string(replay_str: &str) -> Option<Self> {
+        let mut parts = replay_str.split('|');
+
+        let word = parts.next()?.chars().collect::<Vec<_>>();
+        if word.is_empty() {
+            return None;
+        }
+
+        let guesses = parts
+            .next()?
+            .chars()
+            .collect::<Vec<_>>()
+            .chunks(word.len())
+            .map(|chunk| chunk.to_vec())
+            .collect::<Vec<_>>();
+        if guesses.iter().any(|guess| guess.len() != word.len()) {
+            return None;
+        }
+
+        let max_guesses = parts.next()?.parse::<usize>().ok()?;
+        if max_guesses > neluli::MAX_GUESSES {
+            return None;
+        }
+        let finished_at = parts.next()?.parse::<i64>().ok()?;
+        let game_mode = serde_json::from_str(parts.next()?).ok()?;
+        let is_winner = guesses.last() == Some(&word);
+
+        if guesses.is_empty() || guesses.len() > max_guesses {
+            return None;
+        }
+
+        Some(Self {
+            game_mode,
+            word,
+            guesses,
+            max_guesses,
+            finished_at,
+            is_winner,
+            duration_secs: None,
+        })
+    }
+}
+
+/// Points earned in a mode, see `engine::points`
+#[derive(Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+pub struct ModePoints {
+    pub total: u64,
+    pub best: u32,
+    pub games: usize,
+}
+
+/// How a starting word has fared in the recorded games
+#[derive(Clone, PartialEq)]
+pub struct OpenerStatistics {
+    pub word: String,
+    pub games: usize,
+    // Green and yellow tiles of the first row, averaged over the games
+    pub average_yield: f64,
+}
+
+/// The letters of the words of the games, the most often misplaced or missed first. A game is
+/// its word, its scored guesses and whether it was won.
+pub fn letter_accuracies<'a>(
+    games: impl IntoIterator<Item = (&'a [char], Vec<Vec<(char, TileState)>>, bool)>,
+) -> Vec<LetterAccuracy> {
+    let mut letters: HashMap<char, LetterAccuracy> = HashMap::new();
+    for (word, scored_guesses, is_winner) in games {
+        let word_letters = word.iter().copied().collect::<BTreeSet<char>>();
+        for letter in word_letters {
+            let tiles = scored_guesses
+                .iter()
+                .flatten()
+                .filter(|(c, _)| *c == letter)
+                .collect::<Vec<_>>();
+
+            let accuracy = letters.entry(letter).or_insert(LetterAccuracy {
+                letter,
+                words: 0,
+                misplaced: 0,
+                missed: 0,
+            });
+            accuracy.words += 1;
+            if tiles.iter().any(|(_, state)| *state == TileState::Present) {
+                accuracy.misplaced += 1;
+            } else if tiles.is_empty() && !is_winner {
+                accuracy.missed += 1;
+            }
+        }
+    }
+
+    let mut letters = letters
+        .into_values()
+        .filter(|accuracy| accuracy.words >= MIN_LETTER_WORDS)
+        .collect::<Vec<_>>();
+    letters.sort_by(|a, b| {
+        b.trickiness()
+            .total_cmp(&a.trickiness())
+            .then_with(|| a.letter.cmp(&b.letter))
+    });
+
+    letters
+}
+
+/// How often a letter of the words has been misplaced or missed in the recorded games
+#[derive(Clone, PartialEq)]
+pub struct LetterAccuracy {
+    pub letter: char,
+    // Words with the letter
+    pub words: usize,
+    // Words with the letter guessed in a wrong place
+    pub misplaced: usize,
+    // Lost words with the letter never guessed
+    pub missed: usize,
+}
+
+impl LetterAccuracy {
+    pub fn trickiness(&self) -> f64 {
+        (self.misplaced + self.missed) as f64 / self.words as f64
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Serialize, Deserialize)]
 pub enum CharacterCount {
     AtLeast(usize),
     Exactly(usize),
 }
 
+/// Summary of a classic or relay game left mid-progress, shown when the game is opened
+#[derive(Clone, PartialEq)]
+pub struct UnfinishedGame {
+    pub game_mode: GameMode,
+    pub guesses_used: usize,
+    pub max_guesses: usize,
+    // Words solved in a row, lost by starting a new game
+    pub streak: usize,
+}
+
 #[derive(PartialEq, Serialize, Deserialize)]
 pub struct Manager {
     pub current_game_mode: GameMode,
@@ -189,17 +1075,130 @@ pub struct Manager {
     pub previous_game: (GameMode, WordList, usize),
 
     pub theme: Theme,
+    // Colors of the shared results, None follows the theme
+    #[serde(default)]
+    pub share_theme: Option<Theme>,
+    #[serde(default)]
+    pub share_options: ShareOptions,
+    #[serde(default)]
+    pub ui_scale: UiScale,
+    #[serde(default)]
+    pub animation_speed: AnimationSpeed,
+    #[serde(default)]
+    pub rollover: Rollover,
+    #[serde(default)]
+    pub keyboard_hints: KeyboardHints,
+    #[serde(default)]
+    pub keyboard_layout: KeyboardLayout,
 
     pub max_streak: usize,
     pub total_played: usize,
     pub total_solved: usize,
 
+    // Games played with reduced keyboard hints are kept apart from the full hint statistics
+    #[serde(default)]
+    pub assisted_statistics: HashMap<KeyboardHints, Statistics>,
+
+    // Zen games are never mixed with the competitive statistics
+    #[serde(default)]
+    pub zen_statistics: Statistics,
+    #[serde(default)]
+    pub pressure_statistics: Statistics,
+    #[serde(default)]
+    pub relay_statistics: RelayStatistics,
+    // Most words solved in a marathon run
+    #[serde(default)]
+    pub marathon_best: usize,
+    // Races against the bot, the solved ones being the won races
+    #[serde(default)]
+    pub bot_statistics: Statistics,
+    #[serde(default)]
+    pub show_candidates: bool,
+    // Points for the finished games, by `GameMode::name()`
+    #[serde(default)]
+    pub scoring: bool,
+    #[serde(default)]
+    pub mode_points: BTreeMap<String, ModePoints>,
+    #[serde(default)]
+    pub show_letter_frequencies: bool,
+    #[serde(default)]
+    pub show_alphabet: bool,
+    // Swipes on the board remove a letter, guess and continue to the next game
+    #[serde(default)]
+    pub swipe_gestures: bool,
+    // Seconds for every row of the pressure mode, by default the limit shrinks row by row
+    #[serde(default)]
+    pub pressure_time_limit: Option<u32>,
+    #[serde(default)]
+    pub history_density: HistoryDensity,
+    #[serde(default)]
+    pub language: Language,
+    #[serde(default)]
+    pub puzzle_language: Language,
+    // Statistics of the other puzzle languages, the Finnish ones are kept in the fields above
+    #[serde(default)]
+    pub language_statistics: HashMap<Language, HashMap<KeyboardHints, Statistics>>,
+    // Keys standing in for the letters missing from the physical keyboard, eg. ';' for 'Ö'
+    #[serde(default)]
+    pub key_substitutions: BTreeMap<char, char>,
+    #[serde(default)]
+    pub key_bindings: KeyBindings,
+    #[serde(default)]
+    pub voice_input: bool,
+    // Hour of the day after which an unplayed daily word is reminded of
+    #[serde(default)]
+    pub daily_reminder: Option<u32>,
+    #[serde(default)]
+    pub daily_reminder_sent: Option<NaiveDate>,
+    // "Lomapäivä" tokens earned by daily wins, each covering a single missed day of the streak
+    #[serde(default)]
+    pub streak_freezes: usize,
+    #[serde(default)]
+    pub streak_freeze_progress: usize,
+    #[serde(default)]
+    pub frozen_days: BTreeSet<NaiveDate>,
+    // The latest year whose review has opened by itself
+    #[serde(default)]
+    pub year_review_shown: Option<i32>,
+    #[serde(default)]
+    pub evening_word: bool,
+    #[serde(default)]
+    pub daily_hard_mode: bool,
+    // Submitting the daily results is opt-in, the id is random and created on opting in
+    #[serde(default)]
+    pub leaderboard: bool,
+    #[serde(default)]
+    pub leaderboard_client_id: Option<String>,
+    // Anonymous usage events, opt-in
+    #[serde(default)]
+    pub analytics: bool,
+
+    #[serde(skip)]
+    pub daily_word_history: BTreeMap<NaiveDate, DailyWordHistory>,
+    #[serde(skip)]
+    pub evening_word_history: BTreeMap<NaiveDate, DailyWordHistory>,
+    #[serde(skip)]
+    pub double_daily_history: BTreeMap<NaiveDate, DoubleDailyHistory>,
+    // Scores of the finished weekly challenges by the start of the week
+    #[serde(skip)]
+    pub weekly_history: BTreeMap<NaiveDate, Vec<usize>>,
+    #[serde(skip)]
+    pub replays: Vec<ReplayRecord>,
+    #[serde(skip)]
+    pub shared_replay: Option<ReplayRecord>,
+
     #[serde(skip)]
     pub game: Option<Box<dyn Game>>,
     #[serde(skip)]
     pub background_games: HashMap<(GameMode, WordList, usize), Box<dyn Game>>,
     #[serde(skip)]
-    pub word_lists: Rc<WordLists>,
+    pub word_lists: Rc<WordLists>,
+    // Emitted since the app last took them
+    #[serde(skip)]
+    pub events: Vec<GameEvent>,
+    // Seed of the random words, kept over the puzzle language changes
+    #[serde(skip)]
+    pub seed: Option<u64>,
 }
 
 impl Default for Manager {
@@ -217,59 +1216,97 @@ impl Default for Manager {
             ),
 
             theme: Theme::default(),
+            share_theme: None,
+            share_options: ShareOptions::default(),
+            ui_scale: UiScale::default(),
+            animation_speed: AnimationSpeed::default(),
+            rollover: Rollover::default(),
+            keyboard_hints: KeyboardHints::default(),
+            keyboard_layout: KeyboardLayout::default(),
 
             max_streak: 0,
             total_played: 0,
             total_solved: 0,
 
+            assisted_statistics: HashMap::new(),
+
+            zen_statistics: Statistics::default(),
+            pressure_statistics: Statistics::default(),
+            relay_statistics: RelayStatistics::default(),
+            marathon_best: 0,
+            bot_statistics: Statistics::default(),
+            show_candidates: false,
+            scoring: false,
+            mode_points: BTreeMap::new(),
+            show_letter_frequencies: false,
+            show_alphabet: false,
+            swipe_gestures: false,
+            pressure_time_limit: None,
+            history_density: HistoryDensity::default(),
+            language: Language::default(),
+            puzzle_language: Language::default(),
+            language_statistics: HashMap::new(),
+            key_substitutions: BTreeMap::new(),
+            key_bindings: KeyBindings::default(),
+            voice_input: false,
+            daily_reminder: None,
+            daily_reminder_sent: None,
+            streak_freezes: 0,
+            streak_freeze_progress: 0,
+            frozen_days: BTreeSet::new(),
+            year_review_shown: None,
+            evening_word: false,
+            daily_hard_mode: false,
+            leaderboard: false,
+            leaderboard_client_id: None,
+            analytics: false,
+
+            daily_word_history: BTreeMap::new(),
+            evening_word_history: BTreeMap::new(),
+            double_daily_history: BTreeMap::new(),
+            weekly_history: BTreeMap::new(),
+            replays: Vec::new(),
+            shared_replay: None,
+
             game: None,
             background_games: HashMap::new(),
-            word_lists: Rc::new(HashMap::new()),
+            word_lists: Rc::new(WordLists::default()),
+            events: Vec::new(),
+            seed: None,
         }
     }
 }
 
 impl Manager {
-    pub fn new() -> Self {
-        let word_lists = parse_all_words();
+    /// Only the settings, enough to paint the page in the theme, size and language of the
+    /// player. Parsing the word lists and replaying the stored games is left to `new()`.
+    pub fn preload() -> Self {
+        let manager = Self::rehydrate_settings().unwrap_or_default();
+        manager.theme.apply();
+        manager.ui_scale.apply();
+
+        manager
+    }
 
+    pub fn new() -> Self {
         // Attempt to rehydrate manager from localStorage
         let mut initial_manager = if let Ok(mut manager) = Manager::rehydrate() {
-            if let GameMode::DailyWord(date) = manager.current_game_mode {
-                let today = Local::today().naive_local();
-
-                if date < today {
-                    // Page was refreshed after the day changed - rehydrate the daily word of today
-                    manager.current_game_mode = GameMode::DailyWord(today);
-                }
+            let today = rollover::today();
+            manager.move_to_today(today);
+            manager.prune_history(today);
+
+            if !matches!(manager.current_game_mode, GameMode::Shared) {
+                manager.game = Some(manager.new_or_rehydrate_game(
+                    manager.current_game_mode,
+                    manager.current_word_list,
+                    manager.current_word_length,
+                ));
             }
 
-            match manager.current_game_mode {
-                GameMode::Classic | GameMode::Relay | GameMode::DailyWord(_) => {
-                    manager.game = Some(Box::new(Sanuli::new_or_rehydrate(
-                        manager.current_game_mode,
-                        manager.current_word_list,
-                        manager.current_word_length,
-                        manager.allow_profanities,
-                        word_lists.clone(),
-                    )));
-                }
-                GameMode::Quadruple => {
-                    manager.game = Some(Box::new(Neluli::new_or_rehydrate(
-                        manager.current_word_list,
-                        manager.current_word_length,
-                        manager.allow_profanities,
-                        word_lists.clone(),
-                    )));
-                }
-                GameMode::Shared => {}
-            };
-
-            manager.word_lists = word_lists;
-
             manager
         } else {
             // Otherwise either create everything from scratch or recover some data from legacy storage manager
+            let word_lists = parse_all_words(Language::default());
             let game = Sanuli::new(
                 GameMode::Classic,
                 WordList::Common,
@@ -285,12 +1322,15 @@ impl Manager {
                 ..Self::default()
             };
 
-            let _res = manager.persist();
-            let _res = manager.game.as_ref().unwrap().persist();
+            let _res = manager.persist_all();
 
             manager
         };
 
+        if let Some(seed) = Self::rehydrate_seed() {
+            initial_manager.set_seed(seed);
+        }
+
         // If this is a shared game switch to it immediately. Set the game we were going to display in the background
         if let Some(game) = initial_manager.rehydrate_shared_game() {
             initial_manager.current_game_mode = *game.game_mode();
@@ -305,9 +1345,86 @@ impl Manager {
             initial_manager.switch_active_game();
         }
 
+        initial_manager.shared_replay = Self::rehydrate_shared_replay();
+        initial_manager.spend_streak_freeze(rollover::today());
+        initial_manager.theme.apply();
+        initial_manager.ui_scale.apply();
+
         initial_manager
     }
 
+    /// Seed of the random words given in the query string, left in the URL so that reloading
+    /// starts over with the same words
+    fn rehydrate_seed() -> Option<u64> {
+        let qs = window()?.location().search().ok()?;
+
+        qs.trim_start_matches('?')
+            .split('&')
+            .find_map(|param| param.strip_prefix("siemen="))
+            .and_then(|seed| seed.parse().ok())
+    }
+
+    /// Makes the random words and messages repeat for the same seed. The game of a random mode
+    /// is started over with a word of the seed.
+    pub fn set_seed(&mut self, seed: u64) {
+        self.seed = Some(seed);
+        self.word_lists.rng.reseed(seed);
+
+        if matches!(
+            self.current_game_mode,
+            GameMode::Classic
+                | GameMode::Relay
+                | GameMode::Quadruple
+                | GameMode::Zen
+                | GameMode::Pressure
+                | GameMode::Deduction
+                | GameMode::Marathon
+                | GameMode::Blind
+                | GameMode::Definition
+        ) {
+            self.game = Some(self.new_game(
+                self.current_game_mode,
+                self.current_word_list,
+                self.current_word_length,
+            ));
+        }
+    }
+
+    fn rehydrate_shared_replay() -> Option<ReplayRecord> {
+        let window: Window = window().expect("window not available");
+        let qs = window.location().search().ok()?;
+        if qs.is_empty() {
+            return None;
+        }
+
+        // Skip the leading "?"
+        for param in qs.chars().skip(1).collect::<String>().split('&') {
+            let mut parts = param.split('=');
+
+            let key = parts.next()?;
+            let value = parts.next()?;
+
+            if key == "uusinta" && !value.is_empty() {
+                // Replace URL safe characters back to +/=
+                let base64 = value.replace('-', "+").replace('.', "/").replace('_', "=");
+
+                let replay_str = window.atob(&base64).ok()?;
+                let replay = ReplayRecord::from_share_string(&replay_str);
+
+                // Remove the query string
+                window
+                    .history()
+                    .ok()?
+                    .replace_state_with_url(&JsValue::null(), "", Some("/"))
+                    .ok()?;
+
+                return replay;
+            }
+        }
+
+        None
+    }
+
     fn rehydrate_shared_game(&self) -> Option<Sanuli> {
         let window: Window = window().expect("window not available");
         let qs = window.location().search().ok()?;
@@ -356,9 +1473,99 @@ impl Manager {
         }
     }
 
+    /// Empties the current row, wherever the cursor is
+    pub fn clear_row(&mut self) {
+        if let Some(game) = self.game.as_mut() {
+            let word_length = game.word_length();
+            game.move_cursor(word_length);
+            for _ in 0..word_length {
+                game.pop_character();
+            }
+        }
+    }
+
+    /// Replaces the current guess with the letters of the pasted text
+    pub fn paste_word(&mut self, text: &str) {
+        let alphabet = self.puzzle_language.alphabet().collect::<Vec<_>>();
+
+        if let Some(game) = self.game.as_mut() {
+            if !game.is_guessing() {
+                return;
+            }
+
+            game.move_cursor(game.word_length());
+            for _ in 0..game.word_length() {
+                game.pop_character();
+            }
+
+            text.to_uppercase()
+                .chars()
+                .filter(|character| alphabet.contains(&character))
+                .take(game.word_length())
+                .for_each(|character| game.push_character(character));
+        }
+    }
+
+    /// Fills the current guess with the dictated word, preferring the alternatives of the right length
+    pub fn dictate_word(&mut self, alternatives: &[String]) -> Option<String> {
+        let word_length = self.game.as_ref()?.word_length();
+        let alphabet = self.puzzle_language.alphabet().collect::<Vec<_>>();
+
+        let words = alternatives
+            .iter()
+            .map(|alternative| {
+                alternative
+                    .to_uppercase()
+                    .chars()
+                    .filter(|character| alphabet.contains(&character))
+                    .take(word_length)
+                    .collect::<String>()
+            })
+            .filter(|word| !word.is_empty())
+            .collect::<Vec<_>>();
+
+        let word = words
+            .iter()
+            .find(|word| word.chars().count() == word_length)
+            .or_else(|| words.first())?
+            .clone();
+
+        self.paste_word(&word);
+
+        Some(word)
+    }
+
+    pub fn move_cursor(&mut self, position: usize) {
+        if let Some(game) = self.game.as_mut() {
+            game.move_cursor(position);
+        }
+    }
+
+    pub fn move_cursor_left(&mut self) {
+        if let Some(game) = self.game.as_mut() {
+            let position = game.cursor().saturating_sub(1);
+            game.move_cursor(position);
+        }
+    }
+
+    pub fn move_cursor_right(&mut self) {
+        if let Some(game) = self.game.as_mut() {
+            let position = game.cursor() + 1;
+            game.move_cursor(position);
+        }
+    }
+
     pub fn next_word(&mut self) {
         if let Some(game) = self.game.as_mut() {
             game.next_word();
+
+            // The relay carries the answer over as the first row, started without a guess
+            if Self::revealed_rows(game.as_ref()) > 0 {
+                self.events.push(GameEvent::GameStarted {
+                    mode: *game.game_mode(),
+                    word_length: game.word_length(),
+                });
+            }
         }
     }
 
@@ -367,14 +1574,542 @@ impl Manager {
             return;
         }
 
+        let previous_rows = Self::revealed_rows(self.game.as_ref().unwrap().as_ref());
         self.game.as_mut().unwrap().submit_guess();
+        self.on_guess_revealed(previous_rows);
+    }
 
-        if !self.game.as_ref().unwrap().is_guessing() {
-            self.update_game_statistics(
-                self.game.as_ref().unwrap().is_winner(),
-                self.game.as_ref().unwrap().streak(),
-            );
+    /// Expires the current row of a timed game if its time has run out
+    pub fn check_deadline(&mut self) {
+        let now = Utc::now().timestamp_millis();
+
+        match self.game.as_ref().and_then(|game| game.deadline()) {
+            Some(deadline) if deadline <= now => {
+                let previous_rows = Self::revealed_rows(self.game.as_ref().unwrap().as_ref());
+                self.game.as_mut().unwrap().expire_row();
+                self.on_guess_revealed(previous_rows);
+            }
+            _ => {}
+        }
+    }
+
+    pub fn deadline(&self) -> Option<i64> {
+        self.game.as_ref()?.deadline()
+    }
+
+    /// The classic or relay game in progress, if it has guesses
+    pub fn unfinished_game(&self) -> Option<UnfinishedGame> {
+        let game = self.game.as_ref().filter(|game| game.is_guessing())?;
+        let game_mode = *game.game_mode();
+        if !matches!(game_mode, GameMode::Classic | GameMode::Relay) {
+            return None;
+        }
+
+        let board = game.boards().remove(0);
+        // The answer carried over in the relay is not a guess of the word
+        let carried_rows = usize::from(game_mode == GameMode::Relay && game.streak() > 0);
+        let guesses_used = board.current_guess.saturating_sub(carried_rows);
+        if guesses_used == 0 {
+            return None;
+        }
+
+        Some(UnfinishedGame {
+            game_mode,
+            guesses_used,
+            max_guesses: board.guesses.len() - carried_rows,
+            streak: game.streak(),
+        })
+    }
+
+    /// Replaces the game in progress with a new word, ending the streak of the mode
+    pub fn start_new_game(&mut self) {
+        let game = self.new_game(
+            self.current_game_mode,
+            self.current_word_list,
+            self.current_word_length,
+        );
+        let _result = game.persist();
+        self.game = Some(game);
+    }
+
+    /// Whether switching away would lose the game in progress. Shared games are never stored,
+    /// and the clock of a timed row keeps running, forfeiting the streak once it runs out.
+    pub fn is_game_at_risk(&self) -> bool {
+        let game = match &self.game {
+            Some(game) if game.is_guessing() => game,
+            _ => return false,
+        };
+
+        game.deadline().is_some()
+            || (matches!(game.game_mode(), GameMode::Shared | GameMode::Race(_))
+                && Self::is_started(game.as_ref()))
+    }
+
+    /// Whether any letters have been typed or guessed in the game
+    fn is_started(game: &dyn Game) -> bool {
+        game.boards().iter().any(|board| {
+            board.current_guess > 0
+                || matches!(board.guesses.get(board.current_guess), Some(guess) if !guess.is_empty())
+        })
+    }
+
+    /// Only a fresh or finished game takes over a relay chain solved at another word length, a
+    /// game in progress there is kept as it was
+    fn can_take_over_chain(game: &dyn Game) -> bool {
+        !game.is_guessing() || !Self::is_started(game)
+    }
+
+    fn continue_chain(
+        game: &mut dyn Game,
+        word: &[char],
+        streak: usize,
+        chain_scores: Vec<usize>,
+    ) -> bool {
+        if !Self::can_take_over_chain(game) {
+            return false;
+        }
+
+        game.continue_chain(word, streak, chain_scores);
+        true
+    }
+
+    pub fn take_events(&mut self) -> Vec<GameEvent> {
+        mem::take(&mut self.events)
+    }
+
+    /// The rows revealed on the board furthest along
+    fn revealed_rows(game: &dyn Game) -> usize {
+        game.boards()
+            .iter()
+            .map(|board| board.current_guess + usize::from(!board.is_guessing))
+            .max()
+            .unwrap_or(0)
+    }
+
+    fn emit_revealed(&mut self, previous_rows: usize) {
+        let game = self.game.as_ref().unwrap();
+        let guesses = Self::revealed_rows(game.as_ref());
+        // Rejected guesses leave the board as it was
+        if guesses <= previous_rows {
+            return;
+        }
+
+        let mode = *game.game_mode();
+        if previous_rows == 0 {
+            self.events.push(GameEvent::GameStarted {
+                mode,
+                word_length: game.word_length(),
+            });
+        }
+        self.events.push(GameEvent::GuessRevealed { mode, guesses });
+        if !game.is_guessing() {
+            self.events.push(GameEvent::GameFinished {
+                mode,
+                word_length: game.word_length(),
+                is_winner: game.is_winner(),
+                guesses,
+            });
+        }
+    }
+
+    fn on_guess_revealed(&mut self, previous_rows: usize) {
+        self.emit_revealed(previous_rows);
+
+        let game = self.game.as_ref().unwrap();
+        if !game.is_guessing() && !game.is_counted() {
+            let is_winner = self.game.as_ref().unwrap().is_winner();
+            let streak = self.game.as_ref().unwrap().streak();
+
+            match self.current_game_mode {
+                // These modes never count towards the statistics, and the weekly challenges are
+                // kept in their own history
+                GameMode::Weekly(_)
+                | GameMode::Race(_)
+                | GameMode::Deduction
+                | GameMode::Definition
+                | GameMode::Tournament(_) => {}
+                GameMode::Zen => {
+                    Self::update_separate_statistics(&mut self.zen_statistics, is_winner, streak)
+                }
+                GameMode::Pressure => Self::update_separate_statistics(
+                    &mut self.pressure_statistics,
+                    is_winner,
+                    streak,
+                ),
+                GameMode::Relay => {
+                    let guesses = Self::revealed_rows(self.game.as_ref().unwrap().as_ref());
+                    self.relay_statistics.update(is_winner, streak, guesses);
+                }
+                GameMode::Marathon => self.marathon_best = self.marathon_best.max(streak),
+                _ => self.update_game_statistics(is_winner, streak),
+            }
+
+            if let GameMode::DailyWord(date) = self.current_game_mode {
+                self.update_daily_word_history(date);
+                if is_winner && date == rollover::today() {
+                    self.earn_streak_freeze();
+                }
+            }
+
+            if let GameMode::Weekly(week_start) = self.current_game_mode {
+                let game = self.game.as_ref().unwrap();
+                if !game.has_next_word() {
+                    self.weekly_history
+                        .insert(week_start, game.challenge_scores());
+                }
+            }
+
+            if let GameMode::EveningWord(date) = self.current_game_mode {
+                let game = self.game.as_ref().unwrap().as_ref();
+                if let Some(history) = DailyWordHistory::from_game(date, game) {
+                    self.evening_word_history.insert(date, history);
+                }
+            }
+
+            if let GameMode::DoubleDaily(date) = self.current_game_mode {
+                let game = self.game.as_ref().unwrap().as_ref();
+                if let Some(history) = DoubleDailyHistory::from_game(date, game) {
+                    self.double_daily_history.insert(date, history);
+                }
+            }
+
+            if !matches!(self.current_game_mode, GameMode::Shared | GameMode::Race(_)) {
+                self.update_replays();
+                self.record_points();
+            }
+            self.game.as_mut().unwrap().set_counted();
+
+            // The game, statistics and history must never end up out of sync
+            let _res = self.persist_all();
+        } else {
+            let _res = self.game.as_ref().unwrap().persist();
+        }
+    }
+
+    /// Stores the game in progress, including the letters of an unfinished guess
+    pub fn persist_game(&self) {
+        if let Some(game) = &self.game {
+            let _result = game.persist();
+        }
+    }
+
+    /// Compacts the history entries past the retention and deletes their stored games, so that
+    /// the storage doesn't fill up over the years
+    fn prune_history(&mut self, today: NaiveDate) {
+        let cutoff = retention::cutoff(today);
+
+        let mut is_pruned = false;
+        for (_, history) in self
+            .daily_word_history
+            .range_mut(..cutoff)
+            .chain(self.evening_word_history.range_mut(..cutoff))
+        {
+            is_pruned |= history.compact();
+        }
+        for (_, history) in self.double_daily_history.range_mut(..cutoff) {
+            is_pruned |= history.compact();
+        }
+        is_pruned |= retention::prune_games(cutoff) > 0;
+
+        if is_pruned {
+            let _res = self.persist_all();
+        }
+    }
+
+    fn update_daily_word_history(&mut self, date: NaiveDate) {
+        let game = self.game.as_ref().unwrap().as_ref();
+        if let Some(mut history) = DailyWordHistory::from_game(date, game) {
+            // The game ended when it was first stored, not when it is stored again
+            if let Some(previous) = self.daily_word_history.get(&date) {
+                history.finished_at = previous.finished_at.or(history.finished_at);
+            }
+            self.daily_word_history.insert(date, history);
+        }
+    }
+
+    /// Solve time of the finished daily word being shown
+    pub fn daily_duration(&self) -> Option<i64> {
+        match self.current_game_mode {
+            GameMode::DailyWord(date) if !self.game.as_ref()?.is_guessing() => {
+                self.daily_word_history.get(&date)?.duration_secs()
+            }
+            _ => None,
+        }
+    }
+
+    /// The first guesses of the recorded games, the most used first
+    pub fn opener_statistics(&self) -> Vec<OpenerStatistics> {
+        let mut openers: HashMap<String, (usize, usize)> = HashMap::new();
+        for replay in &self.replays {
+            // A relay carries the previous word over and the colors dictate the deductions
+            if matches!(replay.game_mode, GameMode::Relay | GameMode::Deduction) {
+                continue;
+            }
+
+            let guess = match replay.guesses.first() {
+                Some(guess) if guess.len() == replay.word.len() => guess,
+                _ => continue,
+            };
+            if !guess.iter().all(|c| c.is_alphabetic()) {
+                continue;
+            }
+
+            let hits = game::score_guess(guess, &replay.word)
+                .iter()
+                .filter(|state| matches!(state, TileState::Correct | TileState::Present))
+                .count();
+
+            let (games, total_hits) = openers.entry(guess.iter().collect()).or_insert((0, 0));
+            *games += 1;
+            *total_hits += hits;
+        }
+
+        let mut openers = openers
+            .into_iter()
+            .map(|(word, (games, total_hits))| OpenerStatistics {
+                word,
+                games,
+                average_yield: total_hits as f64 / games as f64,
+            })
+            .collect::<Vec<_>>();
+        openers.sort_by(|a, b| b.games.cmp(&a.games).then_with(|| a.word.cmp(&b.word)));
+
+        openers
+    }
+
+    /// The letters of the recorded words, the most often misplaced or missed first
+    pub fn letter_accuracy(&self) -> Vec<LetterAccuracy> {
+        letter_accuracies(
+            self.replays
+                .iter()
+                // The colors of the deductions are given instead of guessed
+                .filter(|replay| replay.game_mode != GameMode::Deduction)
+                .map(|replay| {
+                    (
+                        replay.word.as_slice(),
+                        replay.scored_guesses(),
+                        replay.is_winner,
+                    )
+                }),
+        )
+    }
+
+    /// The summary of the daily words of the year
+    pub fn year_summary(&self, year: i32) -> YearSummary {
+        year_review::summarize(year, &self.daily_word_history, &self.frozen_days)
+    }
+
+    pub fn year_review_image(&self, year: i32) -> Option<String> {
+        self.year_summary(year).share_image(self.share_colors())
+    }
+
+    /// The latest year with a daily word played, the one the review opens to
+    pub fn latest_review_year(&self) -> Option<i32> {
+        self.daily_word_history
+            .keys()
+            .next_back()
+            .map(|date| date.year())
+    }
+
+    /// The years with a daily word played, the latest first
+    pub fn review_years(&self) -> Vec<i32> {
+        let years = self
+            .daily_word_history
+            .keys()
+            .map(|date| date.year())
+            .collect::<BTreeSet<_>>();
+        years.into_iter().rev().collect()
+    }
+
+    /// The review of the year ending or just ended, once and only if a daily word was played in it
+    pub fn take_due_year_review(&mut self, today: NaiveDate) -> Option<i32> {
+        let year = year_review::due_year(today)?;
+        if self.year_review_shown >= Some(year)
+            || !self
+                .daily_word_history
+                .keys()
+                .any(|date| date.year() == year)
+        {
+            return None;
+        }
+
+        self.year_review_shown = Some(year);
+        let _result = self.persist();
+        Some(year)
+    }
+
+    /// Average solve time of the solved daily words with a recorded duration
+    pub fn average_daily_duration(&self) -> Option<i64> {
+        let durations = self
+            .daily_word_history
+            .values()
+            .filter(|history| history.is_winner)
+            .filter_map(DailyWordHistory::duration_secs)
+            .collect::<Vec<_>>();
+
+        if durations.is_empty() {
+            return None;
+        }
+
+        Some(durations.iter().sum::<i64>() / durations.len() as i64)
+    }
+
+    fn update_replays(&mut self) {
+        let game = self.game.as_ref().unwrap().as_ref();
+        if let Some(replay) = ReplayRecord::from_game(game) {
+            self.replays.push(replay);
+            if self.replays.len() > MAX_REPLAYS {
+                self.replays.remove(0);
+            }
+        }
+    }
+
+    pub fn last_replay(&self) -> Option<&ReplayRecord> {
+        let game = self.game.as_ref()?;
+        if game.is_guessing() {
+            return None;
+        }
+
+        self.replays
+            .last()
+            .filter(|replay| replay.game_mode == *game.game_mode() && replay.word == game.word())
+    }
+
+    #[cfg(web_sys_unstable_apis)]
+    pub fn share_replay_link(&self) -> Option<String> {
+        let replay = self.last_replay()?;
+
+        let window: Window = window().expect("window not available");
+        let share_str = window.btoa(&replay.to_share_string()).ok()?;
+        let base_url = window.location().origin().ok()?;
+
+        // Replace +/= at the base64 with URL safe characters
+        let safe_str = share_str
+            .replace('+', "-")
+            .replace('/', ".")
+            .replace('=', "_");
+
+        Some(format!("{}/?uusinta={}", base_url, safe_str))
+    }
+
+    /// Colors only image of the finished board with the result and attribution
+    pub fn share_image(&self) -> Option<String> {
+        let game = self.game.as_ref()?;
+        let boards = game.boards();
+        if boards.len() != 1 || boards[0].is_guessing {
+            return None;
+        }
+
+        let board = &boards[0];
+        let patterns = board
+            .guesses
+            .iter()
+            .take(board.current_guess + 1)
+            .map(|guess| guess.iter().map(|(_, state)| *state).collect())
+            .collect::<Vec<Vec<TileState>>>();
+
+        let daily_index = match game.game_mode() {
+            GameMode::DailyWord(date) | GameMode::EveningWord(date) => {
+                Some(Sanuli::get_daily_word_index(*date) + 1)
+            }
+            _ => None,
+        };
+
+        let result = if game.is_winner() {
+            format!("{}", board.current_guess + 1)
+        } else {
+            "X".to_owned()
+        };
+        let title = format!("{}/{}", result, game.max_guesses());
+
+        share_image::render(
+            &patterns,
+            game.max_guesses(),
+            game.word_length(),
+            &title,
+            &BRAND.footer_lines(daily_index, storage::active_profile().as_deref()),
+            self.share_colors(),
+        )
+    }
+
+    pub fn daily_streak(&self, today: NaiveDate) -> usize {
+        // Today's word still counts towards the streak until the day is over
+        let mut date = if self.is_daily_word_solved(today) {
+            today
+        } else {
+            today.pred()
+        };
+
+        let mut streak = 0;
+        loop {
+            if self.is_daily_word_solved(date) {
+                streak += 1;
+            } else if !self.frozen_days.contains(&date) {
+                break;
+            }
+            date = date.pred();
+        }
+
+        streak
+    }
+
+    fn earn_streak_freeze(&mut self) {
+        self.streak_freeze_progress += 1;
+        if self.streak_freeze_progress >= DAILY_WINS_PER_STREAK_FREEZE {
+            self.streak_freeze_progress = 0;
+            self.streak_freezes = (self.streak_freezes + 1).min(MAX_STREAK_FREEZES);
+        }
+    }
+
+    /// Spends a token on yesterday if it was missed in the middle of a running streak.
+    /// Two or more missed days in a row still break the streak.
+    pub fn spend_streak_freeze(&mut self, today: NaiveDate) -> bool {
+        let missed = today.pred();
+        if self.streak_freezes == 0
+            || self.daily_word_history.contains_key(&missed)
+            || self.frozen_days.contains(&missed)
+            || self.daily_streak(missed) == 0
+        {
+            return false;
         }
+
+        self.streak_freezes -= 1;
+        self.frozen_days.insert(missed);
+        let _result = self.persist();
+
+        true
+    }
+
+    fn is_daily_word_solved(&self, date: NaiveDate) -> bool {
+        self.daily_word_history
+            .get(&date)
+            .is_some_and(|history| history.is_winner)
+    }
+
+    pub fn streak_flame(&self, now: NaiveDateTime) -> Option<(usize, Flame)> {
+        let today = now.date();
+        let streak = self.daily_streak(today);
+        if streak == 0 {
+            return None;
+        }
+
+        if self.daily_word_history.contains_key(&today) {
+            return Some((streak, Flame::Hot));
+        }
+
+        // The flame cools down as the midnight approaches without today's word being played
+        let remaining = today.succ().and_hms(0, 0, 0) - now;
+        let flame = if remaining > Duration::hours(12) {
+            Flame::Hot
+        } else if remaining > Duration::hours(6) {
+            Flame::Warm
+        } else if remaining > Duration::hours(2) {
+            Flame::Cooling
+        } else {
+            Flame::Cold
+        };
+
+        Some((streak, flame))
     }
 
     pub fn change_word_length(&mut self, new_length: usize) {
@@ -382,13 +2117,30 @@ impl Manager {
             return;
         }
 
+        // A solved relay word keeps the chain going on the other word length
+        let chain = self
+            .game
+            .as_ref()
+            .filter(|game| {
+                *game.game_mode() == GameMode::Relay && !game.is_guessing() && game.is_winner()
+            })
+            .map(|game| (game.word(), game.streak(), game.chain_scores()));
+
         self.current_word_length = new_length;
         self.switch_active_game();
 
-        let _res = self.persist();
-        if let Some(game) = self.game.as_mut() {
-            let _res = game.persist();
+        if let Some((word, streak, chain_scores)) = chain {
+            if let Some(game) = self.game.as_mut() {
+                if Self::continue_chain(game.as_mut(), &word, streak, chain_scores) {
+                    self.events.push(GameEvent::GameStarted {
+                        mode: GameMode::Relay,
+                        word_length: new_length,
+                    });
+                }
+            }
         }
+
+        let _res = self.persist_all();
     }
 
     pub fn change_game_mode(&mut self, new_mode: GameMode) {
@@ -396,12 +2148,12 @@ impl Manager {
             return;
         }
 
-        if matches!(self.current_game_mode, GameMode::DailyWord(_)) {
+        if self.current_game_mode.is_dated() {
             self.current_word_list = self.previous_game.1;
             self.current_word_length = self.previous_game.2;
         }
 
-        if matches!(new_mode, GameMode::DailyWord(_)) {
+        if new_mode.is_dated() {
             self.current_word_list = WordList::Daily;
             self.current_word_length = DAILY_WORD_LEN;
         } else if self.current_word_list == WordList::Daily {
@@ -413,8 +2165,7 @@ impl Manager {
 
         self.current_game_mode = new_mode;
         self.switch_active_game();
-        let _res = self.persist();
-        let _res = self.game.as_ref().unwrap().persist();
+        let _res = self.persist_all();
     }
 
     pub fn change_word_list(&mut self, new_list: WordList) {
@@ -425,16 +2176,13 @@ impl Manager {
         self.current_word_list = new_list;
         self.switch_active_game();
 
-        let _res = self.persist();
-        let _res = self.game.as_ref().unwrap().persist();
+        let _res = self.persist_all();
     }
 
     pub fn change_previous_game_mode(&mut self) {
         let (game_mode, word_list, word_length) = self.previous_game;
 
-        if matches!(game_mode, GameMode::DailyWord(_))
-            && matches!(self.current_game_mode, GameMode::DailyWord(_))
-        {
+        if game_mode.is_dated() && self.current_game_mode.is_dated() {
             // Force the user to reset to the base game
             self.current_game_mode = GameMode::default();
             self.current_word_list = WordList::default();
@@ -447,8 +2195,7 @@ impl Manager {
 
         self.switch_active_game();
 
-        let _res = self.persist();
-        let _res = self.game.as_mut().unwrap().persist();
+        let _res = self.persist_all();
     }
 
     pub fn change_allow_profanities(&mut self, is_allowed: bool) {
@@ -465,9 +2212,90 @@ impl Manager {
 
     pub fn change_theme(&mut self, theme: Theme) {
         self.theme = theme;
+        theme.apply();
+        let _result = self.persist();
+    }
+
+    pub fn change_share_theme(&mut self, share_theme: Option<Theme>) {
+        self.share_theme = share_theme;
+        let _result = self.persist();
+    }
+
+    fn share_colors(&self) -> Theme {
+        self.share_theme.unwrap_or(self.theme)
+    }
+
+    pub fn change_ui_scale(&mut self, ui_scale: UiScale) {
+        self.ui_scale = ui_scale;
+        ui_scale.apply();
+        let _result = self.persist();
+    }
+
+    pub fn change_animation_speed(&mut self, animation_speed: AnimationSpeed) {
+        self.animation_speed = animation_speed;
         let _result = self.persist();
     }
 
+    pub fn change_keyboard_layout(&mut self, keyboard_layout: KeyboardLayout) {
+        self.keyboard_layout = keyboard_layout;
+        let _result = self.persist();
+    }
+
+    pub fn toggle_share_option(&mut self, option: ShareOption) {
+        self.share_options.toggle(option);
+        let _result = self.persist();
+    }
+
+    pub fn change_keyboard_hints(&mut self, keyboard_hints: KeyboardHints) {
+        self.keyboard_hints = keyboard_hints;
+        let _result = self.persist();
+    }
+
+    pub fn statistics(&self) -> Statistics {
+        if self.puzzle_language != Language::Finnish {
+            return self
+                .language_statistics
+                .get(&self.puzzle_language)
+                .and_then(|statistics| statistics.get(&self.keyboard_hints))
+                .copied()
+                .unwrap_or_default();
+        }
+
+        match self.keyboard_hints {
+            KeyboardHints::Full => Statistics {
+                max_streak: self.max_streak,
+                total_played: self.total_played,
+                total_solved: self.total_solved,
+            },
+            hints => self
+                .assisted_statistics
+                .get(&hints)
+                .copied()
+                .unwrap_or_default(),
+        }
+    }
+
+    fn set_statistics(&mut self, statistics: Statistics) {
+        if self.puzzle_language != Language::Finnish {
+            self.language_statistics
+                .entry(self.puzzle_language)
+                .or_insert_with(HashMap::new)
+                .insert(self.keyboard_hints, statistics);
+            return;
+        }
+
+        match self.keyboard_hints {
+            KeyboardHints::Full => {
+                self.max_streak = statistics.max_streak;
+                self.total_played = statistics.total_played;
+                self.total_solved = statistics.total_solved;
+            }
+            hints => {
+                self.assisted_statistics.insert(hints, statistics);
+            }
+        }
+    }
+
     fn switch_active_game(&mut self) {
         let next_game = (
             self.current_game_mode,
@@ -493,53 +2321,499 @@ impl Manager {
             return;
         }
 
-        self.previous_game = previous_game;
+        // Keep the classic or relay game as the one to return to while visiting daily words,
+        // otherwise hopping between two dailies would forget it
+        if !previous_game.0.is_dated() || !next_game.0.is_dated() {
+            self.previous_game = previous_game;
+        }
+
+        // The suspended board is also stored so that it survives a reload mid-detour
+        let _res = previous.persist();
 
         // Restore a suspended game or create a new one
-        let game = self
-            .background_games
-            .remove(&next_game)
-            .unwrap_or_else(|| match next_game.0 {
-                GameMode::Classic | GameMode::Relay | GameMode::DailyWord(_) | GameMode::Shared => {
-                    Box::new(Sanuli::new_or_rehydrate(
-                        next_game.0,
-                        next_game.1,
-                        next_game.2,
-                        self.allow_profanities,
-                        self.word_lists.clone(),
-                    ))
-                }
-                GameMode::Quadruple => Box::new(Neluli::new_or_rehydrate(
-                    next_game.1,
-                    next_game.2,
-                    self.allow_profanities,
-                    self.word_lists.clone(),
-                )),
-            });
+        let game = match self.background_games.remove(&next_game) {
+            Some(game) => game,
+            None => self.new_or_rehydrate_game(next_game.0, next_game.1, next_game.2),
+        };
 
         self.game = Some(game);
         self.background_games.insert(previous_game, previous);
+        self.events
+            .push(GameEvent::GameSwitched { mode: next_game.0 });
+    }
+
+    fn new_or_rehydrate_game(
+        &self,
+        game_mode: GameMode,
+        word_list: WordList,
+        word_length: usize,
+    ) -> Box<dyn Game> {
+        let mut game: Box<dyn Game> = match game_mode {
+            GameMode::Classic
+            | GameMode::Relay
+            | GameMode::DailyWord(_)
+            | GameMode::EveningWord(_)
+            | GameMode::Weekly(_)
+            | GameMode::Race(_)
+            | GameMode::Shared
+            | GameMode::Zen
+            | GameMode::Pressure
+            | GameMode::Deduction
+            | GameMode::Marathon
+            | GameMode::Blind
+            | GameMode::Definition
+            | GameMode::Tournament(_) => Box::new(Sanuli::new_or_rehydrate(
+                game_mode,
+                word_list,
+                word_length,
+                self.allow_profanities,
+                self.word_lists.clone(),
+            )),
+            GameMode::Quadruple | GameMode::DoubleDaily(_) => Box::new(Neluli::new_or_rehydrate(
+                game_mode,
+                word_list,
+                word_length,
+                self.allow_profanities,
+                self.word_lists.clone(),
+            )),
+        };
+        game.set_hard_mode(self.daily_hard_mode);
+        game.set_row_time_limit(self.pressure_time_limit);
+        game
+    }
+
+    fn new_game(
+        &self,
+        game_mode: GameMode,
+        word_list: WordList,
+        word_length: usize,
+    ) -> Box<dyn Game> {
+        let mut game: Box<dyn Game> = match game_mode {
+            GameMode::Quadruple | GameMode::DoubleDaily(_) => Box::new(Neluli::new(
+                game_mode,
+                word_list,
+                word_length,
+                self.allow_profanities,
+                self.word_lists.clone(),
+            )),
+            _ => Box::new(Sanuli::new(
+                game_mode,
+                word_list,
+                word_length,
+                DEFAULT_MAX_GUESSES,
+                self.allow_profanities,
+                self.word_lists.clone(),
+            )),
+        };
+        game.set_hard_mode(self.daily_hard_mode);
+        game.set_row_time_limit(self.pressure_time_limit);
+        game
     }
 
     fn update_game_statistics(&mut self, is_winner: bool, streak: usize) {
-        self.total_played += 1;
+        let mut statistics = self.statistics();
+        statistics.total_played += 1;
 
         if is_winner {
-            self.total_solved += 1;
+            statistics.total_solved += 1;
 
-            if streak > self.max_streak {
-                self.max_streak = streak;
+            if streak > statistics.max_streak {
+                statistics.max_streak = streak;
             }
         }
-        let _res = self.persist();
+
+        self.set_statistics(statistics);
+    }
+
+    fn update_separate_statistics(statistics: &mut Statistics, is_winner: bool, streak: usize) {
+        statistics.total_played += 1;
+        if is_winner {
+            statistics.total_solved += 1;
+            statistics.max_streak = statistics.max_streak.max(streak);
+        }
+    }
+
+    pub fn record_bot_race(&mut self, is_winner: bool) {
+        Self::update_separate_statistics(&mut self.bot_statistics, is_winner, 0);
+        let _result = self.persist();
+    }
+
+    pub fn change_pressure_time_limit(&mut self, seconds: Option<u32>) {
+        self.pressure_time_limit = seconds;
+        if let Some(game) = self.game.as_mut() {
+            game.set_row_time_limit(seconds);
+        }
+        let _result = self.persist();
+    }
+
+    pub fn change_show_letter_frequencies(&mut self, is_shown: bool) {
+        self.show_letter_frequencies = is_shown;
+        let _result = self.persist();
+    }
+
+    pub fn change_show_alphabet(&mut self, is_shown: bool) {
+        self.show_alphabet = is_shown;
+        let _result = self.persist();
+    }
+
+    pub fn change_swipe_gestures(&mut self, is_enabled: bool) {
+        self.swipe_gestures = is_enabled;
+        let _result = self.persist();
+    }
+
+    pub fn change_show_candidates(&mut self, is_shown: bool) {
+        self.show_candidates = is_shown;
+        let _result = self.persist();
+    }
+
+    pub fn change_scoring(&mut self, is_enabled: bool) {
+        self.scoring = is_enabled;
+        let _result = self.persist();
+    }
+
+    /// Points of the mode of the game being played, None while scoring is off or in the modes
+    /// without points
+    pub fn current_mode_points(&self) -> Option<ModePoints> {
+        if !self.scoring || matches!(self.current_game_mode, GameMode::Shared | GameMode::Race(_)) {
+            return None;
+        }
+
+        let mode = self.current_game_mode.name();
+        Some(self.mode_points.get(mode).copied().unwrap_or_default())
     }
 
+    /// Points of the finished game being shown as they were recorded, None while scoring is off
     #[cfg(web_sys_unstable_apis)]
-    pub fn share_emojis(&self) -> Option<String> {
-        self.game.as_ref()?.share_emojis(self.theme)
+    pub fn game_points(&self) -> Option<Points> {
+        let game = self.game.as_ref().filter(|_| self.scoring)?;
+        if game.is_guessing() {
+            return None;
+        }
+
+        game.points()
+    }
+
+    /// Scores the game that was just finished, the streak and the solve time change later on
+    fn score_game(&self) -> Option<Points> {
+        let game = self.game.as_ref().filter(|_| self.scoring)?;
+        if game.is_guessing()
+            || matches!(self.current_game_mode, GameMode::Shared | GameMode::Race(_))
+        {
+            return None;
+        }
+
+        Some(engine::points(&ScoredGame {
+            is_winner: game.is_winner(),
+            guesses: Self::revealed_rows(game.as_ref()),
+            max_guesses: game.max_guesses(),
+            duration_secs: self.solve_duration(),
+            streak: self.current_streak(),
+            is_hard_mode: game.is_hard_mode(),
+        }))
+    }
+
+    fn record_points(&mut self) {
+        let points = match (self.score_game(), self.game.as_mut()) {
+            (Some(points), Some(game)) => {
+                game.set_points(points);
+                points.total()
+            }
+            _ => return,
+        };
+
+        let mode = self.current_game_mode.name().to_owned();
+        let mode_points = self.mode_points.entry(mode).or_default();
+        mode_points.total += points as u64;
+        mode_points.best = mode_points.best.max(points);
+        mode_points.games += 1;
+    }
+
+    pub fn change_history_density(&mut self, density: HistoryDensity) {
+        self.history_density = density;
+        let _result = self.persist();
+    }
+
+    pub fn change_language(&mut self, language: Language) {
+        self.language = language;
+        i18n::set_language(language);
+        let _result = self.persist();
+    }
+
+    pub fn change_puzzle_language(&mut self, language: Language) {
+        if language == self.puzzle_language || !puzzle_languages().contains(&language) {
+            return;
+        }
+
+        // The games and history of the previous language stay in the storage under their own keys
+        let _res = self.persist_all();
+
+        if matches!(self.current_game_mode, GameMode::Shared | GameMode::Race(_)) {
+            // Shared games and races are bound to the word lists they were created with
+            let (game_mode, word_list, word_length) = self.previous_game;
+            self.current_game_mode = game_mode;
+            self.current_word_list = word_list;
+            self.current_word_length = word_length;
+        }
+
+        self.puzzle_language = language;
+        self.word_lists = parse_all_words(language);
+        if let Some(seed) = self.seed {
+            self.word_lists.rng.reseed(seed);
+        }
+        self.daily_word_history = Self::rehydrate_daily_word_history(language);
+        self.evening_word_history =
+            storage::get(Self::evening_word_history_key(language)).unwrap_or_default();
+        self.double_daily_history =
+            storage::get(Self::double_daily_history_key(language)).unwrap_or_default();
+        self.weekly_history = storage::get(Self::weekly_history_key(language)).unwrap_or_default();
+        self.background_games.clear();
+        self.game = Some(self.new_or_rehydrate_game(
+            self.current_game_mode,
+            self.current_word_list,
+            self.current_word_length,
+        ));
+
+        let _res = self.persist_all();
+    }
+
+    pub fn change_key_substitution(&mut self, letter: char, key: Option<char>) {
+        self.key_substitutions
+            .retain(|_, substitute| *substitute != letter);
+        if let Some(key) = key {
+            self.key_substitutions.insert(key, letter);
+        }
+        let _result = self.persist();
+    }
+
+    pub fn change_leaderboard(&mut self, is_enabled: bool) {
+        self.leaderboard = is_enabled;
+        if is_enabled && self.leaderboard_client_id.is_none() {
+            let id = rand::random::<u64>();
+            self.leaderboard_client_id = Some(format!("{:016x}", id));
+        }
+        let _result = self.persist();
+    }
+
+    pub fn change_analytics(&mut self, is_enabled: bool) {
+        self.analytics = is_enabled;
+        let _result = self.persist();
+    }
+
+    // Applies to the daily word right away unless it has been guessed already
+    pub fn change_rollover(&mut self, rollover: Rollover) {
+        self.rollover = rollover;
+        rollover.apply();
+
+        let current_game_mode = self.current_game_mode;
+        self.move_to_today(rollover::today());
+        if self.current_game_mode != current_game_mode {
+            self.switch_active_game();
+            let _res = self.persist_all();
+        } else {
+            let _result = self.persist();
+        }
+    }
+
+    /// Starts the daily or evening word over with the word of the index given by the backend,
+    /// unless the player has already guessed the previous one
+    #[cfg(feature = "daily-index")]
+    pub fn refresh_daily_word(&mut self) {
+        let word = match self.current_game_mode {
+            GameMode::DailyWord(date) => self.word_lists.daily_word(date),
+            GameMode::EveningWord(date) => self.word_lists.evening_word(date),
+            _ => return,
+        };
+
+        let is_unplayed = self
+            .game
+            .as_ref()
+            .is_some_and(|game| game.is_guessing() && game.boards()[0].current_guess == 0);
+        let is_changed = match (&self.game, word) {
+            (Some(game), Some(word)) => game.word() != *word,
+            _ => false,
+        };
+        if !is_unplayed || !is_changed {
+            return;
+        }
+
+        let game = self.new_game(
+            self.current_game_mode,
+            self.current_word_list,
+            self.current_word_length,
+        );
+        let _result = game.persist();
+        self.game = Some(game);
+    }
+
+    /// Moves the dated modes to the puzzles of today, eg. after the day has changed
+    fn move_to_today(&mut self, today: NaiveDate) {
+        match self.current_game_mode {
+            GameMode::DailyWord(date) if date != today => {
+                self.current_game_mode = GameMode::DailyWord(today);
+            }
+            GameMode::EveningWord(date) if date != today => {
+                self.current_game_mode = GameMode::EveningWord(today);
+            }
+            GameMode::DoubleDaily(date) if date != today => {
+                self.current_game_mode = GameMode::DoubleDaily(today);
+            }
+            GameMode::Weekly(week_start) if week_start != Sanuli::get_week_start(today) => {
+                self.current_game_mode = GameMode::Weekly(Sanuli::get_week_start(today));
+            }
+            _ => {}
+        }
+    }
+
+    pub fn change_daily_hard_mode(&mut self, is_enabled: bool) {
+        self.daily_hard_mode = is_enabled;
+        if let Some(game) = self.game.as_mut() {
+            game.set_hard_mode(is_enabled);
+            let _result = game.persist();
+        }
+        let _result = self.persist();
+    }
+
+    pub fn change_evening_word(&mut self, is_enabled: bool) {
+        self.evening_word = is_enabled;
+        let _result = self.persist();
+    }
+
+    pub fn change_voice_input(&mut self, is_enabled: bool) {
+        self.voice_input = is_enabled;
+        let _result = self.persist();
+    }
+
+    pub fn change_daily_reminder(&mut self, hour: Option<u32>) {
+        self.daily_reminder = hour;
+        let _result = self.persist();
+    }
+
+    /// Checks whether the reminder of an unplayed daily word is due, once per day. The hour of
+    /// the reminder is on the clock of the device.
+    pub fn take_daily_reminder(&mut self, now: NaiveDateTime) -> bool {
+        let today = rollover::today();
+        let is_due = match self.daily_reminder {
+            Some(hour) => {
+                now.hour() >= hour
+                    && self.daily_reminder_sent != Some(today)
+                    && !self.daily_word_history.contains_key(&today)
+            }
+            None => false,
+        };
+
+        if is_due {
+            self.daily_reminder_sent = Some(today);
+            let _result = self.persist();
+        }
+
+        is_due
+    }
+
+    pub fn change_key_binding(&mut self, action: Action, key: Option<String>) {
+        self.key_bindings.set(action, key);
+        let _result = self.persist();
+    }
+
+    /// Letters of the words still possible on the board, by the number of words having them.
+    /// The letters in every one of the words tell nothing new and are left out.
+    pub fn letter_frequencies(&self) -> Option<(usize, Vec<(char, usize)>)> {
+        let game = self.game.as_ref()?;
+        if !self.show_letter_frequencies || *game.game_mode() == GameMode::Blind {
+            return None;
+        }
+
+        let boards = game.boards();
+        let board = match boards.as_slice() {
+            [board] => board,
+            _ => return None,
+        };
+        let submitted = if board.is_guessing {
+            board.current_guess
+        } else {
+            board.current_guess + 1
+        };
+
+        // Rows forfeited in the pressure mode reveal nothing
+        let revealed = board
+            .guesses
+            .iter()
+            .take(submitted)
+            .filter(|guess| guess.iter().all(|(c, _)| c.is_alphabetic()))
+            .cloned()
+            .collect::<Vec<_>>();
+
+        let words = self
+            .word_lists
+            .answer_words(WordList::Full, game.word_length());
+        let (total, frequencies) = game::letter_frequencies(&words, &revealed);
+
+        Some((
+            total,
+            frequencies
+                .into_iter()
+                .filter(|(_, count)| *count < total)
+                .collect(),
+        ))
+    }
+
+    pub fn remaining_candidates(&self) -> Option<usize> {
+        let game = self.game.as_ref()?;
+        if !self.show_candidates || *game.game_mode() != GameMode::Zen {
+            return None;
+        }
+
+        let board = game.boards().into_iter().next()?;
+        let submitted = if board.is_guessing {
+            board.current_guess
+        } else {
+            board.current_guess + 1
+        };
+
+        let guesses = board
+            .guesses
+            .iter()
+            .take(submitted)
+            .map(|guess| guess.iter().map(|(c, _)| *c).collect::<Vec<char>>())
+            .collect::<Vec<_>>();
+
+        let words = self
+            .word_lists
+            .get(&(*game.word_list(), game.word_length()))?;
+
+        Some(game::remaining_candidates(words, &guesses, &game.word()))
     }
 
+    /// The result of the game with the details chosen in the share options
     #[cfg(web_sys_unstable_apis)]
+    pub fn share_emojis(&self) -> Option<String> {
+        let game = self.game.as_ref()?;
+        let result = game.share_result(self.share_colors())?;
+
+        let context = ShareContext {
+            streak: self.current_streak(),
+            duration_secs: self.solve_duration(),
+            is_expert: self.keyboard_hints == KeyboardHints::Off,
+            points: self.game_points().map(|points| points.total()),
+        };
+
+        Some(share::compose(&result, self.share_options, &context))
+    }
+
+    /// The streak of the game being played, the daily words counting the days
+    fn current_streak(&self) -> usize {
+        match self.game.as_deref().map(|game| *game.game_mode()) {
+            Some(GameMode::DailyWord(_)) => self.daily_streak(rollover::today()),
+            Some(_) => self.game.as_ref().map_or(0, |game| game.streak()),
+            None => 0,
+        }
+    }
+
+    /// Solve time of the finished game being shown
+    fn solve_duration(&self) -> Option<i64> {
+        self.daily_duration()
+            .or_else(|| self.last_replay()?.duration_secs)
+    }
+
     pub fn share_link(&self) -> Option<String> {
         self.game.as_ref()?.share_link()
     }
@@ -550,6 +2824,12 @@ impl Manager {
         }
     }
 
+    pub fn undo_guess(&mut self) {
+        if let Some(game) = self.game.as_mut() {
+            game.undo_guess();
+        }
+    }
+
     pub fn reset_game(&mut self) {
         if let Some(game) = self.game.as_mut() {
             game.reset();
@@ -557,17 +2837,178 @@ impl Manager {
     }
 
     fn persist(&self) -> Result<(), StorageError> {
-        if matches!(self.current_game_mode, GameMode::Shared) {
-            // Never persist shared games
+        let mut transaction = Transaction::new();
+        self.persist_with(&mut transaction)?;
+        transaction.commit()
+    }
+
+    fn persist_with(&self, transaction: &mut Transaction) -> Result<(), StorageError> {
+        if matches!(self.current_game_mode, GameMode::Shared | GameMode::Race(_)) {
+            // Never persist shared games or races
             return Ok(());
         }
 
-        LocalStorage::set("settings", self)
+        // The newer of two synced saves wins
+        transaction.set("saved_at", &Utc::now().timestamp_millis())?;
+        transaction.set("settings", self)
+    }
+
+    /// Persist the settings, statistics, history and the active game together
+    fn persist_all(&self) -> Result<(), StorageError> {
+        let mut transaction = Transaction::new();
+        self.persist_with(&mut transaction)?;
+        transaction.set(
+            Self::daily_word_history_key(self.puzzle_language),
+            &self.daily_word_history,
+        )?;
+        transaction.set(
+            Self::evening_word_history_key(self.puzzle_language),
+            &self.evening_word_history,
+        )?;
+        transaction.set(
+            Self::double_daily_history_key(self.puzzle_language),
+            &self.double_daily_history,
+        )?;
+        transaction.set(
+            Self::weekly_history_key(self.puzzle_language),
+            &self.weekly_history,
+        )?;
+        transaction.set("replays", &self.replays)?;
+        if let Some(game) = self.game.as_ref() {
+            game.persist_with(&mut transaction)?;
+        }
+        transaction.commit()
+    }
+
+    fn rehydrate_settings() -> Result<Self, StorageError> {
+        let mut manager: Self = storage::get("settings")?;
+        // Messages of the rehydrated games are written in the chosen language
+        i18n::set_language(manager.language);
+        manager.rollover.apply();
+        if !puzzle_languages().contains(&manager.puzzle_language) {
+            // Settings saved by a build with more word lists
+            manager.puzzle_language = Language::Finnish;
+        }
+        Ok(manager)
     }
 
     fn rehydrate() -> Result<Self, StorageError> {
-        let mut manager: Self = LocalStorage::get("settings")?;
-        manager.word_lists = parse_all_words();
+        let mut manager = Self::rehydrate_settings()?;
+        manager.word_lists = parse_all_words(manager.puzzle_language);
+        manager.daily_word_history = Self::rehydrate_daily_word_history(manager.puzzle_language);
+        manager.evening_word_history =
+            storage::get(Self::evening_word_history_key(manager.puzzle_language))
+                .unwrap_or_default();
+        manager.double_daily_history =
+            storage::get(Self::double_daily_history_key(manager.puzzle_language))
+                .unwrap_or_default();
+        manager.weekly_history =
+            storage::get(Self::weekly_history_key(manager.puzzle_language)).unwrap_or_default();
+        manager.replays = storage::get("replays").unwrap_or_default();
         Ok(manager)
     }
+
+    fn daily_word_history_key(language: Language) -> String {
+        match language {
+            Language::Finnish => "daily_word_history".to_owned(),
+            language => format!("daily_word_history|{}", language.code()),
+        }
+    }
+
+    fn evening_word_history_key(language: Language) -> String {
+        match language {
+            Language::Finnish => "evening_word_history".to_owned(),
+            language => format!("evening_word_history|{}", language.code()),
+        }
+    }
+
+    fn double_daily_history_key(language: Language) -> String {
+        match language {
+            Language::Finnish => "double_daily_history".to_owned(),
+            language => format!("double_daily_history|{}", language.code()),
+        }
+    }
+
+    fn weekly_history_key(language: Language) -> String {
+        match language {
+            Language::Finnish => "weekly_history".to_owned(),
+            language => format!("weekly_history|{}", language.code()),
+        }
+    }
+
+    fn rehydrate_daily_word_history(language: Language) -> BTreeMap<NaiveDate, DailyWordHistory> {
+        let key = Self::daily_word_history_key(language);
+        match storage::get(&key) {
+            Ok(history) => history,
+            Err(_) if language == Language::Finnish => {
+                let history = Self::migrate_daily_word_history();
+                let _res = storage::set(&key, &history);
+                history
+            }
+            Err(_) => BTreeMap::new(),
+        }
+    }
+
+    fn migrate_daily_word_history() -> BTreeMap<NaiveDate, DailyWordHistory> {
+        // Daily games played before the history existed are still around as separate game keys
+        let mut history = BTreeMap::new();
+
+        for key in storage::keys() {
+            // Games of the other puzzle languages have the language code as the fifth part
+            if !key.starts_with("game|{\"DailyWord\"") || key.split('|').count() != 4 {
+                continue;
+            }
+
+            if let Ok(game) = storage::get::<Sanuli>(&key) {
+                if let GameMode::DailyWord(date) = *game.game_mode() {
+                    if let Some(entry) = DailyWordHistory::from_game(date, &game) {
+                        history.insert(date, entry);
+                    }
+                }
+            }
+        }
+
+        history
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SOLVED: [char; 5] = ['K', 'I', 'S', 'S', 'A'];
+
+    fn relay_game(word_length: usize) -> Sanuli {
+        Sanuli::new(
+            GameMode::Relay,
+            WordList::Common,
+            word_length,
+            DEFAULT_MAX_GUESSES,
+            DEFAULT_ALLOW_PROFANITIES,
+            Rc::new(WordLists::default()),
+        )
+    }
+
+    #[test]
+    fn relay_chain_continues_into_a_fresh_game() {
+        assert!(Manager::can_take_over_chain(&relay_game(6)));
+    }
+
+    #[test]
+    fn relay_chain_keeps_a_game_in_progress_at_the_other_length() {
+        let mut background = relay_game(6);
+        for character in "KALASTAA".chars() {
+            background.push_character(character);
+        }
+        let before = background.clone();
+
+        assert!(!Manager::continue_chain(
+            &mut background,
+            &SOLVED,
+            3,
+            vec![4, 2, 5]
+        ));
+        assert!(background == before);
+        assert_eq!(background.last_guess(), "KALAST");
+    }
 }
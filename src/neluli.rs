@@ -1,23 +1,42 @@
-use rand::seq::SliceRandom;
-use std::collections::HashMap;
 use std::rc::Rc;
 
-use gloo_storage::{errors::StorageError, LocalStorage, Storage};
+use chrono::NaiveDate;
+use gloo_storage::errors::StorageError;
 use serde::{Deserialize, Serialize};
 
-use crate::game::{Board, Game, DEFAULT_ALLOW_PROFANITIES, DEFAULT_WORD_LENGTH, SUCCESS_EMOJIS};
-use crate::manager::{GameMode, KeyState, Theme, TileState, WordList, WordLists};
+use crate::brand::BRAND;
+use crate::engine::Points;
+use crate::game::{
+    self, Board, Game, Knowledge, DEFAULT_ALLOW_PROFANITIES, DEFAULT_WORD_LENGTH, SUCCESS_EMOJIS,
+};
+use crate::i18n::{t, tf};
+use crate::manager::{
+    GameMode, KeyState, KeyboardHints, Theme, TileState, WordList, WordLists, DOUBLE_DAILY_BOARDS,
+};
 use crate::sanuli::Sanuli;
+use crate::share::ShareResult;
+use crate::storage::{self, Transaction};
 
-const MAX_GUESSES: usize = 9;
+/// The most guesses of any board, used to bound the replays from links
+pub const MAX_GUESSES: usize = 9;
+const QUADRUPLE_BOARDS: usize = 4;
 
+fn quadruple() -> GameMode {
+    GameMode::Quadruple
+}
+
+/// Several words guessed at once, four random ones or the two of a double daily
 #[derive(Clone, PartialEq, Serialize, Deserialize)]
 pub struct Neluli {
+    #[serde(default = "quadruple")]
+    game_mode: GameMode,
     word_list: WordList,
     word_length: usize,
     boards: Vec<Sanuli>,
     streak: usize,
     message: String,
+    #[serde(default)]
+    points: Option<Points>,
 
     #[serde(skip)]
     allow_profanities: bool,
@@ -28,57 +47,44 @@ pub struct Neluli {
 impl Default for Neluli {
     fn default() -> Self {
         Neluli::new(
+            GameMode::Quadruple,
             WordList::default(),
             DEFAULT_WORD_LENGTH,
             DEFAULT_ALLOW_PROFANITIES,
-            Rc::new(HashMap::new()),
+            Rc::new(WordLists::default()),
         )
     }
 }
 
 impl Neluli {
     pub fn new(
+        game_mode: GameMode,
         word_list: WordList,
         word_length: usize,
         allow_profanities: bool,
         word_lists: Rc<WordLists>,
     ) -> Self {
-        let boards = vec![
-            Sanuli::new(
-                GameMode::Quadruple,
-                word_list,
-                word_length,
-                MAX_GUESSES,
-                allow_profanities,
-                word_lists.clone(),
-            ),
-            Sanuli::new(
-                GameMode::Quadruple,
-                word_list,
-                word_length,
-                MAX_GUESSES,
-                allow_profanities,
-                word_lists.clone(),
-            ),
-            Sanuli::new(
-                GameMode::Quadruple,
-                word_list,
-                word_length,
-                MAX_GUESSES,
-                allow_profanities,
-                word_lists.clone(),
-            ),
-            Sanuli::new(
-                GameMode::Quadruple,
-                word_list,
-                word_length,
-                MAX_GUESSES,
-                allow_profanities,
-                word_lists.clone(),
-            ),
-        ];
+        let board_count = match game_mode {
+            GameMode::DoubleDaily(_) => DOUBLE_DAILY_BOARDS,
+            _ => QUADRUPLE_BOARDS,
+        };
+
+        let boards = (0..board_count)
+            .map(|board| {
+                Sanuli::new_board(
+                    game_mode,
+                    word_list,
+                    word_length,
+                    MAX_GUESSES,
+                    allow_profanities,
+                    word_lists.clone(),
+                    board,
+                )
+            })
+            .collect();
 
         Self {
+            game_mode,
             word_list,
             word_length,
 
@@ -86,6 +92,7 @@ impl Neluli {
             streak: 0,
 
             message: String::new(),
+            points: None,
 
             allow_profanities: DEFAULT_ALLOW_PROFANITIES,
             word_lists,
@@ -93,12 +100,14 @@ impl Neluli {
     }
 
     pub fn new_or_rehydrate(
+        game_mode: GameMode,
         word_list: WordList,
         word_length: usize,
         allow_profanities: bool,
         word_lists: Rc<WordLists>,
     ) -> Self {
         if let Ok(game) = Self::rehydrate(
+            game_mode,
             word_list,
             word_length,
             allow_profanities,
@@ -106,24 +115,26 @@ impl Neluli {
         ) {
             game
         } else {
-            Self::new(word_list, word_length, allow_profanities, word_lists)
+            Self::new(
+                game_mode,
+                word_list,
+                word_length,
+                allow_profanities,
+                word_lists,
+            )
         }
     }
 
     fn rehydrate(
+        game_mode: GameMode,
         word_list: WordList,
         word_length: usize,
         allow_profanities: bool,
         word_lists: Rc<WordLists>,
     ) -> Result<Self, StorageError> {
-        let game_key = &format!(
-            "game|{}|{}|{}",
-            serde_json::to_string(&GameMode::Quadruple).unwrap(),
-            serde_json::to_string(&word_list).unwrap(),
-            word_length
-        );
+        let game_key = &game::game_key(&game_mode, &word_list, word_length, word_lists.language);
 
-        let mut game: Self = LocalStorage::get(game_key)?;
+        let mut game: Self = storage::get(game_key)?;
 
         for board in game.boards.iter_mut() {
             board.set_word_lists(word_lists.clone());
@@ -142,15 +153,67 @@ impl Neluli {
         self.boards.iter().all(|board| !board.is_guessing())
     }
 
+    fn guess_count(&self) -> String {
+        if self.is_winner() {
+            let guesses = self
+                .boards
+                .iter()
+                .map(|board| board.boards()[0].current_guess + 1)
+                .max();
+            format!("{}", guesses.unwrap_or_default())
+        } else {
+            "X".to_owned()
+        }
+    }
+
+    /// The boards side by side, the rows after a solved word left blank
+    fn share_double_daily(&self, date: NaiveDate, theme: Theme) -> ShareResult {
+        let mut body = String::new();
+
+        let boards = self.boards();
+        let rows = boards
+            .iter()
+            .map(|board| board.current_guess + 1)
+            .max()
+            .unwrap_or(0);
+        for row in 0..rows {
+            let line = boards
+                .iter()
+                .map(|board| {
+                    if row <= board.current_guess {
+                        board.guesses[row]
+                            .iter()
+                            .map(|(_, state)| game::share_tile(state, theme))
+                            .collect::<String>()
+                    } else {
+                        "\u{3000}".repeat(self.word_length)
+                    }
+                })
+                .collect::<Vec<_>>();
+
+            body += line.join(" ").trim_end();
+            body += "\n";
+        }
+
+        ShareResult {
+            title: format!("{} #{}", BRAND.name, Sanuli::get_daily_word_index(date) + 1),
+            mode: Some("×2".to_owned()),
+            score: format!("{}/{}", self.guess_count(), MAX_GUESSES),
+            word_length: self.word_length,
+            is_hard_mode: false,
+            body,
+        }
+    }
+
     fn clear_message(&mut self) {
         self.message = String::new();
     }
 
     fn set_game_end_message(&mut self) {
         if self.is_winner() {
-            self.message = format!(
+            self.message = tf(
                 "Löysit sanulit! {}",
-                SUCCESS_EMOJIS.choose(&mut rand::thread_rng()).unwrap()
+                &[self.word_lists.rng.choose(&SUCCESS_EMOJIS).unwrap()],
             );
         } else {
             let words: Vec<_> = self
@@ -159,14 +222,14 @@ impl Neluli {
                 .filter(|game| !game.is_winner())
                 .map(|game| game.word().iter().collect::<String>())
                 .collect();
-            self.message = format!("Löytämättä jäi: \"{}\"", words.join("\", \""));
+            self.message = tf("Löytämättä jäi: \"{}\"", &[&words.join("\", \"")]);
         }
     }
 }
 
 impl Game for Neluli {
     fn game_mode(&self) -> &GameMode {
-        &GameMode::Quadruple
+        &self.game_mode
     }
     fn word_list(&self) -> &WordList {
         &self.word_list
@@ -183,6 +246,9 @@ impl Game for Neluli {
     fn word(&self) -> Vec<char> {
         Vec::new()
     }
+    fn words(&self) -> Vec<Vec<char>> {
+        self.boards.iter().map(|board| board.word()).collect()
+    }
 
     fn streak(&self) -> usize {
         self.streak
@@ -206,9 +272,26 @@ impl Game for Neluli {
     fn is_unknown(&self) -> bool {
         false
     }
+    fn is_undo_allowed(&self) -> bool {
+        false
+    }
+    fn deadline(&self) -> Option<i64> {
+        None
+    }
+    // A double daily has its two words only
+    fn has_next_word(&self) -> bool {
+        self.game_mode == GameMode::Quadruple
+    }
     fn message(&self) -> String {
         self.message.clone()
     }
+    fn knowledge(&self) -> Vec<Knowledge> {
+        self.boards
+            .iter()
+            .flat_map(|board| board.knowledge())
+            .collect()
+    }
+
     fn previous_guesses(&self) -> Vec<Vec<(char, TileState)>> {
         Vec::new()
     }
@@ -218,57 +301,64 @@ impl Game for Neluli {
     }
 
     fn title(&self) -> String {
-        if self.streak > 0 {
-            format!("Neluli — Putki: {}", self.streak)
+        if let GameMode::DoubleDaily(date) = self.game_mode {
+            tf(
+                "Tuplasanuli #{}",
+                &[&(Sanuli::get_daily_word_index(date) + 1)],
+            )
+        } else if self.streak > 0 {
+            tf("Neluli — Putki: {}", &[&self.streak])
         } else {
             "Neluli".to_owned()
         }
     }
 
     fn next_word(&mut self) {
+        if !self.has_next_word() {
+            return;
+        }
+
         for board in self.boards.iter_mut() {
             board.next_word();
         }
         self.clear_message();
+        self.points = None;
 
         let _res = self.persist();
     }
 
-    fn keyboard_tilestate(&self, key: &char) -> KeyState {
-        KeyState::Quadruple([
-            if let KeyState::Single(state) = self.boards[0].keyboard_tilestate(key) {
-                state
-            } else {
-                TileState::Unknown
-            },
-            if let KeyState::Single(state) = self.boards[1].keyboard_tilestate(key) {
-                state
-            } else {
-                TileState::Unknown
-            },
-            if let KeyState::Single(state) = self.boards[2].keyboard_tilestate(key) {
-                state
-            } else {
-                TileState::Unknown
-            },
-            if let KeyState::Single(state) = self.boards[3].keyboard_tilestate(key) {
-                state
-            } else {
-                TileState::Unknown
-            },
-        ])
+    fn keyboard_tilestate(&self, key: &char, hints: KeyboardHints) -> KeyState {
+        let states = self
+            .boards
+            .iter()
+            .map(|board| {
+                if let KeyState::Single(state) = board.keyboard_tilestate(key, hints) {
+                    state
+                } else {
+                    TileState::Unknown
+                }
+            })
+            .collect::<Vec<_>>();
+
+        match states[..] {
+            [left, right] => KeyState::Double([left, right]),
+            [top_left, top_right, bottom_left, bottom_right] => {
+                KeyState::Quadruple([top_left, top_right, bottom_left, bottom_right])
+            }
+            _ => KeyState::Single(TileState::Unknown),
+        }
     }
 
     fn submit_guess(&mut self) {
         for board in self.boards.iter_mut() {
             if board.is_guessing() {
                 if !board.is_guess_correct_length() {
-                    self.message = "Liian vähän kirjaimia!".to_owned();
+                    self.message = t("Liian vähän kirjaimia!").to_owned();
                     return;
                 }
 
                 if !board.is_guess_accepted_word() {
-                    self.message = "Ei sanulistalla.".to_owned();
+                    self.message = board.unknown_word_message().to_owned();
                     return;
                 }
 
@@ -287,8 +377,6 @@ impl Game for Neluli {
         } else {
             self.clear_message();
         }
-
-        let _res = self.persist();
     }
 
     fn push_character(&mut self, character: char) {
@@ -315,8 +403,25 @@ impl Game for Neluli {
         }
     }
 
-    fn share_emojis(&self, _theme: Theme) -> Option<String> {
-        unimplemented!()
+    fn cursor(&self) -> usize {
+        // The cursor moves in sync on all of the unsolved boards
+        self.boards
+            .iter()
+            .find(|board| board.is_guessing())
+            .map_or(0, |board| board.cursor())
+    }
+
+    fn move_cursor(&mut self, position: usize) {
+        for board in self.boards.iter_mut() {
+            board.move_cursor(position);
+        }
+    }
+
+    fn share_result(&self, theme: Theme) -> Option<ShareResult> {
+        match self.game_mode {
+            GameMode::DoubleDaily(date) => Some(self.share_double_daily(date, theme)),
+            _ => None,
+        }
     }
 
     fn share_link(&self) -> Option<String> {
@@ -327,6 +432,18 @@ impl Game for Neluli {
         unimplemented!()
     }
 
+    fn undo_guess(&mut self) {}
+
+    fn points(&self) -> Option<Points> {
+        self.points
+    }
+
+    fn set_points(&mut self, points: Points) {
+        self.points = Some(points);
+    }
+
+    fn expire_row(&mut self) {}
+
     fn reset(&mut self) {
         unimplemented!()
     }
@@ -337,14 +454,14 @@ impl Game for Neluli {
         }
     }
 
-    fn persist(&self) -> Result<(), StorageError> {
-        let game_key = &format!(
-            "game|{}|{}|{}",
-            serde_json::to_string(&GameMode::Quadruple).unwrap(),
-            serde_json::to_string(&self.word_list).unwrap(),
-            self.word_length
+    fn persist_with(&self, transaction: &mut Transaction) -> Result<(), StorageError> {
+        let game_key = &game::game_key(
+            &self.game_mode,
+            &self.word_list,
+            self.word_length,
+            self.word_lists.language,
         );
 
-        LocalStorage::set(game_key, self)
+        transaction.set(game_key, self)
     }
 }
@@ -0,0 +1,49 @@
+use wasm_bindgen::{prelude::Closure, JsCast, JsValue};
+use wasm_bindgen_futures::JsFuture;
+use web_sys::{window, Notification, NotificationOptions, NotificationPermission};
+
+pub fn is_supported() -> bool {
+    window()
+        .and_then(|window| js_sys::Reflect::has(&window, &JsValue::from_str("Notification")).ok())
+        .unwrap_or(false)
+}
+
+pub fn is_permitted() -> bool {
+    is_supported() && Notification::permission() == NotificationPermission::Granted
+}
+
+/// Asks the player for the permission, resolving to whether it was granted
+pub async fn request_permission() -> bool {
+    if !is_supported() {
+        return false;
+    }
+
+    let promise = match Notification::request_permission() {
+        Ok(promise) => promise,
+        Err(_) => return false,
+    };
+
+    JsFuture::from(promise)
+        .await
+        .ok()
+        .and_then(|permission| permission.as_string())
+        .as_deref()
+        == Some("granted")
+}
+
+/// Shows a notification bringing the game back to the front when clicked
+pub fn show(title: &str, body: &str) {
+    let options = NotificationOptions::new();
+    options.set_body(body);
+    // Replaces the earlier notification of the game instead of piling up
+    options.set_tag("sanuli");
+
+    if let Ok(notification) = Notification::new_with_options(title, &options) {
+        let onclick = Closure::once_into_js(move || {
+            if let Some(window) = window() {
+                let _result = window.focus();
+            }
+        });
+        notification.set_onclick(Some(onclick.as_ref().unchecked_ref()));
+    }
+}
@@ -0,0 +1,529 @@
+// A minimal QR code encoder for challenge links: byte mode, error correction
+// level L and versions 1-10, which fits links of up to 271 bytes.
+
+const MAX_VERSION: usize = 10;
+
+// Error correction codewords per block and the number of blocks at level L,
+// indexed by version
+const ECC_CODEWORDS_PER_BLOCK: [usize; MAX_VERSION + 1] =
+    [0, 7, 10, 15, 20, 26, 18, 20, 24, 30, 18];
+const NUM_ERROR_CORRECTION_BLOCKS: [usize; MAX_VERSION + 1] = [0, 1, 1, 1, 1, 1, 2, 2, 2, 2, 4];
+
+// Format bits of error correction level L
+const ECC_LEVEL_L: u32 = 1;
+
+const PENALTY_N1: i32 = 3;
+const PENALTY_N2: i32 = 3;
+const PENALTY_N3: i32 = 40;
+const PENALTY_N4: i32 = 10;
+
+pub struct QrCode {
+    size: usize,
+    modules: Vec<bool>,
+    is_function: Vec<bool>,
+}
+
+impl QrCode {
+    /// Encodes the text with the smallest version it fits in
+    pub fn encode(text: &str) -> Option<Self> {
+        let bytes = text.as_bytes();
+        let version = (1..=MAX_VERSION).find(|version| {
+            let count_bits = if *version < 10 { 8 } else { 16 };
+            4 + count_bits + bytes.len() * 8 <= data_codewords(*version) * 8
+        })?;
+
+        let data = encode_data(bytes, version);
+        let codewords = add_error_correction(&data, version);
+
+        let size = version * 4 + 17;
+        let mut qr = Self {
+            size,
+            modules: vec![false; size * size],
+            is_function: vec![false; size * size],
+        };
+
+        qr.draw_function_patterns(version);
+        qr.draw_codewords(&codewords);
+
+        let mask = (0..8)
+            .min_by_key(|mask| {
+                qr.apply_mask(*mask);
+                qr.draw_format_bits(*mask);
+                let penalty = qr.penalty_score();
+                qr.apply_mask(*mask);
+                penalty
+            })
+            .unwrap_or(0);
+
+        qr.apply_mask(mask);
+        qr.draw_format_bits(mask);
+
+        Some(qr)
+    }
+
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    pub fn is_dark(&self, x: usize, y: usize) -> bool {
+        self.modules[y * self.size + x]
+    }
+
+    /// SVG path drawing every dark module as a unit square
+    pub fn svg_path(&self) -> String {
+        let mut path = String::new();
+        for y in 0..self.size {
+            for x in 0..self.size {
+                if self.is_dark(x, y) {
+                    path += &format!("M{},{}h1v1h-1z", x, y);
+                }
+            }
+        }
+        path
+    }
+
+    fn set_function(&mut self, x: usize, y: usize, is_dark: bool) {
+        self.modules[y * self.size + x] = is_dark;
+        self.is_function[y * self.size + x] = true;
+    }
+
+    fn draw_function_patterns(&mut self, version: usize) {
+        let size = self.size;
+
+        for i in 0..size {
+            self.set_function(6, i, i % 2 == 0);
+            self.set_function(i, 6, i % 2 == 0);
+        }
+
+        self.draw_finder_pattern(3, 3);
+        self.draw_finder_pattern(size - 4, 3);
+        self.draw_finder_pattern(3, size - 4);
+
+        let positions = alignment_pattern_positions(version);
+        let last = positions.len().saturating_sub(1);
+        for (i, x) in positions.iter().enumerate() {
+            for (j, y) in positions.iter().enumerate() {
+                // Skip the corners overlapping with the finder patterns
+                let is_finder_corner = (i == 0 && (j == 0 || j == last)) || (i == last && j == 0);
+                if !is_finder_corner {
+                    self.draw_alignment_pattern(*x, *y);
+                }
+            }
+        }
+
+        // Reserve the format bit areas, they are drawn after masking
+        self.draw_format_bits(0);
+
+        if version >= 7 {
+            let mut remainder = version as u32;
+            for _ in 0..12 {
+                remainder = (remainder << 1) ^ ((remainder >> 11) * 0x1f25);
+            }
+            let bits = (version as u32) << 12 | remainder;
+
+            for i in 0..18 {
+                let is_dark = (bits >> i) & 1 != 0;
+                let a = size - 11 + i % 3;
+                let b = i / 3;
+                self.set_function(a, b, is_dark);
+                self.set_function(b, a, is_dark);
+            }
+        }
+    }
+
+    fn draw_finder_pattern(&mut self, x: usize, y: usize) {
+        for dy in -4i32..=4 {
+            for dx in -4i32..=4 {
+                let xx = x as i32 + dx;
+                let yy = y as i32 + dy;
+                if (0..self.size as i32).contains(&xx) && (0..self.size as i32).contains(&yy) {
+                    let distance = dx.abs().max(dy.abs());
+                    self.set_function(xx as usize, yy as usize, distance != 2 && distance != 4);
+                }
+            }
+        }
+    }
+
+    fn draw_alignment_pattern(&mut self, x: usize, y: usize) {
+        for dy in -2i32..=2 {
+            for dx in -2i32..=2 {
+                let distance = dx.abs().max(dy.abs());
+                self.set_function(
+                    (x as i32 + dx) as usize,
+                    (y as i32 + dy) as usize,
+                    distance != 1,
+                );
+            }
+        }
+    }
+
+    fn draw_format_bits(&mut self, mask: u32) {
+        let data = ECC_LEVEL_L << 3 | mask;
+        let mut remainder = data;
+        for _ in 0..10 {
+            remainder = (remainder << 1) ^ ((remainder >> 9) * 0x537);
+        }
+        let bits = (data << 10 | remainder) ^ 0x5412;
+        let bit = |i: usize| (bits >> i) & 1 != 0;
+
+        let size = self.size;
+
+        for i in 0..=5 {
+            self.set_function(8, i, bit(i));
+        }
+        self.set_function(8, 7, bit(6));
+        self.set_function(8, 8, bit(7));
+        self.set_function(7, 8, bit(8));
+        for i in 9..15 {
+            self.set_function(14 - i, 8, bit(i));
+        }
+
+        for i in 0..8 {
+            self.set_function(size - 1 - i, 8, bit(i));
+        }
+        for i in 8..15 {
+            self.set_function(8, size - 15 + i, bit(i));
+        }
+        self.set_function(8, size - 8, true);
+    }
+
+    fn draw_codewords(&mut self, codewords: &[u8]) {
+        let size = self.size;
+        let total_bits = codewords.len() * 8;
+        let mut i = 0;
+
+        // Zigzag in two module wide columns from the bottom right corner,
+        // skipping the vertical timing pattern
+        let mut right = size as i32 - 1;
+        while right >= 1 {
+            if right == 6 {
+                right = 5;
+            }
+            let is_upward = (right + 1) & 2 == 0;
+
+            for vertical in 0..size {
+                for j in 0..2 {
+                    let x = (right - j) as usize;
+                    let y = if is_upward {
+                        size - 1 - vertical
+                    } else {
+                        vertical
+                    };
+
+                    if !self.is_function[y * size + x] && i < total_bits {
+                        self.modules[y * size + x] = (codewords[i >> 3] >> (7 - (i & 7))) & 1 != 0;
+                        i += 1;
+                    }
+                }
+            }
+
+            right -= 2;
+        }
+    }
+
+    fn apply_mask(&mut self, mask: u32) {
+        for y in 0..self.size {
+            for x in 0..self.size {
+                let invert = match mask {
+                    0 => (x + y) % 2 == 0,
+                    1 => y % 2 == 0,
+                    2 => x % 3 == 0,
+                    3 => (x + y) % 3 == 0,
+                    4 => (x / 3 + y / 2) % 2 == 0,
+                    5 => x * y % 2 + x * y % 3 == 0,
+                    6 => (x * y % 2 + x * y % 3) % 2 == 0,
+                    _ => ((x + y) % 2 + x * y % 3) % 2 == 0,
+                };
+
+                let index = y * self.size + x;
+                if invert && !self.is_function[index] {
+                    self.modules[index] = !self.modules[index];
+                }
+            }
+        }
+    }
+
+    fn penalty_score(&self) -> i32 {
+        let size = self.size;
+        let mut penalty = 0;
+
+        let lines = (0..size).flat_map(|i| {
+            [
+                (0..size).map(|j| self.is_dark(j, i)).collect::<Vec<bool>>(),
+                (0..size).map(|j| self.is_dark(i, j)).collect::<Vec<bool>>(),
+            ]
+        });
+
+        for line in lines {
+            // Runs of five or more modules of the same color
+            let mut run = 1;
+            for j in 1..=size {
+                if j < size && line[j] == line[j - 1] {
+                    run += 1;
+                } else {
+                    if run >= 5 {
+                        penalty += PENALTY_N1 + run - 5;
+                    }
+                    run = 1;
+                }
+            }
+
+            // Patterns resembling the finder patterns, surrounded by the light quiet zone
+            let mut padded = vec![false; 4];
+            padded.extend(line.iter().copied());
+            padded.extend([false; 4]);
+            for window in padded.windows(11) {
+                let core = [true, false, true, true, true, false, true];
+                if window[4..11] == core && window[..4].iter().all(|m| !m) {
+                    penalty += PENALTY_N3;
+                }
+                if window[..7] == core && window[7..].iter().all(|m| !m) {
+                    penalty += PENALTY_N3;
+                }
+            }
+        }
+
+        for y in 0..size - 1 {
+            for x in 0..size - 1 {
+                let color = self.is_dark(x, y);
+                if color == self.is_dark(x + 1, y)
+                    && color == self.is_dark(x, y + 1)
+                    && color == self.is_dark(x + 1, y + 1)
+                {
+                    penalty += PENALTY_N2;
+                }
+            }
+        }
+
+        let total = (size * size) as i32;
+        let dark = self.modules.iter().filter(|m| **m).count() as i32;
+        let k = ((dark * 20 - total * 10).abs() + total - 1) / total - 1;
+        penalty += k * PENALTY_N4;
+
+        penalty
+    }
+}
+
+fn raw_data_modules(version: usize) -> usize {
+    let mut result = (16 * version + 128) * version + 64;
+    if version >= 2 {
+        let alignments = version / 7 + 2;
+        result -= (25 * alignments - 10) * alignments - 55;
+        if version >= 7 {
+            result -= 36;
+        }
+    }
+    result
+}
+
+fn data_codewords(version: usize) -> usize {
+    raw_data_modules(version) / 8
+        - ECC_CODEWORDS_PER_BLOCK[version] * NUM_ERROR_CORRECTION_BLOCKS[version]
+}
+
+fn alignment_pattern_positions(version: usize) -> Vec<usize> {
+    if version == 1 {
+        return Vec::new();
+    }
+
+    let size = version * 4 + 17;
+    let alignments = version / 7 + 2;
+    let step = (version * 4 + alignments * 2 + 1) / (alignments * 2 - 2) * 2;
+
+    let mut positions = vec![6];
+    let mut position = size - 7;
+    for _ in 0..alignments - 1 {
+        positions.insert(1, position);
+        position -= step;
+    }
+    positions
+}
+
+fn encode_data(bytes: &[u8], version: usize) -> Vec<u8> {
+    let capacity = data_codewords(version) * 8;
+    let count_bits = if version < 10 { 8 } else { 16 };
+
+    let mut bits: Vec<bool> = Vec::with_capacity(capacity);
+    let mut push = |value: u32, length: usize| {
+        for i in (0..length).rev() {
+            bits.push((value >> i) & 1 != 0);
+        }
+    };
+
+    // Byte mode
+    push(0b0100, 4);
+    push(bytes.len() as u32, count_bits);
+    for byte in bytes {
+        push(*byte as u32, 8);
+    }
+
+    let terminator = (capacity - bits.len()).min(4);
+    bits.resize(bits.len() + terminator, false);
+    while !bits.len().is_multiple_of(8) {
+        bits.push(false);
+    }
+
+    let mut data = bits
+        .chunks(8)
+        .map(|chunk| chunk.iter().fold(0u8, |byte, bit| byte << 1 | *bit as u8))
+        .collect::<Vec<u8>>();
+
+    for pad in [0xec, 0x11].iter().cycle() {
+        if data.len() >= capacity / 8 {
+            break;
+        }
+        data.push(*pad);
+    }
+
+    data
+}
+
+fn add_error_correction(data: &[u8], version: usize) -> Vec<u8> {
+    let num_blocks = NUM_ERROR_CORRECTION_BLOCKS[version];
+    let ecc_length = ECC_CODEWORDS_PER_BLOCK[version];
+    let raw_codewords = raw_data_modules(version) / 8;
+    let num_short_blocks = num_blocks - raw_codewords % num_blocks;
+    let short_block_length = raw_codewords / num_blocks;
+
+    let divisor = reed_solomon_divisor(ecc_length);
+
+    let mut blocks: Vec<Vec<u8>> = Vec::with_capacity(num_blocks);
+    let mut offset = 0;
+    for i in 0..num_blocks {
+        let data_length =
+            short_block_length - ecc_length + if i < num_short_blocks { 0 } else { 1 };
+        let mut block = data[offset..offset + data_length].to_vec();
+        offset += data_length;
+
+        let ecc = reed_solomon_remainder(&block, &divisor);
+        // Pad the short blocks so that the interleaving below can skip over the gap
+        if i < num_short_blocks {
+            block.push(0);
+        }
+        block.extend(ecc);
+        blocks.push(block);
+    }
+
+    let mut result = Vec::with_capacity(raw_codewords);
+    for i in 0..=short_block_length {
+        for (j, block) in blocks.iter().enumerate() {
+            if i != short_block_length - ecc_length || j >= num_short_blocks {
+                result.push(block[i]);
+            }
+        }
+    }
+    result
+}
+
+fn reed_solomon_divisor(degree: usize) -> Vec<u8> {
+    let mut result = vec![0u8; degree];
+    result[degree - 1] = 1;
+
+    let mut root = 1u8;
+    for _ in 0..degree {
+        for j in 0..degree {
+            result[j] = gf_multiply(result[j], root);
+            if j + 1 < degree {
+                result[j] ^= result[j + 1];
+            }
+        }
+        root = gf_multiply(root, 0x02);
+    }
+    result
+}
+
+fn reed_solomon_remainder(data: &[u8], divisor: &[u8]) -> Vec<u8> {
+    let mut result = vec![0u8; divisor.len()];
+    for byte in data {
+        let factor = byte ^ result.remove(0);
+        result.push(0);
+        for (coefficient, divisor) in result.iter_mut().zip(divisor) {
+            *coefficient ^= gf_multiply(*divisor, factor);
+        }
+    }
+    result
+}
+
+// Multiplication in GF(2^8) modulo x^8 + x^4 + x^3 + x^2 + 1
+fn gf_multiply(x: u8, y: u8) -> u8 {
+    let mut z: u32 = 0;
+    for i in (0..8).rev() {
+        z = (z << 1) ^ ((z >> 7) * 0x11d);
+        z ^= ((y as u32 >> i) & 1) * x as u32;
+    }
+    z as u8
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Format bits of level L with the masks 0-7, from the table of the standard
+    const FORMAT_BITS_L: [u32; 8] = [
+        0x77c4, 0x72f3, 0x7daa, 0x789d, 0x662f, 0x6318, 0x6c41, 0x6976,
+    ];
+
+    #[test]
+    fn error_correction_matches_the_hello_world_example() {
+        // "HELLO WORLD" at version 1-M, as worked through in the common QR code tutorials
+        let data = [
+            32, 91, 11, 120, 209, 114, 220, 77, 67, 64, 236, 17, 236, 17, 236, 17,
+        ];
+
+        assert_eq!(
+            reed_solomon_remainder(&data, &reed_solomon_divisor(10)),
+            [196, 35, 39, 119, 235, 215, 231, 226, 93, 23]
+        );
+    }
+
+    #[test]
+    fn short_text_is_encoded_at_version_1_level_l() {
+        let data = encode_data(b"SANULI", 1);
+        assert_eq!(
+            data,
+            [
+                64, 101, 52, 20, 229, 84, 196, 144, 236, 17, 236, 17, 236, 17, 236, 17, 236, 17,
+                236
+            ]
+        );
+        assert_eq!(
+            add_error_correction(&data, 1)[data.len()..],
+            [40, 235, 166, 149, 49, 12, 60]
+        );
+    }
+
+    fn read_bits(qr: &QrCode, positions: impl Iterator<Item = (usize, usize)>) -> u32 {
+        positions
+            .enumerate()
+            .fold(0, |bits, (i, (x, y))| bits | (qr.is_dark(x, y) as u32) << i)
+    }
+
+    #[test]
+    fn symbol_carries_the_format_bits_of_level_l() {
+        let qr = QrCode::encode("SANULI").unwrap();
+        let size = qr.size();
+        assert_eq!(size, 21);
+
+        let top_left = [
+            (8, 0),
+            (8, 1),
+            (8, 2),
+            (8, 3),
+            (8, 4),
+            (8, 5),
+            (8, 7),
+            (8, 8),
+            (7, 8),
+        ]
+        .into_iter()
+        .chain((9..15).map(|i| (14 - i, 8)));
+        let split = (0..8)
+            .map(|i| (size - 1 - i, 8))
+            .chain((8..15).map(|i| (8, size - 15 + i)));
+
+        let format_bits = read_bits(&qr, top_left);
+        assert!(FORMAT_BITS_L.contains(&format_bits));
+        assert_eq!(read_bits(&qr, split), format_bits);
+        assert!(qr.is_dark(8, size - 8));
+    }
+}
@@ -0,0 +1,93 @@
+use serde::{Deserialize, Serialize};
+use yew::Callback;
+
+use crate::manager::TileState;
+use crate::transport::{LoopbackTransport, Transport, WebSocketTransport};
+
+// Relay server of the race mode, passing the messages between the two players of a room. Left
+// unset, the race mode stays hidden in release builds and the development builds race alone
+// against a local stand-in: `SANULI_RACE_URL=wss://race.example.com trunk build`
+const RACE_URL: Option<&str> = option_env!("SANULI_RACE_URL");
+
+/// Messages relayed between the players of a room. The server answers the `Join` messages with
+/// the same `Start` to both players once the room is full, and passes everything else on to the
+/// opponent as is.
+#[derive(Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum RaceMessage {
+    Join { room: String },
+    Start { seed: u64 },
+    // Colors of the revealed rows, the letters are never sent
+    Progress { patterns: Vec<Vec<TileState>> },
+    Solved,
+    Left,
+}
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum RaceStatus {
+    Waiting,
+    Racing,
+    Won,
+    Lost,
+}
+
+pub fn is_configured() -> bool {
+    RACE_URL.is_some() || cfg!(debug_assertions)
+}
+
+/// Connection to a race room, left when dropped
+pub struct Race {
+    pub room: String,
+    pub status: RaceStatus,
+    pub opponent: Vec<Vec<TileState>>,
+    transport: Box<dyn Transport<RaceMessage>>,
+}
+
+impl Race {
+    /// Joins the room. The messages of the server are passed to `onmessage`, and `onclose` is
+    /// called if the connection is lost.
+    pub fn join(
+        room: &str,
+        onmessage: Callback<RaceMessage>,
+        onclose: Callback<()>,
+    ) -> Option<Self> {
+        let transport: Box<dyn Transport<RaceMessage>> = match RACE_URL {
+            Some(url) => Box::new(WebSocketTransport::connect(url, onmessage, onclose)?),
+            None if cfg!(debug_assertions) => {
+                Box::new(LoopbackTransport::new(solo_race, onmessage))
+            }
+            None => return None,
+        };
+
+        transport.send(&RaceMessage::Join {
+            room: room.to_owned(),
+        });
+
+        Some(Self {
+            room: room.to_owned(),
+            status: RaceStatus::Waiting,
+            opponent: Vec::new(),
+            transport,
+        })
+    }
+
+    pub fn send(&self, message: &RaceMessage) {
+        self.transport.send(message);
+    }
+}
+
+impl Drop for Race {
+    fn drop(&mut self) {
+        self.send(&RaceMessage::Left);
+    }
+}
+
+/// Server of the local development builds, starting the race at once against an idle opponent
+fn solo_race(message: &RaceMessage) -> Vec<RaceMessage> {
+    match message {
+        RaceMessage::Join { .. } => vec![RaceMessage::Start {
+            seed: rand::random(),
+        }],
+        _ => Vec::new(),
+    }
+}
@@ -0,0 +1,76 @@
+use std::panic;
+
+use gloo_storage::{LocalStorage, Storage};
+use wasm_bindgen::{prelude::Closure, JsCast};
+use web_sys::{window, HtmlElement};
+
+use crate::i18n::t;
+
+/// Logs the panics to the console and replaces the frozen app with a recovery screen, so that
+/// the player is never left stuck with an app that no longer responds
+pub fn install_panic_hook() {
+    panic::set_hook(Box::new(|info| {
+        console_error_panic_hook::hook(info);
+        show_recovery_screen();
+    }));
+}
+
+fn show_recovery_screen() {
+    let document = match window().and_then(|window| window.document()) {
+        Some(document) => document,
+        None => return,
+    };
+    let body = match document.body() {
+        Some(body) => body,
+        None => return,
+    };
+
+    body.set_inner_html(&format!(
+        r#"<div class="recovery" role="alert">
+            <h1 class="title">{}</h1>
+            <p>{}</p>
+            <button id="recovery-restore" class="select select-active">{}</button>
+            <p>{}</p>
+            <button id="recovery-reset" class="select">{}</button>
+        </div>"#,
+        t("Hups, jotain meni pieleen"),
+        t("Sanuli kohtasi odottamattoman virheen. Tallennettu peli ja tilastot ovat yleensä tallessa, ja sivun lataaminen uudelleen palauttaa ne."),
+        t("Yritä palauttaa tallennettu peli"),
+        t("Jos virhe toistuu, voit aloittaa alusta. Tämä poistaa kaikki pelit, tilastot ja asetukset."),
+        t("Tyhjennä kaikki tiedot"),
+    ));
+
+    on_click(&document, "recovery-restore", reload);
+    on_click(&document, "recovery-reset", || {
+        let is_confirmed = window()
+            .and_then(|window| {
+                window
+                    .confirm_with_message(t("Poistetaanko kaikki tiedot pysyvästi?"))
+                    .ok()
+            })
+            .unwrap_or(false);
+
+        if is_confirmed {
+            LocalStorage::clear();
+            reload();
+        }
+    });
+}
+
+fn on_click(document: &web_sys::Document, id: &str, callback: impl Fn() + 'static) {
+    if let Some(button) = document
+        .get_element_by_id(id)
+        .and_then(|element| element.dyn_into::<HtmlElement>().ok())
+    {
+        let onclick = Closure::wrap(Box::new(callback) as Box<dyn Fn()>);
+        button.set_onclick(Some(onclick.as_ref().unchecked_ref()));
+        // The app is gone, the screen lives until the page is reloaded
+        onclick.forget();
+    }
+}
+
+fn reload() {
+    if let Some(window) = window() {
+        let _result = window.location().reload();
+    }
+}
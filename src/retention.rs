@@ -0,0 +1,47 @@
+//! How long the per-day detail of the dated modes is kept. Every daily, evening, double daily
+//! and weekly puzzle is stored under a key of its own, so without pruning the keys pile up until
+//! the storage quota runs out. The statistics are kept forever, but the stored games and the
+//! guesses of the history entries older than a year are dropped.
+
+use chrono::{Duration, NaiveDate};
+
+use crate::manager::GameMode;
+use crate::storage;
+
+const RETENTION_DAYS: i64 = 365;
+
+/// The first date whose detail is kept
+pub fn cutoff(today: NaiveDate) -> NaiveDate {
+    today - Duration::days(RETENTION_DAYS)
+}
+
+fn puzzle_date(game_mode: &GameMode) -> Option<NaiveDate> {
+    match game_mode {
+        GameMode::DailyWord(date)
+        | GameMode::EveningWord(date)
+        | GameMode::DoubleDaily(date)
+        | GameMode::Weekly(date) => Some(*date),
+        _ => None,
+    }
+}
+
+/// Deletes the stored games of the puzzles from before the cutoff, in every puzzle language.
+/// The finished ones live on in the histories.
+pub fn prune_games(cutoff: NaiveDate) -> usize {
+    let expired_keys = storage::keys()
+        .into_iter()
+        .filter(|key| {
+            key.strip_prefix("game|")
+                .and_then(|rest| rest.split('|').next())
+                .and_then(|game_mode| serde_json::from_str::<GameMode>(game_mode).ok())
+                .and_then(|game_mode| puzzle_date(&game_mode))
+                .is_some_and(|date| date < cutoff)
+        })
+        .collect::<Vec<_>>();
+
+    for key in expired_keys.iter() {
+        storage::delete(key);
+    }
+
+    expired_keys.len()
+}
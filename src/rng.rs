@@ -0,0 +1,33 @@
+use std::cell::RefCell;
+
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::SeedableRng;
+
+/// Random numbers of the games, shared by the games of a puzzle language with the word lists.
+/// Once seeded, the picked words and messages repeat exactly, for the end-to-end tests and for
+/// reproducing bug reports.
+pub struct GameRng(RefCell<StdRng>);
+
+impl GameRng {
+    pub fn reseed(&self, seed: u64) {
+        *self.0.borrow_mut() = StdRng::seed_from_u64(seed);
+    }
+
+    pub fn choose<'a, T>(&self, items: &'a [T]) -> Option<&'a T> {
+        items.choose(&mut *self.0.borrow_mut())
+    }
+}
+
+impl Default for GameRng {
+    fn default() -> Self {
+        Self(RefCell::new(StdRng::from_entropy()))
+    }
+}
+
+// The state of the generator never makes two word lists different
+impl PartialEq for GameRng {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
@@ -0,0 +1,63 @@
+//! The midnight at which the daily words change. Both the index of the daily word and the date
+//! of its history come from `today()`, so that they agree with each other whatever the time zone
+//! of the device.
+
+use std::cell::Cell;
+
+use chrono::{Datelike, Duration, Local, NaiveDate, NaiveDateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+#[derive(PartialEq, Clone, Copy, Serialize, Deserialize)]
+pub enum Rollover {
+    // The midnight of the device
+    Local,
+    // The midnight of Finland, the same day as the players in Finland have
+    Finnish,
+}
+
+impl Default for Rollover {
+    fn default() -> Self {
+        Rollover::Local
+    }
+}
+
+impl Rollover {
+    pub fn apply(&self) {
+        ROLLOVER.with(|rollover| rollover.set(*self));
+    }
+}
+
+thread_local! {
+    static ROLLOVER: Cell<Rollover> = Cell::new(Rollover::default());
+}
+
+/// Current time on the clock of the chosen rollover
+pub fn now() -> NaiveDateTime {
+    match ROLLOVER.with(Cell::get) {
+        Rollover::Local => Local::now().naive_local(),
+        Rollover::Finnish => finnish_time(Utc::now().naive_utc()),
+    }
+}
+
+/// Date of the daily words
+pub fn today() -> NaiveDate {
+    now().date()
+}
+
+/// UTC+2, and UTC+3 during the summer time of the EU. The summer time starts at 01:00 UTC on the
+/// last Sunday of March and ends at 01:00 UTC on the last Sunday of October.
+fn finnish_time(utc: NaiveDateTime) -> NaiveDateTime {
+    let summer_starts = last_sunday(utc.year(), 3).and_hms(1, 0, 0);
+    let summer_ends = last_sunday(utc.year(), 10).and_hms(1, 0, 0);
+
+    if utc >= summer_starts && utc < summer_ends {
+        utc + Duration::hours(3)
+    } else {
+        utc + Duration::hours(2)
+    }
+}
+
+fn last_sunday(year: i32, month: u32) -> NaiveDate {
+    let last_day = NaiveDate::from_ymd(year, month + 1, 1).pred();
+    last_day - Duration::days(last_day.weekday().num_days_from_sunday() as i64)
+}
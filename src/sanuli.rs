@@ -1,26 +1,36 @@
-use rand::seq::SliceRandom;
-use std::collections::HashMap;
 use std::mem;
 use std::rc::Rc;
 
-use chrono::NaiveDate;
-use gloo_storage::{errors::StorageError, LocalStorage, Storage};
+use chrono::{Datelike, Duration, NaiveDate, Utc};
+use gloo_storage::errors::StorageError;
 use serde::{Deserialize, Serialize};
 use web_sys::{window, Window};
 
-pub type KnownStates = HashMap<(char, usize), CharacterState>;
-pub type KnownCounts = HashMap<char, CharacterCount>;
-
+use crate::brand::BRAND;
+use crate::engine::Points;
 use crate::game;
 use crate::game::{
-    Board, Game, DEFAULT_ALLOW_PROFANITIES, DEFAULT_MAX_GUESSES, DEFAULT_WORD_LENGTH,
+    Board, Game, Knowledge, DEFAULT_ALLOW_PROFANITIES, DEFAULT_MAX_GUESSES, DEFAULT_WORD_LENGTH,
     SUCCESS_EMOJIS,
 };
+use crate::i18n::{t, tf};
 use crate::manager::{
-    CharacterCount, CharacterState, GameMode, KeyState, Theme, TileState, WordList, WordLists,
+    GameMode, KeyCount, KeyState, KeyboardHints, Theme, TileState, Word, WordList, WordLists,
 };
-
-const DAILY_WORDS: &str = include_str!("../daily-words.txt");
+use crate::rollover;
+use crate::share::ShareResult;
+use crate::storage::{self, Transaction};
+use crate::tournament::{self, TOURNAMENT_WORDS};
+
+// Time limits for the rows in pressure mode, shrinking from the first row to the last
+const PRESSURE_FIRST_ROW_MS: i64 = 60_000;
+const PRESSURE_LAST_ROW_MS: i64 = 10_000;
+// Filler for rows forfeited by running out of time
+const FORFEITED: char = ' ';
+pub const WEEKLY_CHALLENGE_WORDS: usize = 5;
+// A marathon run starts with a pool of guesses, every solved word gives back some of them
+const MARATHON_GUESSES: usize = 12;
+const MARATHON_REFILL: usize = 3;
 
 #[derive(Clone, PartialEq, Serialize, Deserialize)]
 pub struct Sanuli {
@@ -29,7 +39,7 @@ pub struct Sanuli {
     word_length: usize,
     max_guesses: usize,
 
-    word: Vec<char>,
+    word: Word,
     guesses: Vec<Vec<(char, TileState)>>,
     current_guess: usize,
     streak: usize,
@@ -43,17 +53,49 @@ pub struct Sanuli {
 
     message: String,
 
+    #[serde(default)]
+    row_started_at: Option<i64>,
+    // Guess counts of the finished words of a weekly challenge, a failed word counts one over
+    // the maximum
+    #[serde(default)]
+    challenge_scores: Vec<usize>,
+    // Seconds spent on each finished word of a tournament, from the first letter typed
+    #[serde(default)]
+    challenge_durations: Vec<i64>,
+    // Guess counts of the words of the current relay chain or marathon run, the last one failed
+    // once the chain has ended
+    #[serde(default)]
+    chain_scores: Vec<usize>,
+    // Guesses left for the current marathon run, shared by its words
+    #[serde(default)]
+    marathon_guesses: usize,
+    // The revealed hints of a hard mode daily word have to be used in the later guesses
+    #[serde(default)]
+    is_hard_mode: bool,
+    // Colors of the rows to reconstruct in the deduction mode, the word is on the row after them
+    #[serde(default)]
+    deduction_patterns: Vec<Vec<TileState>>,
+    #[serde(default)]
+    started_at: Option<i64>,
+    #[serde(default)]
+    points: Option<Points>,
+    #[serde(default)]
+    is_counted: bool,
+
     #[serde(skip)]
     previous_guesses: Vec<Vec<(char, TileState)>>,
+    // Edited position within the current guess, None is the end of the guess
+    #[serde(skip)]
+    cursor: Option<usize>,
 
     #[serde(skip)]
     allow_profanities: bool,
     #[serde(skip)]
     word_lists: Rc<WordLists>,
     #[serde(skip)]
-    known_states: Vec<KnownStates>,
+    knowledge: Vec<Knowledge>,
     #[serde(skip)]
-    known_counts: Vec<KnownCounts>,
+    fixed_row_time_limit: Option<u32>,
 }
 
 impl Default for Sanuli {
@@ -64,7 +106,7 @@ impl Default for Sanuli {
             DEFAULT_WORD_LENGTH,
             DEFAULT_MAX_GUESSES,
             DEFAULT_ALLOW_PROFANITIES,
-            Rc::new(HashMap::new()),
+            Rc::new(WordLists::default()),
         )
     }
 }
@@ -78,21 +120,36 @@ impl Sanuli {
         allow_profanities: bool,
         word_lists: Rc<WordLists>,
     ) -> Self {
-        let guesses = std::iter::repeat(Vec::with_capacity(word_length))
-            .take(max_guesses)
-            .collect::<Vec<_>>();
+        Self::new_board(
+            game_mode,
+            word_list,
+            word_length,
+            max_guesses,
+            allow_profanities,
+            word_lists,
+            0,
+        )
+    }
 
-        let known_states = std::iter::repeat(HashMap::new())
+    /// One of the boards of a game of several words, the double daily picks the word by it
+    pub fn new_board(
+        game_mode: GameMode,
+        word_list: WordList,
+        word_length: usize,
+        max_guesses: usize,
+        allow_profanities: bool,
+        word_lists: Rc<WordLists>,
+        board: usize,
+    ) -> Self {
+        let guesses = std::iter::repeat(Vec::with_capacity(word_length))
             .take(max_guesses)
             .collect::<Vec<_>>();
 
-        let known_counts = std::iter::repeat(HashMap::new())
-            .take(max_guesses)
-            .collect::<Vec<_>>();
+        let knowledge = vec![Knowledge::default(); max_guesses];
 
         let word = if word_lists.is_empty() {
             // Default initialization runs into this
-            vec!['X'; word_length]
+            vec!['X'; word_length].into()
         } else {
             Self::get_word(
                 game_mode,
@@ -100,10 +157,11 @@ impl Sanuli {
                 word_length,
                 allow_profanities,
                 &word_lists,
+                board,
             )
         };
 
-        Self {
+        let mut game = Self {
             game_mode,
             word_list,
             word_lists,
@@ -117,20 +175,58 @@ impl Sanuli {
             is_reset: false,
             is_hidden: false,
             message: String::new(),
-            known_states,
-            known_counts,
+            knowledge,
             guesses,
             previous_guesses: Vec::new(),
             current_guess: 0,
+            cursor: None,
             streak: 0,
-        }
+            row_started_at: None,
+            challenge_scores: Vec::new(),
+            challenge_durations: Vec::new(),
+            chain_scores: Vec::new(),
+            marathon_guesses: if game_mode == GameMode::Marathon {
+                MARATHON_GUESSES
+            } else {
+                0
+            },
+            is_hard_mode: false,
+            deduction_patterns: Vec::new(),
+            fixed_row_time_limit: None,
+            started_at: None,
+            points: None,
+            is_counted: false,
+        };
+
+        game.start_deduction();
+        game.start_row_timer();
+        game
+    }
+
+    /// A game of the given word that is never persisted nor counted in the streaks, like the
+    /// example of the help
+    pub fn practice(word: &str, max_guesses: usize, word_lists: Rc<WordLists>) -> Self {
+        let word = word.chars().collect::<Word>();
+        // Without the word lists no word is drawn, which would move on the seeded random words
+        let mut game = Self::new(
+            GameMode::Shared,
+            WordList::Full,
+            word.len(),
+            max_guesses,
+            true,
+            Rc::new(WordLists::default()),
+        );
+        game.word = word;
+        game.set_word_lists(word_lists);
+
+        game
     }
 
     pub fn from_shared_link(game_str: &str, word_lists: Rc<WordLists>) -> Option<Self> {
         let max_guesses = DEFAULT_MAX_GUESSES;
 
         let mut parts = game_str.split("|");
-        let word = parts.next()?.chars().collect::<Vec<_>>();
+        let word = parts.next()?.chars().collect::<Word>();
         let word_length = word.len();
 
         let guesses_str = parts.next()?;
@@ -147,13 +243,7 @@ impl Sanuli {
 
         guesses.resize(max_guesses, Vec::with_capacity(word_length));
 
-        let known_states = std::iter::repeat(HashMap::new())
-            .take(max_guesses)
-            .collect::<Vec<_>>();
-
-        let known_counts = std::iter::repeat(HashMap::new())
-            .take(max_guesses)
-            .collect::<Vec<_>>();
+        let knowledge = vec![Knowledge::default(); max_guesses];
 
         let mut game = Self {
             game_mode: GameMode::Shared,
@@ -169,12 +259,23 @@ impl Sanuli {
             is_reset: false,
             is_hidden: true,
             message: String::new(),
-            known_states,
-            known_counts,
+            knowledge,
             guesses,
             previous_guesses: Vec::new(),
             current_guess,
+            cursor: None,
             streak: 0,
+            row_started_at: None,
+            challenge_scores: Vec::new(),
+            challenge_durations: Vec::new(),
+            chain_scores: Vec::new(),
+            marathon_guesses: 0,
+            is_hard_mode: false,
+            deduction_patterns: Vec::new(),
+            fixed_row_time_limit: None,
+            started_at: None,
+            points: None,
+            is_counted: false,
         };
 
         game.refresh();
@@ -219,9 +320,31 @@ impl Sanuli {
         word_length: usize,
         allow_profanities: bool,
         word_lists: &Rc<WordLists>,
-    ) -> Vec<char> {
+        round: usize,
+    ) -> Word {
         if let GameMode::DailyWord(date) = game_mode {
-            Self::get_daily_word(date)
+            word_lists.daily_word(date).unwrap()
+        } else if let GameMode::EveningWord(date) = game_mode {
+            word_lists.evening_word(date).unwrap()
+        } else if let GameMode::DoubleDaily(date) = game_mode {
+            word_lists.double_daily_word(date, round).unwrap()
+        } else if let GameMode::Weekly(week_start) = game_mode {
+            word_lists.weekly_word(week_start, round).unwrap()
+        } else if let GameMode::Tournament(id) = game_mode {
+            word_lists.tournament_word(id, round).unwrap()
+        } else if let GameMode::Race(seed) = game_mode {
+            word_lists.seeded_word(seed).unwrap()
+        } else if game_mode == GameMode::Definition {
+            word_lists
+                .definition_word(rollover::today())
+                .unwrap_or_else(|| {
+                    Self::get_random_word(
+                        WordList::Common,
+                        word_length,
+                        allow_profanities,
+                        word_lists,
+                    )
+                })
         } else {
             Self::get_random_word(word_list, word_length, allow_profanities, word_lists)
         }
@@ -232,35 +355,33 @@ impl Sanuli {
         word_length: usize,
         allow_profanities: bool,
         word_lists: &Rc<WordLists>,
-    ) -> Vec<char> {
-        let mut words = word_lists
-            .get(&(word_list, word_length))
-            .unwrap()
-            .iter()
-            .collect::<Vec<_>>();
+    ) -> Word {
+        let mut words = word_lists.answer_words(word_list, word_length);
 
         if !allow_profanities {
             if let Some(profanities) = word_lists.get(&(WordList::Profanities, word_length)) {
-                words.retain(|word| !profanities.contains(*word));
+                words.retain(|word| !profanities.contains(word));
             }
         }
 
-        let chosen = words.choose(&mut rand::thread_rng()).unwrap();
-        (*chosen).clone()
+        // Sorted, the iteration order of the set would make the seeded words differ between loads
+        words.sort();
+        Rc::clone(word_lists.rng.choose(&words).unwrap())
     }
 
-    fn get_daily_word_index(date: NaiveDate) -> usize {
-        let epoch = NaiveDate::from_ymd(2022, 1, 7); // Epoch of the daily word mode, index 0
-        date.signed_duration_since(epoch).num_days() as usize
+    /// Monday of the ISO week, identifying the weekly challenge
+    pub fn get_week_start(date: NaiveDate) -> NaiveDate {
+        date - Duration::days(date.weekday().num_days_from_monday() as i64)
     }
 
-    fn get_daily_word(date: NaiveDate) -> Vec<char> {
-        DAILY_WORDS
-            .lines()
-            .nth(Self::get_daily_word_index(date))
-            .unwrap()
-            .chars()
-            .collect()
+    pub fn get_daily_word_index(date: NaiveDate) -> usize {
+        #[cfg(feature = "daily-index")]
+        if let Some(index) = crate::daily_index::index_of(date) {
+            return index;
+        }
+
+        let epoch = NaiveDate::from_ymd(2022, 1, 7); // Epoch of the daily word mode, index 0
+        date.signed_duration_since(epoch).num_days() as usize
     }
 
     pub fn is_guess_correct_length(&self) -> bool {
@@ -278,9 +399,23 @@ impl Sanuli {
             .map(|(c, _)| *c)
             .collect();
 
-        match self.word_lists.get(&(WordList::Full, self.word_length)) {
-            Some(list) => list.contains(word),
-            None => false,
+        match self.word_lists.is_dictionary_word(word) {
+            Some(is_accepted) => is_accepted,
+            // The common and easy words are accepted while the full list is loading
+            None => [WordList::Common, WordList::Easy].iter().any(|word_list| {
+                self.word_lists
+                    .get(&(*word_list, self.word_length))
+                    .is_some_and(|list| list.contains(word.as_slice()))
+            }),
+        }
+    }
+
+    /// Message of a guess that isn't accepted
+    pub fn unknown_word_message(&self) -> &'static str {
+        if self.word_lists.is_dictionary_loaded() {
+            t("Ei sanulistalla.")
+        } else {
+            t("Sanakirja latautuu vielä, yritä hetken päästä.")
         }
     }
 
@@ -289,11 +424,306 @@ impl Sanuli {
             .iter()
             .map(|(c, _)| *c)
             .collect::<Vec<char>>()
-            == self.word
+            == *self.word
+    }
+
+    // The colors of a blind game stay hidden from the view until it ends
+    fn is_blind(&self) -> bool {
+        self.game_mode == GameMode::Blind && self.is_guessing
+    }
+
+    // Every row of the deduction has been reconstructed
+    fn is_deduction_solved(&self) -> bool {
+        self.game_mode == GameMode::Deduction
+            && self.current_guess + 1 == self.deduction_patterns.len()
+    }
+
+    /// Makes up the rows of a deduction for the word and shows the word on the row after them
+    fn start_deduction(&mut self) {
+        if self.game_mode != GameMode::Deduction {
+            return;
+        }
+
+        let mut words = self
+            .word_lists
+            .answer_words(self.word_list, self.word_length);
+        // Sorted, the iteration order of the set would make the seeded puzzles differ
+        words.sort();
+
+        self.deduction_patterns = game::deduction_patterns(
+            &words,
+            &self.word,
+            self.max_guesses - 1,
+            &self.word_lists.rng,
+        );
+        if let Some(row) = self.guesses.get_mut(self.deduction_patterns.len()) {
+            *row = self.word.iter().map(|c| (*c, TileState::Correct)).collect();
+        }
     }
 
     pub fn is_game_ended(&self) -> bool {
-        self.is_winner || self.current_guess == self.max_guesses - 1
+        self.is_winner
+            || self.current_guess == self.max_guesses - 1
+            || (self.game_mode == GameMode::Marathon && self.marathon_guesses == 0)
+    }
+
+    fn row_time_limit(&self, row: usize) -> i64 {
+        if let Some(seconds) = self.fixed_row_time_limit {
+            return seconds as i64 * 1000;
+        }
+        if self.max_guesses <= 1 {
+            return PRESSURE_FIRST_ROW_MS;
+        }
+
+        let shrink = (PRESSURE_FIRST_ROW_MS - PRESSURE_LAST_ROW_MS) * row as i64
+            / (self.max_guesses as i64 - 1);
+
+        PRESSURE_FIRST_ROW_MS - shrink
+    }
+
+    fn start_row_timer(&mut self) {
+        if self.game_mode == GameMode::Pressure && self.is_guessing {
+            self.row_started_at = Some(Utc::now().timestamp_millis());
+        } else {
+            self.row_started_at = None;
+        }
+    }
+
+    fn share_weekly_summary(&self, week_start: NaiveDate) -> ShareResult {
+        let total = self.challenge_scores.iter().sum::<usize>();
+
+        let mut body = String::new();
+        for (index, score) in self.challenge_scores.iter().enumerate() {
+            if *score > self.max_guesses {
+                body += &format!("{}. X/{}\n", index + 1, self.max_guesses);
+            } else {
+                body += &format!("{}. {}/{}\n", index + 1, score, self.max_guesses);
+            }
+        }
+
+        ShareResult {
+            title: BRAND.name.to_owned(),
+            mode: Some(tf("viikkohaaste {}", &[&week_start.iso_week().week()])),
+            score: tf("{} arvausta", &[&total]),
+            word_length: self.word_length,
+            is_hard_mode: false,
+            body,
+        }
+    }
+
+    /// The guesses and the time of every word, comparable between the participants
+    fn share_tournament_summary(&self, id: u64) -> ShareResult {
+        let total_guesses = self.challenge_scores.iter().sum::<usize>();
+        let total_secs = self.challenge_durations.iter().sum::<i64>();
+
+        let mut body = String::new();
+        for (index, score) in self.challenge_scores.iter().enumerate() {
+            let guesses = if *score > self.max_guesses {
+                "X".to_owned()
+            } else {
+                score.to_string()
+            };
+            let secs = self.challenge_durations.get(index).copied().unwrap_or(0);
+            body += &format!(
+                "{}. {}/{} ⏱️ {}\n",
+                index + 1,
+                guesses,
+                self.max_guesses,
+                game::format_duration(secs)
+            );
+        }
+
+        ShareResult {
+            title: BRAND.name.to_owned(),
+            mode: Some(tf(
+                "turnaus {} ({}/{})",
+                &[
+                    &tournament::code(id),
+                    &self.challenge_scores.len(),
+                    &TOURNAMENT_WORDS,
+                ],
+            )),
+            score: tf(
+                "{} arvausta, {}",
+                &[&total_guesses, &game::format_duration(total_secs)],
+            ),
+            word_length: self.word_length,
+            is_hard_mode: false,
+            body,
+        }
+    }
+
+    fn share_chain_summary(&self) -> ShareResult {
+        let guesses = self.chain_scores.iter().sum::<usize>();
+
+        ShareResult {
+            title: BRAND.name.to_owned(),
+            mode: Some(t("ketju").to_owned()),
+            score: tf(
+                "{} sanaa, {} arvausta",
+                &[&self.solved_chain_words(), &guesses],
+            ),
+            word_length: self.word_length,
+            is_hard_mode: false,
+            body: self.chain_score_emojis(),
+        }
+    }
+
+    fn share_marathon_summary(&self) -> ShareResult {
+        ShareResult {
+            title: BRAND.name.to_owned(),
+            mode: Some(t("maraton").to_owned()),
+            score: tf("{} sanaa", &[&self.solved_chain_words()]),
+            word_length: self.word_length,
+            is_hard_mode: false,
+            body: self.chain_score_emojis(),
+        }
+    }
+
+    fn solved_chain_words(&self) -> usize {
+        if self.is_winner {
+            self.chain_scores.len()
+        } else {
+            self.chain_scores.len().saturating_sub(1)
+        }
+    }
+
+    fn chain_score_emojis(&self) -> String {
+        let mut emojis = String::new();
+        for (index, score) in self.chain_scores.iter().enumerate() {
+            let is_failed = !self.is_winner && index == self.chain_scores.len() - 1;
+            emojis += &match (is_failed, score) {
+                (true, _) => "❌".to_owned(),
+                (false, 1..=9) => format!("{}\u{fe0f}\u{20e3}", score),
+                (false, _) => score.to_string(),
+            };
+        }
+
+        emojis
+    }
+
+    fn is_forfeited(guess: &[(char, TileState)]) -> bool {
+        !guess.is_empty() && guess.iter().all(|(c, _)| *c == FORFEITED)
+    }
+
+    // The first hint of the revealed rows the current guess leaves unused in hard mode
+    fn hard_mode_violation(&self) -> Option<String> {
+        if !self.is_hard_mode {
+            return None;
+        }
+
+        let guess = &self.guesses[self.current_guess];
+        for previous in &self.guesses[..self.current_guess] {
+            for (index, (character, state)) in previous.iter().enumerate() {
+                if *state == TileState::Correct
+                    && guess.get(index).map(|(c, _)| c) != Some(character)
+                {
+                    return Some(tf(
+                        "{}. kirjaimen täytyy olla {}",
+                        &[&(index + 1), character],
+                    ));
+                }
+            }
+
+            for (character, _) in previous
+                .iter()
+                .filter(|(_, state)| *state == TileState::Present)
+            {
+                let required = previous
+                    .iter()
+                    .filter(|(c, state)| {
+                        c == character && matches!(state, TileState::Correct | TileState::Present)
+                    })
+                    .count();
+                let count = guess.iter().filter(|(c, _)| c == character).count();
+
+                if count < required {
+                    return Some(tf("Arvauksessa täytyy olla {}", &[character]));
+                }
+            }
+        }
+
+        None
+    }
+
+    fn reveal_current_guess(&mut self) {
+        self.is_reset = false;
+        self.cursor = None;
+        self.clear_message();
+
+        self.is_winner = self.is_correct_word() || self.is_deduction_solved();
+        if self.game_mode == GameMode::Marathon {
+            self.marathon_guesses = self.marathon_guesses.saturating_sub(1);
+            if self.is_winner {
+                self.marathon_guesses =
+                    (self.marathon_guesses + MARATHON_REFILL).min(MARATHON_GUESSES);
+            }
+        }
+        game::update_known_information(
+            &mut self.knowledge,
+            &mut self.guesses[self.current_guess],
+            self.current_guess,
+            &self.word,
+            self.max_guesses,
+        );
+        if self.is_game_ended() {
+            self.is_guessing = false;
+
+            if matches!(
+                self.game_mode,
+                GameMode::DailyWord(_)
+                    | GameMode::EveningWord(_)
+                    | GameMode::Weekly(_)
+                    | GameMode::Race(_)
+                    | GameMode::Shared
+                    | GameMode::Quadruple
+                    | GameMode::DoubleDaily(_)
+                    | GameMode::Zen
+                    | GameMode::Deduction
+                    | GameMode::Definition
+                    | GameMode::Tournament(_)
+            ) {
+                // Do nothing, don't update streaks
+            } else if self.game_mode == GameMode::Marathon && !self.is_winner {
+                // The ended run keeps its score until the next one starts
+            } else if self.is_winner {
+                self.streak += 1;
+            } else {
+                self.streak = 0;
+            }
+
+            if matches!(
+                self.game_mode,
+                GameMode::Weekly(_) | GameMode::Tournament(_)
+            ) {
+                let score = if self.is_winner {
+                    self.current_guess + 1
+                } else {
+                    self.max_guesses + 1
+                };
+                self.challenge_scores.push(score);
+            }
+            if let GameMode::Tournament(_) = self.game_mode {
+                let duration_ms = self
+                    .started_at
+                    .map_or(0, |started_at| Utc::now().timestamp_millis() - started_at);
+                self.challenge_durations.push(duration_ms / 1000);
+            }
+
+            if matches!(self.game_mode, GameMode::Relay | GameMode::Marathon) {
+                // The words after the first one of a chain start with the carried over row
+                let is_carried_over =
+                    self.game_mode == GameMode::Relay && !self.chain_scores.is_empty();
+                self.chain_scores
+                    .push(self.current_guess + 1 - usize::from(is_carried_over));
+            }
+
+            self.set_game_end_message();
+        } else {
+            self.current_guess += 1;
+        }
+
+        self.start_row_timer();
     }
 
     fn clear_message(&mut self) {
@@ -303,19 +733,29 @@ impl Sanuli {
 
     fn set_game_end_message(&mut self) {
         if self.is_winner {
-            if let GameMode::DailyWord(_) = self.game_mode {
-                self.message = format!(
+            if self.game_mode == GameMode::Deduction {
+                self.message = tf(
+                    "Päättely onnistui! {}",
+                    &[self.word_lists.rng.choose(&SUCCESS_EMOJIS).unwrap()],
+                );
+            } else if let GameMode::DailyWord(_) | GameMode::EveningWord(_) = self.game_mode {
+                self.message = tf(
                     "Löysit päivän sanulin! {}",
-                    SUCCESS_EMOJIS.choose(&mut rand::thread_rng()).unwrap()
+                    &[self.word_lists.rng.choose(&SUCCESS_EMOJIS).unwrap()],
                 );
             } else {
-                self.message = format!(
+                self.message = tf(
                     "Löysit sanan! {}",
-                    SUCCESS_EMOJIS.choose(&mut rand::thread_rng()).unwrap()
+                    &[self.word_lists.rng.choose(&SUCCESS_EMOJIS).unwrap()],
                 );
             }
+        } else if self.game_mode == GameMode::Marathon {
+            self.message = tf(
+                "Maraton päättyi, {} sanaa! Sana oli \"{}\"",
+                &[&self.streak, &self.word.iter().collect::<String>()],
+            );
         } else {
-            self.message = format!("Sana oli \"{}\"", self.word.iter().collect::<String>());
+            self.message = tf("Sana oli \"{}\"", &[&self.word.iter().collect::<String>()]);
         }
     }
 
@@ -326,14 +766,9 @@ impl Sanuli {
         allow_profanities: bool,
         word_lists: Rc<WordLists>,
     ) -> Result<Self, StorageError> {
-        let game_key = &format!(
-            "game|{}|{}|{}",
-            serde_json::to_string(&game_mode).unwrap(),
-            serde_json::to_string(&word_list).unwrap(),
-            word_length
-        );
+        let game_key = &game::game_key(&game_mode, &word_list, word_length, word_lists.language);
 
-        let mut game: Self = LocalStorage::get(game_key)?;
+        let mut game: Self = storage::get(game_key)?;
         game.allow_profanities = allow_profanities;
         game.word_lists = word_lists;
 
@@ -357,16 +792,42 @@ impl Game for Sanuli {
         self.max_guesses
     }
     fn boards(&self) -> Vec<Board> {
+        let mut guesses = self.guesses.clone();
+        if self.is_blind() {
+            for guess in guesses.iter_mut().take(self.current_guess) {
+                for (_, state) in guess.iter_mut() {
+                    *state = TileState::Unknown;
+                }
+            }
+        }
+        // The rows to reconstruct show their colors under the typed letters
+        for (row, pattern) in self
+            .deduction_patterns
+            .iter()
+            .enumerate()
+            .skip(self.current_guess)
+        {
+            guesses[row] = pattern
+                .iter()
+                .enumerate()
+                .map(|(index, state)| {
+                    let character = self.guesses[row].get(index).map_or(' ', |(c, _)| *c);
+                    (character, *state)
+                })
+                .collect();
+        }
+
         let board = Board {
-            guesses: self.guesses.clone(),
+            guesses,
             current_guess: self.current_guess,
+            cursor: self.cursor(),
             is_guessing: self.is_guessing,
         };
 
         vec![board]
     }
     fn word(&self) -> Vec<char> {
-        self.word.clone()
+        self.word.to_vec()
     }
 
     fn last_guess(&self) -> String {
@@ -394,6 +855,56 @@ impl Game for Sanuli {
     fn is_unknown(&self) -> bool {
         self.is_unknown
     }
+    fn is_undo_allowed(&self) -> bool {
+        // Only practice games, like trying out a shared game, can be undone
+        matches!(self.game_mode, GameMode::Shared | GameMode::Zen) && !self.is_hidden
+    }
+    fn has_next_word(&self) -> bool {
+        match self.game_mode {
+            GameMode::Weekly(_) => self.challenge_scores.len() < WEEKLY_CHALLENGE_WORDS,
+            GameMode::Tournament(_) => self.challenge_scores.len() < TOURNAMENT_WORDS,
+            _ => true,
+        }
+    }
+    fn challenge_scores(&self) -> Vec<usize> {
+        self.challenge_scores.clone()
+    }
+    fn chain_scores(&self) -> Vec<usize> {
+        self.chain_scores.clone()
+    }
+    fn set_hard_mode(&mut self, is_enabled: bool) {
+        if matches!(self.game_mode, GameMode::DailyWord(_))
+            && self.is_guessing
+            && self.current_guess == 0
+        {
+            self.is_hard_mode = is_enabled;
+        }
+    }
+    fn is_hard_mode(&self) -> bool {
+        self.is_hard_mode
+    }
+    fn set_row_time_limit(&mut self, seconds: Option<u32>) {
+        self.fixed_row_time_limit = seconds;
+    }
+    fn started_at(&self) -> Option<i64> {
+        self.started_at
+    }
+    fn points(&self) -> Option<Points> {
+        self.points
+    }
+    fn set_points(&mut self, points: Points) {
+        self.points = Some(points);
+    }
+    fn is_counted(&self) -> bool {
+        self.is_counted
+    }
+    fn set_counted(&mut self) {
+        self.is_counted = true;
+    }
+    fn knowledge(&self) -> Vec<Knowledge> {
+        let row = self.current_guess.min(self.max_guesses - 1);
+        vec![self.knowledge.get(row).cloned().unwrap_or_default()]
+    }
     fn message(&self) -> String {
         self.message.clone()
     }
@@ -407,11 +918,56 @@ impl Game for Sanuli {
 
     fn title(&self) -> String {
         if let GameMode::DailyWord(date) = self.game_mode {
-            format!("Päivän sanuli #{}", Self::get_daily_word_index(date) + 1)
+            let index = Self::get_daily_word_index(date) + 1;
+            if self.is_hard_mode {
+                tf("Päivän sanuli #{} — Vaikea", &[&index])
+            } else {
+                tf("Päivän sanuli #{}", &[&index])
+            }
+        } else if let GameMode::EveningWord(date) = self.game_mode {
+            tf("Iltasanuli #{}", &[&(Self::get_daily_word_index(date) + 1)])
+        } else if let GameMode::Weekly(week_start) = self.game_mode {
+            // The finished last word stays on the board until leaving the challenge
+            let round = self.challenge_scores.len().min(WEEKLY_CHALLENGE_WORDS - 1) + 1;
+            tf(
+                "Viikkohaaste {} — sana {}/{}",
+                &[
+                    &week_start.iso_week().week(),
+                    &round,
+                    &WEEKLY_CHALLENGE_WORDS,
+                ],
+            )
+        } else if let GameMode::Tournament(id) = self.game_mode {
+            let round = self.challenge_scores.len().min(TOURNAMENT_WORDS - 1) + 1;
+            tf(
+                "Turnaus {} — sana {}/{}",
+                &[&tournament::code(id), &round, &TOURNAMENT_WORDS],
+            )
+        } else if let GameMode::Race(_) = self.game_mode {
+            t("Kilpasanuli").to_owned()
         } else if self.game_mode == GameMode::Shared {
-            "Jaettu sanuli".to_owned()
+            t("Jaettu sanuli").to_owned()
+        } else if self.game_mode == GameMode::Zen {
+            "Zen-sanuli".to_owned()
+        } else if self.game_mode == GameMode::Deduction {
+            t("Päättelysanuli").to_owned()
+        } else if self.game_mode == GameMode::Blind {
+            t("Sokkosanuli").to_owned()
+        } else if self.game_mode == GameMode::Definition {
+            t("Määritelmäsanuli").to_owned()
+        } else if self.game_mode == GameMode::Marathon {
+            tf(
+                "Maraton — Sanat: {}, arvauksia jäljellä: {}",
+                &[&self.streak, &self.marathon_guesses],
+            )
+        } else if self.game_mode == GameMode::Pressure && self.streak > 0 {
+            tf("Aikapaine — Putki: {}", &[&self.streak])
+        } else if self.game_mode == GameMode::Pressure {
+            t("Aikapaine").to_owned()
+        } else if self.game_mode == GameMode::Relay && self.streak > 0 {
+            tf("Sanuliketju — Ketju: {}", &[&self.streak])
         } else if self.streak > 0 {
-            format!("Sanuli — Putki: {}", self.streak)
+            tf("Sanuli — Putki: {}", &[&self.streak])
         } else {
             "Sanuli".to_owned()
         }
@@ -424,10 +980,20 @@ impl Game for Sanuli {
             self.word_length,
             self.allow_profanities,
             &self.word_lists,
+            self.challenge_scores.len(),
         );
 
         let previous_word = mem::replace(&mut self.word, next_word);
 
+        // A failed word ends the relay chain or the marathon run
+        if !self.is_winner {
+            self.chain_scores.clear();
+            if self.game_mode == GameMode::Marathon {
+                self.streak = 0;
+                self.marathon_guesses = MARATHON_GUESSES;
+            }
+        }
+
         if previous_word.len() <= self.word_length {
             self.previous_guesses = mem::take(&mut self.guesses);
             if self.game_mode == GameMode::Relay && self.is_winner {
@@ -446,25 +1012,21 @@ impl Game for Sanuli {
 
         self.guesses = Vec::with_capacity(self.max_guesses);
 
-        self.known_states = std::iter::repeat(HashMap::new())
-            .take(self.max_guesses)
-            .collect::<Vec<_>>();
-        self.known_counts = std::iter::repeat(HashMap::new())
-            .take(self.max_guesses)
-            .collect::<Vec<_>>();
+        self.knowledge = vec![Knowledge::default(); self.max_guesses];
 
-        if previous_word.len() == self.word_length
-            && self.is_winner
-            && self.game_mode == GameMode::Relay
-        {
+        if self.is_winner && self.game_mode == GameMode::Relay {
             let empty_guesses = std::iter::repeat(Vec::with_capacity(self.word_length))
                 .take(self.max_guesses - 1)
                 .collect::<Vec<_>>();
 
+            // After a change of the word length only the overlapping letters are carried over
             self.guesses.push(
                 previous_word
                     .iter()
-                    .map(|c| (*c, TileState::Unknown))
+                    .copied()
+                    .chain(std::iter::repeat(FORFEITED))
+                    .take(self.word_length)
+                    .map(|c| (c, TileState::Unknown))
                     .collect(),
             );
             self.guesses.extend(empty_guesses);
@@ -472,8 +1034,7 @@ impl Game for Sanuli {
             self.current_guess = 0;
             // Update the known states of the word from previous round
             game::update_known_information(
-                &mut self.known_states,
-                &mut self.known_counts,
+                &mut self.knowledge,
                 &mut self.guesses[self.current_guess],
                 self.current_guess,
                 &self.word,
@@ -490,129 +1051,232 @@ impl Game for Sanuli {
         self.is_guessing = true;
         self.is_winner = false;
         self.is_reset = true;
+        self.started_at = None;
+        self.points = None;
+        self.is_counted = false;
         self.clear_message();
 
+        self.start_deduction();
+        self.start_row_timer();
+
         let _result = self.persist();
     }
 
-    fn keyboard_tilestate(&self, key: &char) -> KeyState {
+    fn continue_chain(&mut self, previous_word: &[char], streak: usize, chain_scores: Vec<usize>) {
+        // Picks up as if the previous word had just been solved on this board
+        self.guesses = Vec::new();
+        self.current_guess = 0;
+        self.word = previous_word.into();
+        self.streak = streak;
+        self.chain_scores = chain_scores;
+        self.is_winner = true;
+        self.is_guessing = false;
+
+        self.next_word();
+    }
+
+    fn keyboard_tilestate(&self, key: &char, hints: KeyboardHints) -> KeyState {
+        if self.is_blind() {
+            return KeyState::Single(TileState::Unknown);
+        }
+
         KeyState::Single(game::keyboard_tile_state(
             key,
             self.current_guess,
-            &self.known_states,
-            &self.known_counts,
+            &self.knowledge,
+            hints,
         ))
     }
 
+    fn keyboard_count(&self, key: &char, hints: KeyboardHints) -> Option<KeyCount> {
+        if self.is_blind() {
+            return None;
+        }
+
+        game::keyboard_count(key, self.current_guess, &self.knowledge, hints)
+    }
+
     fn submit_guess(&mut self) {
         if !self.is_guess_correct_length() {
-            self.message = "Liian vähän kirjaimia!".to_owned();
+            self.message = t("Liian vähän kirjaimia!").to_owned();
             return;
         }
         if !self.is_guess_accepted_word() {
             self.is_unknown = true;
-            self.message = "Ei sanulistalla.".to_owned();
+            self.message = self.unknown_word_message().to_owned();
             return;
         }
+        if let Some(message) = self.hard_mode_violation() {
+            self.message = message;
+            return;
+        }
+        if let Some(pattern) = self.deduction_patterns.get(self.current_guess) {
+            let guess = self.guesses[self.current_guess]
+                .iter()
+                .map(|(c, _)| *c)
+                .collect::<Vec<_>>();
+            if !game::matches_pattern(&guess, &self.word, pattern) {
+                self.message = t("Värit eivät täsmää!").to_owned();
+                return;
+            }
+        }
 
-        self.is_reset = false;
-        self.clear_message();
+        self.reveal_current_guess();
+    }
 
-        self.is_winner = self.is_correct_word();
-        game::update_known_information(
-            &mut self.known_states,
-            &mut self.known_counts,
-            &mut self.guesses[self.current_guess],
-            self.current_guess,
-            &self.word,
-            self.max_guesses,
-        );
-        if self.is_game_ended() {
-            self.is_guessing = false;
+    fn deadline(&self) -> Option<i64> {
+        if !self.is_guessing {
+            return None;
+        }
 
-            if matches!(self.game_mode, GameMode::DailyWord(_) | GameMode::Shared | GameMode::Quadruple) {
-                // Do nothing, don't update streaks
-            } else if self.is_winner {
-                self.streak += 1;
-            } else {
-                self.streak = 0;
-            }
+        self.row_started_at
+            .map(|started_at| started_at + self.row_time_limit(self.current_guess))
+    }
 
-            self.set_game_end_message();
-        } else {
-            self.current_guess += 1;
+    fn expire_row(&mut self) {
+        if self.deadline().is_none() {
+            return;
         }
 
-        let _result = self.persist();
+        // A complete and accepted word is submitted as is, anything else forfeits the row
+        if self.is_guess_correct_length()
+            && self.is_guess_accepted_word()
+            && self.hard_mode_violation().is_none()
+        {
+            self.reveal_current_guess();
+            return;
+        }
+
+        self.guesses[self.current_guess] = vec![(FORFEITED, TileState::Unknown); self.word_length];
+        self.reveal_current_guess();
+
+        if self.is_guessing {
+            self.message = t("Aika loppui!").to_owned();
+        }
     }
 
     fn push_character(&mut self, character: char) {
-        if !self.is_guessing || self.guesses[self.current_guess].len() >= self.word_length {
+        let position = self.cursor();
+        if !self.is_guessing || position >= self.word_length {
             return;
         }
 
         self.clear_message();
+        if self.started_at.is_none() {
+            self.started_at = Some(Utc::now().timestamp_millis());
+        }
 
         // Display a hint of the tile state based on already known information
-        let tile_state = game::hint_tile_state(
-            character,
-            self.guesses[self.current_guess].len(),
-            self.current_guess,
-            &self.known_states,
-            &self.known_counts,
-        );
-        self.guesses[self.current_guess].push((character, tile_state));
+        let tile_state =
+            game::hint_tile_state(character, position, self.current_guess, &self.knowledge);
+
+        let guess = &mut self.guesses[self.current_guess];
+        if position < guess.len() {
+            // Replace the character under the cursor
+            guess[position] = (character, tile_state);
+        } else {
+            guess.push((character, tile_state));
+        }
+
+        self.move_cursor(position + 1);
     }
 
     fn pop_character(&mut self) {
-        if !self.is_guessing || self.guesses[self.current_guess].is_empty() {
+        let position = self.cursor();
+        if !self.is_guessing || position == 0 {
             return;
         }
 
         self.clear_message();
-        self.guesses[self.current_guess].pop();
+        self.guesses[self.current_guess].remove(position - 1);
+
+        // The hints depend on the position, refresh the ones of the shifted characters
+        for index in position - 1..self.guesses[self.current_guess].len() {
+            let character = self.guesses[self.current_guess][index].0;
+            self.guesses[self.current_guess][index].1 =
+                game::hint_tile_state(character, index, self.current_guess, &self.knowledge);
+        }
+
+        self.move_cursor(position - 1);
     }
 
-    fn share_emojis(&self, theme: Theme) -> Option<String> {
-        let mut message = String::new();
+    fn cursor(&self) -> usize {
+        let length = self
+            .guesses
+            .get(self.current_guess)
+            .map_or(0, |guess| guess.len());
 
-        if let GameMode::DailyWord(date) = self.game_mode {
-            let index = Self::get_daily_word_index(date) + 1;
-            let guess_count = if self.is_winner {
-                format!("{}", self.current_guess + 1)
-            } else {
-                "X".to_owned()
-            };
+        self.cursor.map_or(length, |cursor| cursor.min(length))
+    }
 
-            message += &format!("Sanuli #{} {}/{}", index, guess_count, self.max_guesses);
-            message += "\n\n";
+    fn move_cursor(&mut self, position: usize) {
+        if !self.is_guessing {
+            return;
+        }
 
-            for guess in self.guesses.iter() {
-                if guess.is_empty() {
-                    continue;
-                }
-                let guess_string = guess
-                    .iter()
-                    .map(|(_, state)| match state {
-                        TileState::Correct => match theme {
-                            Theme::Colorblind => "🟧",
-                            _ => "🟩",
-                        },
-                        TileState::Present => match theme {
-                            Theme::Colorblind => "🟦",
-                            _ => "🟨",
-                        },
-                        TileState::Absent => "⬛",
-                        TileState::Unknown => "⬜",
-                    })
-                    .collect::<String>();
+        if position >= self.guesses[self.current_guess].len() {
+            self.cursor = None;
+        } else {
+            self.cursor = Some(position);
+        }
+    }
 
-                message += &guess_string;
-                message += "\n";
+    fn share_result(&self, theme: Theme) -> Option<ShareResult> {
+        let guess_count = if self.is_winner {
+            format!("{}", self.current_guess + 1)
+        } else {
+            "X".to_owned()
+        };
+
+        let (title, mode) = match self.game_mode {
+            GameMode::DailyWord(date) => (
+                format!("{} #{}", BRAND.name, Self::get_daily_word_index(date) + 1),
+                None,
+            ),
+            GameMode::EveningWord(date) => (
+                format!("{} #{}", BRAND.name, Self::get_daily_word_index(date) + 1),
+                Some("🌙".to_owned()),
+            ),
+            // Not the stopwatch of the solve time
+            GameMode::Pressure => (BRAND.name.to_owned(), Some("⏳".to_owned())),
+            GameMode::Weekly(week_start) => return Some(self.share_weekly_summary(week_start)),
+            GameMode::Tournament(id) => return Some(self.share_tournament_summary(id)),
+            GameMode::Marathon if !self.chain_scores.is_empty() => {
+                return Some(self.share_marathon_summary())
+            }
+            GameMode::Relay if !self.chain_scores.is_empty() => {
+                return Some(self.share_chain_summary())
+            }
+            _ => return None,
+        };
+
+        let mut body = String::new();
+        for guess in self.guesses.iter() {
+            if guess.is_empty() {
+                continue;
+            }
+            if Self::is_forfeited(guess) {
+                body += &"⌛".repeat(guess.len());
+                body += "\n";
+                continue;
             }
+            let guess_string = guess
+                .iter()
+                .map(|(_, state)| game::share_tile(state, theme))
+                .collect::<String>();
+
+            body += &guess_string;
+            body += "\n";
         }
 
-        Some(message)
+        Some(ShareResult {
+            title,
+            mode,
+            score: format!("{}/{}", guess_count, self.max_guesses),
+            word_length: self.word_length,
+            is_hard_mode: self.is_hard_mode,
+            body,
+        })
     }
 
     fn share_link(&self) -> Option<String> {
@@ -640,7 +1304,40 @@ impl Game for Sanuli {
 
     fn reveal_hidden_tiles(&mut self) {
         self.is_hidden = false;
-        self.message = format!("Sana oli \"{}\"", self.word.iter().collect::<String>());
+        self.message = tf("Sana oli \"{}\"", &[&self.word.iter().collect::<String>()]);
+    }
+
+    fn undo_guess(&mut self) {
+        if !self.is_undo_allowed() {
+            return;
+        }
+
+        let undone_guess = if self.is_guessing {
+            if self.current_guess == 0 {
+                return;
+            }
+            self.guesses[self.current_guess].clear();
+            self.current_guess - 1
+        } else {
+            self.current_guess
+        };
+
+        self.current_guess = undone_guess;
+        self.is_guessing = true;
+        self.is_winner = false;
+        self.is_reset = false;
+        self.clear_message();
+
+        let undone_word = mem::take(&mut self.guesses[undone_guess]);
+        self.cursor = None;
+
+        // Recompute the known information from the remaining guesses
+        self.refresh();
+
+        // Return the undone word to the row to be edited
+        for (character, _) in undone_word {
+            self.push_character(character);
+        }
     }
 
     fn reset(&mut self) {
@@ -649,39 +1346,31 @@ impl Game for Sanuli {
             .collect::<Vec<_>>();
 
         self.current_guess = 0;
+        self.cursor = None;
 
         self.is_guessing = true;
         self.is_winner = false;
         self.is_unknown = false;
         self.is_reset = false;
         self.is_hidden = false;
-        self.message = "Peli nollattu, arvaa sanuli!".to_owned();
+        self.started_at = None;
+        self.points = None;
+        self.is_counted = false;
+        self.message = t("Peli nollattu, arvaa sanuli!").to_owned();
 
-        self.known_states = std::iter::repeat(HashMap::new())
-            .take(self.max_guesses)
-            .collect::<Vec<_>>();
-
-        self.known_counts = std::iter::repeat(HashMap::new())
-            .take(self.max_guesses)
-            .collect::<Vec<_>>();
+        self.knowledge = vec![Knowledge::default(); self.max_guesses];
 
+        self.start_row_timer();
         self.previous_guesses = Vec::new();
     }
 
     fn refresh(&mut self) {
-        self.known_states = std::iter::repeat(HashMap::new())
-            .take(self.max_guesses)
-            .collect::<Vec<_>>();
+        self.knowledge = vec![Knowledge::default(); self.max_guesses];
 
-        self.known_counts = std::iter::repeat(HashMap::new())
-            .take(self.max_guesses)
-            .collect::<Vec<_>>();
-
-        // Rerun the game to refresh known_states and known_counts
+        // Rerun the game to refresh the knowledge
         for guess_index in 0..self.current_guess {
             game::update_known_information(
-                &mut self.known_states,
-                &mut self.known_counts,
+                &mut self.knowledge,
                 &mut self.guesses[guess_index],
                 guess_index,
                 &self.word,
@@ -692,8 +1381,7 @@ impl Game for Sanuli {
         // If the game is ended also update the current guess
         if !self.is_guessing {
             game::update_known_information(
-                &mut self.known_states,
-                &mut self.known_counts,
+                &mut self.knowledge,
                 &mut self.guesses[self.current_guess],
                 self.current_guess,
                 &self.word,
@@ -702,20 +1390,23 @@ impl Game for Sanuli {
         }
     }
 
-    fn persist(&self) -> Result<(), StorageError> {
-        if matches!(self.game_mode, GameMode::Shared | GameMode::Quadruple) {
-            // Never persist shared or subgames within quadruple mode
-            // The quadruple subgames should never call this persist anyways.
+    fn persist_with(&self, transaction: &mut Transaction) -> Result<(), StorageError> {
+        if matches!(
+            self.game_mode,
+            GameMode::Shared | GameMode::Race(_) | GameMode::Quadruple | GameMode::DoubleDaily(_)
+        ) {
+            // Never persist shared games, races or subgames within quadruple or double daily
+            // mode. The subgames should never call this persist anyways.
             return Ok(());
         }
 
-        let game_key = &format!(
-            "game|{}|{}|{}",
-            serde_json::to_string(&self.game_mode).unwrap(),
-            serde_json::to_string(&self.word_list).unwrap(),
-            self.word_length
+        let game_key = &game::game_key(
+            &self.game_mode,
+            &self.word_list,
+            self.word_length,
+            self.word_lists.language,
         );
 
-        LocalStorage::set(game_key, self)
+        transaction.set(game_key, self)
     }
 }
@@ -0,0 +1,143 @@
+//! Composition of the shared results. A game describes its result as a `ShareResult`, and the
+//! details chosen by the player are added to its header here, so that every mode shares in the
+//! same shape: "<title> <mode> <score><details>", an empty line and the grid or the summary.
+
+use serde::{Deserialize, Serialize};
+
+#[cfg(web_sys_unstable_apis)]
+use crate::game;
+#[cfg(web_sys_unstable_apis)]
+use crate::i18n::tf;
+
+#[cfg(web_sys_unstable_apis)]
+const HARD_MODE_MARK: &str = "*";
+#[cfg(web_sys_unstable_apis)]
+const STREAK_MARK: &str = "🔥";
+#[cfg(web_sys_unstable_apis)]
+const SOLVE_TIME_MARK: &str = "⏱️";
+#[cfg(web_sys_unstable_apis)]
+const EXPERT_MARK: &str = "🧠";
+#[cfg(web_sys_unstable_apis)]
+const POINTS_MARK: &str = "⭐";
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum ShareOption {
+    Mode,
+    WordLength,
+    HardMode,
+    Streak,
+    SolveTime,
+}
+
+pub const SHARE_OPTIONS: [(ShareOption, &str); 5] = [
+    (ShareOption::Mode, "Pelimuoto"),
+    (ShareOption::WordLength, "Sanan pituus"),
+    (ShareOption::HardMode, "Vaikea-merkintä"),
+    (ShareOption::Streak, "Putki"),
+    (ShareOption::SolveTime, "Ratkaisuaika"),
+];
+
+/// The details included in the header of the shared results
+#[derive(Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ShareOptions {
+    pub mode: bool,
+    pub word_length: bool,
+    pub hard_mode: bool,
+    pub streak: bool,
+    pub solve_time: bool,
+}
+
+impl Default for ShareOptions {
+    fn default() -> Self {
+        Self {
+            mode: true,
+            word_length: false,
+            hard_mode: true,
+            streak: false,
+            solve_time: true,
+        }
+    }
+}
+
+impl ShareOptions {
+    pub fn is_enabled(&self, option: ShareOption) -> bool {
+        match option {
+            ShareOption::Mode => self.mode,
+            ShareOption::WordLength => self.word_length,
+            ShareOption::HardMode => self.hard_mode,
+            ShareOption::Streak => self.streak,
+            ShareOption::SolveTime => self.solve_time,
+        }
+    }
+
+    pub fn toggle(&mut self, option: ShareOption) {
+        let value = match option {
+            ShareOption::Mode => &mut self.mode,
+            ShareOption::WordLength => &mut self.word_length,
+            ShareOption::HardMode => &mut self.hard_mode,
+            ShareOption::Streak => &mut self.streak,
+            ShareOption::SolveTime => &mut self.solve_time,
+        };
+        *value = !*value;
+    }
+}
+
+/// The result of a finished game, as described by the game itself
+pub struct ShareResult {
+    // The brand and the puzzle, eg. "Sanuli #123"
+    pub title: String,
+    // Marker of the mode, eg. "🌙" for the evening word, None for the modes without one
+    pub mode: Option<String>,
+    // eg. "3/6" or "5 sanaa"
+    pub score: String,
+    pub word_length: usize,
+    pub is_hard_mode: bool,
+    // The emoji grid or the summary of the words
+    pub body: String,
+}
+
+/// What the game doesn't know of itself
+#[cfg(web_sys_unstable_apis)]
+pub struct ShareContext {
+    pub streak: usize,
+    pub duration_secs: Option<i64>,
+    // Played without the keyboard hints, always marked
+    pub is_expert: bool,
+    // Points of the game when scoring is on, always included then
+    pub points: Option<u32>,
+}
+
+#[cfg(web_sys_unstable_apis)]
+pub fn compose(result: &ShareResult, options: ShareOptions, context: &ShareContext) -> String {
+    let mut header = result.title.clone();
+
+    if let Some(mode) = result.mode.as_ref().filter(|_| options.mode) {
+        header += " ";
+        header += mode;
+    }
+    header += " ";
+    header += &result.score;
+    if options.hard_mode && result.is_hard_mode {
+        header += HARD_MODE_MARK;
+    }
+
+    if options.word_length {
+        header += &format!(" ({})", tf("{} kirjainta", &[&result.word_length]));
+    }
+    if options.streak && context.streak > 0 {
+        header += &format!(" {}{}", STREAK_MARK, context.streak);
+    }
+    if let Some(secs) = context.duration_secs.filter(|_| options.solve_time) {
+        header += &format!(" {} {}", SOLVE_TIME_MARK, game::format_duration(secs));
+    }
+    if context.is_expert {
+        header += " ";
+        header += EXPERT_MARK;
+    }
+    if let Some(points) = context.points {
+        header += &format!(" {}{}", POINTS_MARK, points);
+    }
+
+    format!("{}\n\n{}", header, result.body)
+}
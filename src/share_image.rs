@@ -0,0 +1,117 @@
+use wasm_bindgen::JsCast;
+use web_sys::{window, CanvasRenderingContext2d, HtmlAnchorElement, HtmlCanvasElement};
+
+use crate::manager::{Theme, TileState};
+
+const TILE_SIZE: f64 = 60.0;
+const TILE_GAP: f64 = 8.0;
+const PADDING: f64 = 32.0;
+const TITLE_HEIGHT: f64 = 56.0;
+const FOOTER_LINE_HEIGHT: f64 = 32.0;
+
+const BACKGROUND: &str = "#121212";
+const TEXT: &str = "#d7dadc";
+const BORDER: &str = "#3a3a3c";
+
+fn tile_color(tile_state: TileState, theme: Theme) -> &'static str {
+    match (tile_state, theme) {
+        (TileState::Correct, Theme::Colorblind | Theme::HighContrast) => "#f5793a",
+        (TileState::Correct, Theme::Pastel) => "#8fd0a4",
+        (TileState::Correct, Theme::Terminal) => "#00c83c",
+        (TileState::Correct, _) => "#6aaa64",
+        (TileState::Present, Theme::Colorblind | Theme::HighContrast) => "#85c0f9",
+        (TileState::Present, Theme::Pastel) => "#f2d98b",
+        (TileState::Present, Theme::Terminal) => "#8a9a1f",
+        (TileState::Present, _) => "#c9b458",
+        (TileState::Absent, Theme::Pastel) => "#6b6b7b",
+        (TileState::Absent, Theme::Terminal) => "#10301a",
+        (TileState::Absent, _) => "#3e3e3e",
+        (TileState::Unknown, _) => BACKGROUND,
+    }
+}
+
+/// Draws the colors of a finished board to an offscreen canvas, returning it as a PNG data url
+pub fn render(
+    patterns: &[Vec<TileState>],
+    max_guesses: usize,
+    word_length: usize,
+    title: &str,
+    footer: &[String],
+    theme: Theme,
+) -> Option<String> {
+    let document = window()?.document()?;
+    let canvas: HtmlCanvasElement = document.create_element("canvas").ok()?.dyn_into().ok()?;
+
+    let board_width = word_length as f64 * (TILE_SIZE + TILE_GAP) - TILE_GAP;
+    let board_height = max_guesses as f64 * (TILE_SIZE + TILE_GAP) - TILE_GAP;
+    let width = board_width + 2.0 * PADDING;
+    let height = PADDING
+        + TITLE_HEIGHT
+        + board_height
+        + PADDING
+        + footer.len() as f64 * FOOTER_LINE_HEIGHT
+        + PADDING;
+
+    canvas.set_width(width as u32);
+    canvas.set_height(height as u32);
+
+    let context: CanvasRenderingContext2d = canvas.get_context("2d").ok()??.dyn_into().ok()?;
+
+    context.set_fill_style_str(BACKGROUND);
+    context.fill_rect(0.0, 0.0, width, height);
+
+    context.set_fill_style_str(TEXT);
+    context.set_text_align("center");
+    context.set_text_baseline("middle");
+    context.set_font("bold 32px 'Clear Sans', 'Helvetica Neue', Arial, sans-serif");
+    context
+        .fill_text(title, width / 2.0, PADDING + TITLE_HEIGHT / 2.0)
+        .ok()?;
+
+    let board_top = PADDING + TITLE_HEIGHT;
+    for row in 0..max_guesses {
+        for column in 0..word_length {
+            let tile_state = patterns
+                .get(row)
+                .and_then(|pattern| pattern.get(column))
+                .copied()
+                .unwrap_or(TileState::Unknown);
+
+            let x = PADDING + column as f64 * (TILE_SIZE + TILE_GAP);
+            let y = board_top + row as f64 * (TILE_SIZE + TILE_GAP);
+
+            if tile_state == TileState::Unknown {
+                // Empty tiles are drawn as outlines like on the board
+                context.set_fill_style_str(BORDER);
+                context.fill_rect(x, y, TILE_SIZE, TILE_SIZE);
+                context.set_fill_style_str(BACKGROUND);
+                context.fill_rect(x + 2.0, y + 2.0, TILE_SIZE - 4.0, TILE_SIZE - 4.0);
+            } else {
+                context.set_fill_style_str(tile_color(tile_state, theme));
+                context.fill_rect(x, y, TILE_SIZE, TILE_SIZE);
+            }
+        }
+    }
+
+    context.set_fill_style_str(TEXT);
+    context.set_font("bold 20px 'Clear Sans', 'Helvetica Neue', Arial, sans-serif");
+    let footer_top = board_top + board_height + PADDING;
+    for (index, line) in footer.iter().enumerate() {
+        let y = footer_top + index as f64 * FOOTER_LINE_HEIGHT + FOOTER_LINE_HEIGHT / 2.0;
+        context.fill_text(line, width / 2.0, y).ok()?;
+    }
+
+    canvas.to_data_url_with_type("image/png").ok()
+}
+
+/// Offer the rendered image as a download
+pub fn download(data_url: &str, filename: &str) -> Option<()> {
+    let document = window()?.document()?;
+    let anchor: HtmlAnchorElement = document.create_element("a").ok()?.dyn_into().ok()?;
+
+    anchor.set_href(data_url);
+    anchor.set_download(filename);
+    anchor.click();
+
+    Some(())
+}
@@ -0,0 +1,262 @@
+use serde::{Deserialize, Serialize};
+use wasm_bindgen::JsCast;
+use web_sys::{Element, KeyboardEvent, MouseEvent, MouseEventInit};
+
+use crate::Msg;
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum Action {
+    ToggleHelp,
+    ToggleMenu,
+    ToggleShortcuts,
+    Escape,
+    Enter,
+    Backspace,
+    ClearRow,
+    MoveCursorLeft,
+    MoveCursorRight,
+    UndoGuess,
+    NextWord,
+}
+
+impl Action {
+    pub fn msg(&self) -> Msg {
+        match self {
+            Action::ToggleHelp => Msg::ToggleHelp,
+            Action::ToggleMenu => Msg::ToggleMenu,
+            Action::ToggleShortcuts => Msg::ToggleShortcuts,
+            Action::Escape => Msg::Escape,
+            Action::Enter => Msg::Enter,
+            Action::Backspace => Msg::Backspace,
+            Action::ClearRow => Msg::ClearRow,
+            Action::MoveCursorLeft => Msg::MoveCursorLeft,
+            Action::MoveCursorRight => Msg::MoveCursorRight,
+            Action::UndoGuess => Msg::UndoGuess,
+            Action::NextWord => Msg::NextWord,
+        }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum When {
+    // Even while a modal is open
+    Always,
+    Playing,
+    // Letters are typed into the guess until the game has ended
+    GameOver,
+}
+
+pub struct Shortcut {
+    // `KeyboardEvent.key`, letters are matched case insensitively
+    pub key: &'static str,
+    pub label: &'static str,
+    // Ctrl, or Cmd on macOS
+    pub ctrl: bool,
+    pub when: When,
+    pub action: Action,
+    pub description: &'static str,
+}
+
+/// Drives both the keydown listener and the shortcut overlay
+pub const SHORTCUTS: [Shortcut; 11] = [
+    Shortcut {
+        key: "Enter",
+        label: "Enter",
+        ctrl: false,
+        when: When::Playing,
+        action: Action::Enter,
+        description: "Arvaa sanaa",
+    },
+    Shortcut {
+        key: "Backspace",
+        label: "⌫",
+        ctrl: false,
+        when: When::Playing,
+        action: Action::Backspace,
+        description: "Poista kirjain",
+    },
+    Shortcut {
+        key: "Backspace",
+        label: "Ctrl+⌫",
+        ctrl: true,
+        when: When::Playing,
+        action: Action::ClearRow,
+        description: "Tyhjennä rivi",
+    },
+    Shortcut {
+        key: "ArrowLeft",
+        label: "←",
+        ctrl: false,
+        when: When::Playing,
+        action: Action::MoveCursorLeft,
+        description: "Siirrä kohdistinta vasemmalle",
+    },
+    Shortcut {
+        key: "ArrowRight",
+        label: "→",
+        ctrl: false,
+        when: When::Playing,
+        action: Action::MoveCursorRight,
+        description: "Siirrä kohdistinta oikealle",
+    },
+    Shortcut {
+        key: "z",
+        label: "Ctrl+Z",
+        ctrl: true,
+        when: When::Playing,
+        action: Action::UndoGuess,
+        description: "Peru arvaus",
+    },
+    Shortcut {
+        key: "?",
+        label: "?",
+        ctrl: false,
+        when: When::Always,
+        action: Action::ToggleHelp,
+        description: "Ohjeet",
+    },
+    Shortcut {
+        key: "F1",
+        label: "F1",
+        ctrl: false,
+        when: When::Always,
+        action: Action::ToggleShortcuts,
+        description: "Pikanäppäimet",
+    },
+    Shortcut {
+        key: "Escape",
+        label: "Esc",
+        ctrl: false,
+        when: When::Always,
+        action: Action::Escape,
+        description: "Sulje ikkuna",
+    },
+    Shortcut {
+        key: "m",
+        label: "M",
+        ctrl: false,
+        when: When::GameOver,
+        action: Action::ToggleMenu,
+        description: "Valikko, kun peli on päättynyt",
+    },
+    Shortcut {
+        key: "n",
+        label: "N",
+        ctrl: false,
+        when: When::GameOver,
+        action: Action::NextWord,
+        description: "Uusi peli, kun peli on päättynyt",
+    },
+];
+
+// Keys offered as alternatives for Backspace and Enter, with their labels
+pub const ALTERNATIVE_KEYS: [(&str, &str); 5] = [
+    ("Delete", "Del"),
+    (" ", "␣"),
+    ("Tab", "Tab"),
+    ("-", "-"),
+    ("+", "+"),
+];
+
+/// Extra keys chosen by the player, working alongside the default keys
+#[derive(Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct KeyBindings {
+    pub backspace: Option<String>,
+    pub enter: Option<String>,
+}
+
+impl KeyBindings {
+    pub fn find(&self, key: &str) -> Option<Action> {
+        if self.backspace.as_deref() == Some(key) {
+            Some(Action::Backspace)
+        } else if self.enter.as_deref() == Some(key) {
+            Some(Action::Enter)
+        } else {
+            None
+        }
+    }
+
+    pub fn key(&self, action: Action) -> Option<&str> {
+        match action {
+            Action::Backspace => self.backspace.as_deref(),
+            Action::Enter => self.enter.as_deref(),
+            _ => None,
+        }
+    }
+
+    pub fn set(&mut self, action: Action, key: Option<String>) {
+        // A key can only be bound to one of the actions
+        if key.is_some() {
+            if self.backspace == key {
+                self.backspace = None;
+            }
+            if self.enter == key {
+                self.enter = None;
+            }
+        }
+
+        match action {
+            Action::Backspace => self.backspace = key,
+            Action::Enter => self.enter = key,
+            _ => {}
+        }
+    }
+}
+
+// Controls activated with Enter and Space when focused from the keyboard
+const CONTROLS: &str = "button, [role=button]";
+
+/// Activates the focused control on Enter and Space, like the browser does for clicks.
+/// The controls of the game react to mousedown, so both mousedown and click are dispatched.
+pub fn activate_focused_control(e: &KeyboardEvent) -> bool {
+    if e.key() != "Enter" && e.key() != " " {
+        return false;
+    }
+
+    let control = match e
+        .target()
+        .and_then(|target| target.dyn_into::<Element>().ok())
+    {
+        Some(control) => control,
+        None => return false,
+    };
+
+    // Controls focused with the mouse keep Enter for submitting the guess
+    let is_control = control.matches(CONTROLS).unwrap_or(false);
+    let is_focus_visible = control.matches(":focus-visible").unwrap_or(true);
+    if !is_control || !is_focus_visible {
+        return false;
+    }
+
+    e.prevent_default();
+    for event_type in ["mousedown", "click"] {
+        let init = MouseEventInit::new();
+        init.set_bubbles(true);
+        init.set_cancelable(true);
+        if let Ok(event) = MouseEvent::new_with_mouse_event_init_dict(event_type, &init) {
+            let _result = control.dispatch_event(&event);
+        }
+    }
+
+    true
+}
+
+/// Finds the shortcut of a key press. The letter shortcuts of ended games aren't included,
+/// they arrive as key presses and are resolved with `find_game_over`.
+pub fn find(key: &str, is_ctrl: bool, is_modal_open: bool) -> Option<&'static Shortcut> {
+    SHORTCUTS.iter().find(|shortcut| {
+        shortcut.key.eq_ignore_ascii_case(key)
+            && shortcut.ctrl == is_ctrl
+            && match shortcut.when {
+                When::Always => true,
+                When::Playing => !is_modal_open,
+                When::GameOver => false,
+            }
+    })
+}
+
+pub fn find_game_over(letter: char) -> Option<&'static Shortcut> {
+    SHORTCUTS.iter().find(|shortcut| {
+        shortcut.when == When::GameOver && shortcut.key.chars().eq(letter.to_lowercase())
+    })
+}
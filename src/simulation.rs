@@ -0,0 +1,243 @@
+//! Plays games without the browser to benchmark solver strategies and the word lists. Native
+//! code only, included with `#[path]` by `src/bin/simulate.rs` and `benches/simulate.rs` like
+//! `src/engine.rs`, which has to be included next to it as `engine`.
+
+use rand::prelude::SliceRandom;
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+
+use crate::engine::{self, Feedback};
+
+pub const MAX_GUESSES: usize = 6;
+// The same words are played on every run
+const WORD_SEED: u64 = 0;
+
+pub type History = [(Vec<char>, Vec<Feedback>)];
+
+/// A single game played by feeding guesses and reading the feedback
+pub struct Simulation<'a> {
+    word: &'a [char],
+    max_guesses: usize,
+    history: Vec<(Vec<char>, Vec<Feedback>)>,
+}
+
+impl<'a> Simulation<'a> {
+    pub fn new(word: &'a [char], max_guesses: usize) -> Self {
+        Self {
+            word,
+            max_guesses,
+            history: Vec::with_capacity(max_guesses),
+        }
+    }
+
+    /// Feedback of the guess, or None once the game is over or if the guess is of the wrong length
+    pub fn guess(&mut self, guess: &[char]) -> Option<&[Feedback]> {
+        if self.is_over() || guess.len() != self.word.len() {
+            return None;
+        }
+
+        self.history
+            .push((guess.to_vec(), engine::score(guess, self.word)));
+        self.history.last().map(|(_, feedback)| feedback.as_slice())
+    }
+
+    pub fn history(&self) -> &History {
+        &self.history
+    }
+
+    pub fn is_solved(&self) -> bool {
+        self.history
+            .last()
+            .map(|(_, feedback)| feedback.iter().all(|tile| *tile == Feedback::Correct))
+            .unwrap_or(false)
+    }
+
+    pub fn is_over(&self) -> bool {
+        self.is_solved() || self.history.len() >= self.max_guesses
+    }
+}
+
+pub trait Strategy {
+    fn name(&self) -> &'static str;
+    fn next_guess(&mut self, words: &[Vec<char>], history: &History) -> Vec<char>;
+}
+
+/// Whether the word would have given the same feedback to every guess so far
+fn is_candidate(word: &[char], history: &History) -> bool {
+    history
+        .iter()
+        .all(|(guess, feedback)| engine::score(guess, word) == *feedback)
+}
+
+/// Guesses the first word of the list that could still be the answer
+pub struct FirstCandidate;
+
+impl Strategy for FirstCandidate {
+    fn name(&self) -> &'static str {
+        "first-candidate"
+    }
+
+    fn next_guess(&mut self, words: &[Vec<char>], history: &History) -> Vec<char> {
+        words
+            .iter()
+            .find(|word| is_candidate(word, history))
+            .unwrap_or(&words[0])
+            .clone()
+    }
+}
+
+/// Guesses a random word that could still be the answer, like a careful human player
+pub struct RandomCandidate(StdRng);
+
+impl RandomCandidate {
+    pub fn new(seed: u64) -> Self {
+        Self(StdRng::seed_from_u64(seed))
+    }
+}
+
+impl Strategy for RandomCandidate {
+    fn name(&self) -> &'static str {
+        "random-candidate"
+    }
+
+    fn next_guess(&mut self, words: &[Vec<char>], history: &History) -> Vec<char> {
+        let candidates = words
+            .iter()
+            .filter(|word| is_candidate(word, history))
+            .collect::<Vec<_>>();
+
+        candidates
+            .choose(&mut self.0)
+            .copied()
+            .unwrap_or(&words[0])
+            .clone()
+    }
+}
+
+#[derive(Default)]
+pub struct Summary {
+    // Solved games by the number of guesses, the first one is solved with one guess
+    pub distribution: Vec<usize>,
+    pub failed: usize,
+}
+
+impl Summary {
+    pub fn played(&self) -> usize {
+        self.distribution.iter().sum::<usize>() + self.failed
+    }
+
+    pub fn solved(&self) -> usize {
+        self.distribution.iter().sum()
+    }
+
+    pub fn average_guesses(&self) -> f64 {
+        let guesses = self
+            .distribution
+            .iter()
+            .enumerate()
+            .map(|(index, count)| (index + 1) * count)
+            .sum::<usize>();
+
+        guesses as f64 / self.solved().max(1) as f64
+    }
+}
+
+/// Plays the words of a list with a strategy
+pub struct Simulator {
+    words: Vec<Vec<char>>,
+    max_guesses: usize,
+}
+
+impl Simulator {
+    pub fn new(mut words: Vec<Vec<char>>, max_guesses: usize) -> Self {
+        words.sort();
+        words.dedup();
+        Self { words, max_guesses }
+    }
+
+    /// Number of guesses used to solve the word, None if it wasn't solved
+    pub fn play(&self, word: &[char], strategy: &mut dyn Strategy) -> Option<usize> {
+        let mut simulation = Simulation::new(word, self.max_guesses);
+
+        while !simulation.is_over() {
+            let guess = strategy.next_guess(&self.words, simulation.history());
+            simulation.guess(&guess)?;
+        }
+
+        simulation.is_solved().then(|| simulation.history().len())
+    }
+
+    pub fn benchmark(&self, strategy: &mut dyn Strategy, games: usize) -> Summary {
+        let mut rng = StdRng::seed_from_u64(WORD_SEED);
+        let mut summary = Summary {
+            distribution: vec![0; self.max_guesses],
+            failed: 0,
+        };
+
+        for _ in 0..games {
+            let word = match self.words.choose(&mut rng) {
+                Some(word) => word,
+                None => break,
+            };
+
+            match self.play(word, strategy) {
+                Some(guesses) => summary.distribution[guesses - 1] += 1,
+                None => summary.failed += 1,
+            }
+        }
+
+        summary
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn words(list: &[&str]) -> Vec<Vec<char>> {
+        list.iter().map(|word| word.chars().collect()).collect()
+    }
+
+    #[test]
+    fn every_strategy_solves_a_tiny_list() {
+        let words = words(&["KALA", "KULA", "SALA", "SUKA", "TALO"]);
+        let simulator = Simulator::new(words.clone(), MAX_GUESSES);
+        let strategies: Vec<Box<dyn Strategy>> =
+            vec![Box::new(FirstCandidate), Box::new(RandomCandidate::new(1))];
+
+        for mut strategy in strategies {
+            for word in &words {
+                let guesses = simulator.play(word, strategy.as_mut());
+                assert!(
+                    matches!(guesses, Some(guesses) if guesses <= MAX_GUESSES),
+                    "{} didn't solve {:?}",
+                    strategy.name(),
+                    word
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn benchmark_counts_every_game() {
+        let simulator = Simulator::new(words(&["KALA", "KULA", "SALA"]), MAX_GUESSES);
+        let summary = simulator.benchmark(&mut FirstCandidate, 10);
+
+        assert_eq!(summary.played(), 10);
+        assert_eq!(summary.failed, 0);
+        assert_eq!(summary.distribution.len(), MAX_GUESSES);
+    }
+
+    #[test]
+    fn simulation_is_over_after_the_max_guesses() {
+        let word = "KALA".chars().collect::<Vec<_>>();
+        let guess = "SUKA".chars().collect::<Vec<_>>();
+        let mut simulation = Simulation::new(&word, 2);
+
+        assert!(simulation.guess(&guess).is_some());
+        assert!(simulation.guess(&guess).is_some());
+        assert!(simulation.is_over());
+        assert!(!simulation.is_solved());
+        assert_eq!(simulation.guess(&word), None);
+    }
+}
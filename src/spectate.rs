@@ -0,0 +1,181 @@
+use rand::seq::SliceRandom;
+use serde::{Deserialize, Serialize};
+use wasm_bindgen::JsValue;
+use web_sys::window;
+use yew::Callback;
+
+use crate::game::Game;
+use crate::manager::TileState;
+use crate::transport::{LoopbackTransport, Transport, WebSocketTransport};
+
+// Relay server passing the boards of the players to their spectators. Left unset, the spectator
+// links stay hidden in release builds and the development builds broadcast to a local stand-in:
+// `SANULI_SPECTATE_URL=wss://watch.example.com trunk build`
+const SPECTATE_URL: Option<&str> = option_env!("SANULI_SPECTATE_URL");
+const CHANNEL_LENGTH: usize = 10;
+const CHANNEL_CHARACTERS: &[u8] = b"abcdefghijkmnpqrstuvwxyz23456789";
+// Hidden letters of the rows sent while the game is still running
+const HIDDEN: char = ' ';
+
+/// Messages of a spectated channel. The server passes the boards of the host to the watchers,
+/// and sends the latest board to a watcher as soon as it joins.
+#[derive(Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum SpectateMessage {
+    Host { channel: String },
+    Watch { channel: String },
+    Board { board: SpectatedBoard },
+}
+
+#[derive(Clone, PartialEq, Serialize, Deserialize)]
+pub struct SpectatedBoard {
+    pub title: String,
+    pub word_length: usize,
+    pub max_guesses: usize,
+    // The revealed rows, the letters are left out until the game has ended
+    pub guesses: Vec<Vec<(char, TileState)>>,
+    pub is_guessing: bool,
+}
+
+impl SpectatedBoard {
+    pub fn from_game(game: &dyn Game) -> Option<Self> {
+        let boards = game.boards();
+        if boards.len() != 1 {
+            return None;
+        }
+
+        let board = &boards[0];
+        let revealed = if board.is_guessing {
+            board.current_guess
+        } else {
+            board.current_guess + 1
+        };
+        let guesses = board
+            .guesses
+            .iter()
+            .take(revealed)
+            .map(|guess| {
+                guess
+                    .iter()
+                    .map(|(c, state)| (if board.is_guessing { HIDDEN } else { *c }, *state))
+                    .collect()
+            })
+            .collect();
+
+        Some(Self {
+            title: game.title(),
+            word_length: game.word_length(),
+            max_guesses: game.max_guesses(),
+            guesses,
+            is_guessing: board.is_guessing,
+        })
+    }
+}
+
+pub fn is_configured() -> bool {
+    SPECTATE_URL.is_some() || cfg!(debug_assertions)
+}
+
+fn connect(
+    onmessage: Callback<SpectateMessage>,
+    onclose: Callback<()>,
+) -> Option<Box<dyn Transport<SpectateMessage>>> {
+    match SPECTATE_URL {
+        Some(url) => Some(Box::new(WebSocketTransport::connect(
+            url, onmessage, onclose,
+        )?)),
+        None if cfg!(debug_assertions) => {
+            Some(Box::new(LoopbackTransport::new(|_| Vec::new(), onmessage)))
+        }
+        None => None,
+    }
+}
+
+/// The board of the player sent to the spectators of the channel
+pub struct Broadcast {
+    channel: String,
+    last_board: Option<SpectatedBoard>,
+    transport: Box<dyn Transport<SpectateMessage>>,
+}
+
+impl Broadcast {
+    pub fn start(onclose: Callback<()>) -> Option<Self> {
+        let mut rng = rand::thread_rng();
+        let channel = (0..CHANNEL_LENGTH)
+            .map(|_| *CHANNEL_CHARACTERS.choose(&mut rng).unwrap() as char)
+            .collect::<String>();
+
+        let transport = connect(Callback::noop(), onclose)?;
+        transport.send(&SpectateMessage::Host {
+            channel: channel.clone(),
+        });
+
+        Some(Self {
+            channel,
+            last_board: None,
+            transport,
+        })
+    }
+
+    pub fn link(&self) -> Option<String> {
+        let origin = window()?.location().origin().ok()?;
+        Some(format!("{}/?katso={}", origin, self.channel))
+    }
+
+    /// Sends the board, unless the spectators already have it
+    pub fn send_board(&mut self, board: SpectatedBoard) {
+        if self.last_board.as_ref() == Some(&board) {
+            return;
+        }
+
+        self.transport.send(&SpectateMessage::Board {
+            board: board.clone(),
+        });
+        self.last_board = Some(board);
+    }
+}
+
+/// Board of another player being watched
+pub struct Spectating {
+    pub board: Option<SpectatedBoard>,
+    _transport: Box<dyn Transport<SpectateMessage>>,
+}
+
+impl Spectating {
+    pub fn watch(
+        channel: &str,
+        onmessage: Callback<SpectateMessage>,
+        onclose: Callback<()>,
+    ) -> Option<Self> {
+        let transport = connect(onmessage, onclose)?;
+        transport.send(&SpectateMessage::Watch {
+            channel: channel.to_owned(),
+        });
+
+        Some(Self {
+            board: None,
+            _transport: transport,
+        })
+    }
+}
+
+/// Channel of a spectator link the page was opened with. The query string is removed.
+pub fn channel_from_url() -> Option<String> {
+    let window = window()?;
+    let qs = window.location().search().ok()?;
+
+    let channel = qs
+        .trim_start_matches('?')
+        .split('&')
+        .find_map(|param| param.strip_prefix("katso="))
+        .filter(|channel| !channel.is_empty())?
+        .to_owned();
+
+    window
+        .history()
+        .ok()?
+        .replace_state_with_url(&JsValue::null(), "", Some("/"))
+        .ok()?;
+
+    Some(channel)
+}
@@ -0,0 +1,84 @@
+use wasm_bindgen::{prelude::Closure, JsCast, JsValue};
+use web_sys::{window, SpeechRecognition, SpeechRecognitionEvent};
+use yew::Callback;
+
+use crate::i18n::Language;
+
+// Chromium based browsers still only have the prefixed constructor
+const CONSTRUCTORS: [&str; 2] = ["SpeechRecognition", "webkitSpeechRecognition"];
+const MAX_ALTERNATIVES: u32 = 5;
+
+fn constructor() -> Option<js_sys::Function> {
+    let window = window()?;
+    CONSTRUCTORS.iter().find_map(|name| {
+        js_sys::Reflect::get(&window, &JsValue::from_str(name))
+            .ok()?
+            .dyn_into::<js_sys::Function>()
+            .ok()
+    })
+}
+
+pub fn is_supported() -> bool {
+    constructor().is_some()
+}
+
+/// A single dictation, stopped when dropped
+pub struct Dictation {
+    recognition: SpeechRecognition,
+    _onresult: Closure<dyn FnMut(SpeechRecognitionEvent)>,
+    _onend: Closure<dyn FnMut()>,
+}
+
+impl Dictation {
+    /// Starts listening to a single word. The transcript alternatives are passed to `onresult`,
+    /// the best one first, and `onend` is called once the recognition has stopped.
+    pub fn start(
+        language: Language,
+        onresult: Callback<Vec<String>>,
+        onend: Callback<()>,
+    ) -> Option<Self> {
+        let recognition: SpeechRecognition =
+            js_sys::Reflect::construct(&constructor()?, &js_sys::Array::new())
+                .ok()?
+                .unchecked_into();
+
+        recognition.set_lang(language.locale());
+        recognition.set_interim_results(false);
+        recognition.set_max_alternatives(MAX_ALTERNATIVES);
+
+        let onresult = Closure::wrap(Box::new(move |e: SpeechRecognitionEvent| {
+            let alternatives = e
+                .results()
+                .and_then(|results| results.get(e.result_index()))
+                .map(|result| {
+                    (0..result.length())
+                        .filter_map(|index| result.get(index))
+                        .map(|alternative| alternative.transcript())
+                        .collect()
+                })
+                .unwrap_or_default();
+
+            onresult.emit(alternatives);
+        }) as Box<dyn FnMut(SpeechRecognitionEvent)>);
+
+        let onend = Closure::wrap(Box::new(move || onend.emit(())) as Box<dyn FnMut()>);
+
+        recognition.set_onresult(Some(onresult.as_ref().unchecked_ref()));
+        recognition.set_onend(Some(onend.as_ref().unchecked_ref()));
+        recognition.start().ok()?;
+
+        Some(Self {
+            recognition,
+            _onresult: onresult,
+            _onend: onend,
+        })
+    }
+}
+
+impl Drop for Dictation {
+    fn drop(&mut self) {
+        self.recognition.set_onresult(None);
+        self.recognition.set_onend(None);
+        self.recognition.abort();
+    }
+}
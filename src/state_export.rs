@@ -0,0 +1,144 @@
+//! Export and import of the whole persisted app state, for forks sharing the
+//! storage schema and for moving progress between Sanuli variants, and for the
+//! sync of `src/sync.rs`.
+//!
+//! Built with `--features state-export`, the functions are exported from the
+//! wasm module and can be called from the browser console:
+//!
+//! ```js
+//! const state = wasmBindings.dump_state();
+//! // ...and on the other site
+//! wasmBindings.load_state(state);
+//! ```
+
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+#[cfg(feature = "state-export")]
+use wasm_bindgen::prelude::*;
+#[cfg(feature = "state-export")]
+use web_sys::window;
+
+use crate::storage::{self, Transaction};
+
+const STATE_VERSION: u32 = 1;
+
+// Keys holding the settings, statistics, histories and what the player has already seen or done.
+// Left out are "daily_index", a cache of today's index refetched on every load, and
+// "last_seen_version", which tells what build this browser last ran.
+const STATE_KEYS: [&str; 9] = [
+    "settings",
+    "saved_at",
+    "daily_word_history",
+    "evening_word_history",
+    "double_daily_history",
+    "weekly_history",
+    "replays",
+    "dismissed_announcements",
+    "tutorial_completed",
+];
+// Prefixes of the keys of the in-progress games, the histories of the other puzzle languages,
+// the submitted leaderboard results and the votes
+const STATE_KEY_PREFIXES: [&str; 7] = [
+    "game|",
+    "daily_word_history|",
+    "evening_word_history|",
+    "double_daily_history|",
+    "weekly_history|",
+    "leaderboard|",
+    "vote|",
+];
+
+#[derive(Clone, PartialEq, Serialize, Deserialize)]
+pub struct StateDump {
+    pub version: u32,
+    pub entries: BTreeMap<String, Value>,
+}
+
+fn is_state_key(key: &str) -> bool {
+    STATE_KEYS.contains(&key)
+        || STATE_KEY_PREFIXES
+            .iter()
+            .any(|prefix| key.starts_with(prefix))
+}
+
+// Only the state of the active profile is dumped and replaced
+fn state_keys() -> Vec<String> {
+    storage::keys()
+        .into_iter()
+        .filter(|key| is_state_key(key))
+        .collect()
+}
+
+/// The settings, statistics, histories and in-progress games of the active profile
+#[cfg(any(feature = "state-export", feature = "sync", web_sys_unstable_apis))]
+pub fn dump() -> StateDump {
+    let entries = state_keys()
+        .into_iter()
+        .filter_map(|key| {
+            let value: Value = storage::get(&key).ok()?;
+            Some((key, value))
+        })
+        .collect();
+
+    StateDump {
+        version: STATE_VERSION,
+        entries,
+    }
+}
+
+/// Replaces the whole persisted state with the dump, the page has to be reloaded for it to
+/// take effect. Nothing is changed if the dump is invalid or doesn't fit in the storage.
+pub fn load(dump: &StateDump) -> Result<(), String> {
+    if dump.version != STATE_VERSION {
+        return Err(format!(
+            "Unsupported state version {}, expected {}",
+            dump.version, STATE_VERSION
+        ));
+    }
+
+    if let Some(key) = dump.entries.keys().find(|key| !is_state_key(key)) {
+        return Err(format!("Unknown state key \"{}\"", key));
+    }
+
+    let mut transaction = Transaction::new();
+    for (key, value) in dump.entries.iter() {
+        transaction
+            .set(key.as_str(), value)
+            .map_err(|err| err.to_string())?;
+    }
+    transaction.commit().map_err(|err| err.to_string())?;
+
+    // Games missing from the dump would otherwise resurface in the middle of the loaded state
+    for key in state_keys() {
+        if !dump.entries.contains_key(&key) {
+            storage::delete(&key);
+        }
+    }
+
+    Ok(())
+}
+
+/// Returns the settings, statistics, histories and in-progress games as a JSON string:
+/// `{"version": 1, "entries": {"<localStorage key>": <stored value>, ...}}`
+#[cfg(feature = "state-export")]
+#[wasm_bindgen]
+pub fn dump_state() -> Result<String, JsValue> {
+    serde_json::to_string(&dump()).map_err(|err| JsValue::from_str(&err.to_string()))
+}
+
+/// Replaces the whole persisted state with a dump from `dump_state` and reloads the page.
+/// Nothing is changed if the dump is invalid or doesn't fit in the storage.
+#[cfg(feature = "state-export")]
+#[wasm_bindgen]
+pub fn load_state(json: &str) -> Result<(), JsValue> {
+    let dump: StateDump =
+        serde_json::from_str(json).map_err(|err| JsValue::from_str(&err.to_string()))?;
+    load(&dump).map_err(|err| JsValue::from_str(&err))?;
+
+    window()
+        .ok_or_else(|| JsValue::from_str("window not available"))?
+        .location()
+        .reload()
+}
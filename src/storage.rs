@@ -0,0 +1,245 @@
+//! Every persisted key belongs to the active local profile. The default profile keeps the
+//! plain keys of the builds before the profiles, the keys of the others are prefixed with
+//! "profile|<name>|". Only the list of the profiles and the active one are shared.
+
+use std::cell::Cell;
+
+use gloo_storage::{errors::StorageError, LocalStorage, Storage};
+use serde::{de::DeserializeOwned, Serialize};
+use serde_json::Value;
+
+const PROFILES_KEY: &str = "profiles";
+const ACTIVE_PROFILE_KEY: &str = "active_profile";
+const PROFILE_PREFIX: &str = "profile|";
+// Data shared by the profiles that can be fetched again, eg. the full word lists
+const CACHE_PREFIX: &str = "cache|";
+pub const MAX_PROFILE_NAME_LENGTH: usize = 20;
+
+thread_local! {
+    // Read once, the page is reloaded when the profile is switched
+    static ACTIVE_PROFILE: Option<String> = LocalStorage::get(ACTIVE_PROFILE_KEY).ok();
+    // The latest failed write, until it's shown to the player
+    static FAILURE: Cell<Option<Failure>> = const { Cell::new(None) };
+}
+
+/// Why a write failed, most writes are fire and forget so the failures are collected here
+#[derive(Clone, Copy, PartialEq)]
+pub enum Failure {
+    QuotaExceeded,
+    // Storage disabled by the browser, eg. in some private modes
+    Unavailable,
+    Invalid,
+}
+
+impl Failure {
+    fn from_error(err: &StorageError) -> Self {
+        match err {
+            StorageError::JsError(err)
+                if err.name == "QuotaExceededError" || err.name == "NS_ERROR_DOM_QUOTA_REACHED" =>
+            {
+                Failure::QuotaExceeded
+            }
+            StorageError::JsError(_) => Failure::Unavailable,
+            StorageError::SerdeError(_) | StorageError::KeyNotFound(_) => Failure::Invalid,
+        }
+    }
+
+    pub fn message(&self) -> &'static str {
+        match self {
+            Failure::QuotaExceeded => {
+                "Selaimen tallennustila on täynnä, eikä peliä voitu tallentaa. Vapauta tilaa poistamalla profiileja tai muiden sivujen tietoja."
+            }
+            Failure::Unavailable => "Selain ei salli tallentamista, eikä peliä voitu tallentaa.",
+            Failure::Invalid => "Peliä ei voitu tallentaa.",
+        }
+    }
+}
+
+fn record_failure<T>(result: Result<T, StorageError>) -> Result<T, StorageError> {
+    if let Err(err) = &result {
+        let failure = Failure::from_error(err);
+        FAILURE.with(|cached| cached.set(Some(failure)));
+    }
+
+    result
+}
+
+/// The latest failed write since the previous call
+pub fn take_failure() -> Option<Failure> {
+    FAILURE.with(Cell::take)
+}
+
+/// The active profile, None is the default profile
+pub fn active_profile() -> Option<String> {
+    ACTIVE_PROFILE.with(|profile| profile.clone())
+}
+
+/// Names of the profiles besides the default profile
+pub fn profiles() -> Vec<String> {
+    LocalStorage::get(PROFILES_KEY).unwrap_or_default()
+}
+
+/// Adds a profile, false for an invalid or a taken name
+pub fn add_profile(name: &str) -> bool {
+    let name = name.trim();
+    let mut profiles = profiles();
+    if name.is_empty()
+        || name.contains('|')
+        || name.chars().count() > MAX_PROFILE_NAME_LENGTH
+        || profiles.iter().any(|profile| profile == name)
+    {
+        return false;
+    }
+
+    profiles.push(name.to_owned());
+    LocalStorage::set(PROFILES_KEY, profiles).is_ok()
+}
+
+/// Makes the profile active once the page is reloaded, None switches to the default profile
+pub fn switch_profile(profile: Option<&str>) -> Result<(), StorageError> {
+    match profile {
+        Some(profile) => LocalStorage::set(ACTIVE_PROFILE_KEY, profile),
+        None => {
+            LocalStorage::delete(ACTIVE_PROFILE_KEY);
+            Ok(())
+        }
+    }
+}
+
+/// The localStorage key of a key of the active profile
+pub fn profile_key(key: &str) -> String {
+    match active_profile() {
+        Some(profile) => format!("{}{}|{}", PROFILE_PREFIX, profile, key),
+        None => key.to_owned(),
+    }
+}
+
+pub fn get<T: DeserializeOwned>(key: impl AsRef<str>) -> Result<T, StorageError> {
+    LocalStorage::get(profile_key(key.as_ref()))
+}
+
+pub fn set<T: Serialize>(key: impl AsRef<str>, value: T) -> Result<(), StorageError> {
+    let key = profile_key(key.as_ref());
+    let mut result = LocalStorage::set(&key, &value);
+    // The caches give way to the saves
+    if matches!(&result, Err(err) if Failure::from_error(err) == Failure::QuotaExceeded)
+        && clear_caches()
+    {
+        result = LocalStorage::set(&key, &value);
+    }
+    record_failure(result)
+}
+
+pub fn delete(key: impl AsRef<str>) {
+    LocalStorage::delete(profile_key(key.as_ref()))
+}
+
+pub fn cache_get<T: DeserializeOwned>(key: &str) -> Option<T> {
+    LocalStorage::get(format!("{}{}", CACHE_PREFIX, key)).ok()
+}
+
+/// Failing to cache is no reason to bother the player, unlike a failed save
+pub fn cache_set<T: Serialize>(key: &str, value: T) {
+    let _ = LocalStorage::set(format!("{}{}", CACHE_PREFIX, key), value);
+}
+
+/// Deletes every cache, returning whether there were any
+fn clear_caches() -> bool {
+    let storage = LocalStorage::raw();
+    let keys = (0..LocalStorage::length())
+        .filter_map(|index| storage.key(index).ok().flatten())
+        .filter(|key| key.starts_with(CACHE_PREFIX))
+        .collect::<Vec<_>>();
+    for key in &keys {
+        LocalStorage::delete(key);
+    }
+    !keys.is_empty()
+}
+
+/// The keys of the active profile, without the profile prefix
+pub fn keys() -> Vec<String> {
+    let storage = LocalStorage::raw();
+    let keys = (0..LocalStorage::length()).filter_map(|index| storage.key(index).ok().flatten());
+
+    match active_profile() {
+        Some(profile) => {
+            let prefix = format!("{}{}|", PROFILE_PREFIX, profile);
+            keys.filter_map(|key| key.strip_prefix(&prefix).map(str::to_owned))
+                .collect()
+        }
+        None => keys
+            .filter(|key| {
+                !key.starts_with(PROFILE_PREFIX)
+                    && !key.starts_with(CACHE_PREFIX)
+                    && key != PROFILES_KEY
+                    && key != ACTIVE_PROFILE_KEY
+            })
+            .collect(),
+    }
+}
+
+/// Stages writes to multiple localStorage keys and applies them together.
+/// If any of the writes fails, for example when the storage quota is exceeded,
+/// the keys already written are restored to their previous values.
+#[derive(Default)]
+pub struct Transaction {
+    writes: Vec<(String, Value)>,
+}
+
+impl Transaction {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn set<T: Serialize>(
+        &mut self,
+        key: impl Into<String>,
+        value: &T,
+    ) -> Result<(), StorageError> {
+        let key = profile_key(&key.into());
+        let value = record_failure(serde_json::to_value(value).map_err(StorageError::from))?;
+
+        // Only the latest write to a key matters
+        self.writes.retain(|(staged, _)| *staged != key);
+        self.writes.push((key, value));
+
+        Ok(())
+    }
+
+    pub fn commit(self) -> Result<(), StorageError> {
+        let mut result = self.apply();
+        // The caches give way to the saves, as in set()
+        if matches!(&result, Err(err) if Failure::from_error(err) == Failure::QuotaExceeded)
+            && clear_caches()
+        {
+            result = self.apply();
+        }
+        record_failure(result)
+    }
+
+    fn apply(&self) -> Result<(), StorageError> {
+        let storage = LocalStorage::raw();
+        let mut previous_values: Vec<(&str, Option<String>)> =
+            Vec::with_capacity(self.writes.len());
+
+        for (key, value) in self.writes.iter() {
+            let previous = storage.get_item(key).ok().flatten();
+
+            if let Err(err) = LocalStorage::set(key, value) {
+                // Roll back, and hope that the rollback fits as it did before
+                for (key, previous) in previous_values.into_iter().rev() {
+                    let _res = match previous {
+                        Some(previous) => storage.set_item(key, &previous),
+                        None => storage.remove_item(key),
+                    };
+                }
+
+                return Err(err);
+            }
+
+            previous_values.push((key, previous));
+        }
+
+        Ok(())
+    }
+}
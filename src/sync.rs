@@ -0,0 +1,188 @@
+//! Optional sync of the saved progress through a URL configured by the player, any endpoint
+//! answering GET and PUT of a JSON document will do, eg. a file on a WebDAV server.
+//!
+//! The newer of the local and the remote save wins as a whole, but the daily histories of both
+//! are kept, so that the streak of a player alternating between two devices isn't broken.
+
+use std::fmt;
+
+use serde_json::Value;
+use web_sys::window;
+use yew::Callback;
+
+use crate::api::{ApiError, HttpRequest};
+use crate::state_export::{self, StateDump};
+use crate::storage;
+use crate::transport::{HttpMessage, HttpTransport, Transport};
+
+// Kept out of the synced state, a save can't move itself elsewhere
+const SYNC_URL_KEY: &str = "sync_url";
+const SAVED_AT_KEY: &str = "saved_at";
+// Histories merged by their dates instead of being replaced by the newer save
+const MERGED_KEY_PREFIXES: [&str; 3] = [
+    "daily_word_history",
+    "evening_word_history",
+    "double_daily_history",
+];
+
+#[derive(Debug)]
+pub enum SyncError {
+    Api(ApiError),
+    Storage(String),
+}
+
+impl fmt::Display for SyncError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            SyncError::Api(err) => write!(f, "{}", err),
+            SyncError::Storage(err) => write!(f, "{}", err),
+        }
+    }
+}
+
+/// Messages of the sync endpoint, a pulled save is answered with `Pulled` and a pushed one with
+/// `Pushed`
+#[derive(Clone)]
+enum SyncMessage {
+    Pull(String),
+    Push(String, StateDump),
+    Pulled(Option<StateDump>),
+    Pushed,
+    Failed(ApiError),
+}
+
+impl HttpMessage for SyncMessage {
+    fn request(&self) -> Option<HttpRequest> {
+        match self {
+            SyncMessage::Pull(url) => Some(HttpRequest::get(url.clone())),
+            SyncMessage::Push(url, dump) => HttpRequest::with_body("PUT", url.clone(), dump).ok(),
+            _ => None,
+        }
+    }
+
+    fn reply(self, response: Result<String, ApiError>) -> Self {
+        match (self, response) {
+            (SyncMessage::Pull(_), Ok(body)) if body.trim().is_empty() => SyncMessage::Pulled(None),
+            (SyncMessage::Pull(_), Ok(body)) => match serde_json::from_str(&body) {
+                Ok(dump) => SyncMessage::Pulled(Some(dump)),
+                Err(err) => SyncMessage::Failed(err.into()),
+            },
+            // Nothing has been pushed yet
+            (SyncMessage::Pull(_), Err(ApiError::Status(404))) => SyncMessage::Pulled(None),
+            (SyncMessage::Push(..), Ok(_)) => SyncMessage::Pushed,
+            (_, Err(err)) => SyncMessage::Failed(err),
+            // Only the requests are sent
+            (message, Ok(_)) => message,
+        }
+    }
+}
+
+pub fn sync_url() -> Option<String> {
+    storage::get(SYNC_URL_KEY).ok()
+}
+
+pub fn set_sync_url(url: Option<&str>) {
+    match url.map(str::trim).filter(|url| !url.is_empty()) {
+        Some(url) => {
+            let _res = storage::set(SYNC_URL_KEY, url);
+        }
+        None => storage::delete(SYNC_URL_KEY),
+    }
+}
+
+fn saved_at(dump: &StateDump) -> i64 {
+    dump.entries
+        .get(SAVED_AT_KEY)
+        .and_then(Value::as_i64)
+        .unwrap_or(0)
+}
+
+/// The newer save, with the days played only in the older one added to its histories
+pub fn merge(local: &StateDump, remote: &StateDump) -> StateDump {
+    let (newer, older) = if saved_at(remote) > saved_at(local) {
+        (remote, local)
+    } else {
+        (local, remote)
+    };
+
+    let mut merged = newer.clone();
+    let histories = older.entries.iter().filter(|(key, _)| {
+        MERGED_KEY_PREFIXES
+            .iter()
+            .any(|prefix| key.starts_with(prefix))
+    });
+
+    for (key, older_history) in histories {
+        match (merged.entries.get_mut(key), older_history) {
+            (Some(Value::Object(history)), Value::Object(older_history)) => {
+                for (date, entry) in older_history {
+                    history.entry(date.clone()).or_insert_with(|| entry.clone());
+                }
+            }
+            (Some(_), _) => {}
+            (None, _) => {
+                merged.entries.insert(key.clone(), older_history.clone());
+            }
+        }
+    }
+
+    merged
+}
+
+/// Merges the remote save with the local one and pushes the result. `ondone` is told whether the
+/// local state was changed, the page has to be reloaded for the changes to take effect.
+pub fn sync(url: &str, ondone: Callback<Result<bool, SyncError>>) {
+    let push_url = url.to_owned();
+    let onpulled = Callback::from(move |message| match message {
+        SyncMessage::Pulled(remote) => {
+            let local = state_export::dump();
+            let merged = match &remote {
+                Some(remote) => merge(&local, remote),
+                None => local.clone(),
+            };
+
+            let is_changed = merged != local;
+            if is_changed {
+                if let Err(err) = state_export::load(&merged) {
+                    return ondone.emit(Err(SyncError::Storage(err)));
+                }
+            }
+            if remote.as_ref() == Some(&merged) {
+                return ondone.emit(Ok(is_changed));
+            }
+
+            let ondone = ondone.clone();
+            let onpushed = Callback::from(move |message| match message {
+                SyncMessage::Pushed => ondone.emit(Ok(is_changed)),
+                SyncMessage::Failed(err) => ondone.emit(Err(SyncError::Api(err))),
+                _ => {}
+            });
+            HttpTransport::new(onpushed).send(&SyncMessage::Push(push_url.clone(), merged));
+        }
+        SyncMessage::Failed(err) => ondone.emit(Err(SyncError::Api(err))),
+        _ => {}
+    });
+
+    HttpTransport::new(onpulled).send(&SyncMessage::Pull(url.to_owned()));
+}
+
+/// Syncs with the configured URL, if any, and reloads the page if the local state changed
+pub fn sync_in_background() {
+    let url = match sync_url() {
+        Some(url) => url,
+        None => return,
+    };
+
+    sync(
+        &url,
+        Callback::from(|result| match result {
+            Ok(true) => {
+                if let Some(window) = window() {
+                    let _result = window.location().reload();
+                }
+            }
+            Ok(false) => {}
+            Err(err) => log::info!("Syncing the progress failed: {}", err),
+        }),
+    );
+}
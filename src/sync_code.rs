@@ -0,0 +1,159 @@
+//! The whole saved state as a "sync code" to copy and paste to another device, for moving the
+//! progress without a server. The code is "SANULI<version>:<checksum>:<state>", the state being
+//! the JSON dump of `src/state_export.rs` in URL safe base64.
+
+use crate::state_export;
+
+const SYNC_CODE_PREFIX: &str = "SANULI";
+const SYNC_CODE_VERSION: u32 = 1;
+const BASE64_ALPHABET: &[u8; 64] =
+    b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_";
+
+#[derive(Debug, PartialEq)]
+pub enum SyncCodeError {
+    Invalid,
+    Version(u32),
+    Checksum,
+    Storage(String),
+}
+
+impl SyncCodeError {
+    /// Explanation for the player, to be translated
+    pub fn message(&self) -> &'static str {
+        match self {
+            SyncCodeError::Invalid => "Siirtokoodi ei kelpaa.",
+            SyncCodeError::Version(_) => {
+                "Siirtokoodi on eri versiosta, päivitä peli molemmilla laitteilla."
+            }
+            SyncCodeError::Checksum => "Siirtokoodi on vaillinainen, kopioi se uudelleen kokonaan.",
+            SyncCodeError::Storage(_) => "Siirtokoodin tallentaminen epäonnistui.",
+        }
+    }
+}
+
+/// FNV-1a, catches the codes cut short or mangled on the way
+fn checksum(bytes: &[u8]) -> u32 {
+    bytes.iter().fold(0x811c9dc5, |hash, byte| {
+        (hash ^ u32::from(*byte)).wrapping_mul(0x01000193)
+    })
+}
+
+#[cfg(any(web_sys_unstable_apis, test))]
+fn encode(bytes: &[u8]) -> String {
+    let mut encoded = String::with_capacity(bytes.len() * 4 / 3 + 3);
+    for chunk in bytes.chunks(3) {
+        let bits = chunk.iter().enumerate().fold(0u32, |bits, (index, byte)| {
+            bits | (u32::from(*byte) << (16 - 8 * index))
+        });
+
+        // Without padding, the length tells how many bytes the last chunk had
+        for index in 0..=chunk.len() {
+            encoded.push(BASE64_ALPHABET[((bits >> (18 - 6 * index)) & 0x3f) as usize] as char);
+        }
+    }
+
+    encoded
+}
+
+fn decode(encoded: &str) -> Option<Vec<u8>> {
+    let values = encoded
+        .bytes()
+        .map(|c| BASE64_ALPHABET.iter().position(|letter| *letter == c))
+        .collect::<Option<Vec<_>>>()?;
+
+    let mut bytes = Vec::with_capacity(values.len() * 3 / 4);
+    for chunk in values.chunks(4) {
+        if chunk.len() < 2 {
+            return None;
+        }
+
+        let bits = chunk.iter().enumerate().fold(0u32, |bits, (index, value)| {
+            bits | ((*value as u32) << (18 - 6 * index))
+        });
+        for index in 0..chunk.len() - 1 {
+            bytes.push((bits >> (16 - 8 * index)) as u8);
+        }
+    }
+
+    Some(bytes)
+}
+
+/// The saved state of the active profile as a sync code
+#[cfg(web_sys_unstable_apis)]
+pub fn export() -> String {
+    let json = serde_json::to_string(&state_export::dump()).unwrap_or_default();
+
+    format!(
+        "{}{}:{:08x}:{}",
+        SYNC_CODE_PREFIX,
+        SYNC_CODE_VERSION,
+        checksum(json.as_bytes()),
+        encode(json.as_bytes())
+    )
+}
+
+/// Replaces the saved state with the one of the sync code, the page has to be reloaded for it
+/// to take effect
+pub fn import(code: &str) -> Result<(), SyncCodeError> {
+    // Messaging apps like to break the long lines
+    let code = code.split_whitespace().collect::<String>();
+
+    let mut parts = code
+        .strip_prefix(SYNC_CODE_PREFIX)
+        .ok_or(SyncCodeError::Invalid)?
+        .split(':');
+
+    let version = parts
+        .next()
+        .and_then(|version| version.parse::<u32>().ok())
+        .ok_or(SyncCodeError::Invalid)?;
+    if version != SYNC_CODE_VERSION {
+        return Err(SyncCodeError::Version(version));
+    }
+
+    let expected = parts
+        .next()
+        .and_then(|checksum| u32::from_str_radix(checksum, 16).ok())
+        .ok_or(SyncCodeError::Invalid)?;
+    let json = parts
+        .next()
+        .and_then(decode)
+        .ok_or(SyncCodeError::Invalid)?;
+    if checksum(&json) != expected {
+        return Err(SyncCodeError::Checksum);
+    }
+
+    let dump = serde_json::from_slice(&json).map_err(|_| SyncCodeError::Invalid)?;
+    state_export::load(&dump).map_err(SyncCodeError::Storage)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn state_survives_the_round_trip() {
+        let json = br#"{"version":1,"entries":{"settings":{"word_length":5}}}"#;
+
+        // Every length of the last chunk
+        for len in json.len() - 3..=json.len() {
+            assert_eq!(decode(&encode(&json[..len])), Some(json[..len].to_vec()));
+        }
+        assert_eq!(decode("A"), None);
+        assert_eq!(decode("U2F+dWxp"), None);
+    }
+
+    #[test]
+    fn state_is_url_safe_base64_without_padding() {
+        assert_eq!(encode(b"Sanuli"), "U2FudWxp");
+        assert_eq!(encode(b"{}"), "e30");
+        assert_eq!(encode(&[0xfb, 0xff]), "-_8");
+    }
+
+    #[test]
+    fn checksum_is_fnv_1a() {
+        assert_eq!(checksum(b""), 0x811c_9dc5);
+        assert_eq!(checksum(b"a"), 0xe40c_292c);
+        assert_eq!(checksum(b"foobar"), 0xbf9c_f968);
+    }
+}
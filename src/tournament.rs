@@ -0,0 +1,48 @@
+//! Tournaments among friends: a code shared in the group defines a series of words, which every
+//! participant plays on their own. The codes are numbers written with letters that are easy to
+//! tell apart, and the words are picked from the common words by the number.
+
+use rand::Rng;
+
+pub const TOURNAMENT_WORDS: usize = 7;
+const CODE_LENGTH: usize = 6;
+const CODE_LETTERS: &[u8] = b"ACDEFHJKLMNPRTUVY";
+
+fn code_count() -> u64 {
+    (CODE_LETTERS.len() as u64).pow(CODE_LENGTH as u32)
+}
+
+/// A new tournament with a random code
+pub fn random_id() -> u64 {
+    rand::thread_rng().gen_range(0..code_count())
+}
+
+/// The code of the tournament, eg. "KALVEN"
+pub fn code(id: u64) -> String {
+    let base = CODE_LETTERS.len() as u64;
+    let mut id = id % code_count();
+    let mut letters = Vec::with_capacity(CODE_LENGTH);
+    for _ in 0..CODE_LENGTH {
+        letters.push(CODE_LETTERS[(id % base) as usize] as char);
+        id /= base;
+    }
+
+    letters.into_iter().rev().collect()
+}
+
+/// The tournament of a code typed in by the player, ignoring the case and the whitespace
+pub fn parse(code: &str) -> Option<u64> {
+    let letters = code
+        .chars()
+        .filter(|c| !c.is_whitespace())
+        .map(|c| c.to_ascii_uppercase())
+        .collect::<Vec<_>>();
+    if letters.len() != CODE_LENGTH {
+        return None;
+    }
+
+    letters.into_iter().try_fold(0, |id, letter| {
+        let digit = CODE_LETTERS.iter().position(|c| *c as char == letter)?;
+        Some(id * CODE_LETTERS.len() as u64 + digit as u64)
+    })
+}
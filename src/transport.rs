@@ -0,0 +1,167 @@
+use std::cell::RefCell;
+use std::marker::PhantomData;
+use std::rc::Rc;
+
+use serde::{de::DeserializeOwned, Serialize};
+use wasm_bindgen::{prelude::Closure, JsCast};
+use web_sys::{MessageEvent, WebSocket};
+use yew::Callback;
+
+#[cfg(feature = "api")]
+use crate::api::{self, ApiError, HttpRequest};
+
+/// Two way channel of typed messages to a backend. The received messages are passed to the
+/// callback given when the transport is created, and the connection is closed when dropped.
+pub trait Transport<M> {
+    fn send(&self, message: &M);
+}
+
+/// JSON messages over a WebSocket. Messages sent before the socket has opened are queued.
+pub struct WebSocketTransport<M> {
+    socket: WebSocket,
+    pending: Rc<RefCell<Vec<String>>>,
+    _onopen: Closure<dyn FnMut()>,
+    _onmessage: Closure<dyn FnMut(MessageEvent)>,
+    _onclose: Closure<dyn FnMut()>,
+    _message: PhantomData<M>,
+}
+
+impl<M: Serialize + DeserializeOwned + 'static> WebSocketTransport<M> {
+    /// `onclose` is called if the connection is lost, or can't be opened at all
+    pub fn connect(url: &str, onmessage: Callback<M>, onclose: Callback<()>) -> Option<Self> {
+        let socket = WebSocket::new(url).ok()?;
+        let pending = Rc::new(RefCell::new(Vec::<String>::new()));
+
+        let onopen = {
+            let socket = socket.clone();
+            let pending = pending.clone();
+            Closure::wrap(Box::new(move || {
+                for message in pending.borrow_mut().drain(..) {
+                    let _res = socket.send_with_str(&message);
+                }
+            }) as Box<dyn FnMut()>)
+        };
+
+        let onmessage = Closure::wrap(Box::new(move |e: MessageEvent| {
+            let message = e
+                .data()
+                .as_string()
+                .and_then(|data| serde_json::from_str(&data).ok());
+
+            match message {
+                Some(message) => onmessage.emit(message),
+                None => log::info!("Ignoring an unknown message"),
+            }
+        }) as Box<dyn FnMut(MessageEvent)>);
+
+        let onclose = Closure::wrap(Box::new(move || onclose.emit(())) as Box<dyn FnMut()>);
+
+        socket.set_onopen(Some(onopen.as_ref().unchecked_ref()));
+        socket.set_onmessage(Some(onmessage.as_ref().unchecked_ref()));
+        socket.set_onclose(Some(onclose.as_ref().unchecked_ref()));
+
+        Some(Self {
+            socket,
+            pending,
+            _onopen: onopen,
+            _onmessage: onmessage,
+            _onclose: onclose,
+            _message: PhantomData,
+        })
+    }
+}
+
+impl<M: Serialize> Transport<M> for WebSocketTransport<M> {
+    fn send(&self, message: &M) {
+        let message = match serde_json::to_string(message) {
+            Ok(message) => message,
+            Err(_) => return,
+        };
+
+        if self.socket.ready_state() == WebSocket::CONNECTING {
+            self.pending.borrow_mut().push(message);
+        } else {
+            let _res = self.socket.send_with_str(&message);
+        }
+    }
+}
+
+impl<M> Drop for WebSocketTransport<M> {
+    fn drop(&mut self) {
+        self.socket.set_onopen(None);
+        self.socket.set_onmessage(None);
+        self.socket.set_onclose(None);
+        let _res = self.socket.close();
+    }
+}
+
+type Responder<M> = Box<dyn Fn(&M) -> Vec<M>>;
+
+/// In-process stand-in for a backend. Every sent message is answered by `respond`, and the
+/// replies are received asynchronously like the ones of a real server would be.
+pub struct LoopbackTransport<M> {
+    respond: Responder<M>,
+    onmessage: Callback<M>,
+}
+
+impl<M> LoopbackTransport<M> {
+    pub fn new(respond: impl Fn(&M) -> Vec<M> + 'static, onmessage: Callback<M>) -> Self {
+        Self {
+            respond: Box::new(respond),
+            onmessage,
+        }
+    }
+}
+
+impl<M: 'static> Transport<M> for LoopbackTransport<M> {
+    fn send(&self, message: &M) {
+        let replies = (self.respond)(message);
+        let onmessage = self.onmessage.clone();
+
+        wasm_bindgen_futures::spawn_local(async move {
+            for reply in replies {
+                onmessage.emit(reply);
+            }
+        });
+    }
+}
+
+/// Messages of a backend answering HTTP requests instead of keeping a connection open. A sent
+/// message is one request, and its response is received as the reply to it.
+#[cfg(feature = "api")]
+pub trait HttpMessage: Sized {
+    /// None for the messages that are only received, or if the request can't be made at all
+    fn request(&self) -> Option<HttpRequest>;
+    fn reply(self, response: Result<String, ApiError>) -> Self;
+}
+
+/// JSON messages over HTTP, see `HttpMessage`. The requests in flight are still answered after the
+/// transport has been dropped.
+#[cfg(feature = "api")]
+pub struct HttpTransport<M> {
+    onmessage: Callback<M>,
+}
+
+#[cfg(feature = "api")]
+impl<M> HttpTransport<M> {
+    pub fn new(onmessage: Callback<M>) -> Self {
+        Self { onmessage }
+    }
+}
+
+#[cfg(feature = "api")]
+impl<M: HttpMessage + Clone + 'static> Transport<M> for HttpTransport<M> {
+    fn send(&self, message: &M) {
+        let request = match message.request() {
+            Some(request) => request,
+            None => return,
+        };
+        let message = message.clone();
+        let onmessage = self.onmessage.clone();
+
+        wasm_bindgen_futures::spawn_local(async move {
+            let response = api::fetch(&request).await;
+            onmessage.emit(message.reply(response));
+        });
+    }
+}
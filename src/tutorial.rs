@@ -0,0 +1,55 @@
+//! The practice puzzle of the first visit. The player types a scripted guess, reads its colors
+//! and then solves the hidden word, before the first real game.
+
+use crate::engine;
+use crate::i18n::Language;
+use crate::manager::TileState;
+use crate::storage;
+
+// false while the tutorial waits to be played, true once it's finished or skipped
+const COMPLETED_KEY: &str = "tutorial_completed";
+
+/// The practice guess and the hidden word of the puzzle language. The guess has letters of
+/// every color.
+pub fn words(language: Language) -> (&'static str, &'static str) {
+    match language {
+        Language::Finnish => ("TALVI", "MAITO"),
+        Language::Swedish => ("KLOSS", "SKOLA"),
+        Language::English => ("TRAIL", "HEART"),
+    }
+}
+
+/// The letters needed for both words, in alphabetical order
+pub fn letters(language: Language) -> Vec<char> {
+    let (guess, word) = words(language);
+    let mut letters = guess.chars().chain(word.chars()).collect::<Vec<_>>();
+    letters.sort_unstable();
+    letters.dedup();
+
+    letters
+}
+
+pub fn score(guess: &[char], word: &str) -> Vec<(char, TileState)> {
+    let word = word.chars().collect::<Vec<_>>();
+
+    guess
+        .iter()
+        .zip(engine::score(guess, &word))
+        .map(|(character, feedback)| (*character, feedback.into()))
+        .collect()
+}
+
+/// Whether the tutorial waits to be played. The players from before the tutorial have no flag
+/// at all and aren't shown it.
+pub fn is_pending() -> bool {
+    matches!(storage::get::<bool>(COMPLETED_KEY), Ok(false))
+}
+
+/// Leaves the tutorial waiting for the first visit, until it's completed
+pub fn start() {
+    let _result = storage::set(COMPLETED_KEY, false);
+}
+
+pub fn complete() {
+    let _result = storage::set(COMPLETED_KEY, true);
+}
@@ -0,0 +1,85 @@
+use wasm_bindgen::JsCast;
+use wasm_bindgen_futures::JsFuture;
+use web_sys::{window, Response};
+
+use crate::storage;
+
+// Deployed next to index.html, and compared against the version embedded in the build
+const VERSION_PATH: &str = "/version.txt";
+const BUILD_VERSION: &str = include_str!("../static/version.txt");
+
+pub fn current() -> &'static str {
+    BUILD_VERSION.trim()
+}
+
+/// Fetches the version of the deployed game, bypassing the caches
+pub async fn fetch_deployed() -> Option<String> {
+    let window = window()?;
+    let url = format!("{}?t={}", VERSION_PATH, js_sys::Date::now() as u64);
+
+    let response: Response = JsFuture::from(window.fetch_with_str(&url))
+        .await
+        .ok()?
+        .dyn_into()
+        .ok()?;
+    if !response.ok() {
+        return None;
+    }
+
+    let version = JsFuture::from(response.text().ok()?)
+        .await
+        .ok()?
+        .as_string()?;
+
+    Some(version.trim().to_owned()).filter(|version| !version.is_empty())
+}
+
+const CHANGELOG: &str = include_str!("../CHANGELOG.md");
+const LAST_SEEN_KEY: &str = "last_seen_version";
+
+/// A release of CHANGELOG.md, eg. "v1.14 - 2022-02-19"
+pub struct Release {
+    pub title: &'static str,
+    // Headings like "Added" with their items
+    pub sections: Vec<(&'static str, Vec<&'static str>)>,
+}
+
+/// The releases listed in the changelog shipped with the build, newest first
+pub fn releases() -> Vec<Release> {
+    let mut releases: Vec<Release> = Vec::new();
+
+    for line in CHANGELOG.lines().map(str::trim) {
+        if let Some(title) = line.strip_prefix("## ") {
+            releases.push(Release {
+                title,
+                sections: Vec::new(),
+            });
+        } else if let Some(heading) = line.strip_prefix("### ") {
+            if let Some(release) = releases.last_mut() {
+                release.sections.push((heading, Vec::new()));
+            }
+        } else if let Some(item) = line.strip_prefix("- ") {
+            if let Some((_, items)) = releases
+                .last_mut()
+                .and_then(|release| release.sections.last_mut())
+            {
+                items.push(item);
+            }
+        }
+    }
+
+    releases
+}
+
+/// Whether the game is opened for the first time with this browser and profile
+pub fn is_first_visit() -> bool {
+    storage::get::<String>(LAST_SEEN_KEY).is_err()
+}
+
+/// Whether the game was updated since it was last opened. The first visit doesn't count as one.
+pub fn take_is_updated() -> bool {
+    let last_seen: Option<String> = storage::get(LAST_SEEN_KEY).ok();
+    let _result = storage::set(LAST_SEEN_KEY, current());
+
+    matches!(last_seen, Some(version) if version != current())
+}
@@ -0,0 +1,175 @@
+//! The "Sanuli-vuosi" review of the daily words of a calendar year. It opens by itself once
+//! when the year ends, and any year with a daily word played can be opened from the menu.
+
+use std::collections::{BTreeMap, BTreeSet};
+
+use chrono::{Datelike, NaiveDate};
+
+use crate::brand::BRAND;
+use crate::i18n::tf;
+use crate::manager::{self, DailyWordHistory, Theme, TileState};
+use crate::share_image;
+
+// The review of the ended year opens by itself until this day of January
+const LAST_REVIEW_DAY: u32 = 14;
+const MONTHS: u32 = 12;
+const MAX_MONTH_DAYS: usize = 31;
+
+#[derive(Clone, PartialEq)]
+pub struct YearSummary {
+    pub year: i32,
+    pub played: usize,
+    pub won: usize,
+    pub longest_streak: usize,
+    // Guesses of the solved words on average
+    pub average_guesses: Option<f64>,
+    // The letter most often misplaced or missed
+    pub tricky_letter: Option<char>,
+    // Whether the daily word of each day was won, by the month, None for the unplayed days
+    pub days: Vec<Vec<Option<bool>>>,
+}
+
+/// The year whose review is due on the day: the year ending on its last day, and the year
+/// just ended in the first days of January
+pub fn due_year(today: NaiveDate) -> Option<i32> {
+    match (today.month(), today.day()) {
+        (12, 31) => Some(today.year()),
+        (1, day) if day <= LAST_REVIEW_DAY => Some(today.year() - 1),
+        _ => None,
+    }
+}
+
+pub fn summarize(
+    year: i32,
+    history: &BTreeMap<NaiveDate, DailyWordHistory>,
+    frozen_days: &BTreeSet<NaiveDate>,
+) -> YearSummary {
+    let first_day = NaiveDate::from_ymd(year, 1, 1);
+    let last_day = NaiveDate::from_ymd(year, 12, 31);
+    let games = history
+        .range(first_day..=last_day)
+        .map(|(_, game)| game)
+        .collect::<Vec<_>>();
+
+    let won = games.iter().filter(|game| game.is_winner).count();
+    let guesses = games
+        .iter()
+        .filter(|game| game.is_winner)
+        .map(|game| game.current_guess + 1)
+        .sum::<usize>();
+
+    // The guesses of the games past the retention are gone, see `retention::cutoff`
+    let tricky_letter =
+        manager::letter_accuracies(games.iter().filter(|game| !game.guesses.is_empty()).map(
+            |game| {
+                let guesses = game
+                    .guesses
+                    .iter()
+                    .take(game.current_guess + 1)
+                    .cloned()
+                    .collect();
+                (game.word.as_slice(), guesses, game.is_winner)
+            },
+        ))
+        .first()
+        .map(|accuracy| accuracy.letter);
+
+    // A frozen day keeps the streak going like in `Manager::daily_streak`
+    let mut longest_streak = 0;
+    let mut streak = 0;
+    for date in first_day.iter_days().take_while(|date| *date <= last_day) {
+        match history.get(&date) {
+            Some(game) if game.is_winner => {
+                streak += 1;
+                longest_streak = longest_streak.max(streak);
+            }
+            _ if frozen_days.contains(&date) => {}
+            _ => streak = 0,
+        }
+    }
+
+    let days = (1..=MONTHS)
+        .map(|month| {
+            (1..=MAX_MONTH_DAYS as u32)
+                .map_while(|day| NaiveDate::from_ymd_opt(year, month, day))
+                .map(|date| history.get(&date).map(|game| game.is_winner))
+                .collect()
+        })
+        .collect();
+
+    YearSummary {
+        year,
+        played: games.len(),
+        won,
+        longest_streak,
+        average_guesses: (won > 0).then(|| guesses as f64 / won as f64),
+        tricky_letter,
+        days,
+    }
+}
+
+impl YearSummary {
+    pub fn title(&self) -> String {
+        tf("{}-vuosi {}", &[&BRAND.name, &self.year])
+    }
+
+    /// Percentage of the played daily words won
+    pub fn win_rate(&self) -> Option<usize> {
+        (self.played > 0).then(|| self.won * 100 / self.played)
+    }
+
+    fn lines(&self) -> Vec<String> {
+        let mut lines = vec![tf("📅 Päivän sanoja pelattu: {}", &[&self.played])];
+        if let Some(win_rate) = self.win_rate() {
+            lines.push(tf("🏆 Voitettu: {} %", &[&win_rate]));
+        }
+        lines.push(tf("🔥 Pisin putki: {}", &[&self.longest_streak]));
+        if let Some(average_guesses) = self.average_guesses {
+            lines.push(tf(
+                "🎯 Arvauksia keskimäärin: {}",
+                &[&format!("{:.1}", average_guesses)],
+            ));
+        }
+        if let Some(letter) = self.tricky_letter {
+            lines.push(tf("🔤 Vaikein kirjain: {}", &[&letter]));
+        }
+        lines
+    }
+
+    pub fn share_text(&self) -> String {
+        let mut lines = vec![self.title()];
+        lines.extend(self.lines());
+        lines.push(BRAND.display_url().to_owned());
+        lines.join("\n")
+    }
+
+    /// The days of the year as tiles, a row per month, with the numbers below them
+    pub fn share_image(&self, theme: Theme) -> Option<String> {
+        let patterns = self
+            .days
+            .iter()
+            .map(|month| {
+                month
+                    .iter()
+                    .map(|day| match day {
+                        Some(true) => TileState::Correct,
+                        Some(false) => TileState::Absent,
+                        None => TileState::Unknown,
+                    })
+                    .collect()
+            })
+            .collect::<Vec<Vec<TileState>>>();
+
+        let mut footer = self.lines();
+        footer.push(BRAND.display_url().to_owned());
+
+        share_image::render(
+            &patterns,
+            MONTHS as usize,
+            MAX_MONTH_DAYS,
+            &self.title(),
+            &footer,
+            theme,
+        )
+    }
+}
@@ -0,0 +1,102 @@
+// Caches the game for offline play. The daily and common words are embedded in the wasm
+// module, and the full word lists fetched by the game are cached once loaded.
+const CACHE = "sanuli";
+
+self.addEventListener("install", (event) => {
+  event.waitUntil(
+    caches.open(CACHE).then((cache) => cache.add("/")).then(() => self.skipWaiting())
+  );
+});
+
+self.addEventListener("activate", (event) => {
+  event.waitUntil(self.clients.claim());
+});
+
+// Trunk names the built files by their hashes, the files no longer referenced
+// by the latest index.html are left over from the previous versions
+const pruneCache = async (cache, html) => {
+  const assets = new Set(
+    Array.from(html.matchAll(/(?:href=|src=|from\s*|init\()["']([^"']+)["']/g), (match) =>
+      new URL(match[1], self.location.origin).pathname
+    )
+  );
+
+  for (const request of await cache.keys()) {
+    const path = new URL(request.url).pathname;
+    if (path !== "/" && !path.endsWith("full-words.txt") && !assets.has(path)) {
+      await cache.delete(request);
+    }
+  }
+};
+
+// Pages from the network, so that new versions are picked up, and from the cache when offline
+const fetchPage = async (request) => {
+  const cache = await caches.open(CACHE);
+
+  try {
+    const response = await fetch(request);
+    if (response.ok) {
+      await cache.put("/", response.clone());
+      await pruneCache(cache, await response.clone().text());
+    }
+    return response;
+  } catch (error) {
+    const cached = await cache.match("/");
+    if (cached) {
+      return cached;
+    }
+    throw error;
+  }
+};
+
+// The full word lists aren't hashed, they are fetched from the network to pick up the updates
+const fetchWordList = async (request) => {
+  const cache = await caches.open(CACHE);
+
+  try {
+    const response = await fetch(request);
+    if (response.ok) {
+      await cache.put(request, response.clone());
+    }
+    return response;
+  } catch (error) {
+    const cached = await cache.match(request);
+    if (cached) {
+      return cached;
+    }
+    throw error;
+  }
+};
+
+// The hashed files never change, so they are served from the cache when available
+const fetchAsset = async (request) => {
+  const cache = await caches.open(CACHE);
+  const cached = await cache.match(request);
+  if (cached) {
+    return cached;
+  }
+
+  const response = await fetch(request);
+  if (response.ok) {
+    await cache.put(request, response.clone());
+  }
+  return response;
+};
+
+self.addEventListener("fetch", (event) => {
+  const request = event.request;
+  const url = new URL(request.url);
+
+  // The backend of the online features, and the version checks for updates, are never cached
+  if (request.method !== "GET" || url.origin !== self.location.origin || url.pathname === "/version.txt") {
+    return;
+  }
+
+  if (request.mode === "navigate") {
+    event.respondWith(fetchPage(request));
+  } else if (url.pathname.endsWith("full-words.txt")) {
+    event.respondWith(fetchWordList(request));
+  } else {
+    event.respondWith(fetchAsset(request));
+  }
+});